@@ -0,0 +1,81 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use embedded_multi_page_hmi::{
+    page::{BasicPage, MenuPage, TextPage},
+    PageInterface, PageManager, PageNavigation,
+};
+use std::hint::black_box;
+
+// h1. Benchmarks of the page navigation core
+//
+// Run with `cargo bench --bench navigation`
+
+struct NullDisplay;
+
+impl PageInterface<NullDisplay> for MenuPage<'_> {
+    fn display(&self, _display_driver: &mut NullDisplay) {}
+}
+
+impl PageInterface<NullDisplay> for TextPage {
+    fn display(&self, _display_driver: &mut NullDisplay) {}
+}
+
+/// Home page and ten menus on the top level with ten text pages below each menu
+fn page_tree() -> PageManager<'static, NullDisplay> {
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(NullDisplay, Box::new(home));
+    for menu in 0..10 {
+        m.dispatch(PageNavigation::Home).unwrap();
+        let title = format!("Menu {}", menu);
+        m.register(Box::new(MenuPage::new(BasicPage::new(title, None), None)));
+        for page in 0..10 {
+            let basic = BasicPage::new(format!("Page {}", page), None);
+            let text = Box::new(TextPage::new(basic, "Some text"));
+            if page == 0 {
+                m.register_sub(text);
+            } else {
+                m.register(text);
+            }
+        }
+    }
+    m.dispatch(PageNavigation::Home).unwrap();
+    m
+}
+
+fn navigation(c: &mut Criterion) {
+    let mut m = page_tree();
+    c.bench_function("dispatch left", |b| {
+        b.iter(|| m.dispatch(black_box(PageNavigation::Left)).unwrap())
+    });
+
+    let mut m = page_tree();
+    m.dispatch(PageNavigation::Left).unwrap();
+    c.bench_function("dispatch sub page and up", |b| {
+        b.iter(|| {
+            m.dispatch(black_box(PageNavigation::NthSubpage(5)))
+                .unwrap();
+            m.dispatch(black_box(PageNavigation::Up)).unwrap()
+        })
+    });
+
+    let mut m = page_tree();
+    c.bench_function("navigate path", |b| {
+        b.iter(|| m.navigate_path(black_box(&["Menu 0", "Page 0"])).unwrap())
+    });
+
+    let mut m = page_tree();
+    m.dispatch(PageNavigation::Left).unwrap();
+    c.bench_function("update menu with new titles", |b| {
+        b.iter(|| {
+            m.invalidate_titles();
+            m.dispatch(black_box(PageNavigation::Update)).unwrap()
+        })
+    });
+
+    let m = page_tree();
+    c.bench_function("tree iter", |b| {
+        b.iter(|| black_box(&m).tree_iter().count())
+    });
+}
+
+criterion_group!(benches, navigation);
+criterion_main!(benches);
@@ -1,6 +1,9 @@
 use chrono::{DateTime, Utc};
 use embedded_multi_page_hmi::{
-    page::{BasicPage, EnterStringPage, MenuPage, ShutdownPage, StartupPage, TextPage},
+    page::{
+        ActionPage, BasicPage, DynamicTextPage, EnterStringPage, MenuPage, ShutdownPage,
+        StartupPage, TextPage,
+    },
     CellSetting, Interaction, PageBaseInterface, PageInteractionInterface, PageInterface,
     PageLifetime, PageManager, PageNavigation, Setting,
 };
@@ -11,21 +14,21 @@ use std::{
 };
 use std::{thread, time};
 
-/// h1. Showcase all capabilities on host via pancurses
-///
-/// pancurses supports  Windows, Linux and OS X. (mac)
-///
-/// h2. Input is the keyboard
-///
-/// Keys are:
-/// * n - next page
-/// * p - previous page
-/// * h - home page or exit if on home page
-/// * b - go back, i.e. the page up the hierarchy
-/// * SPACE - action = go to the selected page
-///
-/// h2. Output is a terminal used as a fixed window
-///
+// h1. Showcase all capabilities on host via pancurses
+//
+// pancurses supports  Windows, Linux and OS X. (mac)
+//
+// h2. Input is the keyboard
+//
+// Keys are:
+// * n - next page
+// * p - previous page
+// * h - home page or exit if on home page
+// * b - go back, i.e. the page up the hierarchy
+// * SPACE - action = go to the selected page
+//
+// h2. Output is a terminal used as a fixed window
+//
 
 // ** Input implementation **
 
@@ -81,16 +84,18 @@ pub struct HomePage(pub TextPage);
 
 impl HomePage {
     pub fn new(home_message: &'static str) -> Self {
-        HomePage(TextPage {
-            basic: BasicPage::new("Home", None),
-            text: home_message,
-        })
+        HomePage(TextPage::new(BasicPage::new("Home", None), home_message))
     }
 }
 
 impl PageBaseInterface for HomePage {
     fn title(&self) -> &str {
-        self.0.basic.title
+        &self.0.basic.title
+    }
+
+    // the home page navigates to the main menu
+    fn is_menu(&self) -> bool {
+        true
     }
 }
 
@@ -112,7 +117,7 @@ struct TimePage(pub BasicPage);
 
 impl PageBaseInterface for TimePage {
     fn title(&self) -> &str {
-        self.0.title
+        &self.0.title
     }
 }
 
@@ -164,7 +169,8 @@ impl PageInterface<TerminalDisplay<'_>> for TimePage {
     }
 }
 
-impl<T: Display + FromStr + Copy> PageInterface<TerminalDisplay<'_>> for EnterStringPage<'_, T>
+impl<T: Display + FromStr + Copy + 'static> PageInterface<TerminalDisplay<'_>>
+    for EnterStringPage<'_, T>
 where
     <T as FromStr>::Err: Debug,
 {
@@ -179,6 +185,20 @@ where
     }
 }
 
+impl PageInterface<TerminalDisplay<'_>> for DynamicTextPage<'_> {
+    fn display(&self, display_driver: &mut TerminalDisplay) {
+        let output = format!("{}: {}", &self.basic.title, &self.text);
+        display_driver.update(&output);
+    }
+}
+
+impl PageInterface<TerminalDisplay<'_>> for ActionPage<'_> {
+    fn display(&self, display_driver: &mut TerminalDisplay) {
+        let output = format!("{}: executed", &self.basic.title);
+        display_driver.update(&output);
+    }
+}
+
 // ** Arbitrary functions **
 
 fn sleep_ms(millis: u64) {
@@ -188,7 +208,7 @@ fn sleep_ms(millis: u64) {
 
 fn main() {
     let config_value: CellSetting<f32> = Default::default();
-    config_value.set(3.14); // to have something different than 0.0
+    config_value.set(2.5); // to have something different than 0.0
     println!("Start with config value {}", config_value.get());
 
     // pancurses Initialization
@@ -244,6 +264,23 @@ fn main() {
     );
     m.register(Box::new(set_number));
 
+    // An action resetting the config value and returning to the menu
+    let reset = ActionPage::new(
+        BasicPage::new("Reset", None),
+        Box::new(|| {
+            config_value.set(0.0);
+            PageNavigation::Up
+        }),
+    );
+    m.register(Box::new(reset));
+
+    // Show the current config value
+    let show_value = DynamicTextPage::new(
+        BasicPage::new("Value", None),
+        Box::new(|| format!("Config value is {}", config_value.get())),
+    );
+    m.register(Box::new(show_value));
+
     // A submenu
     let sub_menu = MenuPage::new(BasicPage::new("Sub-Menu", None), None);
     m.register(Box::new(sub_menu));
@@ -264,10 +301,9 @@ fn main() {
             None => m.dispatch(navigation),
             Some(interaction) => m.dispatch_interaction(interaction),
         };
-        // in this example shutdown page returns PageError after it's lifetime is over
-        // this is used for a clean exit
+        // the shutdown page terminates the HMI after it's lifetime is over
         match result {
-            Err(_e) => break,
+            Ok(PageNavigation::Terminate) | Err(_) => break,
             Ok(nav) => navigation = nav,
         };
 
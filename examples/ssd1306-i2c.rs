@@ -0,0 +1,186 @@
+// h1. A complete HMI on a 128x64 SSD1306 OLED with three buttons
+//
+// The most common hobbyist hardware: the OLED on I2C at the address 0x3C and
+// three push buttons between GPIO pins and GND, read with pull-up resistors.
+//
+// h2. Wiring
+//
+// * OLED SDA, SCL - the I2C pins of the board
+// * button action - GPIO to GND
+// * button next - GPIO to GND
+// * button previous - GPIO to GND
+//
+// h2. Interaction
+//
+// * action - enter the selected page
+// * next, previous - select the next or previous entry or page
+// * holding previous - back
+// * holding next and previous - home
+//
+// The HMI is generic over the `embedded-hal` traits. `main()` runs it on the
+// host with stand-ins of the bus and the pins - replace them by the I2C bus
+// and the pins of your HAL, e.g. of `esp-hal` or `rp2040-hal`, and call
+// `tick()` every 10ms.
+//
+// Run on the host with `cargo run --example ssd1306-i2c --features ssd1306`.
+
+use embedded_hal::{
+    digital::{ErrorType as PinErrorType, InputPin},
+    i2c::{ErrorType as I2cErrorType, I2c, Operation},
+};
+use embedded_multi_page_hmi::{
+    page::{BasicPage, MenuPage, TextPage},
+    ssd1306::{init_i2c, Ssd1306Display},
+    ChordEvent, ChordInput, DisplaySize, Interaction, PageError, PageManager, PageNavigation,
+};
+use ssd1306::prelude::{DisplaySize128x64, I2CInterface};
+use std::cell::Cell;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+/// Number of ticks of 10ms to hold buttons down, e.g. for back
+const HOLD: u32 = 80;
+
+/// Number of ticks of 10ms between updates of the page shown
+const UPDATE: u32 = 50;
+
+// ** The HMI - independent of the board **
+
+struct Hmi<'a, I: I2c, P: InputPin> {
+    manager: PageManager<'a, Ssd1306Display<I2CInterface<I>, DisplaySize128x64>>,
+    buttons: [(P, Interaction); 3],
+    input: ChordInput,
+    navigation: PageNavigation,
+    ticks: u32,
+}
+
+impl<I: I2c, P: InputPin> Hmi<'_, I, P> {
+    fn new(i2c: I, buttons: [(P, Interaction); 3]) -> Result<Self, PageError> {
+        let display = init_i2c(i2c)?;
+        let home = MenuPage::new(BasicPage::new("Home", None), None);
+        let mut manager = PageManager::new(display, Box::new(home));
+        manager.register_sub(Box::new(TextPage::new(
+            BasicPage::new("About", None),
+            "embedded-multi-page-hmi\non an SSD1306 OLED",
+        )));
+        manager.register(Box::new(TextPage::new(
+            BasicPage::new("Status", None),
+            "All systems go",
+        )));
+        manager.set_display_info(&DisplaySize::from_info(manager.display()));
+
+        let input = ChordInput::new()
+            .with_long_press(
+                Interaction::Previous,
+                HOLD,
+                ChordEvent::Interaction(Interaction::Back),
+            )
+            .with_chord(
+                &[Interaction::Next, Interaction::Previous],
+                HOLD,
+                ChordEvent::Navigation(PageNavigation::Home),
+            );
+        let navigation = manager.dispatch(PageNavigation::SystemStart)?;
+        manager.display_mut().flush()?;
+        Ok(Hmi {
+            manager,
+            buttons,
+            input,
+            navigation,
+            ticks: 0,
+        })
+    }
+
+    /// Read the buttons and update the page shown - called every 10ms
+    fn tick(&mut self) -> Result<(), PageError> {
+        let mut pressed = Vec::new();
+        for (pin, interaction) in self.buttons.iter_mut() {
+            // pressed buttons pull the pin low
+            if pin.is_low().map_err(|_| PageError)? {
+                pressed.push(*interaction);
+            }
+        }
+        self.ticks += 1;
+        let navigation = match self.input.sample(&pressed) {
+            Some(event) => event.dispatch(&mut self.manager)?,
+            None if self.ticks.is_multiple_of(UPDATE) => self.manager.dispatch(self.navigation)?,
+            None => return Ok(()),
+        };
+        self.navigation = navigation;
+        self.manager.display_mut().flush()
+    }
+}
+
+// ** Host stand-ins - replace by the I2C bus and the pins of the HAL **
+
+/// I2C bus counting the bytes sent to the OLED
+struct Bus(Rc<Cell<usize>>);
+
+impl I2cErrorType for Bus {
+    type Error = Infallible;
+}
+
+impl I2c for Bus {
+    fn transaction(
+        &mut self,
+        _address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations {
+            if let Operation::Write(bytes) = operation {
+                self.0.set(self.0.get() + bytes.len());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Button pressed during the ticks given
+struct Button {
+    tick: Rc<Cell<u32>>,
+    pressed: &'static [(u32, u32)],
+}
+
+impl PinErrorType for Button {
+    type Error = Infallible;
+}
+
+impl InputPin for Button {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        self.is_low().map(|low| !low)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        let tick = self.tick.get();
+        Ok(self
+            .pressed
+            .iter()
+            .any(|(from, to)| (*from..*to).contains(&tick)))
+    }
+}
+
+fn main() -> Result<(), PageError> {
+    let sent = Rc::new(Cell::new(0));
+    let tick = Rc::new(Cell::new(0));
+    let button = |pressed| Button {
+        tick: Rc::clone(&tick),
+        pressed,
+    };
+    // enter "About", go back by holding previous, select and enter "Status"
+    let buttons = [
+        (button(&[(100, 105), (450, 455)]), Interaction::Action),
+        (button(&[(400, 405)]), Interaction::Next),
+        (button(&[(200, 300)]), Interaction::Previous),
+    ];
+    let mut hmi = Hmi::new(Bus(Rc::clone(&sent)), buttons)?;
+    while tick.get() < 500 {
+        tick.set(tick.get() + 1);
+        hmi.tick()?;
+    }
+    println!(
+        "{} shown, {} bytes sent to the OLED",
+        hmi.manager.active().title,
+        sent.get()
+    );
+    Ok(())
+}
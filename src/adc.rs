@@ -0,0 +1,112 @@
+use super::Interaction;
+
+/// Default number of equal samples for a button to be pressed or released
+const DEBOUNCE: u8 = 3;
+
+/// Input of buttons wired to one ADC pin by a resistor ladder
+///
+/// Every button pulls the ADC pin to a level of its own. The levels are given
+/// by a calibration table, e.g. measured by the `AdcCalibrationPage`. A sample
+/// belongs to the button with the nearest level within the tolerance; samples
+/// far from all levels, e.g. the idle level, mean no button is pressed.
+///
+/// Samples are debounced: a button is pressed or released only after a number
+/// of equal samples in a row. A press is one interaction, holding the button
+/// does not repeat it.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{AdcButtons, Interaction};
+///
+///     let mut buttons = AdcButtons::new(50)
+///         .with_button(0, Interaction::Action)
+///         .with_button(330, Interaction::Next)
+///         .with_debounce(2);
+///
+///     // e.g. sampled every 10ms
+///     let presses: Vec<_> = [1023, 340, 325, 330, 1023, 1023]
+///         .iter()
+///         .map(|sample| buttons.sample(*sample))
+///         .collect();
+///     assert_eq!(presses, [None, None, Some(Interaction::Next), None, None, None]);
+/// ```
+pub struct AdcButtons {
+    levels: Vec<(u16, Interaction)>,
+    tolerance: u16,
+    debounce: u8,
+    candidate: Option<Interaction>,
+    stable: u8,
+    pressed: Option<Interaction>,
+}
+
+impl AdcButtons {
+    /// Arguments
+    ///
+    /// * `tolerance`: - Maximum distance of a sample to the level of a button
+    pub fn new(tolerance: u16) -> Self {
+        AdcButtons {
+            levels: Vec::new(),
+            tolerance,
+            debounce: DEBOUNCE,
+            candidate: None,
+            stable: 0,
+            pressed: None,
+        }
+    }
+
+    /// Add a button of the calibration table
+    ///
+    /// Arguments
+    ///
+    /// * `level`: - The sample while the button is pressed
+    /// * `interaction`: - The interaction of the button
+    pub fn with_button(mut self, level: u16, interaction: Interaction) -> Self {
+        self.levels.push((level, interaction));
+        self
+    }
+
+    /// Set the number of equal samples for a button to be pressed or released
+    ///
+    /// Default is 3 samples.
+    pub fn with_debounce(mut self, samples: u8) -> Self {
+        self.debounce = samples.max(1);
+        self
+    }
+
+    /// The button a sample belongs to, without debouncing
+    pub fn classify(&self, sample: u16) -> Option<Interaction> {
+        self.levels
+            .iter()
+            .map(|(level, interaction)| (level.abs_diff(sample), *interaction))
+            .filter(|(distance, _)| *distance <= self.tolerance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, interaction)| interaction)
+    }
+
+    /// Handle a sample of the ADC
+    ///
+    /// Returns the interaction of a button as soon as it is pressed, otherwise `None`.
+    pub fn sample(&mut self, sample: u16) -> Option<Interaction> {
+        let button = self.classify(sample);
+        if button == self.candidate {
+            self.stable = self.stable.saturating_add(1);
+        } else {
+            self.candidate = button;
+            self.stable = 1;
+        }
+        if self.stable >= self.debounce && self.pressed != self.candidate {
+            self.pressed = self.candidate;
+            return self.pressed;
+        }
+        None
+    }
+
+    /// The button that is pressed
+    pub fn pressed(&self) -> Option<Interaction> {
+        self.pressed
+    }
+}
+
+#[cfg(test)]
+mod tests;
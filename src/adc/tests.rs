@@ -0,0 +1,69 @@
+use super::*;
+
+fn ladder() -> AdcButtons {
+    AdcButtons::new(40)
+        .with_button(0, Interaction::Action)
+        .with_button(150, Interaction::Next)
+        .with_button(330, Interaction::Previous)
+        .with_button(500, Interaction::Back)
+        .with_button(700, Interaction::Home)
+}
+
+#[test]
+fn classify_samples() {
+    let sut = ladder();
+    assert_eq!(sut.classify(10), Some(Interaction::Action));
+    assert_eq!(sut.classify(185), Some(Interaction::Next));
+    assert_eq!(sut.classify(300), Some(Interaction::Previous));
+    assert_eq!(sut.classify(230), None);
+    assert_eq!(sut.classify(1023), None);
+}
+
+#[test]
+fn classify_nearest_level() {
+    let sut = AdcButtons::new(100)
+        .with_button(100, Interaction::Next)
+        .with_button(200, Interaction::Previous);
+    assert_eq!(sut.classify(140), Some(Interaction::Next));
+    assert_eq!(sut.classify(160), Some(Interaction::Previous));
+}
+
+#[test]
+fn debounce_press_and_release() {
+    let mut sut = ladder();
+    let presses: Vec<_> = [
+        1023, 150, 1023, 150, 150, 150, 150, 1023, 150, 1023, 1023, 1023,
+    ]
+    .iter()
+    .map(|sample| sut.sample(*sample))
+    .collect();
+    assert_eq!(
+        presses,
+        [
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Interaction::Next),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None
+        ]
+    );
+    assert_eq!(sut.pressed(), None);
+}
+
+#[test]
+fn press_other_button_while_held() {
+    let mut sut = ladder().with_debounce(1);
+    assert_eq!(sut.sample(0), Some(Interaction::Action));
+    assert_eq!(sut.pressed(), Some(Interaction::Action));
+    assert_eq!(sut.sample(500), Some(Interaction::Back));
+    assert_eq!(sut.sample(500), None);
+    assert_eq!(sut.sample(1023), None);
+    assert_eq!(sut.sample(500), Some(Interaction::Back));
+}
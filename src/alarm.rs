@@ -0,0 +1,169 @@
+use super::DateTime;
+
+use std::borrow::Cow;
+
+/// When an alarm fires
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlarmTime {
+    /// Every day at the hour and minute, e.g. `Daily(7, 30)`
+    Daily(u8, u8),
+    /// Repeatedly after the seconds, counted from enabling the alarm
+    Interval(u32),
+}
+
+/// Identifier of an alarm added to the `AlarmScheduler`
+///
+/// Ids are assigned in the order alarms are added, starting with zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlarmId(usize);
+
+impl AlarmId {
+    /// The number of the alarm in the order it was added
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// An alarm of the `AlarmScheduler`
+pub struct Alarm {
+    title: Cow<'static, str>,
+    time: AlarmTime,
+    enabled: bool,
+    due: Option<i64>,
+}
+
+impl Alarm {
+    /// The name of the alarm, e.g. shown by the `AlarmListPage`
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn time(&self) -> AlarmTime {
+        self.time
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// The next time the alarm fires - the time of day counts if not passed
+    fn next(&self, now: i64) -> i64 {
+        match self.time {
+            AlarmTime::Daily(hour, minute) => {
+                let day = now.div_euclid(86400) * 86400;
+                let at = day + hour as i64 * 3600 + minute as i64 * 60;
+                match at >= now {
+                    true => at,
+                    false => at + 86400,
+                }
+            }
+            AlarmTime::Interval(seconds) => now + seconds.max(1) as i64,
+        }
+    }
+}
+
+/// Alarms of the application firing at a time of day or repeatedly
+///
+/// The scheduler is polled with the local time, e.g. by the `PageManager`
+/// with every update, see `PageManager::set_alarms()`. Alarms missed, e.g.
+/// while the device was off, do not fire.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{AlarmScheduler, AlarmTime, DateTime};
+///
+///     let mut alarms = AlarmScheduler::new();
+///     let wake_up = alarms.add("Wake up", AlarmTime::Daily(7, 30));
+///     assert_eq!(alarms.poll(DateTime::new(2024, 5, 1, 7, 0, 0)), None);
+///     assert_eq!(alarms.poll(DateTime::new(2024, 5, 1, 7, 30, 0)), Some(wake_up));
+///     assert_eq!(alarms.poll(DateTime::new(2024, 5, 1, 7, 30, 1)), None);
+/// ```
+#[derive(Default)]
+pub struct AlarmScheduler {
+    alarms: Vec<Alarm>,
+}
+
+impl AlarmScheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an enabled alarm
+    ///
+    /// Arguments
+    ///
+    /// * `title`: - The name of the alarm
+    /// * `time`: - When the alarm fires
+    ///
+    /// Returns
+    ///
+    /// * The id of the alarm added.
+    pub fn add(&mut self, title: impl Into<Cow<'static, str>>, time: AlarmTime) -> AlarmId {
+        self.alarms.push(Alarm {
+            title: title.into(),
+            time,
+            enabled: true,
+            due: None,
+        });
+        AlarmId(self.alarms.len() - 1)
+    }
+
+    /// All alarms in the order they were added
+    pub fn alarms(&self) -> &[Alarm] {
+        &self.alarms
+    }
+
+    /// The ids of all alarms in the order they were added
+    pub fn ids(&self) -> impl Iterator<Item = AlarmId> {
+        (0..self.alarms.len()).map(AlarmId)
+    }
+
+    pub fn get(&self, id: AlarmId) -> Option<&Alarm> {
+        self.alarms.get(id.0)
+    }
+
+    /// Enable or disable an alarm - interval alarms start over when enabled
+    pub fn set_enabled(&mut self, id: AlarmId, enabled: bool) {
+        if let Some(alarm) = self.alarms.get_mut(id.0) {
+            if alarm.enabled != enabled {
+                alarm.enabled = enabled;
+                alarm.due = None;
+            }
+        }
+    }
+
+    /// Fire the alarm that is due
+    ///
+    /// Alarms due at the same time fire with consecutive polls.
+    ///
+    /// Arguments
+    ///
+    /// * `now`: - The local time
+    ///
+    /// Returns the id of the alarm fired, otherwise `None`.
+    pub fn poll(&mut self, now: DateTime) -> Option<AlarmId> {
+        let now = now.timestamp();
+        for alarm in self.alarms.iter_mut().filter(|alarm| alarm.enabled) {
+            if alarm.due.is_none() {
+                alarm.due = Some(alarm.next(now));
+            }
+        }
+        let (index, alarm) = self
+            .alarms
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, alarm)| alarm.enabled)
+            .find(|(_, alarm)| alarm.due.is_some_and(|due| due <= now))?;
+        // a daily alarm fires at its time only once a day
+        alarm.due = match alarm.time {
+            AlarmTime::Daily(..) => Some(alarm.next(now + 1)),
+            AlarmTime::Interval(_) => Some(alarm.next(now)),
+        };
+        Some(AlarmId(index))
+    }
+}
+
+#[cfg(test)]
+mod tests;
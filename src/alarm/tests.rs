@@ -0,0 +1,64 @@
+use super::*;
+
+fn at(hour: u8, minute: u8, second: u8) -> DateTime {
+    DateTime::new(2024, 5, 1, hour, minute, second)
+}
+
+#[test]
+fn daily_alarm_fires_once_a_day() {
+    let mut sut = AlarmScheduler::new();
+    let id = sut.add("Wake up", AlarmTime::Daily(7, 30));
+    assert_eq!(sut.get(id).unwrap().title(), "Wake up");
+    assert_eq!(sut.poll(at(7, 29, 59)), None);
+    assert_eq!(sut.poll(at(7, 30, 5)), Some(id));
+    assert_eq!(sut.poll(at(7, 30, 30)), None);
+    assert_eq!(sut.poll(at(23, 59, 59)), None);
+    assert_eq!(sut.poll(DateTime::new(2024, 5, 2, 7, 30, 0)), Some(id));
+}
+
+#[test]
+fn daily_alarm_passed_fires_tomorrow() {
+    let mut sut = AlarmScheduler::new();
+    let id = sut.add("Wake up", AlarmTime::Daily(7, 30));
+    assert_eq!(sut.poll(at(8, 0, 0)), None);
+    assert_eq!(sut.poll(DateTime::new(2024, 5, 2, 7, 29, 0)), None);
+    assert_eq!(sut.poll(DateTime::new(2024, 5, 2, 7, 31, 0)), Some(id));
+}
+
+#[test]
+fn interval_alarm_repeats() {
+    let mut sut = AlarmScheduler::new();
+    let id = sut.add("Stir", AlarmTime::Interval(60));
+    assert_eq!(sut.poll(at(10, 0, 0)), None);
+    assert_eq!(sut.poll(at(10, 0, 59)), None);
+    assert_eq!(sut.poll(at(10, 1, 0)), Some(id));
+    assert_eq!(sut.poll(at(10, 1, 59)), None);
+    assert_eq!(sut.poll(at(10, 2, 0)), Some(id));
+}
+
+#[test]
+fn disabled_alarm_does_not_fire() {
+    let mut sut = AlarmScheduler::new();
+    let id = sut.add("Stir", AlarmTime::Interval(60));
+    sut.poll(at(10, 0, 0));
+    sut.set_enabled(id, false);
+    assert!(!sut.alarms()[0].is_enabled());
+    assert_eq!(sut.poll(at(10, 1, 0)), None);
+
+    // starts over when enabled
+    sut.set_enabled(id, true);
+    assert_eq!(sut.poll(at(10, 1, 30)), None);
+    assert_eq!(sut.poll(at(10, 2, 0)), None);
+    assert_eq!(sut.poll(at(10, 2, 30)), Some(id));
+}
+
+#[test]
+fn alarms_due_together_fire_one_by_one() {
+    let mut sut = AlarmScheduler::new();
+    let first = sut.add("First", AlarmTime::Daily(12, 0));
+    let second = sut.add("Second", AlarmTime::Daily(12, 0));
+    sut.poll(at(11, 0, 0));
+    assert_eq!(sut.poll(at(12, 0, 0)), Some(first));
+    assert_eq!(sut.poll(at(12, 0, 1)), Some(second));
+    assert_eq!(sut.poll(at(12, 0, 2)), None);
+}
@@ -0,0 +1,196 @@
+use super::{Interaction, PageError, PageId, PageManager, PageNavigation};
+
+/// The input of buttons or a button combination, or the result of a global shortcut
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChordEvent {
+    /// Dispatch an interaction to the active page
+    Interaction(Interaction),
+    /// Dispatch a navigation to the page manager
+    Navigation(PageNavigation),
+    /// Navigate to the page of the path of titles, e.g. a hidden service menu
+    Path(&'static [&'static str]),
+    /// Navigate to the page of the id, e.g. a hidden calibration page
+    Page(PageId),
+}
+
+impl ChordEvent {
+    /// Dispatch the event
+    ///
+    /// Arguments
+    ///
+    /// * `manager`: - The page manager to dispatch the event to
+    pub fn dispatch<D, C>(
+        self,
+        manager: &mut PageManager<'_, D, C>,
+    ) -> Result<PageNavigation, PageError> {
+        match self {
+            ChordEvent::Interaction(interaction) => manager.dispatch_interaction(interaction),
+            ChordEvent::Navigation(navigation) => manager.dispatch(navigation),
+            ChordEvent::Path(path) => {
+                manager.navigate_path(path)?;
+                Ok(PageNavigation::Update)
+            }
+            ChordEvent::Page(id) => {
+                manager.navigate_to(id)?;
+                Ok(PageNavigation::Update)
+            }
+        }
+    }
+}
+
+/// A button combination held down for some time
+struct Chord {
+    buttons: u8,
+    hold: u32,
+    event: ChordEvent,
+}
+
+/// Input of buttons detecting button combinations
+///
+/// Buttons are named by their interactions. Holding a combination of buttons
+/// down for some time triggers the event of the combination, e.g. holding
+/// next and previous for 3s enters a service menu hidden from the menu tree.
+///
+/// Buttons that are not part of any combination trigger their interaction
+/// when pressed. Buttons that are part of a combination trigger it when
+/// released, and only if no other button was pressed meanwhile, i.e. entering
+/// a combination does not trigger the interactions of its buttons.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{ChordEvent, ChordInput, Interaction};
+///
+///     let mut input = ChordInput::new().with_chord(
+///         &[Interaction::Next, Interaction::Previous],
+///         2,
+///         ChordEvent::Path(&["Service"]),
+///     );
+///     // e.g. the buttons read every 10ms
+///     let both = [Interaction::Next, Interaction::Previous];
+///     assert_eq!(input.sample(&both[..1]), None);
+///     assert_eq!(input.sample(&both), None);
+///     assert_eq!(input.sample(&both), Some(ChordEvent::Path(&["Service"])));
+///     assert_eq!(input.sample(&[]), None);
+/// ```
+#[derive(Default)]
+pub struct ChordInput {
+    chords: Vec<Chord>,
+    pressed: u8,
+    ticks: u32,
+    combined: bool,
+}
+
+fn button(interaction: Interaction) -> u8 {
+    match interaction {
+        Interaction::Action => 1,
+        Interaction::Next => 2,
+        Interaction::Previous => 4,
+        Interaction::Back => 8,
+        Interaction::Home => 16,
+    }
+}
+
+fn buttons(interactions: &[Interaction]) -> u8 {
+    interactions.iter().fold(0, |mask, i| mask | button(*i))
+}
+
+fn interaction(button: u8) -> Option<Interaction> {
+    [
+        Interaction::Action,
+        Interaction::Next,
+        Interaction::Previous,
+        Interaction::Back,
+        Interaction::Home,
+    ]
+    .iter()
+    .copied()
+    .find(|interaction| self::button(*interaction) == button)
+}
+
+impl ChordInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a button combination
+    ///
+    /// Arguments
+    ///
+    /// * `buttons`: - The buttons to hold down at a time
+    /// * `hold`: - Number of samples to hold the buttons down
+    /// * `event`: - The event triggered
+    pub fn with_chord(mut self, buttons: &[Interaction], hold: u32, event: ChordEvent) -> Self {
+        self.chords.push(Chord {
+            buttons: self::buttons(buttons),
+            hold: hold.max(1),
+            event,
+        });
+        self
+    }
+
+    /// Add a long press of a single button, e.g. holding home to power off
+    ///
+    /// A short press of the button triggers its interaction on release.
+    ///
+    /// Arguments
+    ///
+    /// * `button`: - The button to hold down
+    /// * `hold`: - Number of samples to hold the button down
+    /// * `event`: - The event triggered
+    pub fn with_long_press(self, button: Interaction, hold: u32, event: ChordEvent) -> Self {
+        self.with_chord(&[button], hold, event)
+    }
+
+    /// Handle the buttons read
+    ///
+    /// Arguments
+    ///
+    /// * `pressed`: - The buttons pressed right now
+    ///
+    /// Returns the event of a button or combination, otherwise `None`.
+    pub fn sample(&mut self, pressed: &[Interaction]) -> Option<ChordEvent> {
+        let previous = self.pressed;
+        self.pressed = buttons(pressed);
+        let in_chords = self.chords.iter().fold(0, |mask, c| mask | c.buttons);
+
+        if self.pressed != previous {
+            self.ticks = 0;
+        }
+        if self.pressed.count_ones() > 1 {
+            self.combined = true;
+        }
+
+        // single buttons that are part of a combination on release
+        if self.pressed == 0 {
+            let combined = std::mem::replace(&mut self.combined, false);
+            return match (combined, previous & in_chords) {
+                (false, released) if released != 0 => {
+                    interaction(released).map(ChordEvent::Interaction)
+                }
+                _ => None,
+            };
+        }
+        // single buttons that are not part of a combination on press
+        let newly = self.pressed & !previous & !in_chords;
+        if newly != 0 {
+            let lowest = newly & newly.wrapping_neg();
+            return interaction(lowest).map(ChordEvent::Interaction);
+        }
+
+        self.ticks = self.ticks.saturating_add(1);
+        let event = self
+            .chords
+            .iter()
+            .find(|c| c.buttons == self.pressed && c.hold == self.ticks)
+            .map(|c| c.event);
+        // a long press does not trigger the interaction of the button on release
+        if event.is_some() {
+            self.combined = true;
+        }
+        event
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,160 @@
+use super::*;
+use crate::page::{BasicPage, MenuPage, PowerOffPage, ShutdownPage, TextPage};
+use crate::testing::FrameBufferDisplay;
+use Interaction::{Action, Back, Next, Previous};
+
+fn service_input() -> ChordInput {
+    ChordInput::new()
+        .with_chord(&[Next, Previous], 3, ChordEvent::Path(&["Service"]))
+        .with_chord(
+            &[Action, Back],
+            1,
+            ChordEvent::Navigation(PageNavigation::Home),
+        )
+}
+
+fn samples(input: &mut ChordInput, pressed: &[&[Interaction]]) -> Vec<Option<ChordEvent>> {
+    pressed.iter().map(|p| input.sample(p)).collect()
+}
+
+#[test]
+fn single_buttons_without_chords() {
+    let mut sut = ChordInput::new();
+    assert_eq!(
+        samples(&mut sut, &[&[Next], &[Next], &[], &[Action, Back]]),
+        vec![
+            Some(ChordEvent::Interaction(Next)),
+            None,
+            None,
+            Some(ChordEvent::Interaction(Action))
+        ]
+    );
+}
+
+#[test]
+fn chord_buttons_trigger_on_release() {
+    let mut sut = service_input();
+    assert_eq!(
+        samples(&mut sut, &[&[Next], &[Next], &[], &[Back], &[]]),
+        vec![
+            None,
+            None,
+            Some(ChordEvent::Interaction(Next)),
+            None,
+            Some(ChordEvent::Interaction(Back))
+        ]
+    );
+}
+
+#[test]
+fn hold_chord() {
+    let mut sut = service_input();
+    let events = samples(
+        &mut sut,
+        &[
+            &[Next],
+            &[Next, Previous],
+            &[Next, Previous],
+            &[Next, Previous],
+            &[Next, Previous],
+            &[Previous],
+            &[],
+        ],
+    );
+    assert_eq!(
+        events,
+        vec![
+            None,
+            None,
+            None,
+            Some(ChordEvent::Path(&["Service"])),
+            None,
+            None,
+            None
+        ]
+    );
+    // too short
+    let events = samples(&mut sut, &[&[Next, Previous], &[Next, Previous], &[]]);
+    assert!(events.iter().all(Option::is_none));
+}
+
+#[test]
+fn enter_hidden_service_menu() {
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(FrameBufferDisplay::new(16, 2), Box::new(home));
+    m.register_sub(Box::new(TextPage::new(BasicPage::new("Info", None), "1.0")));
+    let service = m.register(Box::new(TextPage::new(
+        BasicPage::new("Service", None),
+        "Calibrate",
+    )));
+    m.dispatch(PageNavigation::SystemStart).unwrap();
+
+    let mut input = service_input();
+    let both: &[Interaction] = &[Next, Previous];
+    let event = samples(&mut input, &[both; 3])[2].unwrap();
+    event.dispatch(&mut m).unwrap();
+    assert_eq!(m.active().id, service);
+    let event = input.sample(&[Action, Back]).unwrap();
+    event.dispatch(&mut m).unwrap();
+    assert_eq!(m.active().title, "Home");
+}
+
+#[test]
+fn long_press_of_single_button() {
+    let mut sut = ChordInput::new().with_long_press(
+        Back,
+        2,
+        ChordEvent::Navigation(PageNavigation::SystemStop),
+    );
+    // short press
+    assert_eq!(
+        samples(&mut sut, &[&[Back], &[]]),
+        vec![None, Some(ChordEvent::Interaction(Back))]
+    );
+    // long press
+    assert_eq!(
+        samples(&mut sut, &[&[Back], &[Back], &[Back], &[]]),
+        vec![
+            None,
+            Some(ChordEvent::Navigation(PageNavigation::SystemStop)),
+            None,
+            None
+        ]
+    );
+}
+
+#[test]
+fn power_off_by_long_press() {
+    let home = TextPage::new(BasicPage::new("Home", None), "Hello");
+    let mut m = PageManager::new(FrameBufferDisplay::new(16, 2), Box::new(home));
+    m.register_sub(Box::new(PowerOffPage::new(BasicPage::new(
+        "Power off",
+        None,
+    ))));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.register(Box::new(TextPage::new(BasicPage::new("Info", None), "1.0")));
+    m.register_shutdown(Box::new(ShutdownPage::new("Bye", 10).with_cancel(Back)));
+    m.dispatch(PageNavigation::SystemStart).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "Info");
+
+    let mut input =
+        ChordInput::new().with_long_press(Interaction::Home, 2, ChordEvent::Path(&["Power off"]));
+    let home: &[Interaction] = &[Interaction::Home];
+    let event = samples(&mut input, &[home; 2])[1].unwrap();
+    event.dispatch(&mut m).unwrap();
+    assert_eq!(m.active().title, "Power off");
+    assert_eq!(input.sample(&[]), None);
+
+    m.dispatch_interaction(Next).unwrap();
+    assert_eq!(
+        m.dispatch_interaction(Action).unwrap(),
+        PageNavigation::SystemStop
+    );
+    // changed mind while shutting down
+    assert_eq!(
+        m.dispatch_interaction(Back).unwrap(),
+        PageNavigation::Update
+    );
+    assert_eq!(m.active().title, "Power off");
+}
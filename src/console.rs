@@ -0,0 +1,152 @@
+use arrayvec::ArrayString;
+use core::cell::RefCell;
+use core::fmt;
+use critical_section::Mutex;
+
+/// A fixed size ring buffer of the latest log lines
+///
+/// Keeps the latest `LINES` lines of at most `COLS` bytes each; older lines
+/// are overwritten, longer lines are truncated. Text is written by
+/// `core::fmt::Write`, e.g. by `writeln!`, and shown by the `LogPage`.
+///
+/// Like the `InteractionQueue`, the buffer is guarded by a critical section
+/// and can live in a `static`, i.e. it can be written from anywhere. With the
+/// `log` feature it is a logger of the `log` crate.
+///
+/// # Example
+///
+/// ```
+///     use core::fmt::Write;
+///     use embedded_multi_page_hmi::LogBuffer;
+///     static LOG: LogBuffer<4, 20> = LogBuffer::new();
+///
+///     writeln!(&LOG, "pump started").unwrap();
+///     writeln!(&LOG, "pressure {} bar", 3).unwrap();
+///     assert_eq!(LOG.lines(), vec!["pump started", "pressure 3 bar"]);
+/// ```
+pub struct LogBuffer<const LINES: usize, const COLS: usize> {
+    ring: Mutex<RefCell<Ring<LINES, COLS>>>,
+}
+
+struct Ring<const LINES: usize, const COLS: usize> {
+    lines: [ArrayString<COLS>; LINES],
+    head: usize,
+    len: usize,
+    line_done: bool,
+}
+
+impl<const LINES: usize, const COLS: usize> Ring<LINES, COLS> {
+    /// Start a new line - overwrite the oldest line if the buffer is full
+    fn start_line(&mut self) {
+        if self.len < LINES {
+            self.len += 1;
+        } else {
+            self.head = (self.head + 1) % LINES;
+        }
+        self.lines[(self.head + self.len - 1) % LINES].clear();
+        self.line_done = false;
+    }
+
+    fn push(&mut self, c: char) {
+        if LINES == 0 {
+            return;
+        }
+        if c == '\n' {
+            if self.len == 0 || self.line_done {
+                self.start_line();
+            }
+            self.line_done = true;
+            return;
+        }
+        if self.len == 0 || self.line_done {
+            self.start_line();
+        }
+        let last = (self.head + self.len - 1) % LINES;
+        // a line too long is truncated
+        let _ = self.lines[last].try_push(c);
+    }
+}
+
+impl<const LINES: usize, const COLS: usize> Default for LogBuffer<LINES, COLS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const LINES: usize, const COLS: usize> LogBuffer<LINES, COLS> {
+    pub const fn new() -> Self {
+        LogBuffer {
+            ring: Mutex::new(RefCell::new(Ring {
+                lines: [ArrayString::new_const(); LINES],
+                head: 0,
+                len: 0,
+                line_done: false,
+            })),
+        }
+    }
+
+    /// All lines from the oldest to the latest
+    pub fn lines(&self) -> Vec<String> {
+        critical_section::with(|cs| {
+            let ring = self.ring.borrow_ref(cs);
+            (0..ring.len)
+                .map(|i| String::from(ring.lines[(ring.head + i) % LINES].as_str()))
+                .collect()
+        })
+    }
+
+    /// Number of lines
+    pub fn len(&self) -> usize {
+        critical_section::with(|cs| self.ring.borrow_ref(cs).len)
+    }
+
+    /// There are no lines
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all lines
+    pub fn clear(&self) {
+        critical_section::with(|cs| {
+            let mut ring = self.ring.borrow_ref_mut(cs);
+            ring.head = 0;
+            ring.len = 0;
+            ring.line_done = false;
+        })
+    }
+}
+
+impl<const LINES: usize, const COLS: usize> fmt::Write for &LogBuffer<LINES, COLS> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        critical_section::with(|cs| {
+            let mut ring = self.ring.borrow_ref_mut(cs);
+            s.chars().for_each(|c| ring.push(c));
+        });
+        Ok(())
+    }
+}
+
+/// Log records as lines `<level> <message>`, e.g. `W low battery`
+#[cfg(feature = "log")]
+impl<const LINES: usize, const COLS: usize> log::Log for LogBuffer<LINES, COLS> {
+    fn enabled(&self, _metadata: &log::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        use fmt::Write;
+        let level = match record.level() {
+            log::Level::Error => 'E',
+            log::Level::Warn => 'W',
+            log::Level::Info => 'I',
+            log::Level::Debug => 'D',
+            log::Level::Trace => 'T',
+        };
+        let _ = writeln!(&*self, "{} {}", level, record.args());
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod tests;
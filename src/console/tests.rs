@@ -0,0 +1,40 @@
+use super::*;
+use core::fmt::Write;
+
+#[test]
+fn keep_latest_lines() {
+    let log: LogBuffer<3, 8> = LogBuffer::new();
+    assert!(log.is_empty());
+    for i in 1..=5 {
+        writeln!(&log, "line {}", i).unwrap();
+    }
+    assert_eq!(log.lines(), vec!["line 3", "line 4", "line 5"]);
+    assert_eq!(log.len(), 3);
+    log.clear();
+    assert!(log.lines().is_empty());
+}
+
+#[test]
+fn compose_and_truncate_lines() {
+    let log: LogBuffer<3, 8> = LogBuffer::new();
+    write!(&log, "temp").unwrap();
+    write!(&log, " {} °C", 21).unwrap();
+    writeln!(&log).unwrap();
+    writeln!(&log).unwrap();
+    write!(&log, "a line too long").unwrap();
+    assert_eq!(log.lines(), vec!["temp 21 ", "", "a line t"]);
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn log_records() {
+    use log::Log;
+    let log: LogBuffer<3, 20> = LogBuffer::new();
+    log.log(
+        &log::Record::builder()
+            .level(log::Level::Warn)
+            .args(format_args!("low battery"))
+            .build(),
+    );
+    assert_eq!(log.lines(), vec!["W low battery"]);
+}
@@ -0,0 +1,378 @@
+use std::borrow::Cow;
+use std::fmt;
+
+/// Marks text that is cut off to fit the display
+pub const ELLIPSIS: &str = "...";
+
+/// Capabilities of a display pages can adapt their content to
+///
+/// Character displays report their size in characters. Graphical displays
+/// report the number of characters fitting the display with the font used.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{DisplayInfo, DisplaySize};
+///     pub struct Lcd1602;
+///
+///     impl DisplayInfo for Lcd1602 {
+///         fn cols(&self) -> usize {
+///             16
+///         }
+///
+///         fn rows(&self) -> usize {
+///             2
+///         }
+///     }
+///
+///     assert_eq!(DisplaySize::from_info(&Lcd1602), DisplaySize::new(16, 2));
+/// ```
+pub trait DisplayInfo {
+    /// Number of characters per row
+    fn cols(&self) -> usize;
+
+    /// Number of rows
+    fn rows(&self) -> usize;
+}
+
+/// The size of a display in characters
+///
+/// The default is an unlimited single line, i.e. content is never wrapped or
+/// truncated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplaySize {
+    pub cols: usize,
+    pub rows: usize,
+}
+
+impl DisplaySize {
+    pub fn new(cols: usize, rows: usize) -> Self {
+        DisplaySize { cols, rows }
+    }
+
+    /// Take over the size of any display
+    pub fn from_info(info: &dyn DisplayInfo) -> Self {
+        DisplaySize::new(info.cols(), info.rows())
+    }
+
+    /// Number of characters fitting the display
+    pub fn chars(&self) -> usize {
+        self.cols.saturating_mul(self.rows)
+    }
+}
+
+impl Default for DisplaySize {
+    fn default() -> Self {
+        DisplaySize::new(usize::MAX, 1)
+    }
+}
+
+impl DisplayInfo for DisplaySize {
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn rows(&self) -> usize {
+        self.rows
+    }
+}
+
+/// Characters not taking a column on their own, i.e. combining marks
+fn is_combining(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+/// Number of display columns a text takes
+///
+/// Every character takes one column, except combining marks (e.g. the
+/// decomposed umlaut dots) which are shown on top of the previous character.
+pub fn width(text: &str) -> usize {
+    text.chars().filter(|c| !is_combining(*c)).count()
+}
+
+/// Byte offset of the text behind `cols` columns or the length of the text
+///
+/// Never splits a character or the combining marks belonging to it.
+fn column_offset(text: &str, cols: usize) -> usize {
+    let mut columns = 0;
+    for (offset, c) in text.char_indices() {
+        if !is_combining(c) {
+            if columns == cols {
+                return offset;
+            }
+            columns += 1;
+        }
+    }
+    text.len()
+}
+
+/// The character at column `index` including its combining marks
+///
+/// An empty str if the text is shorter.
+pub fn char_at(text: &str, index: usize) -> &str {
+    let start = column_offset(text, index);
+    let text = &text[start..];
+    &text[..column_offset(text, 1)]
+}
+
+/// Truncate a text to at most `cols` columns
+///
+/// Truncated text ends with an ellipsis.
+pub fn truncate(text: &str, cols: usize) -> Cow<'_, str> {
+    if width(text) <= cols {
+        return Cow::Borrowed(text);
+    }
+    if cols <= ELLIPSIS.len() {
+        return Cow::Borrowed(&ELLIPSIS[..cols]);
+    }
+    let end = column_offset(text, cols - ELLIPSIS.len());
+    Cow::Owned(format!("{}{}", &text[..end], ELLIPSIS))
+}
+
+/// Fill up a text with spaces to `cols` columns - left aligned
+///
+/// A longer text is truncated.
+pub fn pad(text: &str, cols: usize) -> Cow<'_, str> {
+    let text = truncate(text, cols);
+    match width(&text) {
+        w if w == cols => text,
+        w => Cow::Owned(format!("{}{:2$}", text, "", cols - w)),
+    }
+}
+
+/// Center a text within `cols` columns by filling up with spaces on both sides
+///
+/// A longer text is truncated.
+pub fn center(text: &str, cols: usize) -> Cow<'_, str> {
+    let text = truncate(text, cols);
+    match width(&text) {
+        w if w == cols => text,
+        w => {
+            let left = (cols - w) / 2;
+            Cow::Owned(format!("{:3$}{}{:4$}", "", text, "", left, cols - w - left))
+        }
+    }
+}
+
+/// Wrap a text into lines of at most `cols` columns
+///
+/// Lines are broken at spaces. Words longer than a line are broken
+/// within the word.
+pub fn wrap(text: &str, cols: usize) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut rest = text.trim_start();
+    let cols = cols.max(1);
+    while !rest.is_empty() {
+        let end = column_offset(rest, cols);
+        if end == rest.len() {
+            lines.push(rest);
+            break;
+        }
+        // break at the last space fitting the line, if there is any
+        let end = match rest[..column_offset(rest, cols.saturating_add(1))].rfind(' ') {
+            Some(space) if space > 0 => space,
+            _ => end,
+        };
+        lines.push(rest[..end].trim_end());
+        rest = rest[end..].trim_start();
+    }
+    lines
+}
+
+/// Horizontal scrolling of a text longer than the display width
+///
+/// The text pauses at the start, scrolls by `speed` characters with every
+/// update until its end is visible, pauses at the end and starts over.
+/// Pages call `advance()` on update and show the `window()` on display.
+/// A text fitting the display does not scroll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marquee {
+    speed: usize,
+    pause: u16,
+    offset: usize,
+    wait: u16,
+}
+
+impl Marquee {
+    /// Arguments
+    ///
+    /// * `speed`: - Characters to scroll per update
+    /// * `pause`: - Updates to wait at the start and the end of the text
+    pub fn new(speed: usize, pause: u16) -> Self {
+        Marquee {
+            speed: speed.max(1),
+            pause,
+            offset: 0,
+            wait: pause,
+        }
+    }
+
+    /// Start over at the beginning of the text, e.g. if the text changed
+    pub fn reset(&mut self) {
+        self.offset = 0;
+        self.wait = self.pause;
+    }
+
+    /// Scroll the text one update further
+    pub fn advance(&mut self, text: &str, cols: usize) {
+        let len = width(text);
+        if len <= cols {
+            self.reset();
+            return;
+        }
+        let end = len - cols;
+        if self.wait > 0 {
+            self.wait -= 1;
+        } else if self.offset >= end {
+            self.reset();
+        } else {
+            self.offset = end.min(self.offset + self.speed);
+            if self.offset == end {
+                self.wait = self.pause;
+            }
+        }
+    }
+
+    /// The part of the text visible on a display with `cols` columns
+    pub fn window<'b>(&self, text: &'b str, cols: usize) -> &'b str {
+        let start = column_offset(text, self.offset);
+        let text = &text[start..];
+        &text[..column_offset(text, cols)]
+    }
+}
+
+impl Default for Marquee {
+    /// Scroll one character per update and pause three updates at the ends
+    fn default() -> Self {
+        Marquee::new(1, 3)
+    }
+}
+
+/// The part of the content shown by a page with more content than fits the display
+///
+/// Pages scrolling or paginating their content, e.g. the lines of a text or
+/// the entries of a menu, report their position by
+/// `PageBaseInterface::scroll_position()`. Renderers indicate it so users know
+/// there is more content: character displays by the page, e.g. `2/5`,
+/// graphical displays by a scrollbar.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::ScrollPosition;
+///
+///     // lines 4 to 6 of 10 lines are shown
+///     let position = ScrollPosition::new(3, 3, 10);
+///     assert_eq!(position.to_string(), "2/4");
+///     assert_eq!(position.dots(), ".o..");
+///     assert_eq!(position.scrollbar(5), "|#|||");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollPosition {
+    /// Index of the first item shown
+    pub first: usize,
+    /// Number of items fitting the display at once
+    pub shown: usize,
+    /// Number of all items
+    pub total: usize,
+}
+
+impl ScrollPosition {
+    /// Arguments
+    ///
+    /// * `first`: - Index of the first item shown, e.g. of a line or a menu entry
+    /// * `shown`: - Number of items fitting the display at once
+    /// * `total`: - Number of all items
+    pub fn new(first: usize, shown: usize, total: usize) -> Self {
+        ScrollPosition {
+            first,
+            shown,
+            total,
+        }
+    }
+
+    /// Not all items are shown
+    pub fn is_scrollable(&self) -> bool {
+        self.shown < self.total
+    }
+
+    /// The page shown and the number of pages - the last items are the last page
+    pub fn page(&self) -> (usize, usize) {
+        let shown = self.shown.max(1);
+        let pages = self.total.div_ceil(shown).max(1);
+        match self.first + shown >= self.total {
+            true => (pages, pages),
+            false => ((self.first / shown + 1).min(pages), pages),
+        }
+    }
+
+    /// A dot per page, the page shown marked, e.g. `.o..`
+    pub fn dots(&self) -> String {
+        let (page, pages) = self.page();
+        (1..=pages)
+            .map(|dot| if dot == page { 'o' } else { '.' })
+            .collect()
+    }
+
+    /// The thumb of a scrollbar
+    ///
+    /// Arguments
+    ///
+    /// * `length`: - The length of the scrollbar, e.g. in pixels or rows
+    ///
+    /// Returns
+    ///
+    /// * The offset and the length of the thumb within the scrollbar
+    pub fn thumb(&self, length: usize) -> (usize, usize) {
+        if !self.is_scrollable() {
+            return (0, length);
+        }
+        let thumb = (length * self.shown / self.total).clamp(1.min(length), length);
+        let scrolled = self.first.min(self.total - self.shown);
+        let offset = (length - thumb) * scrolled / (self.total - self.shown);
+        (offset, thumb)
+    }
+
+    /// A vertical scrollbar of characters, `#` for the thumb, `|` for the track
+    ///
+    /// Arguments
+    ///
+    /// * `length`: - The number of characters, e.g. the rows of the display
+    pub fn scrollbar(&self, length: usize) -> String {
+        let (offset, thumb) = self.thumb(length);
+        (0..length)
+            .map(|cell| match cell >= offset && cell < offset + thumb {
+                true => '#',
+                false => '|',
+            })
+            .collect()
+    }
+}
+
+/// The page shown and the number of pages, e.g. `2/5`
+impl fmt::Display for ScrollPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (page, pages) = self.page();
+        write!(f, "{}/{}", page, pages)
+    }
+}
+
+/// The title followed by the page shown if there is more content, e.g. `Info 2/5`
+///
+/// Used by character displays to indicate the scroll position.
+pub fn paginated_title(title: &str, position: Option<ScrollPosition>) -> Cow<'_, str> {
+    match position {
+        Some(position) if position.is_scrollable() => Cow::Owned(format!("{} {}", title, position)),
+        _ => Cow::Borrowed(title),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,103 @@
+use super::*;
+
+#[test]
+fn truncate_with_ellipsis() {
+    assert_eq!(truncate("Settings", 8), "Settings");
+    assert_eq!(truncate("Settings", 7), "Sett...");
+    assert_eq!(truncate("Settings", 2), "..");
+    assert_eq!(truncate("Settings", usize::MAX), "Settings");
+}
+
+#[test]
+fn wrap_at_spaces() {
+    assert_eq!(
+        wrap("The quick brown fox jumps", 10),
+        vec!["The quick", "brown fox", "jumps"]
+    );
+    assert_eq!(wrap("Temperature: 21", 5), vec!["Tempe", "ratur", "e: 21"]);
+    assert_eq!(wrap("short", usize::MAX), vec!["short"]);
+    assert!(wrap("", 10).is_empty());
+}
+
+#[test]
+fn marquee_scrolls_and_pauses_at_the_ends() {
+    let text = "Temperature";
+    let mut sut = Marquee::new(3, 1);
+    let mut windows = Vec::new();
+    for _ in 0..8 {
+        windows.push(sut.window(text, 6));
+        sut.advance(text, 6);
+    }
+    assert_eq!(
+        windows,
+        vec!["Temper", "Temper", "peratu", "rature", "rature", "Temper", "Temper", "peratu"]
+    );
+}
+
+#[test]
+fn marquee_keeps_short_text() {
+    let mut sut = Marquee::default();
+    sut.advance("Info", 6);
+    sut.advance("Info", 6);
+    assert_eq!(sut.window("Info", 6), "Info");
+}
+
+#[test]
+fn width_of_non_ascii_text() {
+    assert_eq!(width("Grüße"), 5);
+    assert_eq!(width("Gru\u{308}ße"), 5);
+    assert_eq!(width("21°C"), 4);
+    assert_eq!(char_at("Gru\u{308}ße", 2), "u\u{308}");
+    assert_eq!(char_at("21°C", 2), "°");
+    assert_eq!(char_at("21°C", 4), "");
+}
+
+#[test]
+fn truncate_never_splits_characters() {
+    assert_eq!(truncate("Übersicht", 6), "Übe...");
+    assert_eq!(truncate("Gru\u{308}ße aus Köln", 7), "Gru\u{308}ß...");
+    assert_eq!(wrap("Grüße aus Köln", 6), vec!["Grüße", "aus", "Köln"]);
+}
+
+#[test]
+fn pad_and_center() {
+    assert_eq!(pad("Köln", 6), "Köln  ");
+    assert_eq!(pad("Übersicht", 6), "Übe...");
+    assert_eq!(center("Köln", 7), " Köln  ");
+    assert_eq!(center("Köln", 4), "Köln");
+}
+
+#[test]
+fn scroll_position_pages() {
+    assert_eq!(ScrollPosition::new(0, 2, 5).page(), (1, 3));
+    assert_eq!(ScrollPosition::new(2, 2, 5).page(), (2, 3));
+    // the last items are the last page even if not aligned to pages
+    assert_eq!(ScrollPosition::new(3, 2, 5).page(), (3, 3));
+    assert_eq!(ScrollPosition::new(0, 4, 4).page(), (1, 1));
+    assert_eq!(ScrollPosition::new(0, 0, 0).page(), (1, 1));
+    assert_eq!(ScrollPosition::new(4, 2, 5).dots(), "..o");
+}
+
+#[test]
+fn scroll_position_thumb() {
+    assert_eq!(ScrollPosition::new(0, 2, 8).thumb(8), (0, 2));
+    assert_eq!(ScrollPosition::new(6, 2, 8).thumb(8), (6, 2));
+    assert_eq!(ScrollPosition::new(3, 2, 8).thumb(8), (3, 2));
+    // the thumb is at least one cell
+    assert_eq!(ScrollPosition::new(99, 1, 100).thumb(4), (3, 1));
+    assert_eq!(ScrollPosition::new(0, 3, 3).thumb(4), (0, 4));
+    assert_eq!(ScrollPosition::new(0, 1, 3).scrollbar(3), "#||");
+}
+
+#[test]
+fn paginated_title_if_scrollable() {
+    assert_eq!(
+        paginated_title("Info", Some(ScrollPosition::new(2, 2, 5))),
+        "Info 2/3"
+    );
+    assert_eq!(
+        paginated_title("Info", Some(ScrollPosition::new(0, 5, 5))),
+        "Info"
+    );
+    assert_eq!(paginated_title("Info", None), "Info");
+}
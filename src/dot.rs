@@ -0,0 +1,69 @@
+use super::*;
+use std::fmt::Write;
+
+impl<'a, D, C> PageManager<'a, D, C> {
+    /// Export the page tree in Graphviz DOT format
+    ///
+    /// Every page is a node labeled with its title. Edges are the navigation
+    /// paths between pages:
+    ///
+    /// * solid edges lead from a page to its sub pages, labeled with the index
+    ///   used by `PageNavigation::NthSubpage`,
+    /// * dotted edges lead from a page to its hidden sub pages and to the sub
+    ///   pages above the access level, reached by id or path only,
+    /// * dashed edges lead from a page to the next page on the same level
+    ///   reached by `PageNavigation::Left`.
+    ///
+    /// Sub pages are numbered like by the navigation at the current access level.
+    ///
+    /// Render it on the host e.g. by `dot -Tsvg pages.dot > pages.svg`.
+    pub fn to_dot(&self) -> String {
+        let pages: Vec<PageTreeItem> = self.tree_iter().collect();
+        let mut dot = String::from("digraph pages {\n");
+        for page in pages.iter() {
+            writeln!(
+                dot,
+                "    p{} [label=\"{}\"];",
+                page.id.index(),
+                page.title.replace('\\', "\\\\").replace('"', "\\\"")
+            )
+            .unwrap();
+        }
+        for (index, page) in pages.iter().enumerate() {
+            let below = pages[index + 1..]
+                .iter()
+                .take_while(|p| p.depth > page.depth)
+                .filter(|p| p.depth == page.depth + 1);
+            let mut nth = 0;
+            for subpage in below {
+                let (from, to) = (page.id.index(), subpage.id.index());
+                match subpage.is_listed(self.access_level()) {
+                    true => {
+                        nth += 1;
+                        writeln!(dot, "    p{} -> p{} [label=\"{}\"];", from, to, nth)
+                    }
+                    false => writeln!(dot, "    p{} -> p{} [style=dotted];", from, to),
+                }
+                .unwrap();
+            }
+            let next = pages[index + 1..]
+                .iter()
+                .take_while(|p| p.depth >= page.depth)
+                .find(|p| p.depth == page.depth && p.is_listed(self.access_level()));
+            if let Some(next) = next {
+                writeln!(
+                    dot,
+                    "    p{} -> p{} [style=dashed];",
+                    page.id.index(),
+                    next.id.index()
+                )
+                .unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests;
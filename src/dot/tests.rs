@@ -0,0 +1,70 @@
+use super::*;
+use crate::page::{BasicPage, MenuPage, TextPage};
+
+struct DisplayStub;
+
+impl PageInterface<DisplayStub> for TextPage {
+    fn display(&self, _display_driver: &mut DisplayStub) {}
+}
+
+impl PageInterface<DisplayStub> for MenuPage<'_> {
+    fn display(&self, _display_driver: &mut DisplayStub) {}
+}
+
+#[test]
+fn export_page_tree() {
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayStub, Box::new(home));
+    m.register(Box::new(TextPage::new(BasicPage::new("Info", None), "")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.register_sub(Box::new(TextPage::new(
+        BasicPage::new("Config-1", None),
+        "",
+    )));
+    m.register(Box::new(TextPage::new(
+        BasicPage::new("\"Config-2\"", None),
+        "",
+    )));
+
+    assert_eq!(
+        m.to_dot(),
+        r#"digraph pages {
+    p0 [label="Home"];
+    p2 [label="Config-1"];
+    p3 [label="\"Config-2\""];
+    p1 [label="Info"];
+    p0 -> p2 [label="1"];
+    p0 -> p3 [label="2"];
+    p0 -> p1 [style=dashed];
+    p2 -> p3 [style=dashed];
+}
+"#
+    );
+}
+
+#[test]
+fn number_listed_subpages_only() {
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayStub, Box::new(home));
+    let service = m.register_sub(Box::new(TextPage::new(BasicPage::new("Service", None), "")));
+    let debug = m.register(Box::new(TextPage::new(BasicPage::new("Debug", None), "")));
+    m.register(Box::new(TextPage::new(BasicPage::new("Info", None), "")));
+    m.set_page_access(service, AccessLevel::Service);
+    m.set_page_hidden(debug, true);
+
+    assert_eq!(
+        m.to_dot(),
+        r#"digraph pages {
+    p0 [label="Home"];
+    p1 [label="Service"];
+    p2 [label="Debug"];
+    p3 [label="Info"];
+    p0 -> p1 [style=dotted];
+    p0 -> p2 [style=dotted];
+    p0 -> p3 [label="1"];
+    p1 -> p3 [style=dashed];
+    p2 -> p3 [style=dashed];
+}
+"#
+    );
+}
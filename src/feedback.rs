@@ -0,0 +1,136 @@
+/// Feedback to the user besides the display
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Feedback {
+    /// A button was pressed
+    KeyClick,
+    /// A setting was committed or a command executed
+    Confirm,
+    /// Something went wrong, e.g. an error page is shown
+    Error,
+    /// The user needs to take care, e.g. an alarm page is shown
+    Alarm,
+}
+
+/// Audible or haptic feedback, e.g. by a buzzer or a vibration motor
+///
+/// Is invoked by the `PageManager` on interactions, on commits and errors
+/// reported by pages and if dispatching fails. All feedback is ignored by
+/// default, i.e. a device implements the feedback it is capable of.
+///
+/// # Example
+///
+/// ```ignore
+/// struct Buzzer(Pwm);
+///
+/// impl FeedbackSink for Buzzer {
+///     fn key_click(&mut self) {
+///         self.0.beep(4000, 5); // artificial code
+///     }
+/// }
+///
+/// m.set_feedback(Box::new(Buzzer(pwm)));
+/// ```
+pub trait FeedbackSink {
+    /// A button was pressed
+    fn key_click(&mut self) {}
+
+    /// A setting was committed or a command executed
+    fn confirm(&mut self) {}
+
+    /// Something went wrong
+    fn error(&mut self) {}
+
+    /// The user needs to take care
+    fn alarm(&mut self) {}
+
+    /// Give a feedback
+    fn give(&mut self, feedback: Feedback) {
+        match feedback {
+            Feedback::KeyClick => self.key_click(),
+            Feedback::Confirm => self.confirm(),
+            Feedback::Error => self.error(),
+            Feedback::Alarm => self.alarm(),
+        }
+    }
+}
+
+/// The state of the HMI as shown by front panel LEDs
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Indication {
+    /// The startup page is shown
+    pub startup: bool,
+    /// The shutdown page is shown
+    pub shutdown: bool,
+    /// The active page is a setting page or below one
+    pub settings: bool,
+    /// The active page shows an alarm
+    pub alarm: bool,
+}
+
+/// Front panel LEDs mirroring the state of the HMI
+///
+/// Is invoked by the `PageManager` whenever the indication changes, i.e.
+/// the application does not need to track the active page to drive LEDs.
+///
+/// # Example
+///
+/// ```ignore
+/// struct Leds(LedPin, LedPin);
+///
+/// impl IndicatorSink for Leds {
+///     fn indicate(&mut self, indication: Indication) {
+///         self.0.set_state(indication.settings.into()).ok(); // artificial code
+///         self.1.set_state(indication.alarm.into()).ok();
+///     }
+/// }
+///
+/// m.set_indicator(Box::new(Leds(yellow, red)));
+/// ```
+pub trait IndicatorSink {
+    /// Show the state of the HMI
+    fn indicate(&mut self, indication: Indication);
+}
+
+/// What is announced to the user by an `AudioRenderer`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Announcement<'b> {
+    /// Another page is shown - its title
+    Page(&'b str),
+    /// Another item of the page is selected, counting from one
+    ///
+    /// The text is the title of the sub page selected in menus - none for
+    /// other pages, e.g. lists, and for the back entry of menus.
+    Selection { index: usize, text: Option<&'b str> },
+}
+
+/// Spoken or audible output of the HMI, e.g. a text-to-speech engine
+///
+/// Is invoked by the `PageManager` after dispatching whenever another page
+/// is shown or another item of the page is selected. Products are made
+/// accessible to visually impaired users without changing the pages: the
+/// announcements are spoken, beeped as patterns or shown by a Braille
+/// display instead of or in addition to the display.
+///
+/// Closures taking the announcement are audio renderers.
+///
+/// # Example
+///
+/// ```ignore
+/// m.set_audio(Box::new(|announcement: Announcement<'_>| match announcement {
+///     Announcement::Page(title) => tts.say(title), // artificial code
+///     Announcement::Selection { text: Some(text), .. } => tts.say(text),
+///     Announcement::Selection { index, .. } => buzzer.beep_times(index),
+/// }));
+/// ```
+pub trait AudioRenderer {
+    /// Arguments
+    ///
+    /// * `announcement`: - The page shown or the item selected
+    fn announce(&mut self, announcement: Announcement<'_>);
+}
+
+impl<F: FnMut(Announcement<'_>)> AudioRenderer for F {
+    fn announce(&mut self, announcement: Announcement<'_>) {
+        self(announcement)
+    }
+}
@@ -0,0 +1,574 @@
+//! Pages rendered to graphical displays
+//!
+//! Requires the `graphics` feature. The `GraphicsDisplay` renders all pages
+//! of this crate to any `embedded-graphics` draw target, e.g. a buffered
+//! SSD1306 OLED. How the pages look is defined by a `Theme`: the fonts, the
+//! colors and how the selected entry of a menu is highlighted.
+//!
+//! A display can switch between a day and a night theme at runtime, e.g.
+//! triggered by an ambient light sensor; the page shown is redrawn at once.
+//! Custom pages render to the display by drawing to `target_mut()` styled by
+//! `theme()`.
+//!
+//! Page changes slide or fade in over a few frames if the page manager
+//! animates them, see `PageManager::set_transition_frames()`. E-paper
+//! displays are flushed as `refresh()` says to avoid ghosting.
+//!
+//! # Example
+//!
+//! ```
+//!     use embedded_graphics::mock_display::MockDisplay;
+//!     use embedded_graphics::pixelcolor::BinaryColor;
+//!     use embedded_graphics::prelude::*;
+//!     use embedded_multi_page_hmi::graphics::{GraphicsDisplay, Theme};
+//!     use embedded_multi_page_hmi::page::{BasicPage, TextPage};
+//!     use embedded_multi_page_hmi::{PageManager, PageNavigation};
+//!
+//!     let mut oled = MockDisplay::new();
+//!     oled.set_allow_overdraw(true);
+//!     let display = GraphicsDisplay::new(oled, Theme::default())
+//!         .with_night_theme(Theme::default().inverted());
+//!
+//!     let home = TextPage::new(BasicPage::new("Home", None), "Hello");
+//!     let mut m = PageManager::new(display, Box::new(home));
+//!     m.dispatch(PageNavigation::Update).unwrap();
+//!     m.display_mut().set_night_mode(true);
+//!     assert_eq!(m.display().target().get_pixel(Point::new(63, 63)), Some(BinaryColor::On));
+//! ```
+
+use super::{DisplayInfo, Refresh, RefreshDisplay, ScrollPosition, Transition, TransitionDisplay};
+
+use embedded_graphics::{
+    mono_font::{ascii::FONT_6X10, MonoFont, MonoTextStyle, MonoTextStyleBuilder},
+    pixelcolor::{BinaryColor, PixelColor, Rgb565, WebColors},
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{Baseline, Text},
+};
+
+mod line_diff;
+
+#[allow(unused_imports)]
+pub use line_diff::LineDiffBuffer;
+
+/// Width of the scrollbar in pixels
+const SCROLLBAR_WIDTH: u32 = 2;
+
+/// How the selected entry of a menu is highlighted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionStyle {
+    /// The entry is drawn in the background color on the highlight color
+    #[default]
+    Inverted,
+    /// The entry is enclosed by brackets, e.g. `[ Settings ]`
+    Marker,
+}
+
+/// How a page change is animated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransitionStyle {
+    /// The new page pushes the page shown out in the direction navigated to
+    #[default]
+    Slide,
+    /// The new page is dithered in over the page shown
+    Fade,
+}
+
+/// The look of the pages on a graphical display
+#[derive(Debug, Clone, Copy)]
+pub struct Theme<P: PixelColor> {
+    /// The font of the content
+    pub font: &'static MonoFont<'static>,
+    /// The font of the title row
+    pub title_font: &'static MonoFont<'static>,
+    /// The color of the content
+    pub foreground: P,
+    /// The color the display is cleared with
+    pub background: P,
+    /// The color of the title bar and the scrollbar
+    pub accent: P,
+    /// The color the selected entry of a menu is highlighted with
+    pub highlight: P,
+    /// The color of the bar of gauges
+    pub gauge: P,
+    /// The color of the bar of gauges if the value is out of range
+    pub alert: P,
+    pub selection: SelectionStyle,
+}
+
+impl<P: PixelColor> Theme<P> {
+    /// A theme with the 6x10 font and the selected entry inverted
+    ///
+    /// Arguments
+    ///
+    /// * `foreground`: - The color of the content
+    /// * `background`: - The color the display is cleared with
+    /// * `accent`: - The color of the title bar, the selection and the gauges
+    pub fn new(foreground: P, background: P, accent: P) -> Self {
+        Theme {
+            font: &FONT_6X10,
+            title_font: &FONT_6X10,
+            foreground,
+            background,
+            accent,
+            highlight: accent,
+            gauge: accent,
+            alert: accent,
+            selection: SelectionStyle::default(),
+        }
+    }
+
+    /// Arguments
+    ///
+    /// * `font`: - The font of the content
+    /// * `title_font`: - The font of the title row
+    pub fn with_fonts(
+        mut self,
+        font: &'static MonoFont<'static>,
+        title_font: &'static MonoFont<'static>,
+    ) -> Self {
+        self.font = font;
+        self.title_font = title_font;
+        self
+    }
+
+    pub fn with_selection(mut self, selection: SelectionStyle) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Highlight the selected entry in another color than the accent
+    pub fn with_highlight(mut self, highlight: P) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Arguments
+    ///
+    /// * `gauge`: - The color of the bar of gauges
+    /// * `alert`: - The color of the bar if the value is out of range
+    pub fn with_gauge_colors(mut self, gauge: P, alert: P) -> Self {
+        self.gauge = gauge;
+        self.alert = alert;
+        self
+    }
+
+    /// The theme with foreground and background swapped, e.g. the night
+    /// theme of a monochrome display
+    ///
+    /// The accent colors become the new foreground so the title bar stays visible.
+    pub fn inverted(mut self) -> Self {
+        core::mem::swap(&mut self.foreground, &mut self.background);
+        self.accent = self.foreground;
+        self.highlight = self.foreground;
+        self.gauge = self.foreground;
+        self.alert = self.foreground;
+        self
+    }
+
+    /// Height of the title bar in pixels
+    fn title_height(&self) -> u32 {
+        self.title_font.character_size.height
+    }
+}
+
+/// Lit pixels on a dark display
+impl Default for Theme<BinaryColor> {
+    fn default() -> Self {
+        Theme::new(BinaryColor::On, BinaryColor::Off, BinaryColor::On)
+    }
+}
+
+/// Themes of color TFTs, e.g. ST7735 or ST7789 displays driven by `mipidsi`
+impl Theme<Rgb565> {
+    /// White text on black, a blue title bar, the selection in yellow, gauges
+    /// in green - red if out of range
+    pub fn dark() -> Self {
+        Theme::new(Rgb565::WHITE, Rgb565::BLACK, Rgb565::CSS_DODGER_BLUE)
+            .with_highlight(Rgb565::YELLOW)
+            .with_gauge_colors(Rgb565::GREEN, Rgb565::RED)
+    }
+
+    /// Black text on white, a navy title bar, the selection in royal blue,
+    /// gauges in green - red if out of range
+    pub fn light() -> Self {
+        Theme::new(Rgb565::BLACK, Rgb565::WHITE, Rgb565::CSS_NAVY)
+            .with_highlight(Rgb565::CSS_ROYAL_BLUE)
+            .with_gauge_colors(Rgb565::CSS_FOREST_GREEN, Rgb565::CSS_CRIMSON)
+    }
+}
+
+/// An `embedded-graphics` draw target as display of the pages
+///
+/// The title is shown in a bar of the accent color, the content below - a
+/// row per line. Every page is a full redraw. Draw errors of the target are
+/// ignored, the next page redraws everything anyway. A buffered target is
+/// flushed by the application after a dispatch, e.g.
+/// `m.display_mut().target_mut().flush()`.
+pub struct GraphicsDisplay<T: DrawTarget> {
+    target: T,
+    day: Theme<T::Color>,
+    night: Option<Theme<T::Color>>,
+    night_mode: bool,
+    shown: Option<(String, String)>,
+    transition_style: TransitionStyle,
+    transition: Option<(Transition, u8)>,
+    from: Option<(String, String)>,
+    refresh: Refresh,
+    position: Option<ScrollPosition>,
+}
+
+impl<T: DrawTarget> GraphicsDisplay<T> {
+    /// Arguments
+    ///
+    /// * `target`: - The display driver to draw to
+    /// * `theme`: - The theme, also at night if there is no night theme
+    pub fn new(target: T, theme: Theme<T::Color>) -> Self {
+        GraphicsDisplay {
+            target,
+            day: theme,
+            night: None,
+            night_mode: false,
+            shown: None,
+            transition_style: TransitionStyle::default(),
+            transition: None,
+            from: None,
+            refresh: Refresh::default(),
+            position: None,
+        }
+    }
+
+    /// Arguments
+    ///
+    /// * `theme`: - The theme used in night mode
+    pub fn with_night_theme(mut self, theme: Theme<T::Color>) -> Self {
+        self.night = Some(theme);
+        self
+    }
+
+    /// Arguments
+    ///
+    /// * `style`: - How the page changes are animated, see `PageManager::set_transition_frames()`
+    pub fn with_transition_style(mut self, style: TransitionStyle) -> Self {
+        self.transition_style = style;
+        self
+    }
+
+    /// The theme in use
+    pub fn theme(&self) -> &Theme<T::Color> {
+        match (self.night_mode, &self.night) {
+            (true, Some(night)) => night,
+            _ => &self.day,
+        }
+    }
+
+    /// Replace the theme in use and redraw the page shown
+    ///
+    /// Arguments
+    ///
+    /// * `theme`: - The new day theme, or night theme in night mode
+    pub fn set_theme(&mut self, theme: Theme<T::Color>) {
+        match (self.night_mode, &mut self.night) {
+            (true, Some(night)) => *night = theme,
+            _ => self.day = theme,
+        }
+        self.redraw();
+    }
+
+    /// Switch between day and night theme
+    ///
+    /// The page shown is redrawn if the theme changes.
+    ///
+    /// Arguments
+    ///
+    /// * `on`: - Use the night theme
+    pub fn set_night_mode(&mut self, on: bool) {
+        if self.night_mode != on {
+            self.night_mode = on;
+            self.redraw();
+        }
+    }
+
+    pub fn is_night_mode(&self) -> bool {
+        self.night_mode
+    }
+
+    /// The display driver
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// The display driver - mutable, e.g. to flush a buffered display
+    pub fn target_mut(&mut self) -> &mut T {
+        &mut self.target
+    }
+
+    /// Show a page
+    ///
+    /// A selected menu entry enclosed by `[ ` and ` ]` is highlighted as the
+    /// theme says.
+    ///
+    /// Arguments
+    ///
+    /// * `title`: - The title shown in the title bar
+    /// * `message`: - The content; lines are separated by `\n`
+    pub fn update(&mut self, title: &str, message: &str) {
+        self.update_scrolled(title, message, None);
+    }
+
+    /// Show a page scrolling its content with a scrollbar at the right edge
+    ///
+    /// Arguments
+    ///
+    /// * `title`: - The title shown in the title bar
+    /// * `message`: - The content; lines are separated by `\n`
+    /// * `position`: - The part of the content shown
+    pub fn update_scrolled(
+        &mut self,
+        title: &str,
+        message: &str,
+        position: Option<ScrollPosition>,
+    ) {
+        self.draw(title, message);
+        match position {
+            Some(position) if position.is_scrollable() && self.transition.is_none() => {
+                self.draw_scrollbar(position)
+            }
+            _ => (),
+        }
+        self.shown = Some((String::from(title), String::from(message)));
+        self.position = position;
+    }
+
+    /// How the page drawn last is refreshed, e.g. by an e-paper driver
+    ///
+    /// See `PageManager::set_full_refresh_interval()`; a page drawn again
+    /// with another theme is always refreshed fully.
+    pub fn refresh(&self) -> Refresh {
+        self.refresh
+    }
+
+    /// Draw the page shown again, e.g. with another theme
+    fn redraw(&mut self) {
+        if let Some((title, message)) = self.shown.take() {
+            self.refresh = Refresh::Full;
+            self.update_scrolled(&title, &message, self.position);
+        }
+    }
+
+    fn draw_scrollbar(&mut self, position: ScrollPosition) {
+        let theme = *self.theme();
+        let size = self.target.bounding_box().size;
+        let top = theme.title_height();
+        let length = size.height.saturating_sub(top);
+        let x = size.width.saturating_sub(SCROLLBAR_WIDTH) as i32;
+        let track = Rectangle::new(
+            Point::new(x, top as i32),
+            Size::new(SCROLLBAR_WIDTH, length),
+        );
+        let _ = track
+            .into_styled(PrimitiveStyle::with_fill(theme.background))
+            .draw(&mut self.target);
+        let (offset, thumb) = position.thumb(length as usize);
+        let thumb = Rectangle::new(
+            Point::new(x, top as i32 + offset as i32),
+            Size::new(SCROLLBAR_WIDTH, thumb as u32),
+        );
+        let _ = thumb
+            .into_styled(PrimitiveStyle::with_fill(theme.accent))
+            .draw(&mut self.target);
+    }
+
+    fn draw(&mut self, title: &str, message: &str) {
+        let theme = *self.theme();
+        let area = self.target.bounding_box();
+        match (self.transition, &self.from) {
+            (Some((transition, progress)), Some((from_title, from_message))) => {
+                match self.transition_style {
+                    TransitionStyle::Slide => {
+                        let (from, to) = slide_offsets(transition, progress, area.size);
+                        let mut clipped = self.target.clipped(&area);
+                        let (target, size) = (&mut clipped.translated(from), area.size);
+                        draw_page(target, &theme, size, from_title, from_message);
+                        let target = &mut clipped.translated(to);
+                        draw_page(target, &theme, size, title, message);
+                    }
+                    TransitionStyle::Fade => {
+                        draw_page(
+                            &mut self.target,
+                            &theme,
+                            area.size,
+                            from_title,
+                            from_message,
+                        );
+                        let target = &mut Dissolve {
+                            target: &mut self.target,
+                            progress,
+                        };
+                        draw_page(target, &theme, area.size, title, message);
+                    }
+                }
+            }
+            _ => draw_page(&mut self.target, &theme, area.size, title, message),
+        }
+    }
+}
+
+/// Page transitions by sliding or fading
+impl<T: DrawTarget> TransitionDisplay for GraphicsDisplay<T> {
+    fn set_transition(&mut self, transition: Option<(Transition, u8)>) {
+        match (self.transition, transition) {
+            // the page shown before is composed with the new page
+            (None, Some(_)) => self.from = self.shown.clone(),
+            (_, None) => self.from = None,
+            _ => (),
+        }
+        self.transition = transition;
+    }
+}
+
+impl<T: DrawTarget> RefreshDisplay for GraphicsDisplay<T> {
+    fn set_refresh(&mut self, refresh: Refresh) {
+        self.refresh = refresh;
+    }
+}
+
+/// Draw a page - the title bar and the content below
+fn draw_page<T: DrawTarget>(
+    target: &mut T,
+    theme: &Theme<T::Color>,
+    size: Size,
+    title: &str,
+    message: &str,
+) {
+    let _ = Rectangle::new(Point::zero(), size)
+        .into_styled(PrimitiveStyle::with_fill(theme.background))
+        .draw(target);
+    let bar = Rectangle::new(Point::zero(), Size::new(size.width, theme.title_height()));
+    let _ = bar
+        .into_styled(PrimitiveStyle::with_fill(theme.accent))
+        .draw(target);
+    let title_style = text_style(theme.title_font, theme.background, theme.accent);
+    let _ = Text::with_baseline(title, Point::zero(), title_style, Baseline::Top).draw(target);
+
+    let normal = text_style(theme.font, theme.foreground, theme.background);
+    let selected = text_style(theme.font, theme.background, theme.highlight);
+    let line_height = theme.font.character_size.height as i32;
+    for (row, line) in message.lines().enumerate() {
+        let mut position = Point::new(0, theme.title_height() as i32 + row as i32 * line_height);
+        let parts = match theme.selection {
+            SelectionStyle::Inverted => split_selection(line),
+            SelectionStyle::Marker => None,
+        };
+        let parts = match parts {
+            Some((before, selection, after)) => {
+                [(before, normal), (selection, selected), (after, normal)]
+            }
+            None => [(line, normal), ("", normal), ("", normal)],
+        };
+        for (text, style) in parts.iter().filter(|(text, _)| !text.is_empty()) {
+            match Text::with_baseline(text, position, *style, Baseline::Top).draw(target) {
+                Ok(next) => position = next,
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+/// Offsets of the page shown before and of the new page sliding in
+fn slide_offsets(transition: Transition, progress: u8, size: Size) -> (Point, Point) {
+    let moved = |length: u32| (length * progress as u32 / 100) as i32;
+    let (width, height) = (size.width as i32, size.height as i32);
+    match transition {
+        Transition::Left => {
+            let x = moved(size.width);
+            (Point::new(-x, 0), Point::new(width - x, 0))
+        }
+        Transition::Right => {
+            let x = moved(size.width);
+            (Point::new(x, 0), Point::new(x - width, 0))
+        }
+        Transition::Down => {
+            let y = moved(size.height);
+            (Point::new(0, -y), Point::new(0, height - y))
+        }
+        Transition::Up => {
+            let y = moved(size.height);
+            (Point::new(0, y), Point::new(0, y - height))
+        }
+    }
+}
+
+/// Ordered dither thresholds, pixels fade in by ascending threshold
+const DITHER: [[u32; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// A draw target drawing the pixels of a dither pattern only, i.e. a page fades in
+struct Dissolve<'t, T> {
+    target: &'t mut T,
+    progress: u8,
+}
+
+impl<T: DrawTarget> Dimensions for Dissolve<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<T: DrawTarget> DrawTarget for Dissolve<'_, T> {
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let progress = self.progress as u32;
+        self.target
+            .draw_iter(pixels.into_iter().filter(|Pixel(point, _)| {
+                DITHER[(point.y & 3) as usize][(point.x & 3) as usize] * 100 / 16 < progress
+            }))
+    }
+}
+
+fn text_style<P: PixelColor>(
+    font: &'static MonoFont<'static>,
+    color: P,
+    background: P,
+) -> MonoTextStyle<'static, P> {
+    MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(color)
+        .background_color(background)
+        .build()
+}
+
+/// Split a line at the selected entry `[ entry ]`
+///
+/// Returns the text before, the entry without brackets and the text after.
+fn split_selection(line: &str) -> Option<(&str, &str, &str)> {
+    let start = line.find("[ ")?;
+    let end = start + line[start..].find(" ]")?;
+    Some((&line[..start], &line[start + 2..end], &line[end + 2..]))
+}
+
+/// Characters per row and rows below the title bar of the content font
+impl<T: DrawTarget> DisplayInfo for GraphicsDisplay<T> {
+    fn cols(&self) -> usize {
+        let font = self.theme().font;
+        let advance = font.character_size.width + font.character_spacing;
+        (self.target.bounding_box().size.width / advance.max(1)) as usize
+    }
+
+    fn rows(&self) -> usize {
+        let theme = self.theme();
+        let height = self.target.bounding_box().size.height;
+        (height.saturating_sub(theme.title_height()) / theme.font.character_size.height.max(1))
+            as usize
+    }
+}
+
+// ** All pages of this crate render to graphical displays
+impl_text_display!(@graphics <G: DrawTarget> GraphicsDisplay<G>);
+
+#[cfg(test)]
+mod tests;
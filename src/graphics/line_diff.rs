@@ -0,0 +1,131 @@
+use core::convert::Infallible;
+
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+
+/// A monochrome frame buffer sending changed lines only, e.g. to a Sharp memory display
+///
+/// Sharp memory displays are written line by line, each line addressed by
+/// its number, and keep what was written without refresh. Sending only the
+/// lines that changed since the last flush keeps the bus idle most of the
+/// time, e.g. while a clock page changes a few lines once a minute, which
+/// makes always-on HMIs of a few microwatts possible.
+///
+/// Pages are drawn to the buffer by the `GraphicsDisplay`; the application
+/// flushes the changed lines after a dispatch. A line is given as bytes of 8
+/// pixels each, the leftmost pixel in the least significant bit, a set bit
+/// is `BinaryColor::On`.
+///
+/// # Example
+///
+/// ```
+///     use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+///     use embedded_multi_page_hmi::graphics::LineDiffBuffer;
+///
+///     let mut buffer = LineDiffBuffer::new(16, 4);
+///     // all lines are sent first
+///     assert_eq!(buffer.flush(|_, _| Ok::<(), ()>(())), Ok(4));
+///     Pixel(Point::new(9, 2), BinaryColor::On).draw(&mut buffer).unwrap();
+///     let mut sent = Vec::new();
+///     buffer.flush(|line, bytes| Ok::<(), ()>(sent.push((line, bytes.to_vec())))).unwrap();
+///     assert_eq!(sent, vec![(2, vec![0x00, 0x02])]);
+/// ```
+pub struct LineDiffBuffer {
+    size: Size,
+    stride: usize,
+    frame: Vec<u8>,
+    sent: Vec<u8>,
+    valid: bool,
+}
+
+impl LineDiffBuffer {
+    /// Arguments
+    ///
+    /// * `width`: - The width of the display in pixels
+    /// * `height`: - The number of lines of the display
+    pub fn new(width: u32, height: u32) -> Self {
+        let stride = width.div_ceil(8) as usize;
+        let bytes = stride * height as usize;
+        LineDiffBuffer {
+            size: Size::new(width, height),
+            stride,
+            frame: vec![0; bytes],
+            sent: vec![0; bytes],
+            valid: false,
+        }
+    }
+
+    /// The line of the frame drawn, bytes of 8 pixels each
+    pub fn line(&self, line: usize) -> &[u8] {
+        &self.frame[line * self.stride..(line + 1) * self.stride]
+    }
+
+    /// The lines changed since the last flush
+    pub fn changed_lines(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.size.height as usize).filter(move |line| {
+            let range = line * self.stride..(line + 1) * self.stride;
+            !self.valid || self.frame[range.clone()] != self.sent[range]
+        })
+    }
+
+    /// Send all lines with the next flush, e.g. after the display got cleared
+    pub fn invalidate(&mut self) {
+        self.valid = false;
+    }
+
+    /// Send the lines changed since the last flush
+    ///
+    /// Arguments
+    ///
+    /// * `send`: - Sends a line given by its number and its bytes, e.g. over SPI
+    ///
+    /// Returns
+    ///
+    /// * `Ok(n)` - the number of lines sent
+    /// * `Err(e)` - the error of sending; the lines not sent are sent with the next flush
+    pub fn flush<E>(
+        &mut self,
+        mut send: impl FnMut(usize, &[u8]) -> Result<(), E>,
+    ) -> Result<usize, E> {
+        let changed: Vec<usize> = self.changed_lines().collect();
+        for &line in &changed {
+            let range = line * self.stride..(line + 1) * self.stride;
+            send(line, &self.frame[range.clone()])?;
+            self.sent[range.clone()].copy_from_slice(&self.frame[range]);
+        }
+        self.valid = true;
+        Ok(changed.len())
+    }
+}
+
+impl OriginDimensions for LineDiffBuffer {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl DrawTarget for LineDiffBuffer {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            let (x, y) = (point.x as u32, point.y as u32);
+            if point.x < 0 || point.y < 0 || x >= self.size.width || y >= self.size.height {
+                continue;
+            }
+            let index = y as usize * self.stride + x as usize / 8;
+            let bit = 1 << (x % 8);
+            match color {
+                BinaryColor::On => self.frame[index] |= bit,
+                BinaryColor::Off => self.frame[index] &= !bit,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+use super::*;
+use crate::graphics::{GraphicsDisplay, Theme};
+use embedded_graphics::mono_font::ascii::FONT_4X6;
+
+/// Flush the buffer - the numbers of the lines sent
+fn flush(buffer: &mut LineDiffBuffer) -> Vec<usize> {
+    let mut sent = Vec::new();
+    buffer
+        .flush(|line, _| {
+            sent.push(line);
+            Ok::<(), Infallible>(())
+        })
+        .unwrap();
+    sent
+}
+
+#[test]
+fn pixels_in_lines_of_bytes() {
+    let mut buffer = LineDiffBuffer::new(10, 3);
+    assert_eq!(buffer.size(), Size::new(10, 3));
+    Pixel(Point::new(0, 1), BinaryColor::On)
+        .draw(&mut buffer)
+        .unwrap();
+    Pixel(Point::new(9, 1), BinaryColor::On)
+        .draw(&mut buffer)
+        .unwrap();
+    // outside the display
+    Pixel(Point::new(10, 1), BinaryColor::On)
+        .draw(&mut buffer)
+        .unwrap();
+    Pixel(Point::new(-1, 1), BinaryColor::On)
+        .draw(&mut buffer)
+        .unwrap();
+    assert_eq!(buffer.line(1), &[0x01, 0x02]);
+    Pixel(Point::new(0, 1), BinaryColor::Off)
+        .draw(&mut buffer)
+        .unwrap();
+    assert_eq!(buffer.line(1), &[0x00, 0x02]);
+    assert_eq!(buffer.line(0), &[0x00, 0x00]);
+}
+
+#[test]
+fn send_changed_lines_only() {
+    let mut buffer = LineDiffBuffer::new(8, 4);
+    assert_eq!(flush(&mut buffer), vec![0, 1, 2, 3]);
+    assert_eq!(flush(&mut buffer), Vec::<usize>::new());
+
+    Pixel(Point::new(3, 1), BinaryColor::On)
+        .draw(&mut buffer)
+        .unwrap();
+    Pixel(Point::new(3, 3), BinaryColor::On)
+        .draw(&mut buffer)
+        .unwrap();
+    // drawn and cleared again before the flush
+    Pixel(Point::new(3, 2), BinaryColor::On)
+        .draw(&mut buffer)
+        .unwrap();
+    Pixel(Point::new(3, 2), BinaryColor::Off)
+        .draw(&mut buffer)
+        .unwrap();
+    assert_eq!(buffer.changed_lines().collect::<Vec<_>>(), vec![1, 3]);
+
+    // a failing line is sent again with the next flush
+    let result = buffer.flush(|line, _| match line {
+        3 => Err(()),
+        _ => Ok(()),
+    });
+    assert_eq!(result, Err(()));
+    assert_eq!(flush(&mut buffer), vec![3]);
+
+    buffer.invalidate();
+    assert_eq!(flush(&mut buffer), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn page_updates_send_the_changed_lines() {
+    let theme = Theme::default().with_fonts(&FONT_4X6, &FONT_4X6);
+    let mut display = GraphicsDisplay::new(LineDiffBuffer::new(32, 24), theme);
+    display.update("Clock", "12:00");
+    assert_eq!(flush(display.target_mut()).len(), 24);
+    display.update("Clock", "12:01");
+    // the row of the time only
+    let sent = flush(display.target_mut());
+    assert!(!sent.is_empty());
+    assert!(sent.iter().all(|line| (6..12).contains(line)));
+}
@@ -0,0 +1,168 @@
+use super::*;
+use embedded_graphics::mock_display::MockDisplay;
+
+fn display() -> GraphicsDisplay<MockDisplay<BinaryColor>> {
+    let mut target = MockDisplay::new();
+    target.set_allow_overdraw(true);
+    GraphicsDisplay::new(target, Theme::default())
+}
+
+fn pixel(
+    display: &GraphicsDisplay<MockDisplay<BinaryColor>>,
+    x: i32,
+    y: i32,
+) -> Option<BinaryColor> {
+    display.target().get_pixel(Point::new(x, y))
+}
+
+#[test]
+fn split_the_selected_entry() {
+    assert_eq!(split_selection("a [ b ] c"), Some(("a ", "b", " c")));
+    assert_eq!(split_selection("a b c"), None);
+    assert_eq!(split_selection("a [ b"), None);
+}
+
+#[test]
+fn size_in_characters_of_the_font() {
+    let display = display();
+    assert_eq!(display.cols(), 10);
+    assert_eq!(display.rows(), 5);
+}
+
+#[test]
+fn title_bar_in_accent_color() {
+    let mut display = display();
+    display.update("Home", "Hello");
+    assert_eq!(pixel(&display, 63, 0), Some(BinaryColor::On));
+    assert_eq!(pixel(&display, 63, 63), Some(BinaryColor::Off));
+}
+
+#[test]
+fn night_mode_redraws() {
+    let mut display = display().with_night_theme(Theme::default().inverted());
+    display.update("Home", "Hello");
+    display.set_night_mode(true);
+    assert!(display.is_night_mode());
+    assert_eq!(pixel(&display, 63, 63), Some(BinaryColor::On));
+    assert_eq!(pixel(&display, 63, 0), Some(BinaryColor::Off));
+    display.set_night_mode(false);
+    assert_eq!(pixel(&display, 63, 63), Some(BinaryColor::Off));
+}
+
+#[test]
+fn night_mode_without_night_theme() {
+    let mut display = display();
+    display.update("Home", "Hello");
+    display.set_night_mode(true);
+    assert_eq!(pixel(&display, 63, 63), Some(BinaryColor::Off));
+}
+
+#[test]
+fn set_theme_redraws() {
+    let mut display = display();
+    display.update("Home", "Hello");
+    display.set_theme(Theme::new(
+        BinaryColor::Off,
+        BinaryColor::On,
+        BinaryColor::Off,
+    ));
+    assert_eq!(pixel(&display, 63, 63), Some(BinaryColor::On));
+}
+
+#[test]
+fn selection_style() {
+    // bottom right pixel of the first character in the first content row
+    let mut display = display();
+    display.update("Menu", "[ A ] B");
+    assert_eq!(pixel(&display, 5, 19), Some(BinaryColor::On));
+
+    display.set_theme(Theme::default().with_selection(SelectionStyle::Marker));
+    assert_eq!(pixel(&display, 5, 19), Some(BinaryColor::Off));
+}
+
+#[test]
+fn color_theme_of_tfts() {
+    let mut target = MockDisplay::new();
+    target.set_allow_overdraw(true);
+    let mut display = GraphicsDisplay::new(target, Theme::<Rgb565>::dark());
+    display.update("Menu", "[ A ] B");
+    let pixel = |display: &GraphicsDisplay<MockDisplay<Rgb565>>, x, y| {
+        display.target().get_pixel(Point::new(x, y))
+    };
+    assert_eq!(pixel(&display, 63, 0), Some(Rgb565::CSS_DODGER_BLUE));
+    assert_eq!(pixel(&display, 5, 19), Some(Rgb565::YELLOW));
+    assert_eq!(pixel(&display, 63, 63), Some(Rgb565::BLACK));
+
+    let light = Theme::<Rgb565>::light();
+    assert_eq!(light.gauge, Rgb565::CSS_FOREST_GREEN);
+    assert_eq!(light.inverted().highlight, Rgb565::WHITE);
+}
+
+#[test]
+fn slide_offsets_by_direction() {
+    let size = Size::new(64, 32);
+    assert_eq!(
+        slide_offsets(Transition::Left, 50, size),
+        (Point::new(-32, 0), Point::new(32, 0))
+    );
+    assert_eq!(
+        slide_offsets(Transition::Right, 25, size),
+        (Point::new(16, 0), Point::new(-48, 0))
+    );
+    assert_eq!(
+        slide_offsets(Transition::Down, 50, size),
+        (Point::new(0, -16), Point::new(0, 16))
+    );
+    assert_eq!(
+        slide_offsets(Transition::Up, 75, size),
+        (Point::new(0, 24), Point::new(0, -8))
+    );
+}
+
+#[test]
+fn slide_the_page_shown_out() {
+    // the selection of the page shown covers the first content row
+    let mut display = display();
+    display.update("A", "[ AAAAAAAAAA ]");
+    display.set_transition(Some((Transition::Left, 50)));
+    display.update("B", "");
+    assert_eq!(pixel(&display, 5, 19), Some(BinaryColor::On));
+    assert_eq!(pixel(&display, 40, 19), Some(BinaryColor::Off));
+
+    display.set_transition(None);
+    display.update("B", "");
+    assert_eq!(pixel(&display, 5, 19), Some(BinaryColor::Off));
+}
+
+#[test]
+fn fade_the_new_page_in() {
+    let mut display = display().with_transition_style(TransitionStyle::Fade);
+    display.update("A", "[ AAAAAAAAAA ]");
+    display.set_transition(Some((Transition::Down, 50)));
+    display.update("B", "");
+    assert_eq!(pixel(&display, 0, 19), Some(BinaryColor::On));
+    assert_eq!(pixel(&display, 1, 19), Some(BinaryColor::Off));
+}
+
+#[test]
+fn full_refresh_after_theme_switch() {
+    let mut display = display().with_night_theme(Theme::default().inverted());
+    display.set_refresh(Refresh::Partial);
+    display.update("Home", "Hello");
+    assert_eq!(display.refresh(), Refresh::Partial);
+    display.set_night_mode(true);
+    assert_eq!(display.refresh(), Refresh::Full);
+}
+
+#[test]
+fn scrollbar_at_the_right_edge() {
+    let mut display = display();
+    display.update_scrolled("Info", "", Some(ScrollPosition::new(0, 2, 4)));
+    assert_eq!(pixel(&display, 63, 11), Some(BinaryColor::On));
+    assert_eq!(pixel(&display, 62, 36), Some(BinaryColor::On));
+    assert_eq!(pixel(&display, 63, 60), Some(BinaryColor::Off));
+
+    // no scrollbar if all content is shown
+    display.update_scrolled("Info", "", Some(ScrollPosition::new(0, 4, 4)));
+    assert_eq!(pixel(&display, 63, 11), Some(BinaryColor::Off));
+}
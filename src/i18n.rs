@@ -0,0 +1,110 @@
+use super::PageError;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Identifier of a translated text
+///
+/// The ids below `TextId::USER` are used by the stock pages. Applications
+/// number their own texts starting with `TextId::USER`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextId(pub usize);
+
+impl TextId {
+    /// Menu entry to navigate back, e.g. "Back"
+    pub const BACK: TextId = TextId(0);
+    /// Menu entry to confirm and leave, e.g. "Ok"
+    pub const OK: TextId = TextId(1);
+    /// Title of the startup page, e.g. "Startup"
+    pub const STARTUP: TextId = TextId(2);
+    /// Title of the shutdown page, e.g. "Shutdown"
+    pub const SHUTDOWN: TextId = TextId(3);
+    /// Title of the language select page, e.g. "Language"
+    pub const LANGUAGE: TextId = TextId(4);
+    /// First id free for application texts
+    pub const USER: TextId = TextId(5);
+}
+
+/// String tables of all languages with a language selectable at runtime
+///
+/// Every language has a name and a table of texts indexed by `TextId`.
+/// The translations can be placed in a `static`, so pages can refer to it
+/// for their whole lifetime. Pages look up their texts whenever they are
+/// updated or displayed, i.e. switching the language takes effect with the
+/// next update.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{TextId, Translations};
+///
+///     static TRANSLATIONS: Translations = Translations::new(
+///         &["English", "Deutsch"],
+///         &[
+///             &["Back", "Ok", "Startup", "Shutdown", "Language"],
+///             &["Zurück", "Ok", "Start", "Ende", "Sprache"],
+///         ],
+///     );
+///
+///     assert_eq!(TRANSLATIONS.get(TextId::BACK), "Back");
+///     TRANSLATIONS.set_language(1).unwrap();
+///     assert_eq!(TRANSLATIONS.get(TextId::BACK), "Zurück");
+/// ```
+pub struct Translations {
+    languages: &'static [&'static str],
+    tables: &'static [&'static [&'static str]],
+    active: AtomicUsize,
+}
+
+impl Translations {
+    /// Arguments
+    ///
+    /// * `languages`: - The names of the languages
+    /// * `tables`: - One table of texts per language in the order of `languages`
+    pub const fn new(
+        languages: &'static [&'static str],
+        tables: &'static [&'static [&'static str]],
+    ) -> Self {
+        Translations {
+            languages,
+            tables,
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// The names of all languages
+    pub fn languages(&self) -> &'static [&'static str] {
+        self.languages
+    }
+
+    /// The index of the active language
+    pub fn language(&self) -> usize {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// Activate a language by its index
+    ///
+    /// Returns `Err(PageError)` if there is no such language.
+    pub fn set_language(&self, language: usize) -> Result<(), PageError> {
+        if language >= self.tables.len() {
+            return Err(PageError);
+        }
+        self.active.store(language, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// The text in the active language
+    ///
+    /// Falls back to the first language if the text is not translated and
+    /// to an empty str if the text is not known at all.
+    pub fn get(&self, id: TextId) -> &'static str {
+        let text = |language: usize| {
+            self.tables
+                .get(language)
+                .and_then(|table| table.get(id.0))
+                .copied()
+        };
+        text(self.language()).or_else(|| text(0)).unwrap_or("")
+    }
+}
+
+#[cfg(test)]
+mod tests;
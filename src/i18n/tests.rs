@@ -0,0 +1,27 @@
+use super::*;
+
+static TRANSLATIONS: Translations = Translations::new(
+    &["English", "Deutsch"],
+    &[&["Back", "Ok", "Startup"], &["Zurück", "Ok"]],
+);
+
+#[test]
+fn switch_language() {
+    let sut = Translations::new(TRANSLATIONS.languages, TRANSLATIONS.tables);
+    assert_eq!(sut.languages(), &["English", "Deutsch"]);
+    assert_eq!(sut.language(), 0);
+    assert_eq!(sut.get(TextId::BACK), "Back");
+    sut.set_language(1).unwrap();
+    assert_eq!(sut.language(), 1);
+    assert_eq!(sut.get(TextId::BACK), "Zurück");
+    assert!(sut.set_language(2).is_err());
+    assert_eq!(sut.language(), 1);
+}
+
+#[test]
+fn fall_back_to_first_language() {
+    let sut = Translations::new(TRANSLATIONS.languages, TRANSLATIONS.tables);
+    sut.set_language(1).unwrap();
+    assert_eq!(sut.get(TextId::STARTUP), "Startup");
+    assert_eq!(sut.get(TextId::USER), "");
+}
@@ -0,0 +1,185 @@
+use super::Interaction;
+
+/// Infrared remote control protocols
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IrProtocol {
+    /// NEC protocol; held keys send repeat frames without command
+    Nec,
+    /// Philips RC5 protocol; held keys resend the frame with the same toggle bit
+    Rc5,
+}
+
+/// A key of an infrared remote control
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IrCode {
+    pub protocol: IrProtocol,
+    /// The device address
+    pub address: u16,
+    /// The key
+    pub command: u8,
+}
+
+impl IrCode {
+    pub fn nec(address: u16, command: u8) -> Self {
+        IrCode {
+            protocol: IrProtocol::Nec,
+            address,
+            command,
+        }
+    }
+
+    pub fn rc5(address: u8, command: u8) -> Self {
+        IrCode {
+            protocol: IrProtocol::Rc5,
+            address: address as u16,
+            command,
+        }
+    }
+}
+
+/// RC5 address of TV sets
+const RC5_TV: u8 = 0;
+
+/// Input of the interactions of an infrared remote control
+///
+/// Maps the codes decoded by an infrared receiver, e.g. by the `infrared`
+/// crate, to interactions. Decoders typically run in an interrupt service
+/// routine; the interactions can be handed over to the page manager by an
+/// `InteractionQueue`.
+///
+/// A key held down is sent again and again by the remote control. These
+/// repeats are suppressed, i.e. holding a key is one interaction, unless
+/// repeating is enabled by `with_repeat()`.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{Interaction, IrCode, IrInput};
+///
+///     let mut input = IrInput::new()
+///         .with_key(IrCode::nec(0x00, 0x40), Interaction::Action)
+///         .with_key(IrCode::nec(0x00, 0x15), Interaction::Next);
+///
+///     // e.g. in the interrupt service routine of the infrared receiver
+///     assert_eq!(input.nec(0x00, 0x15, false), Some(Interaction::Next));
+///     assert_eq!(input.nec(0x00, 0x15, true), None);
+/// ```
+pub struct IrInput {
+    keymap: Vec<(IrCode, Interaction)>,
+    repeat_after: Option<u32>,
+    held: Option<(IrCode, bool)>,
+    repeats: u32,
+}
+
+impl Default for IrInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IrInput {
+    /// An input without keys
+    pub fn new() -> Self {
+        IrInput {
+            keymap: Vec::new(),
+            repeat_after: None,
+            held: None,
+            repeats: 0,
+        }
+    }
+
+    /// Map a key to an interaction
+    ///
+    /// A key mapped before is mapped to the new interaction.
+    pub fn with_key(mut self, code: IrCode, interaction: Interaction) -> Self {
+        self.keymap.retain(|(c, _)| *c != code);
+        self.keymap.push((code, interaction));
+        self
+    }
+
+    /// Map the cursor keys of RC5 TV remote controls
+    ///
+    /// | Key   | Command | Interaction |
+    /// | ----- | ------- | ----------- |
+    /// | up    | `0x50`  | previous    |
+    /// | down  | `0x51`  | next        |
+    /// | left  | `0x55`  | back        |
+    /// | OK    | `0x57`  | action      |
+    pub fn with_rc5_tv_keys(self) -> Self {
+        self.with_key(IrCode::rc5(RC5_TV, 0x50), Interaction::Previous)
+            .with_key(IrCode::rc5(RC5_TV, 0x51), Interaction::Next)
+            .with_key(IrCode::rc5(RC5_TV, 0x55), Interaction::Back)
+            .with_key(IrCode::rc5(RC5_TV, 0x57), Interaction::Action)
+    }
+
+    /// Repeat the interaction of a key held down
+    ///
+    /// Arguments
+    ///
+    /// * `after`: - Number of repeats received before the interaction is repeated,
+    ///   i.e. the delay until a held key starts to scroll
+    pub fn with_repeat(mut self, after: u32) -> Self {
+        self.repeat_after = Some(after);
+        self
+    }
+
+    /// Handle a frame decoded by a NEC receiver
+    ///
+    /// Arguments
+    ///
+    /// * `address`: - The device address
+    /// * `command`: - The key; is ignored for repeat frames
+    /// * `repeat`: - The frame is a repeat frame of a key held down
+    ///
+    /// Returns the interaction of the key or `None` if the key is not mapped
+    /// or the frame is a suppressed repeat.
+    pub fn nec(&mut self, address: u16, command: u8, repeat: bool) -> Option<Interaction> {
+        match (repeat, self.held) {
+            (true, Some((code, _))) if code.protocol == IrProtocol::Nec => self.repeat(code),
+            (true, _) => None,
+            (false, _) => self.press(IrCode::nec(address, command), false),
+        }
+    }
+
+    /// Handle a frame decoded by a RC5 receiver
+    ///
+    /// Arguments
+    ///
+    /// * `address`: - The device address
+    /// * `command`: - The key
+    /// * `toggle`: - The toggle bit; it changes with every key press
+    ///
+    /// Returns the interaction of the key or `None` if the key is not mapped
+    /// or the frame is a suppressed repeat.
+    pub fn rc5(&mut self, address: u8, command: u8, toggle: bool) -> Option<Interaction> {
+        let code = IrCode::rc5(address, command);
+        match self.held {
+            Some(held) if held == (code, toggle) => self.repeat(code),
+            _ => self.press(code, toggle),
+        }
+    }
+
+    fn press(&mut self, code: IrCode, toggle: bool) -> Option<Interaction> {
+        self.held = Some((code, toggle));
+        self.repeats = 0;
+        self.interaction(code)
+    }
+
+    fn repeat(&mut self, code: IrCode) -> Option<Interaction> {
+        self.repeats = self.repeats.saturating_add(1);
+        match self.repeat_after {
+            Some(after) if self.repeats > after => self.interaction(code),
+            _ => None,
+        }
+    }
+
+    fn interaction(&self, code: IrCode) -> Option<Interaction> {
+        self.keymap
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, interaction)| *interaction)
+    }
+}
+
+#[cfg(test)]
+mod tests;
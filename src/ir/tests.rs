@@ -0,0 +1,67 @@
+use super::*;
+
+fn nec_input() -> IrInput {
+    IrInput::new()
+        .with_key(IrCode::nec(0x00, 0x40), Interaction::Action)
+        .with_key(IrCode::nec(0x00, 0x15), Interaction::Next)
+}
+
+#[test]
+fn map_codes_to_interactions() {
+    let mut input = nec_input();
+    assert_eq!(input.nec(0x00, 0x40, false), Some(Interaction::Action));
+    assert_eq!(input.nec(0x00, 0x15, false), Some(Interaction::Next));
+    // other key or other device
+    assert_eq!(input.nec(0x00, 0x16, false), None);
+    assert_eq!(input.nec(0x01, 0x40, false), None);
+    // same codes of another protocol
+    assert_eq!(input.rc5(0x00, 0x40, false), None);
+}
+
+#[test]
+fn remap_key() {
+    let mut input = nec_input().with_key(IrCode::nec(0x00, 0x40), Interaction::Home);
+    assert_eq!(input.nec(0x00, 0x40, false), Some(Interaction::Home));
+}
+
+#[test]
+fn suppress_nec_repeats() {
+    let mut input = nec_input();
+    // repeat without key press before
+    assert_eq!(input.nec(0x00, 0x00, true), None);
+    assert_eq!(input.nec(0x00, 0x15, false), Some(Interaction::Next));
+    assert_eq!(input.nec(0x00, 0x15, true), None);
+    assert_eq!(input.nec(0x00, 0x15, true), None);
+    // pressing the same key again
+    assert_eq!(input.nec(0x00, 0x15, false), Some(Interaction::Next));
+}
+
+#[test]
+fn suppress_rc5_repeats() {
+    let mut input = IrInput::new().with_rc5_tv_keys();
+    assert_eq!(input.rc5(0, 0x51, false), Some(Interaction::Next));
+    assert_eq!(input.rc5(0, 0x51, false), None);
+    // pressing the same key again toggles
+    assert_eq!(input.rc5(0, 0x51, true), Some(Interaction::Next));
+    assert_eq!(input.rc5(0, 0x51, true), None);
+    assert_eq!(input.rc5(0, 0x57, true), Some(Interaction::Action));
+}
+
+#[test]
+fn repeat_held_keys() {
+    let mut input = nec_input().with_repeat(2);
+    let interactions: Vec<_> = [false, true, true, true, true]
+        .iter()
+        .map(|repeat| input.nec(0x00, 0x15, *repeat))
+        .collect();
+    assert_eq!(
+        interactions,
+        vec![
+            Some(Interaction::Next),
+            None,
+            None,
+            Some(Interaction::Next),
+            Some(Interaction::Next)
+        ]
+    );
+}
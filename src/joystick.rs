@@ -0,0 +1,134 @@
+use super::Interaction;
+
+/// The switches of a five-way joystick closed at a time
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct JoystickState {
+    pub up: bool,
+    pub down: bool,
+    pub left: bool,
+    pub right: bool,
+    pub center: bool,
+}
+
+impl JoystickState {
+    /// The one closed switch; `None` if no switch or several switches are closed
+    fn direction(&self) -> Option<usize> {
+        let switches = [self.up, self.down, self.left, self.right, self.center];
+        match switches.iter().filter(|closed| **closed).count() {
+            1 => switches.iter().position(|closed| *closed),
+            _ => None,
+        }
+    }
+
+    fn is_released(&self) -> bool {
+        *self == JoystickState::default()
+    }
+}
+
+/// Input of a five-way joystick, e.g. a tactile navigation switch
+///
+/// The directions map onto the five interactions:
+///
+/// | Direction | Interaction |
+/// | --------- | ----------- |
+/// | up        | previous    |
+/// | down      | next        |
+/// | left      | back        |
+/// | right     | home        |
+/// | center    | action      |
+///
+/// Diagonals, i.e. several switches closed at a time, are rejected: the
+/// joystick keeps the direction pressed before. A direction held down is one
+/// interaction unless auto-repeat is enabled by `with_repeat()`.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{Interaction, Joystick, JoystickState};
+///
+///     let mut joystick = Joystick::new();
+///     // e.g. the switches read every 10ms
+///     let down = JoystickState { down: true, ..Default::default() };
+///     assert_eq!(joystick.sample(down), Some(Interaction::Next));
+///     assert_eq!(joystick.sample(down), None);
+/// ```
+pub struct Joystick {
+    interactions: [Interaction; 5],
+    repeat: Option<(u32, u32)>,
+    held: Option<usize>,
+    ticks: u32,
+}
+
+impl Default for Joystick {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Joystick {
+    pub fn new() -> Self {
+        Joystick {
+            interactions: [
+                Interaction::Previous,
+                Interaction::Next,
+                Interaction::Back,
+                Interaction::Home,
+                Interaction::Action,
+            ],
+            repeat: None,
+            held: None,
+            ticks: 0,
+        }
+    }
+
+    /// Map the directions to other interactions
+    ///
+    /// Arguments
+    ///
+    /// * `directions`: - The interactions of up, down, left, right and center
+    pub fn with_interactions(mut self, directions: [Interaction; 5]) -> Self {
+        self.interactions = directions;
+        self
+    }
+
+    /// Repeat next and previous while held down, e.g. to scroll through long menus
+    ///
+    /// Arguments
+    ///
+    /// * `delay`: - Number of samples held down before the first repeat
+    /// * `period`: - Number of samples between repeats
+    pub fn with_repeat(mut self, delay: u32, period: u32) -> Self {
+        self.repeat = Some((delay, period.max(1)));
+        self
+    }
+
+    /// Handle the switches read
+    ///
+    /// Returns the interaction of a direction as soon as it is pressed or
+    /// repeated, otherwise `None`.
+    pub fn sample(&mut self, state: JoystickState) -> Option<Interaction> {
+        if state.is_released() {
+            self.held = None;
+            return None;
+        }
+        let direction = state.direction()?;
+        if self.held != Some(direction) {
+            self.held = Some(direction);
+            self.ticks = 0;
+            return Some(self.interactions[direction]);
+        }
+        self.ticks = self.ticks.saturating_add(1);
+        let interaction = self.interactions[direction];
+        match (self.repeat, interaction) {
+            (Some((delay, period)), Interaction::Next | Interaction::Previous)
+                if self.ticks >= delay && (self.ticks - delay).is_multiple_of(period) =>
+            {
+                Some(interaction)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,98 @@
+use super::*;
+
+const RELEASED: JoystickState = JoystickState {
+    up: false,
+    down: false,
+    left: false,
+    right: false,
+    center: false,
+};
+const UP: JoystickState = JoystickState {
+    up: true,
+    ..RELEASED
+};
+const RIGHT: JoystickState = JoystickState {
+    right: true,
+    ..RELEASED
+};
+const CENTER: JoystickState = JoystickState {
+    center: true,
+    ..RELEASED
+};
+const UP_RIGHT: JoystickState = JoystickState {
+    up: true,
+    right: true,
+    ..RELEASED
+};
+
+fn samples(joystick: &mut Joystick, states: &[JoystickState]) -> Vec<Option<Interaction>> {
+    states.iter().map(|state| joystick.sample(*state)).collect()
+}
+
+#[test]
+fn map_directions() {
+    let mut sut = Joystick::new();
+    assert_eq!(
+        samples(&mut sut, &[UP, RELEASED, RIGHT, CENTER]),
+        vec![
+            Some(Interaction::Previous),
+            None,
+            Some(Interaction::Home),
+            Some(Interaction::Action)
+        ]
+    );
+    let mut sut = Joystick::new().with_interactions([
+        Interaction::Previous,
+        Interaction::Next,
+        Interaction::Back,
+        Interaction::Action,
+        Interaction::Action,
+    ]);
+    assert_eq!(sut.sample(RIGHT), Some(Interaction::Action));
+}
+
+#[test]
+fn reject_diagonals() {
+    let mut sut = Joystick::new();
+    // rolling from up to right does not release up
+    assert_eq!(
+        samples(&mut sut, &[UP, UP_RIGHT, UP, UP_RIGHT, RIGHT]),
+        vec![
+            Some(Interaction::Previous),
+            None,
+            None,
+            None,
+            Some(Interaction::Home)
+        ]
+    );
+    let mut sut = Joystick::new();
+    assert_eq!(sut.sample(UP_RIGHT), None);
+}
+
+#[test]
+fn suppress_repeats_by_default() {
+    let mut sut = Joystick::new();
+    let interactions = samples(&mut sut, &[UP; 10]);
+    assert_eq!(interactions.iter().flatten().count(), 1);
+}
+
+#[test]
+fn repeat_scrolling() {
+    let mut sut = Joystick::new().with_repeat(3, 2);
+    assert_eq!(
+        samples(&mut sut, &[UP; 8]),
+        vec![
+            Some(Interaction::Previous),
+            None,
+            None,
+            Some(Interaction::Previous),
+            None,
+            Some(Interaction::Previous),
+            None,
+            Some(Interaction::Previous)
+        ]
+    );
+    // action is not repeated
+    let interactions = samples(&mut sut, &[CENTER; 8]);
+    assert_eq!(interactions.iter().flatten().count(), 1);
+}
@@ -0,0 +1,175 @@
+//! Matrix keypad input
+//!
+//! Requires the `keypad` feature. Scans a keypad whose keys connect row and
+//! column lines, e.g. the common 4x4 membrane keypads, by `embedded-hal` pins.
+//!
+//! The row pins are outputs, the column pins inputs with pull-up resistors.
+//! One row after another is driven low; a key of the row is pressed if its
+//! column reads low.
+//!
+//! Keys are mapped either to interactions or to characters, both scanned as
+//! `InputEvent`. Characters are entered directly into pages like the
+//! `EnterStringPage`, i.e. numbers are typed instead of selected digit by digit.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut keypad = Keypad::new_4x4(rows, cols);
+//! loop {
+//!     if let Some(input) = keypad.scan().unwrap() {
+//!         m.dispatch_input(input).unwrap();
+//!     }
+//!     // wait e.g. 10ms
+//! }
+//! ```
+
+use super::{InputEvent, Interaction, PageError};
+
+use embedded_hal::digital::{InputPin, OutputPin};
+
+/// The keys of a 4x4 keypad
+pub const KEYS_4X4: [[char; 4]; 4] = [
+    ['1', '2', '3', 'A'],
+    ['4', '5', '6', 'B'],
+    ['7', '8', '9', 'C'],
+    ['*', '0', '#', 'D'],
+];
+
+/// Default number of equal scans for a key to be pressed or released
+const DEBOUNCE: u8 = 2;
+
+/// A matrix keypad
+///
+/// Keys are characters unless they are mapped to an interaction by `with_key()`.
+pub struct Keypad<R, L, const ROWS: usize, const COLS: usize> {
+    rows: [R; ROWS],
+    cols: [L; COLS],
+    keys: [[char; COLS]; ROWS],
+    mapping: Vec<(char, Interaction)>,
+    debounce: u8,
+    candidate: Option<char>,
+    stable: u8,
+    pressed: Option<char>,
+}
+
+impl<R: OutputPin, L: InputPin> Keypad<R, L, 4, 4> {
+    /// A 4x4 keypad
+    ///
+    /// The letter keys are mapped to interactions, all other keys are characters:
+    ///
+    /// | Key | Interaction |
+    /// | --- | ----------- |
+    /// | `A` | previous    |
+    /// | `B` | next        |
+    /// | `C` | back        |
+    /// | `D` | action      |
+    /// | `*` | home        |
+    pub fn new_4x4(rows: [R; 4], cols: [L; 4]) -> Self {
+        Keypad::new(rows, cols, KEYS_4X4)
+            .with_key('A', Interaction::Previous)
+            .with_key('B', Interaction::Next)
+            .with_key('C', Interaction::Back)
+            .with_key('D', Interaction::Action)
+            .with_key('*', Interaction::Home)
+    }
+}
+
+impl<R: OutputPin, L: InputPin, const ROWS: usize, const COLS: usize> Keypad<R, L, ROWS, COLS> {
+    /// Arguments
+    ///
+    /// * `rows`: - The row pins
+    /// * `cols`: - The column pins
+    /// * `keys`: - The key at every row and column
+    pub fn new(rows: [R; ROWS], cols: [L; COLS], keys: [[char; COLS]; ROWS]) -> Self {
+        Keypad {
+            rows,
+            cols,
+            keys,
+            mapping: Vec::new(),
+            debounce: DEBOUNCE,
+            candidate: None,
+            stable: 0,
+            pressed: None,
+        }
+    }
+
+    /// Map a key to an interaction
+    ///
+    /// A key mapped before is mapped to the new interaction.
+    pub fn with_key(mut self, key: char, interaction: Interaction) -> Self {
+        self.mapping.retain(|(k, _)| *k != key);
+        self.mapping.push((key, interaction));
+        self
+    }
+
+    /// Set the number of equal scans for a key to be pressed or released
+    ///
+    /// Default is 2 scans.
+    pub fn with_debounce(mut self, scans: u8) -> Self {
+        self.debounce = scans.max(1);
+        self
+    }
+
+    /// The key pressed right now, without debouncing
+    ///
+    /// If several keys are pressed, the first one in row order is returned.
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if a pin fails
+    pub fn read_key(&mut self) -> Result<Option<char>, PageError> {
+        for row in self.rows.iter_mut() {
+            row.set_high().map_err(|_| PageError)?;
+        }
+        let mut key = None;
+        for (r, row) in self.rows.iter_mut().enumerate() {
+            row.set_low().map_err(|_| PageError)?;
+            for (c, col) in self.cols.iter_mut().enumerate() {
+                if key.is_none() && col.is_low().map_err(|_| PageError)? {
+                    key = Some(self.keys[r][c]);
+                }
+            }
+            row.set_high().map_err(|_| PageError)?;
+        }
+        Ok(key)
+    }
+
+    /// Scan the keypad
+    ///
+    /// Is called periodically, e.g. every 10ms. A key held down is one input.
+    ///
+    /// Returns
+    ///
+    /// * `Ok(Some(input))` - the input of a key as soon as it is pressed
+    /// * `Err(PageError)` - if a pin fails
+    pub fn scan(&mut self) -> Result<Option<InputEvent>, PageError> {
+        let key = self.read_key()?;
+        if key == self.candidate {
+            self.stable = self.stable.saturating_add(1);
+        } else {
+            self.candidate = key;
+            self.stable = 1;
+        }
+        if self.stable >= self.debounce && self.pressed != self.candidate {
+            self.pressed = self.candidate;
+            return Ok(self.pressed.map(|key| self.input(key)));
+        }
+        Ok(None)
+    }
+
+    /// The input of a key - the interaction mapped or the character
+    pub fn input(&self, key: char) -> InputEvent {
+        match self.mapping.iter().find(|(k, _)| *k == key) {
+            Some((_, interaction)) => InputEvent::Primary(*interaction),
+            None => InputEvent::Char(key),
+        }
+    }
+
+    /// The row and column pins
+    pub fn release(self) -> ([R; ROWS], [L; COLS]) {
+        (self.rows, self.cols)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,143 @@
+use super::*;
+use crate::page::{BasicPage, EnterStringPage, MenuPage};
+use crate::testing::FrameBufferDisplay;
+use crate::{CellSetting, PageManager, PageNavigation};
+use embedded_hal::digital::ErrorType;
+use std::cell::RefCell;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+/// The wiring of a keypad: rows driven low and keys pressed
+#[derive(Default)]
+struct Matrix {
+    low_rows: Vec<usize>,
+    pressed: Vec<(usize, usize)>,
+}
+
+struct RowPin(Rc<RefCell<Matrix>>, usize);
+
+struct ColPin(Rc<RefCell<Matrix>>, usize);
+
+impl ErrorType for RowPin {
+    type Error = Infallible;
+}
+
+impl OutputPin for RowPin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.0.borrow_mut().low_rows.push(self.1);
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.0.borrow_mut().low_rows.retain(|r| *r != self.1);
+        Ok(())
+    }
+}
+
+impl ErrorType for ColPin {
+    type Error = Infallible;
+}
+
+impl InputPin for ColPin {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        self.is_low().map(|low| !low)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        let matrix = self.0.borrow();
+        Ok(matrix
+            .pressed
+            .iter()
+            .any(|(r, c)| *c == self.1 && matrix.low_rows.contains(r)))
+    }
+}
+
+fn keypad() -> (Rc<RefCell<Matrix>>, Keypad<RowPin, ColPin, 4, 4>) {
+    let matrix = Rc::new(RefCell::new(Matrix::default()));
+    let rows = [0, 1, 2, 3].map(|r| RowPin(Rc::clone(&matrix), r));
+    let cols = [0, 1, 2, 3].map(|c| ColPin(Rc::clone(&matrix), c));
+    (matrix, Keypad::new_4x4(rows, cols))
+}
+
+#[test]
+fn read_pressed_key() {
+    let (matrix, mut sut) = keypad();
+    assert_eq!(sut.read_key().unwrap(), None);
+    matrix.borrow_mut().pressed = vec![(2, 1)];
+    assert_eq!(sut.read_key().unwrap(), Some('8'));
+    matrix.borrow_mut().pressed = vec![(3, 3), (1, 0)];
+    assert_eq!(sut.read_key().unwrap(), Some('4'));
+    assert!(matrix.borrow().low_rows.is_empty());
+}
+
+#[test]
+fn map_keys() {
+    let (_, sut) = keypad();
+    assert_eq!(sut.input('5'), InputEvent::Char('5'));
+    assert_eq!(sut.input('#'), InputEvent::Char('#'));
+    assert_eq!(sut.input('D'), InputEvent::Primary(Interaction::Action));
+    assert_eq!(sut.input('*'), InputEvent::Primary(Interaction::Home));
+    let sut = sut.with_key('#', Interaction::Action);
+    assert_eq!(sut.input('#'), InputEvent::Primary(Interaction::Action));
+}
+
+#[test]
+fn debounce_scans() {
+    let (matrix, mut sut) = keypad();
+    assert_eq!(sut.scan().unwrap(), None);
+    matrix.borrow_mut().pressed = vec![(0, 0)];
+    assert_eq!(sut.scan().unwrap(), None);
+    assert_eq!(sut.scan().unwrap(), Some(InputEvent::Char('1')));
+    assert_eq!(sut.scan().unwrap(), None);
+    // bouncing release
+    matrix.borrow_mut().pressed.clear();
+    assert_eq!(sut.scan().unwrap(), None);
+    matrix.borrow_mut().pressed = vec![(0, 0)];
+    assert_eq!(sut.scan().unwrap(), None);
+    matrix.borrow_mut().pressed.clear();
+    assert_eq!(sut.scan().unwrap(), None);
+    assert_eq!(sut.scan().unwrap(), None);
+    matrix.borrow_mut().pressed = vec![(1, 3)];
+    sut.scan().unwrap();
+    assert_eq!(
+        sut.scan().unwrap(),
+        Some(InputEvent::Primary(Interaction::Next))
+    );
+}
+
+#[test]
+fn enter_number_by_keypad() {
+    let value: CellSetting<u32> = Default::default();
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(FrameBufferDisplay::new(16, 3), Box::new(home));
+    m.register_sub(Box::new(EnterStringPage::new(
+        BasicPage::new("Amount", None),
+        "0123456789",
+        None,
+        Some("Ok"),
+        &value,
+    )));
+    m.dispatch(PageNavigation::SystemStart).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+
+    let (matrix, mut sut) = keypad();
+    let mut scan = |key: Option<(usize, usize)>| {
+        matrix.borrow_mut().pressed = key.into_iter().collect();
+        (0..2)
+            .filter_map(|_| sut.scan().unwrap())
+            .collect::<Vec<_>>()
+    };
+    let inputs: Vec<_> = [Some((2, 2)), None, Some((1, 1)), None]
+        .iter()
+        .flat_map(|key| scan(*key))
+        .collect();
+    assert_eq!(inputs, vec![InputEvent::Char('9'), InputEvent::Char('5')]);
+    // the page starts with the stored value 0
+    for input in inputs {
+        m.dispatch_input(input).unwrap();
+    }
+    assert_eq!(
+        m.display().last_frame(),
+        Some("|Amount          |\n|095             |\n|Action: 5       |")
+    );
+}
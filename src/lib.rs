@@ -20,7 +20,8 @@
 //! | fourth | back      | navigate to the previous position |
 //! | fifth  | home      | go to home page, reset  |
 //!
-//! A rotary knop can be modelled as three buttons (action, next, previous).
+//! A rotary knop can be modelled as three buttons (action, next, previous),
+//! see `RotaryEncoder`.
 //!
 //! ## Output
 //!
@@ -100,7 +101,8 @@
 #![allow(clippy::type_complexity)]
 
 /// Possible Interactions derived from the input
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Interaction {
     /// Primary HMI event to trigger some action e.g. go to next page
     Action,
@@ -115,15 +117,68 @@ pub enum Interaction {
     Home,
 }
 
+/// Input of the HMI - a primary interaction or a richer input
+///
+/// Keypads, host simulators or remote protocols provide more than the primary
+/// interactions. Pages take the richer inputs by
+/// `PageInteractionInterface::dispatch_char()` and `dispatch_custom()`; they
+/// are dispatched by `PageManager::dispatch_input()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InputEvent {
+    /// One of the primary interactions
+    Primary(Interaction),
+    /// A character entered directly, e.g. a digit of a numeric keypad
+    Char(char),
+    /// An event defined by the application, e.g. a function key or a command
+    /// of a remote protocol
+    Custom(u8),
+}
+
+impl From<Interaction> for InputEvent {
+    fn from(interaction: Interaction) -> Self {
+        InputEvent::Primary(interaction)
+    }
+}
+
+/// An interaction and the time it was detected
+///
+/// Input drivers that know when an interaction happened, e.g. by a timestamp
+/// taken in the interrupt service routine, dispatch it by
+/// `PageManager::dispatch_event()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InteractionEvent {
+    pub interaction: Interaction,
+    /// Milliseconds of a free running clock; the clock may wrap around
+    pub timestamp: u32,
+}
+
+impl InteractionEvent {
+    pub fn new(interaction: Interaction, timestamp: u32) -> Self {
+        InteractionEvent {
+            interaction,
+            timestamp,
+        }
+    }
+}
+
 /// Page navigation events dispatched by pagemanager
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum PageNavigation {
     /// Start the HMI.
     SystemStart,
     /// Stop the HMI.
     SystemStop,
+    /// The HMI is shut down, i.e. the event loop ends.
+    ///
+    /// Is returned instead of `SystemStop` once the shutdown is over.
+    Terminate,
     /// Stay at the active page and initiate an update.
     Update,
+    /// Stay at the active page, let it reload the data it caches and initiate an update.
+    Reload,
     /// Navigate to the left page.
     Left,
     /// Navigate to the right page.
@@ -132,23 +187,157 @@ pub enum PageNavigation {
     Up,
     /// Navigate down the n-th subpage. Start counting with one.
     NthSubpage(usize),
-    /// Event to go to home page.
+    /// Event to go to home page, see `PageManager::set_home()`.
     Home,
+    /// Navigate to a page and hand it a parameter, e.g. the setting to edit.
+    ///
+    /// The page gets the parameter by `PageBaseInterface::on_enter()`, i.e.
+    /// one page can serve many purposes.
+    ToWith(PageId, usize),
+    /// Navigate to a page, e.g. the next step of a timed wizard.
+    To(PageId),
+    /// The first navigation leading to another page, e.g. `Left` and `Home`
+    /// at the end of a slideshow.
+    ///
+    /// Navigations not changing the page, e.g. `Left` at the edge of a level
+    /// without wrapping around, are skipped; the page stays active if none
+    /// leads away. Navigations not meant to change the page, e.g. `Update`
+    /// or `SystemStop`, are always taken.
+    FirstOf(#[cfg_attr(feature = "defmt", defmt(Debug2Format))] &'static [PageNavigation]),
+}
+
+/// Identifier of a page registered at the PageManager
+///
+/// Ids are assigned in the order of registration. The home page has the id 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PageId(usize);
+
+impl PageId {
+    /// The page the PageManager was constructed with, i.e. the root of the default mode
+    pub const HOME: PageId = PageId(0);
+
+    /// The number of the page in order of registration
+    pub fn index(&self) -> usize {
+        self.0
+    }
 }
 
 /// Any error a page update my run into
 #[derive(Debug, Clone)]
 pub struct PageError;
 
+/// A result a sub page returns to its parent, e.g. the value entered
+///
+/// Holds a value of any type; the parent gets it by the type it expects.
+pub struct PageResult(Box<dyn std::any::Any>);
+
+impl PageResult {
+    /// Arguments
+    ///
+    /// * `value`: - The value returned to the parent page
+    pub fn new<T: std::any::Any>(value: T) -> Self {
+        PageResult(Box::new(value))
+    }
+
+    /// The value if it is of type `T`
+    pub fn get<T: std::any::Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+/// A sub page of the page updated, see `PageBaseInterface::update_with_subpages()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SubPageItem<'b> {
+    /// The title of the sub page
+    pub title: &'b str,
+    /// The id of the sub page, e.g. to navigate to it
+    pub id: PageId,
+    /// The sub page is hidden from menus, see `PageManager::set_page_hidden()`
+    pub hidden: bool,
+    /// The sub page requires a higher access level, see `PageManager::set_page_access()`
+    pub disabled: bool,
+    /// The preview of the sub page, see `PageBaseInterface::preview()`
+    pub preview: Option<&'b str>,
+}
+
+impl<'b> SubPageItem<'b> {
+    /// The sub page is shown by menus, i.e. it is neither hidden nor disabled
+    ///
+    /// `PageNavigation::NthSubpage` counts the sub pages shown only.
+    pub fn is_shown(&self) -> bool {
+        !self.hidden && !self.disabled
+    }
+
+    /// The titles of the sub pages shown - adapts the sub pages to `PageBaseInterface::update()`
+    pub fn titles(
+        subpages: Box<dyn Iterator<Item = SubPageItem<'b>> + 'b>,
+    ) -> Box<dyn Iterator<Item = &'b str> + 'b> {
+        Box::new(
+            subpages
+                .filter(SubPageItem::is_shown)
+                .map(|subpage| subpage.title),
+        )
+    }
+}
+
+/// The titles of the pages next to the page updated on its level, see `PageBaseInterface::set_siblings()`
+///
+/// Shown as navigation hint, e.g. `◄ Time | Temperature ►`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Siblings<'b> {
+    /// The page navigated to by `PageNavigation::Right`, e.g. by the previous interaction
+    pub previous: Option<&'b str>,
+    /// The page navigated to by `PageNavigation::Left`, e.g. by the next interaction
+    pub next: Option<&'b str>,
+}
+
+impl std::fmt::Display for Siblings<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.previous, self.next) {
+            (Some(previous), Some(next)) => write!(f, "◄ {} | {} ►", previous, next),
+            (Some(previous), None) => write!(f, "◄ {}", previous),
+            (None, Some(next)) => write!(f, "{} ►", next),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
 /// Data structures that implement the Page trait are Pages and can be handled
 /// by the PageManager type
 ///
 /// Args
-/// * `display_driver` - The display to render the page content
-/// * `
-pub trait PageInterface<D>: PageInteractionInterface {
+/// * `D` - The display to render the page content
+/// * `C` - The application context shared by all pages - default is no context.
+///   Pages can read sensors or trigger actuators via the context without
+///   resorting to global data.
+pub trait PageInterface<D, C = ()>: PageInteractionInterface {
     /// Force updating the page content on the display
     fn display(&self, display_driver: &mut D);
+
+    /// Trigger a page-internal update with access to the application context
+    ///
+    /// Is called by `PageManager` instead of `update_with_subpages()`.
+    /// Default is to ignore the context and call `update_with_subpages()`.
+    fn update_with_context<'a>(
+        &mut self,
+        _context: &mut C,
+        subpages: Option<Box<dyn Iterator<Item = SubPageItem<'a>> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.update_with_subpages(subpages)
+    }
+
+    /// Handle page interaction with access to the application context
+    ///
+    /// Is called by `PageManager` instead of `dispatch()`.
+    /// Default is to ignore the context and call `dispatch()`.
+    fn dispatch_with_context(
+        &mut self,
+        _context: &mut C,
+        interaction: Interaction,
+    ) -> PageNavigation {
+        self.dispatch(interaction)
+    }
 }
 
 /// Data structures that implement the Page trait are Pages and can be handled
@@ -161,13 +350,13 @@ pub trait PageBaseInterface {
     /// Handles Page Lifetime management
     ///
     /// Args:
-    ///     title_of_subpages: Iterator to titles of subpages (Optional)
+    ///     title_of_subpages: Iterator to titles of subpages (Optional); the
+    ///         iterator knows the number of sub pages, i.e. `size_hint()` is exact
     ///
     /// Returns:
     ///     `Ok(<PageNavigation>)` - In case update is went well, to indicate the which page
     ///         to navigate to next.
-    ///     `Error` - Indicate an error. (Note: Could be on purpose to force a controlled
-    ///         gui process shutdown)
+    ///     `Error` - Indicate an error.
     fn update<'a>(
         &mut self,
         _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
@@ -175,10 +364,166 @@ pub trait PageBaseInterface {
         Ok(PageNavigation::Update)
     }
 
+    /// Trigger a page-internal update given the sub pages
+    ///
+    /// Is called by `PageManager` with all sub pages, including the hidden
+    /// and disabled ones, e.g. for menus rendering richer entries.
+    /// Default is to call `update()` with the titles of the sub pages shown,
+    /// see `SubPageItem::titles()`.
+    fn update_with_subpages<'a>(
+        &mut self,
+        subpages: Option<Box<dyn Iterator<Item = SubPageItem<'a>> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.update(subpages.map(SubPageItem::titles))
+    }
+
     /// Every page has a title - default is empty &str
     fn title(&self) -> &str {
         ""
     }
+
+    /// An abbreviated representation of the page - default is the title
+    ///
+    /// Is shown by auxiliary displays of a few characters, e.g. the value
+    /// without its unit on a 7-segment display, see `SegmentRenderer`.
+    fn short_text(&self) -> &str {
+        self.title()
+    }
+
+    /// A preview of the content shown next to the title by menus - default is none
+    ///
+    /// Is asked for with every update of the parent page, e.g. the value of a
+    /// setting or a measured value.
+    fn preview(&self) -> Option<&str> {
+        None
+    }
+
+    /// A menu page navigates to its sub pages - default is false
+    ///
+    /// Pages that navigate to sub pages via `PageNavigation::NthSubpage` should
+    /// return true.
+    fn is_menu(&self) -> bool {
+        false
+    }
+
+    /// A setting page changes settings - default is false
+    ///
+    /// Is used to indicate the HMI is in settings, e.g. by a LED.
+    fn is_setting(&self) -> bool {
+        false
+    }
+
+    /// An alarm page shows an alarm - default is false
+    ///
+    /// Is used to indicate an active alarm, e.g. by a LED.
+    fn is_alarm(&self) -> bool {
+        false
+    }
+
+    /// A locked page must not be left - default is false
+    ///
+    /// Is used by `PageManager` to keep the page active, e.g. while a firmware
+    /// update is running: navigations, alarms, shortcuts and the auto cycle
+    /// are held back until the page is not locked anymore.
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    /// The page is not the active page anymore - default is to do nothing
+    ///
+    /// Is called by `PageManager` after navigating away from the page.
+    /// Pages can release resources they only need while being active.
+    fn on_leave(&mut self) {}
+
+    /// The page is the active page now - default is to do nothing
+    ///
+    /// Is called by `PageManager` after navigating to the page. A page entered
+    /// by `PageNavigation::ToWith` gets the parameter, e.g. which setting to
+    /// edit. A parent page entered by `PageNavigation::Up` gets the result of
+    /// the sub page left, see `take_result()`.
+    fn on_enter(&mut self, _param: Option<usize>, _result: Option<&PageResult>) {}
+
+    /// The result returned to the parent page - default is none
+    ///
+    /// Is called by `PageManager` when navigating up from the page, e.g. a page
+    /// to select from a list returns the index selected.
+    fn take_result(&mut self) -> Option<PageResult> {
+        None
+    }
+
+    /// The page can be left - default is yes
+    ///
+    /// Is called by `PageManager` before navigating away from the page.
+    /// Pages with uncommitted changes, e.g. a half entered value, veto with a
+    /// message why, see `PageManager::leave_veto()`.
+    fn can_leave(&self) -> Result<(), &str> {
+        Ok(())
+    }
+
+    /// The HMI shuts down - default is to do nothing
+    ///
+    /// Is called by `PageManager` for every registered page when
+    /// `PageNavigation::SystemStop` is dispatched, before the shutdown page is
+    /// shown. Pages can commit pending settings or stop actuators.
+    fn on_shutdown(&mut self) {}
+
+    /// Reload the data the page caches - default is to do nothing
+    ///
+    /// Is called by `PageManager` on the active page when
+    /// `PageNavigation::Reload` is dispatched, before the page is updated.
+    /// Pages re-read bound settings or data sources, e.g. after a background
+    /// task changed them.
+    fn reload(&mut self) {}
+
+    /// The selected item of a page with selectable items - default is none
+    ///
+    /// Is used to persist the state of the HMI, e.g. the selected menu entry.
+    /// Items are counted starting with one.
+    fn selected(&self) -> Option<usize> {
+        None
+    }
+
+    /// Select an item as previously reported by `selected()` - default is to ignore it
+    fn select(&mut self, _index: usize) {}
+
+    /// Feedback requested by the page since the last call - default is none
+    ///
+    /// Is called by `PageManager` after dispatching to the page. Pages can
+    /// request feedback, e.g. when a setting is committed.
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        None
+    }
+
+    /// Access level granted by the page since the last call - default is none
+    ///
+    /// Is called by `PageManager` after dispatching to the page, e.g. a PIN
+    /// page grants a higher access level once the PIN is entered correctly.
+    fn take_access_level(&mut self) -> Option<AccessLevel> {
+        None
+    }
+
+    /// The part of the content shown if the page scrolls - default is none
+    ///
+    /// Is used by renderers to indicate there is more content than visible,
+    /// e.g. by a scrollbar.
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        None
+    }
+
+    /// Adapt the page content to the display - default is to ignore it
+    ///
+    /// Is called by `PageManager` before updating the page if the display
+    /// capabilities are known. Pages can wrap, truncate or paginate their content.
+    fn set_display_info(&mut self, _info: &dyn DisplayInfo) {}
+
+    /// Show the titles of the pages next to the page - default is to ignore them
+    ///
+    /// Is called by `PageManager` before updating the page, after
+    /// `set_display_info()`. The siblings respect the navigation policy,
+    /// e.g. the first page of the level is the next one of the last page if
+    /// navigation wraps around. Pages render them as navigation hints, e.g.
+    /// `◄ Time | Temperature ►`, see `Siblings`.
+    fn set_siblings(&mut self, _siblings: Siblings<'_>) {}
 }
 
 /// A page is responsible to implement user interaction
@@ -205,17 +550,144 @@ pub trait PageInteractionInterface: PageBaseInterface {
             Interaction::Previous => PageNavigation::Right,
         }
     }
+
+    /// Handle a character entered directly, e.g. by a keypad - default is to ignore it
+    ///
+    /// Pages to enter text or numbers can take the character instead of
+    /// selecting it by interactions.
+    fn dispatch_char(&mut self, _c: char) -> PageNavigation {
+        PageNavigation::Update
+    }
+
+    /// Handle an event defined by the application - default is to ignore it
+    ///
+    /// Pages can e.g. take a function key as shortcut to a sub page.
+    fn dispatch_custom(&mut self, _code: u8) -> PageNavigation {
+        PageNavigation::Update
+    }
 }
 
+#[macro_use]
+mod trace;
+
+mod adc;
+mod alarm;
+mod chord;
+mod console;
+mod display;
+mod dot;
+mod feedback;
+mod i18n;
+mod ir;
+mod joystick;
+#[cfg(feature = "keypad")]
+pub mod keypad;
 mod lifetime;
+mod multi_display;
+mod number;
+mod orientation;
+#[macro_use]
 pub mod page;
+// after `page`, all pages are rendered by its macro
+#[cfg(feature = "graphics")]
+pub mod graphics;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
 mod page_manager;
+mod queue;
+mod refresh;
+mod registry;
+#[cfg(feature = "remote")]
+pub mod remote;
+mod rotary;
+mod script;
+mod segment;
 mod setting;
+#[cfg(feature = "sim-terminal")]
+pub mod simulator;
+#[cfg(feature = "ssd1306")]
+pub mod ssd1306;
+mod static_manager;
+#[cfg(feature = "async")]
+mod task;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+mod time;
+mod touch;
+mod transition;
+mod units;
+mod update;
+mod validation;
+#[cfg(feature = "vt100")]
+pub mod vt100;
+#[cfg(feature = "web-sim")]
+pub mod web_sim;
 
 // Re-exports
 #[allow(unused_imports)]
+pub use adc::AdcButtons;
+#[allow(unused_imports)]
+pub use alarm::{Alarm, AlarmId, AlarmScheduler, AlarmTime};
+#[allow(unused_imports)]
+pub use chord::{ChordEvent, ChordInput};
+#[allow(unused_imports)]
+pub use console::LogBuffer;
+#[allow(unused_imports)]
+pub use display::{
+    center, char_at, pad, paginated_title, truncate, width, wrap, DisplayInfo, DisplaySize,
+    Marquee, ScrollPosition, ELLIPSIS,
+};
+#[allow(unused_imports)]
+pub use feedback::{
+    Announcement, AudioRenderer, Feedback, FeedbackSink, Indication, IndicatorSink,
+};
+#[allow(unused_imports)]
+pub use i18n::{TextId, Translations};
+#[allow(unused_imports)]
+pub use ir::{IrCode, IrInput, IrProtocol};
+#[allow(unused_imports)]
+pub use joystick::{Joystick, JoystickState};
+#[allow(unused_imports)]
 pub use lifetime::PageLifetime;
 #[allow(unused_imports)]
-pub use page_manager::PageManager;
+pub use multi_display::{OnBoth, OnPrimary, OnSecondary};
+#[allow(unused_imports)]
+pub use number::NumberFormat;
+#[allow(unused_imports)]
+pub use orientation::{Orientation, Rotation};
+#[allow(unused_imports)]
+pub use page_manager::{
+    AccessLevel, ActivePage, DisplayMaintenance, EdgeBehavior, Heartbeat, ModeId,
+    NavigationMiddleware, NavigationPolicy, PageManager, PageTreeItem, ShutdownTask, UsageObserver,
+};
+#[allow(unused_imports)]
+pub use queue::InteractionQueue;
+#[allow(unused_imports)]
+pub use refresh::{Refresh, RefreshDisplay};
+#[allow(unused_imports)]
+pub use registry::{InfoField, SettingsLoad, SettingsRegistry, SettingsSnapshot, TextSetting};
+#[allow(unused_imports)]
+pub use rotary::RotaryEncoder;
+#[allow(unused_imports)]
+pub use script::{InputRecorder, ScriptStep, ScriptedInput};
+#[allow(unused_imports)]
+pub use segment::{SegmentDisplay, SegmentKind, SegmentRenderer};
+#[allow(unused_imports)]
+pub use setting::{CellSetting, Setting, SettingsStore};
+#[allow(unused_imports)]
+pub use static_manager::StaticPageManager;
+#[cfg(feature = "async")]
+#[allow(unused_imports)]
+pub use task::PageManagerTask;
+#[allow(unused_imports)]
+pub use time::{DateTime, TimeSource};
+#[allow(unused_imports)]
+pub use touch::{PointerInput, TouchCalibration};
+#[allow(unused_imports)]
+pub use transition::{Transition, TransitionDisplay};
+#[allow(unused_imports)]
+pub use units::{Quantity, TemperatureUnit, UnitSystem, Units};
+#[allow(unused_imports)]
+pub use update::{UpdateProgress, UpdateStatus, STAGE_LEN};
 #[allow(unused_imports)]
-pub use setting::{CellSetting, Setting};
+pub use validation::Diagnostic;
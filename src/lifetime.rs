@@ -6,6 +6,8 @@ use crate::PageNavigation;
 /// Each page type is responsible to care if page lifetime is to be considered.
 /// Page lifetime is measured in update events. I.e. an update event shall cause a call to
 /// increase_age.
+/// The target is any navigation, e.g. `PageNavigation::To` a page or a chain of
+/// fallbacks by `PageNavigation::FirstOf` - the next slide, home after the last one.
 #[derive(Clone, Copy)]
 pub struct PageLifetime {
     target: PageNavigation,
@@ -0,0 +1,175 @@
+//! Remote inspection and control over MQTT
+//!
+//! Requires the `mqtt` feature. Devices in the field are driven by the
+//! commands of the `remote` feature, received by any MQTT client of the
+//! application, e.g. `rumqttc` or `paho-mqtt`. The rendered pages are
+//! published, so a dashboard shows what the device shows.
+//!
+//! Topics below the prefix given, e.g. `hmi/device-42`:
+//!
+//! | Topic | Direction | Payload |
+//! | ----- | --------- | ------- |
+//! | `<prefix>/cmd` | subscribed | A command of the remote control, e.g. `next` or `nav sub 2` |
+//! | `<prefix>/title` | published, retained | The title of the page shown |
+//! | `<prefix>/text` | published, retained | The content of the page shown; lines are separated by `\n` |
+//! | `<prefix>/err` | published | The reason an invalid command is rejected |
+//!
+//! Pages are only published if title or content changed, so the update
+//! ticks of the page manager do not flood the broker.
+//!
+//! The `MqttBridge` is a display all pages of this crate render to. Devices
+//! with a display of their own mirror the page text by
+//! `MqttBridge::publish_frame()`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let bridge = MqttBridge::new(client, "hmi/device-42")?;
+//! let mut m = PageManager::new(bridge, Box::new(home));
+//! let mut navigation = m.dispatch(PageNavigation::SystemStart).unwrap();
+//! loop {
+//!     navigation = match m.display_mut().poll() {
+//!         Ok(Some(command)) => command.dispatch(&mut m),
+//!         _ => m.dispatch(navigation),
+//!     }
+//!     .unwrap();
+//! }
+//! ```
+
+use super::remote::Command;
+use super::{PageError, ScrollPosition};
+
+/// The MQTT client of the application
+///
+/// Adapts the client library used; messages received are queued by the
+/// client and taken without blocking.
+pub trait MqttClient {
+    /// Subscribe to a topic
+    fn subscribe(&mut self, topic: &str) -> Result<(), PageError>;
+
+    /// Publish a message
+    ///
+    /// Arguments
+    ///
+    /// * `topic`: - The topic published to
+    /// * `payload`: - The message
+    /// * `retain`: - The broker keeps the message for later subscribers
+    fn publish(&mut self, topic: &str, payload: &[u8], retain: bool) -> Result<(), PageError>;
+
+    /// Take a message received, if any, as topic and payload - must not block
+    fn receive(&mut self) -> Option<(String, Vec<u8>)>;
+}
+
+/// Remote inspection and control over MQTT
+pub struct MqttBridge<M> {
+    client: M,
+    prefix: String,
+    published: Option<(String, String)>,
+}
+
+impl<M: MqttClient> MqttBridge<M> {
+    /// Subscribe to the command topic
+    ///
+    /// Arguments
+    ///
+    /// * `client`: - The MQTT client, connected to the broker
+    /// * `prefix`: - The topic prefix of the device, e.g. `hmi/device-42`
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if the subscription fails
+    pub fn new(mut client: M, prefix: &str) -> Result<Self, PageError> {
+        client.subscribe(&format!("{}/cmd", prefix))?;
+        Ok(MqttBridge {
+            client,
+            prefix: String::from(prefix),
+            published: None,
+        })
+    }
+
+    /// Receive a command without blocking
+    ///
+    /// Takes the messages received until a command is found. Messages of
+    /// other topics are dropped; invalid commands are answered on the error
+    /// topic.
+    ///
+    /// Returns
+    ///
+    /// * `Ok(None)` - if no command is received
+    /// * `Err(PageError)` - if publishing the error fails
+    pub fn poll(&mut self) -> Result<Option<Command>, PageError> {
+        while let Some((topic, payload)) = self.client.receive() {
+            if topic != self.topic("cmd") {
+                continue;
+            }
+            let command = core::str::from_utf8(&payload)
+                .map_err(|_| PageError)
+                .and_then(|line| Command::parse(line.trim()));
+            match command {
+                Ok(command) => return Ok(Some(command)),
+                Err(_) => {
+                    warn!("invalid mqtt command");
+                    let topic = self.topic("err");
+                    self.client.publish(&topic, b"unknown command", false)?;
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Publish the text of a rendered page - unless already published
+    ///
+    /// Arguments
+    ///
+    /// * `title`: - The title of the page
+    /// * `message`: - The content; lines are separated by `\n`
+    pub fn publish_frame(&mut self, title: &str, message: &str) -> Result<(), PageError> {
+        if let Some((published_title, published_message)) = &self.published {
+            if published_title == title && published_message == message {
+                return Ok(());
+            }
+        }
+        self.published = None;
+        let topic = self.topic("title");
+        self.client.publish(&topic, title.as_bytes(), true)?;
+        let topic = self.topic("text");
+        self.client.publish(&topic, message.as_bytes(), true)?;
+        self.published = Some((String::from(title), String::from(message)));
+        Ok(())
+    }
+
+    /// Show a page by publishing its text - errors of the client are ignored
+    pub fn update(&mut self, title: &str, message: &str) {
+        let _ = self.publish_frame(title, message);
+    }
+
+    /// Show a scrolling page - the title is published as is
+    pub fn update_scrolled(
+        &mut self,
+        title: &str,
+        message: &str,
+        _position: Option<ScrollPosition>,
+    ) {
+        self.update(title, message);
+    }
+
+    /// The MQTT client
+    pub fn client_mut(&mut self) -> &mut M {
+        &mut self.client
+    }
+
+    /// The MQTT client
+    pub fn into_inner(self) -> M {
+        self.client
+    }
+
+    fn topic(&self, name: &str) -> String {
+        format!("{}/{}", self.prefix, name)
+    }
+}
+
+// ** All pages of this crate render to the MQTT bridge
+impl_text_display!(<M: MqttClient> MqttBridge<M>);
+
+#[cfg(test)]
+mod tests;
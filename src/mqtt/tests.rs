@@ -0,0 +1,116 @@
+use super::*;
+use crate::page::{BasicPage, MenuPage, TextPage};
+use crate::{Interaction, PageManager, PageNavigation};
+use std::collections::VecDeque;
+
+/// MQTT client with received messages given and published messages collected
+#[derive(Default)]
+struct ClientMock {
+    subscribed: Vec<String>,
+    received: VecDeque<(String, Vec<u8>)>,
+    published: Vec<(String, String, bool)>,
+}
+
+impl ClientMock {
+    fn receive(&mut self, topic: &str, payload: &str) {
+        self.received
+            .push_back((String::from(topic), Vec::from(payload.as_bytes())));
+    }
+
+    fn take_published(&mut self) -> Vec<(String, String, bool)> {
+        std::mem::take(&mut self.published)
+    }
+}
+
+impl MqttClient for ClientMock {
+    fn subscribe(&mut self, topic: &str) -> Result<(), PageError> {
+        self.subscribed.push(String::from(topic));
+        Ok(())
+    }
+
+    fn publish(&mut self, topic: &str, payload: &[u8], retain: bool) -> Result<(), PageError> {
+        let payload = String::from_utf8(payload.to_vec()).unwrap();
+        self.published.push((String::from(topic), payload, retain));
+        Ok(())
+    }
+
+    fn receive(&mut self) -> Option<(String, Vec<u8>)> {
+        self.received.pop_front()
+    }
+}
+
+fn message(topic: &str, payload: &str, retain: bool) -> (String, String, bool) {
+    (String::from(topic), String::from(payload), retain)
+}
+
+#[test]
+fn poll_commands_of_the_command_topic() {
+    let mut bridge = MqttBridge::new(ClientMock::default(), "hmi/dev").unwrap();
+    assert_eq!(bridge.client_mut().subscribed, vec!["hmi/dev/cmd"]);
+    assert_eq!(bridge.poll().unwrap(), None);
+
+    let client = bridge.client_mut();
+    client.receive("hmi/other/cmd", "home");
+    client.receive("hmi/dev/cmd", "jump");
+    client.receive("hmi/dev/cmd", "nav sub 2\n");
+    client.receive("hmi/dev/cmd", "next");
+    assert_eq!(
+        bridge.poll().unwrap(),
+        Some(Command::Navigation(PageNavigation::NthSubpage(2)))
+    );
+    assert_eq!(
+        bridge.poll().unwrap(),
+        Some(Command::Interaction(Interaction::Next))
+    );
+    assert_eq!(bridge.poll().unwrap(), None);
+    assert_eq!(
+        bridge.client_mut().take_published(),
+        vec![message("hmi/dev/err", "unknown command", false)]
+    );
+}
+
+#[test]
+fn publish_changed_frames_only() {
+    let mut bridge = MqttBridge::new(ClientMock::default(), "hmi/dev").unwrap();
+    bridge.update("Info", "Line 1\nLine 2");
+    bridge.update("Info", "Line 1\nLine 2");
+    bridge.update("Info", "Line 1\nLine 3");
+    assert_eq!(
+        bridge.into_inner().take_published(),
+        vec![
+            message("hmi/dev/title", "Info", true),
+            message("hmi/dev/text", "Line 1\nLine 2", true),
+            message("hmi/dev/title", "Info", true),
+            message("hmi/dev/text", "Line 1\nLine 3", true),
+        ]
+    );
+}
+
+#[test]
+fn remote_controlled_page_manager() {
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let bridge = MqttBridge::new(ClientMock::default(), "hmi/dev").unwrap();
+    let mut m = PageManager::new(bridge, Box::new(home));
+    m.register_sub(Box::new(TextPage::new(
+        BasicPage::new("Info", None),
+        "Line 1\nLine 2",
+    )));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.display_mut().client_mut().take_published();
+
+    m.display_mut()
+        .client_mut()
+        .receive("hmi/dev/cmd", "action");
+    let command = m.display_mut().poll().unwrap().unwrap();
+    command.dispatch(&mut m).unwrap();
+    assert_eq!(m.active().title, "Info");
+    assert_eq!(
+        m.display_mut().client_mut().take_published(),
+        vec![
+            message("hmi/dev/title", "Info", true),
+            message("hmi/dev/text", "Line 1\nLine 2", true),
+        ]
+    );
+    m.update().unwrap();
+    assert!(m.display_mut().client_mut().take_published().is_empty());
+}
@@ -0,0 +1,254 @@
+//! Pages rendering to one of two displays
+//!
+//! Devices with a secondary display, e.g. a 7-segment value display or a
+//! status OLED next to the main LCD, let the `PageManager` own both displays
+//! as a tuple `(primary, secondary)`. Where a page renders to is chosen when
+//! it is registered, by wrapping it:
+//!
+//! * `OnPrimary` - renders to the primary display only
+//! * `OnSecondary` - renders to the secondary display only
+//! * `OnBoth` - renders to both displays
+//!
+//! A page only needs to implement `PageInterface` for the displays it renders
+//! to. A display keeps what was rendered last, i.e. the secondary display
+//! keeps showing the last value while pages of the primary display are navigated.
+//!
+//! # Example
+//!
+//! ```
+//!     use embedded_multi_page_hmi::page::{BasicPage, TextPage};
+//!     use embedded_multi_page_hmi::{
+//!         OnBoth, OnPrimary, PageBaseInterface, PageInteractionInterface, PageInterface,
+//!         PageManager,
+//!     };
+//!
+//!     struct Lcd(String);
+//!     struct SevenSegment(u16);
+//!
+//!     struct Pressure(u16);
+//!     impl PageBaseInterface for Pressure {
+//!         fn title(&self) -> &str { "Pressure" }
+//!     }
+//!     impl PageInteractionInterface for Pressure {}
+//!     impl PageInterface<Lcd> for Pressure {
+//!         fn display(&self, lcd: &mut Lcd) { lcd.0 = format!("{} mbar", self.0); }
+//!     }
+//!     impl PageInterface<SevenSegment> for Pressure {
+//!         fn display(&self, segments: &mut SevenSegment) { segments.0 = self.0; }
+//!     }
+//!     impl PageInterface<Lcd> for TextPage {
+//!         fn display(&self, lcd: &mut Lcd) { lcd.0 = String::from(self.text); }
+//!     }
+//!
+//!     let home = TextPage::new(BasicPage::new("Home", None), "Hello");
+//!     let mut m = PageManager::new((Lcd(String::new()), SevenSegment(0)), Box::new(OnPrimary(home)));
+//!     m.register(Box::new(OnBoth(Pressure(1013))));
+//!     m.update().unwrap();
+//!     assert_eq!(m.display().0 .0, "1013 mbar");
+//!     assert_eq!(m.display().1 .0, 1013);
+//! ```
+
+use super::{
+    AccessLevel, DisplayInfo, Feedback, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageInterface, PageNavigation, PageResult, ScrollPosition, Siblings,
+    SubPageItem,
+};
+
+/// Forward the page behavior to the wrapped page
+macro_rules! forward_to_page {
+    ($name:ident) => {
+        impl<T: PageBaseInterface> PageBaseInterface for $name<T> {
+            fn update<'a>(
+                &mut self,
+                title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
+            ) -> Result<PageNavigation, PageError> {
+                self.0.update(title_of_subpages)
+            }
+
+            fn update_with_subpages<'a>(
+                &mut self,
+                subpages: Option<Box<dyn Iterator<Item = SubPageItem<'a>> + 'a>>,
+            ) -> Result<PageNavigation, PageError> {
+                self.0.update_with_subpages(subpages)
+            }
+
+            fn title(&self) -> &str {
+                self.0.title()
+            }
+
+            fn short_text(&self) -> &str {
+                self.0.short_text()
+            }
+
+            fn preview(&self) -> Option<&str> {
+                self.0.preview()
+            }
+
+            fn is_menu(&self) -> bool {
+                self.0.is_menu()
+            }
+
+            fn is_setting(&self) -> bool {
+                self.0.is_setting()
+            }
+
+            fn is_alarm(&self) -> bool {
+                self.0.is_alarm()
+            }
+
+            fn is_locked(&self) -> bool {
+                self.0.is_locked()
+            }
+
+            fn on_leave(&mut self) {
+                self.0.on_leave()
+            }
+
+            fn on_enter(&mut self, param: Option<usize>, result: Option<&PageResult>) {
+                self.0.on_enter(param, result)
+            }
+
+            fn take_result(&mut self) -> Option<PageResult> {
+                self.0.take_result()
+            }
+
+            fn can_leave(&self) -> Result<(), &str> {
+                self.0.can_leave()
+            }
+
+            fn on_shutdown(&mut self) {
+                self.0.on_shutdown()
+            }
+
+            fn reload(&mut self) {
+                self.0.reload()
+            }
+
+            fn selected(&self) -> Option<usize> {
+                self.0.selected()
+            }
+
+            fn select(&mut self, index: usize) {
+                self.0.select(index)
+            }
+
+            fn take_feedback(&mut self) -> Option<Feedback> {
+                self.0.take_feedback()
+            }
+
+            fn take_access_level(&mut self) -> Option<AccessLevel> {
+                self.0.take_access_level()
+            }
+
+            fn scroll_position(&self) -> Option<ScrollPosition> {
+                self.0.scroll_position()
+            }
+
+            fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+                self.0.set_display_info(info)
+            }
+
+            fn set_siblings(&mut self, siblings: Siblings<'_>) {
+                self.0.set_siblings(siblings)
+            }
+        }
+
+        impl<T: PageInteractionInterface> PageInteractionInterface for $name<T> {
+            fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+                self.0.dispatch(interaction)
+            }
+
+            fn dispatch_char(&mut self, c: char) -> PageNavigation {
+                self.0.dispatch_char(c)
+            }
+
+            fn dispatch_custom(&mut self, code: u8) -> PageNavigation {
+                self.0.dispatch_custom(code)
+            }
+        }
+    };
+}
+
+/// A page rendering to the primary display only
+pub struct OnPrimary<T>(pub T);
+
+/// A page rendering to the secondary display only
+pub struct OnSecondary<T>(pub T);
+
+/// A page rendering to both displays
+pub struct OnBoth<T>(pub T);
+
+forward_to_page!(OnPrimary);
+forward_to_page!(OnSecondary);
+forward_to_page!(OnBoth);
+
+impl<T: PageInterface<P, C>, P, S, C> PageInterface<(P, S), C> for OnPrimary<T> {
+    fn display(&self, display_driver: &mut (P, S)) {
+        self.0.display(&mut display_driver.0);
+    }
+
+    fn update_with_context<'a>(
+        &mut self,
+        context: &mut C,
+        subpages: Option<Box<dyn Iterator<Item = SubPageItem<'a>> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.0.update_with_context(context, subpages)
+    }
+
+    fn dispatch_with_context(
+        &mut self,
+        context: &mut C,
+        interaction: Interaction,
+    ) -> PageNavigation {
+        self.0.dispatch_with_context(context, interaction)
+    }
+}
+
+impl<T: PageInterface<S, C>, P, S, C> PageInterface<(P, S), C> for OnSecondary<T> {
+    fn display(&self, display_driver: &mut (P, S)) {
+        self.0.display(&mut display_driver.1);
+    }
+
+    fn update_with_context<'a>(
+        &mut self,
+        context: &mut C,
+        subpages: Option<Box<dyn Iterator<Item = SubPageItem<'a>> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.0.update_with_context(context, subpages)
+    }
+
+    fn dispatch_with_context(
+        &mut self,
+        context: &mut C,
+        interaction: Interaction,
+    ) -> PageNavigation {
+        self.0.dispatch_with_context(context, interaction)
+    }
+}
+
+/// The update and the dispatch with context are the ones for the primary display
+impl<T: PageInterface<P, C> + PageInterface<S, C>, P, S, C> PageInterface<(P, S), C> for OnBoth<T> {
+    fn display(&self, display_driver: &mut (P, S)) {
+        PageInterface::<P, C>::display(&self.0, &mut display_driver.0);
+        PageInterface::<S, C>::display(&self.0, &mut display_driver.1);
+    }
+
+    fn update_with_context<'a>(
+        &mut self,
+        context: &mut C,
+        subpages: Option<Box<dyn Iterator<Item = SubPageItem<'a>> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        PageInterface::<P, C>::update_with_context(&mut self.0, context, subpages)
+    }
+
+    fn dispatch_with_context(
+        &mut self,
+        context: &mut C,
+        interaction: Interaction,
+    ) -> PageNavigation {
+        PageInterface::<P, C>::dispatch_with_context(&mut self.0, context, interaction)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,51 @@
+use super::*;
+use crate::PageManager;
+
+struct Lcd(Vec<&'static str>);
+struct Segments(Vec<&'static str>);
+
+/// Page rendering its title to both kinds of displays
+struct Page(&'static str);
+
+impl PageBaseInterface for Page {
+    fn title(&self) -> &str {
+        self.0
+    }
+}
+
+impl PageInteractionInterface for Page {}
+
+impl PageInterface<Lcd> for Page {
+    fn display(&self, display_driver: &mut Lcd) {
+        display_driver.0.push(self.0);
+    }
+}
+
+impl PageInterface<Segments> for Page {
+    fn display(&self, display_driver: &mut Segments) {
+        display_driver.0.push(self.0);
+    }
+}
+
+#[test]
+fn render_where_routed() {
+    let mut m = PageManager::new(
+        (Lcd(Vec::new()), Segments(Vec::new())),
+        Box::new(OnPrimary(Page("Home"))),
+    );
+    m.register(Box::new(OnSecondary(Page("Value"))));
+    m.register(Box::new(OnBoth(Page("Both"))));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.display().0 .0, vec!["Home", "Both"]);
+    assert_eq!(m.display().1 .0, vec!["Value", "Both"]);
+}
+
+#[test]
+fn forward_to_the_page() {
+    let mut page = OnSecondary(Page("Value"));
+    assert_eq!(page.title(), "Value");
+    assert_eq!(page.dispatch(Interaction::Next), PageNavigation::Left);
+    assert_eq!(page.update(None).unwrap(), PageNavigation::Update);
+}
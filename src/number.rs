@@ -0,0 +1,197 @@
+use super::PageError;
+
+/// Maximal decimal digits of an `i64`
+const DIGITS: usize = 19;
+
+/// Bytes of the buffer of `NumberFormat::push_to()`
+const BUFFER: usize = 64;
+
+/// Two digits padded by zeros, e.g. minutes of a time
+pub(crate) const TWO_DIGITS: NumberFormat = NumberFormat::new().with_width(2).with_zeros();
+
+/// Format integers and fixed-point numbers into a buffer - without heap and floats
+///
+/// Numbers are right aligned to a width, so values keep their position on
+/// character displays when they change. Fixed-point numbers are integers
+/// scaled by a power of ten, e.g. `2150` with two decimals is `21.50`.
+/// Thousands are separated if a separator is given.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::NumberFormat;
+///
+///     let mut buffer = [0u8; 16];
+///     let temperature = NumberFormat::new().with_decimals(1).with_width(6);
+///     assert_eq!(temperature.format(-35, &mut buffer).unwrap(), "  -3.5");
+///     let counter = NumberFormat::new().with_separator('\'');
+///     assert_eq!(counter.format(1234567, &mut buffer).unwrap(), "1'234'567");
+///     let clock = NumberFormat::new().with_width(2).with_zeros();
+///     assert_eq!(clock.format(7, &mut buffer).unwrap(), "07");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberFormat {
+    width: usize,
+    zeros: bool,
+    decimals: usize,
+    point: char,
+    separator: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumberFormat {
+    /// Plain integers without padding
+    pub const fn new() -> Self {
+        NumberFormat {
+            width: 0,
+            zeros: false,
+            decimals: 0,
+            point: '.',
+            separator: None,
+        }
+    }
+
+    /// Pad to a width of characters - numbers not fitting are not cut
+    pub const fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Pad by zeros after the sign instead of spaces before it
+    pub const fn with_zeros(mut self) -> Self {
+        self.zeros = true;
+        self
+    }
+
+    /// The digits after the decimal point the integers are scaled by
+    pub const fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// The decimal point - default is `.`, e.g. `,` for German
+    pub const fn with_point(mut self, point: char) -> Self {
+        self.point = point;
+        self
+    }
+
+    /// Separate thousands of the integer part, e.g. by `,`
+    pub const fn with_separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    /// The digits after the decimal point
+    pub fn decimals(&self) -> usize {
+        self.decimals
+    }
+
+    /// Append a number to a text, e.g. a line of a page
+    ///
+    /// Returns `Err(PageError)` if the number takes more than 64 bytes.
+    pub fn push_to(&self, value: i64, text: &mut String) -> Result<(), PageError> {
+        let mut buffer = [0u8; BUFFER];
+        text.push_str(self.format(value, &mut buffer)?);
+        Ok(())
+    }
+
+    /// Format a number into the buffer
+    ///
+    /// Arguments
+    ///
+    /// * `value`: - The number, scaled by the decimals
+    /// * `buffer`: - The buffer the UTF-8 encoded text is written to
+    ///
+    /// Returns the text written, `Err(PageError)` if the buffer is too small.
+    pub fn format<'b>(&self, value: i64, buffer: &'b mut [u8]) -> Result<&'b str, PageError> {
+        // the digits least significant first, at least a zero before the point
+        let mut digits = [0u8; DIGITS];
+        let mut count = 0;
+        let mut rest = value.unsigned_abs();
+        while rest > 0 || count <= self.decimals.min(DIGITS - 1) {
+            digits[count] = (rest % 10) as u8;
+            rest /= 10;
+            count += 1;
+            if count == DIGITS {
+                break;
+            }
+        }
+        let decimals = self.decimals.min(count - 1);
+        let integers = count - decimals;
+        let separators = match self.separator {
+            Some(_) => (integers - 1) / 3,
+            None => 0,
+        };
+        let point = (decimals > 0) as usize;
+        let sign = (value < 0) as usize;
+        let padding = self
+            .width
+            .saturating_sub(sign + integers + separators + point + decimals);
+
+        let mut writer = Writer { buffer, len: 0 };
+        if !self.zeros {
+            (0..padding).try_for_each(|_| writer.push(' '))?;
+        }
+        if sign > 0 {
+            writer.push('-')?;
+        }
+        if self.zeros {
+            (0..padding).try_for_each(|_| writer.push('0'))?;
+        }
+        for position in (0..count).rev() {
+            writer.push((b'0' + digits[position]) as char)?;
+            match (position, self.separator) {
+                (position, _) if decimals > 0 && position == decimals => writer.push(self.point)?,
+                (position, Some(separator))
+                    if position > decimals && (position - decimals).is_multiple_of(3) =>
+                {
+                    writer.push(separator)?
+                }
+                _ => (),
+            }
+        }
+        Ok(writer.into_str())
+    }
+}
+
+/// Format a time of day or a duration padded to two digits per field, e.g. `07:30`
+pub(crate) fn clock(fields: &[u32]) -> String {
+    let mut text = String::new();
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            text.push(':');
+        }
+        // the digits of an u32 fit the buffer
+        let _ = TWO_DIGITS.push_to(*field as i64, &mut text);
+    }
+    text
+}
+
+/// Writes characters to the start of a buffer
+struct Writer<'b> {
+    buffer: &'b mut [u8],
+    len: usize,
+}
+
+impl<'b> Writer<'b> {
+    fn push(&mut self, c: char) -> Result<(), PageError> {
+        let end = self.len + c.len_utf8();
+        let target = self.buffer.get_mut(self.len..end).ok_or(PageError)?;
+        c.encode_utf8(target);
+        self.len = end;
+        Ok(())
+    }
+
+    fn into_str(self) -> &'b str {
+        // only whole characters are written
+        core::str::from_utf8(&self.buffer[..self.len]).unwrap_or("")
+    }
+}
+
+#[cfg(test)]
+mod tests;
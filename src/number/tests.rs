@@ -0,0 +1,69 @@
+use super::*;
+
+fn format(sut: NumberFormat, value: i64) -> String {
+    let mut buffer = [0u8; 32];
+    sut.format(value, &mut buffer).unwrap().to_string()
+}
+
+#[test]
+fn pad_integers() {
+    assert_eq!(format(NumberFormat::new(), 0), "0");
+    assert_eq!(format(NumberFormat::new(), -42), "-42");
+    assert_eq!(format(NumberFormat::new().with_width(5), -42), "  -42");
+    assert_eq!(
+        format(NumberFormat::new().with_width(5).with_zeros(), -42),
+        "-0042"
+    );
+    assert_eq!(format(NumberFormat::new().with_width(2), 12345), "12345");
+    assert_eq!(
+        format(NumberFormat::new(), i64::MIN),
+        "-9223372036854775808"
+    );
+}
+
+#[test]
+fn separate_thousands() {
+    let sut = NumberFormat::new().with_separator(',');
+    assert_eq!(format(sut, 999), "999");
+    assert_eq!(format(sut, 1000), "1,000");
+    assert_eq!(format(sut, -123456), "-123,456");
+    assert_eq!(format(sut.with_width(10), 1234567), " 1,234,567");
+}
+
+#[test]
+fn fixed_point() {
+    let sut = NumberFormat::new().with_decimals(2);
+    assert_eq!(format(sut, 2150), "21.50");
+    assert_eq!(format(sut, 5), "0.05");
+    assert_eq!(format(sut, -5), "-0.05");
+    assert_eq!(format(sut, 0), "0.00");
+    let sut = sut.with_point(',').with_separator('.').with_width(10);
+    assert_eq!(format(sut, 123456789), "1.234.567,89");
+    assert_eq!(format(sut, -100), "     -1,00");
+}
+
+#[test]
+fn buffer_too_small() {
+    let mut buffer = [0u8; 4];
+    let sut = NumberFormat::new();
+    assert_eq!(sut.format(1234, &mut buffer).unwrap(), "1234");
+    assert!(sut.format(12345, &mut buffer).is_err());
+    let mut buffer = [0u8; 3];
+    assert!(sut.with_separator('’').format(1000, &mut buffer).is_err());
+}
+
+#[test]
+fn append_to_text() {
+    let mut text = String::from("Heap ");
+    NumberFormat::new()
+        .with_separator(',')
+        .push_to(65536, &mut text)
+        .unwrap();
+    assert_eq!(text, "Heap 65,536");
+    assert!(NumberFormat::new()
+        .with_width(65)
+        .push_to(1, &mut text)
+        .is_err());
+    assert_eq!(clock(&[7, 5]), "07:05");
+    assert_eq!(clock(&[100, 0, 9]), "100:00:09");
+}
@@ -0,0 +1,82 @@
+/// Clockwise rotation of the display content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    #[default]
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+/// How a graphical display is mounted in the enclosure
+///
+/// Pages draw on the logical display, i.e. they do not care about the
+/// orientation. Display adapters take the orientation of the enclosure
+/// variant and map every point to the physical display, so the same page
+/// code works for a display mounted upside down or in portrait mode.
+/// Mirroring flips the content horizontally before it is rotated, e.g. for
+/// head-up displays.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{Orientation, Rotation};
+///
+///     // a 128x64 display mounted upside down
+///     let orientation = Orientation::new(Rotation::Deg180, false);
+///     assert_eq!(orientation.logical_size(128, 64), (128, 64));
+///     assert_eq!(orientation.transform(0, 0, 128, 64), (127, 63));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Orientation {
+    pub rotation: Rotation,
+    pub mirrored: bool,
+}
+
+impl Orientation {
+    /// Arguments
+    ///
+    /// * `rotation`: - The clockwise rotation of the content
+    /// * `mirrored`: - Flip the content horizontally
+    pub fn new(rotation: Rotation, mirrored: bool) -> Self {
+        Orientation { rotation, mirrored }
+    }
+
+    /// Width and height pages draw on - swapped if rotated by 90 or 270 degrees
+    ///
+    /// Arguments
+    ///
+    /// * `width`: - The width of the physical display in pixels
+    /// * `height`: - The height of the physical display in pixels
+    pub fn logical_size(&self, width: u32, height: u32) -> (u32, u32) {
+        match self.rotation {
+            Rotation::Deg0 | Rotation::Deg180 => (width, height),
+            Rotation::Deg90 | Rotation::Deg270 => (height, width),
+        }
+    }
+
+    /// Map a point drawn by a page to the point of the physical display
+    ///
+    /// Arguments
+    ///
+    /// * `x`, `y`: - The point on the logical display, see `logical_size()`
+    /// * `width`: - The width of the physical display in pixels
+    /// * `height`: - The height of the physical display in pixels
+    pub fn transform(&self, x: u32, y: u32, width: u32, height: u32) -> (u32, u32) {
+        let (logical_width, _) = self.logical_size(width, height);
+        let x = match self.mirrored {
+            true => logical_width.saturating_sub(1).saturating_sub(x),
+            false => x,
+        };
+        let (right, bottom) = (width.saturating_sub(1), height.saturating_sub(1));
+        match self.rotation {
+            Rotation::Deg0 => (x, y),
+            Rotation::Deg90 => (right.saturating_sub(y), x),
+            Rotation::Deg180 => (right.saturating_sub(x), bottom.saturating_sub(y)),
+            Rotation::Deg270 => (y, bottom.saturating_sub(x)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
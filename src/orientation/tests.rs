@@ -0,0 +1,33 @@
+use super::*;
+
+/// The corners top left, top right and bottom left of a 4x2 display
+fn corners(orientation: Orientation) -> Vec<(u32, u32)> {
+    let (width, height) = orientation.logical_size(4, 2);
+    vec![(0, 0), (width - 1, 0), (0, height - 1)]
+        .into_iter()
+        .map(|(x, y)| orientation.transform(x, y, 4, 2))
+        .collect()
+}
+
+#[test]
+fn rotate_clockwise() {
+    let rotated = |rotation| corners(Orientation::new(rotation, false));
+    assert_eq!(rotated(Rotation::Deg0), vec![(0, 0), (3, 0), (0, 1)]);
+    assert_eq!(rotated(Rotation::Deg90), vec![(3, 0), (3, 1), (0, 0)]);
+    assert_eq!(rotated(Rotation::Deg180), vec![(3, 1), (0, 1), (3, 0)]);
+    assert_eq!(rotated(Rotation::Deg270), vec![(0, 1), (0, 0), (3, 1)]);
+}
+
+#[test]
+fn mirror_before_rotating() {
+    let mirrored = |rotation| corners(Orientation::new(rotation, true));
+    assert_eq!(mirrored(Rotation::Deg0), vec![(3, 0), (0, 0), (3, 1)]);
+    assert_eq!(mirrored(Rotation::Deg90), vec![(3, 1), (3, 0), (0, 1)]);
+}
+
+#[test]
+fn swap_size_in_portrait_mode() {
+    let portrait = Orientation::new(Rotation::Deg270, false);
+    assert_eq!(portrait.logical_size(128, 64), (64, 128));
+    assert_eq!(Orientation::default().logical_size(128, 64), (128, 64));
+}
@@ -1,13 +1,327 @@
 //! Home of several general purpose page implementations
 
+mod action;
+mod adc_calibration;
+mod alarm_list;
 mod basic;
+#[cfg(feature = "graphics")]
+mod calendar;
+mod confirm;
+mod countdown;
+mod dynamic_text;
 mod enter_string;
+mod factory_reset;
+mod file_list;
+mod language;
+mod lazy;
+mod log;
 mod menu;
+mod multi_select;
+mod network;
+mod pin;
+mod power_off;
+#[cfg(feature = "qr-code")]
+mod qr_code;
+mod self_test;
+mod settings_list;
+#[cfg(feature = "graphics")]
+mod slider;
+mod sys_info;
+#[cfg(feature = "graphics")]
+mod touch_calibration;
+mod units;
+mod update;
+mod value;
+mod value_editor;
 
 // Re-exports
 #[allow(unused_imports)]
+pub use action::ActionPage;
+#[allow(unused_imports)]
+pub use adc_calibration::AdcCalibrationPage;
+#[allow(unused_imports)]
+pub use alarm_list::AlarmListPage;
+#[allow(unused_imports)]
 pub use basic::{BasicPage, ShutdownPage, StartupPage, TextPage};
+#[cfg(feature = "graphics")]
+#[allow(unused_imports)]
+pub use calendar::CalendarPage;
+#[allow(unused_imports)]
+pub use confirm::{Answer, Confirmation};
+#[allow(unused_imports)]
+pub use countdown::CountdownPage;
+#[allow(unused_imports)]
+pub use dynamic_text::DynamicTextPage;
 #[allow(unused_imports)]
 pub use enter_string::EnterStringPage;
 #[allow(unused_imports)]
+pub use factory_reset::FactoryResetFlow;
+#[allow(unused_imports)]
+pub use file_list::{DirEntry, Directory, FileListPage};
+#[allow(unused_imports)]
+pub use language::LanguageSelectPage;
+#[allow(unused_imports)]
+pub use lazy::LazyPage;
+#[allow(unused_imports)]
+pub use log::LogPage;
+#[allow(unused_imports)]
 pub use menu::MenuPage;
+#[allow(unused_imports)]
+pub use multi_select::MultiSelectPage;
+#[allow(unused_imports)]
+pub use network::{NetworkInfoPage, NetworkStatusProvider};
+#[allow(unused_imports)]
+pub use pin::PinPage;
+#[allow(unused_imports)]
+pub use power_off::PowerOffPage;
+#[cfg(feature = "qr-code")]
+#[allow(unused_imports)]
+pub use qr_code::QrCodePage;
+#[allow(unused_imports)]
+pub use self_test::SelfTestPage;
+#[allow(unused_imports)]
+pub use settings_list::SettingsListPage;
+#[cfg(feature = "graphics")]
+#[allow(unused_imports)]
+pub use slider::SliderPage;
+#[allow(unused_imports)]
+pub use sys_info::{BuildInfo, SysInfoPage, SystemInfo};
+#[cfg(feature = "graphics")]
+#[allow(unused_imports)]
+pub use touch_calibration::TouchCalibrationPage;
+#[allow(unused_imports)]
+pub use units::UnitSelectPage;
+#[allow(unused_imports)]
+pub use update::UpdatePage;
+#[allow(unused_imports)]
+pub use value::ValuePage;
+#[allow(unused_imports)]
+pub use value_editor::GenericValueEditorPage;
+
+/// Implement `PageInterface` of all pages of this crate for a display
+///
+/// The display needs a function `update(&mut self, title: &str, message: &str)`
+/// showing the title and the content; lines of the content are separated by `\n`.
+/// Pages scrolling their content are shown by a function
+/// `update_scrolled(&mut self, title: &str, message: &str, position: Option<ScrollPosition>)`
+/// indicating the scroll position, e.g. by `paginated_title()`.
+/// Generic displays are given with their type parameters first, e.g.
+/// `impl_text_display!(<S: Write> SomeDisplay<S>)`.
+///
+/// Graphical displays drawing some pages themselves, e.g. the signal bars of
+/// the `NetworkInfoPage` or the gauge of the `ValuePage`, are given with
+/// `@graphics` first and implement `PageInterface` of these pages on their own.
+#[allow(unused_macros)]
+macro_rules! impl_text_display {
+    (@graphics <$($param:ident: $bound:path),*> $display:ty) => {
+        impl_text_display!(@pages <$($param: $bound),*> $display);
+    };
+    // pages drawn by graphical displays on their own
+    (@text <$($param:ident: $bound:path),*> $display:ty) => {
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::NetworkInfoPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::ValuePage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+    };
+    (@pages <$($param:ident: $bound:path),*> $display:ty) => {
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::TextPage {
+            fn display(&self, display_driver: &mut $display) {
+                let position = $crate::PageBaseInterface::scroll_position(self);
+                display_driver.update_scrolled(self.visible_title(), &self.lines().join("\n"), position);
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::StartupPage {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.0.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::ShutdownPage {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.0.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::DynamicTextPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::MenuPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                let position = $crate::PageBaseInterface::scroll_position(self);
+                display_driver.update_scrolled(title, &self.sub_titles, position);
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::ActionPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, "executed");
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::AdcCalibrationPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C, const LINES: usize, const COLS: usize> $crate::PageInterface<$display, C>
+            for $crate::page::LogPage<'_, LINES, COLS>
+        {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                let position = $crate::PageBaseInterface::scroll_position(self);
+                display_driver.update_scrolled(title, &self.lines().join("\n"), position);
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::FactoryResetFlow<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::AlarmListPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::CountdownPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::PowerOffPage {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::UpdatePage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::PinPage {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.line());
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::SelfTestPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::SysInfoPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::FileListPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                let position = $crate::PageBaseInterface::scroll_position(self);
+                display_driver.update_scrolled(title, &self.lines().join("\n"), position);
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::MultiSelectPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                let position = $crate::PageBaseInterface::scroll_position(self);
+                display_driver.update_scrolled(title, &self.lines().join("\n"), position);
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::SettingsListPage<'_> {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::UnitSelectPage {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, &self.lines().join("\n"));
+            }
+        }
+
+        impl<$($param: $bound,)* C> $crate::PageInterface<$display, C> for $crate::page::LanguageSelectPage {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                display_driver.update(title, self.selected_language());
+            }
+        }
+
+        impl<$($param: $bound,)* T, C> $crate::PageInterface<$display, C> for $crate::page::EnterStringPage<'_, T>
+        where
+            T: ::std::fmt::Display + ::std::str::FromStr + Copy + 'static,
+            <T as ::std::str::FromStr>::Err: ::std::fmt::Debug,
+        {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                let output = format!("{}\nAction: {}", self.buffer, self.action_string());
+                display_driver.update(title, &output);
+            }
+        }
+
+        impl<$($param: $bound,)* T, C> $crate::PageInterface<$display, C>
+            for $crate::page::GenericValueEditorPage<'_, T>
+        where
+            T: ::std::fmt::Display + ::std::str::FromStr + Copy + 'static,
+            <T as ::std::str::FromStr>::Err: ::std::fmt::Debug,
+        {
+            fn display(&self, display_driver: &mut $display) {
+                let title = $crate::PageBaseInterface::title(self);
+                match self.editor() {
+                    Some(editor) => {
+                        let output =
+                            format!("{}\nAction: {}", editor.buffer, editor.action_string());
+                        display_driver.update(title, &output);
+                    }
+                    None => display_driver.update(title, ""),
+                }
+            }
+        }
+    };
+    (<$($param:ident: $bound:path),*> $display:ty) => {
+        impl_text_display!(@pages <$($param: $bound),*> $display);
+        impl_text_display!(@text <$($param: $bound),*> $display);
+    };
+    ($display:ty) => {
+        impl_text_display!(<> $display);
+    };
+}
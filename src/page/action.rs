@@ -0,0 +1,75 @@
+use super::super::{
+    Feedback, Interaction, PageBaseInterface, PageError, PageInteractionInterface, PageNavigation,
+};
+use super::basic::BasicPage;
+
+/// An action page executes a command as soon as it gets activated
+///
+/// An action page is typically registered as sub page of a menu. Selecting it
+/// in the menu runs the user supplied command (e.g. "Start pump",
+/// "Reset counters") and navigates to where the command says, e.g.
+/// `PageNavigation::Up` to return to the menu.
+///
+/// The command is executed on every update of the page. If the command returns
+/// `PageNavigation::Update` the page stays active and the command is executed
+/// again with the next update. Otherwise the command is done and the page
+/// requests `Feedback::Confirm`.
+pub struct ActionPage<'a> {
+    pub basic: BasicPage,
+    command: Box<dyn FnMut() -> PageNavigation + 'a>,
+    executed: bool,
+}
+
+impl<'a> ActionPage<'a> {
+    pub fn new(basic: BasicPage, command: Box<dyn FnMut() -> PageNavigation + 'a>) -> Self {
+        ActionPage {
+            basic,
+            command,
+            executed: false,
+        }
+    }
+
+    /// Execute the command and return where to navigate to
+    pub fn execute(&mut self) -> PageNavigation {
+        let navigation = (self.command)();
+        self.executed = navigation != PageNavigation::Update;
+        navigation
+    }
+}
+
+impl PageBaseInterface for ActionPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    /// Execute the command and let it decide where to navigate to
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        Ok(self.execute())
+    }
+
+    /// Confirm a command that is done, i.e. navigated away
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        match std::mem::replace(&mut self.executed, false) {
+            true => Some(Feedback::Confirm),
+            false => None,
+        }
+    }
+}
+
+impl PageInteractionInterface for ActionPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Action => self.execute(),
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+            Interaction::Next => PageNavigation::Left,
+            Interaction::Previous => PageNavigation::Right,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,44 @@
+use super::*;
+use std::cell::Cell;
+
+#[test]
+fn check_title() {
+    let sut = ActionPage::new(
+        BasicPage::new("Start pump", None),
+        Box::new(|| PageNavigation::Up),
+    );
+    assert_eq!(sut.title(), "Start pump");
+}
+
+#[test]
+fn update_executes_command() {
+    let counter = Cell::new(0);
+    let mut sut = ActionPage::new(
+        BasicPage::new("Reset counters", None),
+        Box::new(|| {
+            counter.set(counter.get() + 1);
+            PageNavigation::Up
+        }),
+    );
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Up);
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Up);
+    drop(sut);
+    assert_eq!(counter.get(), 2);
+}
+
+#[test]
+fn interaction_action_executes_command() {
+    let counter = Cell::new(0);
+    let mut sut = ActionPage::new(
+        BasicPage::new("Reset counters", None),
+        Box::new(|| {
+            counter.set(counter.get() + 1);
+            PageNavigation::Home
+        }),
+    );
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Home);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert_eq!(sut.dispatch(Interaction::Next), PageNavigation::Left);
+    drop(sut);
+    assert_eq!(counter.get(), 1);
+}
@@ -0,0 +1,121 @@
+use super::super::script::interaction_name;
+use super::super::{
+    AdcButtons, Interaction, PageBaseInterface, PageError, PageInteractionInterface, PageNavigation,
+};
+use super::basic::BasicPage;
+
+/// A page to measure the levels of buttons wired to one ADC pin
+///
+/// Is meant to be run on the host, e.g. by the terminal simulator with the
+/// ADC sampled by a closure over a debug link, since the buttons of the panel
+/// being calibrated are not decoded yet.
+///
+/// The page asks to hold one button after another and shows the live sample.
+/// Action records the sample as the level of the button, previous discards
+/// the last recorded level. When all levels are recorded, the calibration
+/// table is shown and `decoder()` provides the `AdcButtons`.
+pub struct AdcCalibrationPage<'a> {
+    pub basic: BasicPage,
+    buttons: Vec<Interaction>,
+    levels: Vec<u16>,
+    sample: u16,
+    sampler: Box<dyn FnMut() -> u16 + 'a>,
+}
+
+impl<'a> AdcCalibrationPage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The basic page
+    /// * `buttons`: - The buttons to calibrate in the order they are asked for
+    /// * `sampler`: - Sample the ADC
+    pub fn new(
+        basic: BasicPage,
+        buttons: &[Interaction],
+        sampler: Box<dyn FnMut() -> u16 + 'a>,
+    ) -> Self {
+        AdcCalibrationPage {
+            basic,
+            buttons: buttons.to_vec(),
+            levels: Vec::new(),
+            sample: 0,
+            sampler,
+        }
+    }
+
+    /// The levels of all buttons are recorded
+    pub fn is_complete(&self) -> bool {
+        self.levels.len() >= self.buttons.len()
+    }
+
+    /// The recorded levels and their buttons
+    pub fn levels(&self) -> impl Iterator<Item = (u16, Interaction)> + '_ {
+        self.levels
+            .iter()
+            .copied()
+            .zip(self.buttons.iter().copied())
+    }
+
+    /// A decoder of the recorded levels
+    ///
+    /// Arguments
+    ///
+    /// * `tolerance`: - Maximum distance of a sample to the level of a button
+    pub fn decoder(&self, tolerance: u16) -> AdcButtons {
+        self.levels()
+            .fold(AdcButtons::new(tolerance), |decoder, (level, button)| {
+                decoder.with_button(level, button)
+            })
+    }
+
+    /// The lines to display
+    pub fn lines(&self) -> Vec<String> {
+        match self.buttons.get(self.levels.len()) {
+            Some(button) => vec![
+                format!("Hold {}", interaction_name(*button)),
+                format!("ADC {}", self.sample),
+            ],
+            None => self
+                .levels()
+                .map(|(level, button)| format!("{} {}", interaction_name(button), level))
+                .collect(),
+        }
+    }
+}
+
+impl PageBaseInterface for AdcCalibrationPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    /// Sample the ADC
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.sample = (self.sampler)();
+        Ok(PageNavigation::Update)
+    }
+}
+
+impl PageInteractionInterface for AdcCalibrationPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Action => {
+                if !self.is_complete() {
+                    self.levels.push(self.sample);
+                }
+                PageNavigation::Update
+            }
+            Interaction::Previous => {
+                self.levels.pop();
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+            Interaction::Next => PageNavigation::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,48 @@
+use super::*;
+use std::cell::Cell;
+
+#[test]
+fn record_levels() {
+    let sample = Cell::new(1023);
+    let mut sut = AdcCalibrationPage::new(
+        BasicPage::new("Calibrate", None),
+        &[Interaction::Action, Interaction::Next],
+        Box::new(|| sample.get()),
+    );
+    assert_eq!(sut.title(), "Calibrate");
+    sample.set(3);
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["Hold action", "ADC 3"]);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+
+    sample.set(160);
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["Hold next", "ADC 160"]);
+    // redo the level of the first button
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.lines(), vec!["Hold action", "ADC 160"]);
+    sample.set(0);
+    sut.update(None).unwrap();
+    sut.dispatch(Interaction::Action);
+    sample.set(150);
+    sut.update(None).unwrap();
+    sut.dispatch(Interaction::Action);
+    sut.dispatch(Interaction::Action);
+
+    assert!(sut.is_complete());
+    assert_eq!(sut.lines(), vec!["action 0", "next 150"]);
+    let mut decoder = sut.decoder(30).with_debounce(1);
+    assert_eq!(decoder.sample(140), Some(Interaction::Next));
+}
+
+#[test]
+fn navigate_away() {
+    let mut sut = AdcCalibrationPage::new(
+        BasicPage::new("Calibrate", None),
+        &[Interaction::Action],
+        Box::new(|| 0),
+    );
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Home);
+    assert!(!sut.is_complete());
+}
@@ -0,0 +1,148 @@
+use super::super::{
+    number::clock, truncate, AlarmScheduler, AlarmTime, DisplayInfo, DisplaySize, Interaction,
+    PageBaseInterface, PageError, PageInteractionInterface, PageNavigation,
+};
+use super::basic::BasicPage;
+
+use std::cell::RefCell;
+
+/// A page listing the alarms of an `AlarmScheduler` to enable or disable them
+///
+/// Every alarm is shown in a line with its time and whether it is enabled;
+/// the selected alarm is marked by `>`. The lines shown follow the selection
+/// if there are more alarms than display rows.
+///
+/// * previous, next - select the previous or next alarm
+/// * action - enables or disables the selected alarm
+/// * back - leaves the page with up-navigation
+/// * home - leaves the page with home-navigation
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{AlarmListPage, BasicPage};
+///     use embedded_multi_page_hmi::{AlarmScheduler, AlarmTime, Interaction, PageInteractionInterface};
+///     use std::cell::RefCell;
+///
+///     let alarms = RefCell::new(AlarmScheduler::new());
+///     let wake_up = alarms.borrow_mut().add("Wake up", AlarmTime::Daily(7, 30));
+///     let mut page = AlarmListPage::new(BasicPage::new("Alarms", None), &alarms);
+///     assert_eq!(page.lines(), vec!["> Wake up 07:30 on"]);
+///     page.dispatch(Interaction::Action);
+///     assert!(!alarms.borrow().get(wake_up).unwrap().is_enabled());
+/// ```
+pub struct AlarmListPage<'a> {
+    pub basic: BasicPage,
+    scheduler: &'a RefCell<AlarmScheduler>,
+    selected: usize,
+    size: DisplaySize,
+}
+
+impl<'a> AlarmListPage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `scheduler`: - The alarms listed, e.g. shared with the `PageManager`
+    pub fn new(basic: BasicPage, scheduler: &'a RefCell<AlarmScheduler>) -> Self {
+        AlarmListPage {
+            basic,
+            scheduler,
+            selected: 0,
+            size: DisplaySize::default(),
+        }
+    }
+
+    /// The lines of the alarms fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        let scheduler = self.scheduler.borrow();
+        let rows = self.size.rows.max(1);
+        let first = self.selected.saturating_sub(rows - 1);
+        scheduler
+            .alarms()
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(rows)
+            .map(|(index, alarm)| {
+                let line = format!(
+                    "{}{} {} {}",
+                    if index == self.selected { "> " } else { "  " },
+                    alarm.title(),
+                    format_time(alarm.time()),
+                    if alarm.is_enabled() { "on" } else { "off" },
+                );
+                truncate(&line, self.size.cols).into_owned()
+            })
+            .collect()
+    }
+
+    fn count(&self) -> usize {
+        self.scheduler.borrow().alarms().len()
+    }
+}
+
+/// Format the time of an alarm, e.g. `07:30` or `every 60s`
+fn format_time(time: AlarmTime) -> String {
+    match time {
+        AlarmTime::Daily(hour, minute) => clock(&[hour as u32, minute as u32]),
+        AlarmTime::Interval(seconds) => format!("every {}s", seconds),
+    }
+}
+
+impl PageBaseInterface for AlarmListPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        Ok(PageNavigation::Update)
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    fn selected(&self) -> Option<usize> {
+        Some(self.selected + 1)
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = (index.max(1) - 1).min(self.count().saturating_sub(1));
+    }
+}
+
+impl PageInteractionInterface for AlarmListPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        let count = self.count();
+        match interaction {
+            Interaction::Action => {
+                let mut scheduler = self.scheduler.borrow_mut();
+                if let Some(id) = scheduler.ids().nth(self.selected) {
+                    let enabled = scheduler.alarms()[id.index()].is_enabled();
+                    scheduler.set_enabled(id, !enabled);
+                }
+                PageNavigation::Update
+            }
+            Interaction::Next => {
+                self.selected = (self.selected + 1) % count.max(1);
+                PageNavigation::Update
+            }
+            Interaction::Previous => {
+                self.selected = (self.selected + count.max(1) - 1) % count.max(1);
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
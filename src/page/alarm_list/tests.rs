@@ -0,0 +1,65 @@
+use super::*;
+
+fn scheduler() -> RefCell<AlarmScheduler> {
+    let mut scheduler = AlarmScheduler::new();
+    scheduler.add("Wake up", AlarmTime::Daily(7, 5));
+    scheduler.add("Stir", AlarmTime::Interval(90));
+    scheduler.add("Tea", AlarmTime::Daily(16, 0));
+    RefCell::new(scheduler)
+}
+
+#[test]
+fn list_alarms() {
+    let scheduler = scheduler();
+    let mut sut = AlarmListPage::new(BasicPage::new("Alarms", None), &scheduler);
+    assert_eq!(sut.title(), "Alarms");
+    assert!(sut.is_setting());
+    sut.set_display_info(&DisplaySize::new(20, 4));
+    assert_eq!(
+        sut.lines(),
+        vec![
+            "> Wake up 07:05 on",
+            "  Stir every 90s on",
+            "  Tea 16:00 on"
+        ]
+    );
+}
+
+#[test]
+fn toggle_selected_alarm() {
+    let scheduler = scheduler();
+    let mut sut = AlarmListPage::new(BasicPage::new("Alarms", None), &scheduler);
+    assert_eq!(sut.dispatch(Interaction::Previous), PageNavigation::Update);
+    assert_eq!(sut.selected(), Some(3));
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    assert!(!scheduler.borrow().alarms()[2].is_enabled());
+    assert_eq!(sut.lines(), vec!["> Tea 16:00 off"]);
+    sut.dispatch(Interaction::Action);
+    assert!(scheduler.borrow().alarms()[2].is_enabled());
+
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.selected(), Some(1));
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Home);
+}
+
+#[test]
+fn lines_follow_the_selection() {
+    let scheduler = scheduler();
+    let mut sut = AlarmListPage::new(BasicPage::new("Alarms", None), &scheduler);
+    sut.set_display_info(&DisplaySize::new(12, 2));
+    sut.select(3);
+    assert_eq!(sut.lines(), vec!["  Stir ev...", "> Tea 16:..."]);
+    sut.select(9);
+    assert_eq!(sut.selected(), Some(3));
+}
+
+#[test]
+fn empty_list() {
+    let scheduler = RefCell::new(AlarmScheduler::new());
+    let mut sut = AlarmListPage::new(BasicPage::new("Alarms", None), &scheduler);
+    assert_eq!(sut.dispatch(Interaction::Next), PageNavigation::Update);
+    assert_eq!(sut.dispatch(Interaction::Previous), PageNavigation::Update);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    assert!(sut.lines().is_empty());
+}
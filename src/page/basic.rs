@@ -1,38 +1,172 @@
+use std::borrow::Cow;
+
 use super::super::{
-    Interaction, PageBaseInterface, PageError, PageInteractionInterface, PageLifetime,
-    PageNavigation,
+    truncate, wrap, DisplayInfo, DisplaySize, Interaction, Marquee, PageBaseInterface, PageError,
+    PageInteractionInterface, PageLifetime, PageNavigation, ScrollPosition, Siblings, TextId,
+    Translations,
 };
 
 /// A basic page has at least a title and an optional lifetime
 ///
 /// A basic page can be used to construct other pages, since every pages
 /// is supposed to have a title and an optional lifetime.
+///
+/// The title is either a static str or an owned string containing runtime
+/// data, e.g. a device name or a count.
 pub struct BasicPage {
-    pub title: &'static str,
+    pub title: Cow<'static, str>,
     pub lifetime: Option<PageLifetime>,
 }
 
 impl BasicPage {
-    pub fn new(title: &'static str, lifetime: Option<PageLifetime>) -> Self {
-        BasicPage { title, lifetime }
+    pub fn new(title: impl Into<Cow<'static, str>>, lifetime: Option<PageLifetime>) -> Self {
+        BasicPage {
+            title: title.into(),
+            lifetime,
+        }
+    }
+
+    /// Change the title at runtime
+    pub fn set_title(&mut self, title: impl Into<Cow<'static, str>>) {
+        self.title = title.into();
     }
 }
 
 /// A text page holds a text as content and contains the behavior of a BasicPage
+///
+/// If the display size is known, the text is wrapped into the rows of the
+/// display. A text not fitting the display is paginated: The action interaction
+/// shows the next rows.
+/// A title longer than the display width optionally scrolls by a marquee.
+/// The title is optionally translated.
+/// The last row optionally shows the titles of the pages next to it, e.g.
+/// `◄ Time | Temperature ►`.
 pub struct TextPage {
     pub basic: BasicPage,
     pub text: &'static str,
+    size: DisplaySize,
+    first_line: usize,
+    marquee: Option<Marquee>,
+    translated_title: Option<(&'static Translations, TextId)>,
+    hints: Option<String>,
 }
 
 impl TextPage {
     pub fn new(basic: BasicPage, text: &'static str) -> Self {
-        TextPage { basic, text }
+        TextPage {
+            basic,
+            text,
+            size: DisplaySize::default(),
+            first_line: 0,
+            marquee: None,
+            translated_title: None,
+            hints: None,
+        }
+    }
+
+    /// Show the title in the active language instead of the title of the basic page
+    pub fn with_translated_title(
+        mut self,
+        translations: &'static Translations,
+        id: TextId,
+    ) -> Self {
+        self.translated_title = Some((translations, id));
+        self
+    }
+
+    /// Scroll a title longer than the display width
+    pub fn with_marquee(mut self, marquee: Marquee) -> Self {
+        self.marquee = Some(marquee);
+        self
+    }
+
+    /// Show the titles of the previous and next page in the last row of the display
+    ///
+    /// The hints are shown if the display has more than one row.
+    pub fn with_sibling_hints(mut self) -> Self {
+        self.hints = Some(String::new());
+        self
+    }
+
+    /// The title in the active language
+    fn current_title(&self) -> &str {
+        match self.translated_title {
+            Some((translations, id)) => translations.get(id),
+            None => &self.basic.title,
+        }
+    }
+
+    /// The part of the title fitting the display
+    pub fn visible_title(&self) -> &str {
+        match &self.marquee {
+            Some(marquee) => marquee.window(self.title(), self.size.cols),
+            None => self.title(),
+        }
+    }
+
+    /// The navigation hint shown in the last row, if any
+    fn hint(&self) -> Option<&str> {
+        self.hints.as_deref().filter(|_| self.size.rows > 1)
+    }
+
+    /// The rows of the display showing the text
+    fn text_rows(&self) -> usize {
+        match self.hint() {
+            Some(_) => self.size.rows - 1,
+            None => self.size.rows,
+        }
+    }
+
+    /// The lines of the text fitting the display - followed by the navigation hint, if any
+    pub fn lines(&self) -> Vec<&str> {
+        let mut lines: Vec<&str> = wrap(self.text, self.size.cols)
+            .into_iter()
+            .skip(self.first_line)
+            .take(self.text_rows())
+            .collect();
+        if let Some(hint) = self.hint().filter(|hint| !hint.is_empty()) {
+            lines.resize(self.text_rows(), "");
+            lines.push(hint);
+        }
+        lines
+    }
+
+    /// Show the next rows of the text - turn around at the end of the text
+    pub fn next_lines(&mut self) {
+        self.first_line += self.text_rows();
+        if self.first_line >= wrap(self.text, self.size.cols).len() {
+            self.first_line = 0;
+        }
     }
 }
 
 impl PageBaseInterface for TextPage {
     fn title(&self) -> &str {
-        self.basic.title
+        self.current_title()
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        let size = DisplaySize::from_info(info);
+        if size != self.size {
+            self.size = size;
+            self.first_line = 0;
+        }
+    }
+
+    fn set_siblings(&mut self, siblings: Siblings<'_>) {
+        if let Some(hints) = &mut self.hints {
+            *hints = truncate(&siblings.to_string(), self.size.cols).into_owned();
+        }
+    }
+
+    /// The rows of the text shown
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        let total = wrap(self.text, self.size.cols).len();
+        Some(ScrollPosition::new(
+            self.first_line,
+            self.text_rows().min(total),
+            total,
+        ))
     }
 
     // Static page still need to take care for their own lifetime
@@ -40,11 +174,19 @@ impl PageBaseInterface for TextPage {
         &mut self,
         _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
     ) -> Result<PageNavigation, PageError> {
+        let title = match self.translated_title {
+            Some((translations, id)) => translations.get(id),
+            None => &self.basic.title,
+        };
+        if let Some(marquee) = &mut self.marquee {
+            marquee.advance(title, self.size.cols);
+        }
         match self.basic.lifetime {
             Some(mut lifetime) => {
                 let mut result = PageNavigation::Update;
                 lifetime.increase_age();
                 if lifetime.is_over() {
+                    debug!("lifetime of page {} is over", self.title());
                     lifetime.reset_age();
                     result = lifetime.get_target();
                 }
@@ -56,7 +198,20 @@ impl PageBaseInterface for TextPage {
     }
 }
 
-impl PageInteractionInterface for TextPage {}
+impl PageInteractionInterface for TextPage {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Action => {
+                self.next_lines();
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+            Interaction::Next => PageNavigation::Left,
+            Interaction::Previous => PageNavigation::Right,
+        }
+    }
+}
 
 /// A startup page - optionally shown before stopping the HMI
 ///
@@ -72,10 +227,12 @@ impl StartupPage {
             "Startup",
             Some(PageLifetime::new(PageNavigation::Home, lifetime_in_updates)),
         );
-        StartupPage(TextPage {
-            basic,
-            text: startup_message,
-        })
+        StartupPage(TextPage::new(basic, startup_message))
+    }
+
+    /// Show the title in the active language
+    pub fn with_translations(self, translations: &'static Translations) -> Self {
+        StartupPage(self.0.with_translated_title(translations, TextId::STARTUP))
     }
 }
 
@@ -84,6 +241,10 @@ impl PageBaseInterface for StartupPage {
         self.0.title()
     }
 
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.0.set_display_info(info);
+    }
+
     /// Update checks lifetime
     ///
     /// Return an error if lifetime is over
@@ -96,6 +257,7 @@ impl PageBaseInterface for StartupPage {
                 let mut result = PageNavigation::SystemStart;
                 lifetime.increase_age();
                 if lifetime.is_over() {
+                    debug!("lifetime of page {} is over", self.0.title());
                     lifetime.reset_age();
                     result = lifetime.get_target();
                 }
@@ -118,23 +280,38 @@ impl PageInteractionInterface for StartupPage {
 ///
 /// * Is a text page with title "Shutdown"
 /// * Has a dedicated lifetime
-/// * Any user interaction is suppressed
-/// * If lifetime is over it turns returns an PageError
-pub struct ShutdownPage(pub TextPage);
+/// * Any user interaction is suppressed - except the cancel interaction, if any
+/// * If lifetime is over it turns to `PageNavigation::Terminate`
+/// * Stays beyond its lifetime while shutdown tasks of the `PageManager` are not done
+///
+/// The cancel interaction set by `with_cancel()` aborts the shutdown during the
+/// lifetime of the page; the `PageManager` returns to the page active before.
+pub struct ShutdownPage(pub TextPage, Option<Interaction>);
 
 impl ShutdownPage {
     pub fn new(shutdown_message: &'static str, lifetime_in_updates: u16) -> Self {
         let basic = BasicPage::new(
             "Shutdown",
             Some(PageLifetime::new(
-                PageNavigation::SystemStop,
+                PageNavigation::Terminate,
                 lifetime_in_updates,
             )),
         );
-        ShutdownPage(TextPage {
-            basic,
-            text: shutdown_message,
-        })
+        ShutdownPage(TextPage::new(basic, shutdown_message), None)
+    }
+
+    /// Show the title in the active language
+    pub fn with_translations(self, translations: &'static Translations) -> Self {
+        ShutdownPage(
+            self.0.with_translated_title(translations, TextId::SHUTDOWN),
+            self.1,
+        )
+    }
+
+    /// Abort the shutdown by the interaction, e.g. if power-down is user-triggered
+    pub fn with_cancel(mut self, interaction: Interaction) -> Self {
+        self.1 = Some(interaction);
+        self
     }
 }
 
@@ -143,24 +320,28 @@ impl PageBaseInterface for ShutdownPage {
         self.0.title()
     }
 
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.0.set_display_info(info);
+    }
+
     /// Update checks lifetime
     ///
-    /// Return an error if lifetime is over
+    /// Return `PageNavigation::Terminate` if lifetime is over
     fn update<'a>(
         &mut self,
         _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
     ) -> Result<PageNavigation, PageError> {
         match self.0.basic.lifetime {
             Some(mut lifetime) => {
+                let mut result = PageNavigation::SystemStop;
                 lifetime.increase_age();
-                self.0.basic.lifetime = Some(lifetime);
                 if lifetime.is_over() {
+                    debug!("lifetime of page {} is over", self.0.title());
                     lifetime.reset_age();
-                    self.0.basic.lifetime = Some(lifetime);
-                    Err(PageError)
-                } else {
-                    Ok(PageNavigation::SystemStop)
+                    result = lifetime.get_target();
                 }
+                self.0.basic.lifetime = Some(lifetime);
+                Ok(result)
             }
             None => Ok(PageNavigation::SystemStop),
         }
@@ -168,9 +349,18 @@ impl PageBaseInterface for ShutdownPage {
 }
 
 impl PageInteractionInterface for ShutdownPage {
-    /// Do not react on any interaction
-    fn dispatch(&mut self, _interaction: Interaction) -> PageNavigation {
-        PageNavigation::SystemStop
+    /// Do not react on any interaction but the cancel interaction
+    ///
+    /// Returns `PageNavigation::Update` to stay at the page active before.
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        if self.1 != Some(interaction) {
+            return PageNavigation::SystemStop;
+        }
+        // the lifetime starts anew with the next shutdown
+        if let Some(lifetime) = &mut self.0.basic.lifetime {
+            lifetime.reset_age();
+        }
+        PageNavigation::Update
     }
 }
 
@@ -23,12 +23,108 @@ mod text_page {
         let mut sut = TextPage::new(BasicPage::new("MyTitle", None), "MyContent");
         assert_eq!(sut.update(None).unwrap(), PageNavigation::Update);
     }
+
+    #[test]
+    fn owned_title() {
+        let devices = 3;
+        let mut sut = TextPage::new(
+            BasicPage::new(format!("Devices ({})", devices), None),
+            "MyContent",
+        );
+        assert_eq!(sut.title(), "Devices (3)");
+        sut.basic.set_title("Devices");
+        assert_eq!(sut.title(), "Devices");
+    }
+
+    #[test]
+    fn wrap_and_paginate_text() {
+        let mut sut = TextPage::new(
+            BasicPage::new("MyTitle", None),
+            "The quick brown fox jumps over the lazy dog",
+        );
+        assert_eq!(
+            sut.lines(),
+            vec!["The quick brown fox jumps over the lazy dog"]
+        );
+        sut.set_display_info(&DisplaySize::new(10, 2));
+        assert_eq!(sut.lines(), vec!["The quick", "brown fox"]);
+        assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+        assert_eq!(sut.lines(), vec!["jumps over", "the lazy"]);
+        sut.dispatch(Interaction::Action);
+        assert_eq!(sut.lines(), vec!["dog"]);
+        assert_eq!(sut.scroll_position().unwrap().to_string(), "3/3");
+        sut.dispatch(Interaction::Action);
+        assert_eq!(sut.lines(), vec!["The quick", "brown fox"]);
+    }
+
+    #[test]
+    fn show_sibling_hints_in_the_last_row() {
+        let mut sut = TextPage::new(
+            BasicPage::new("MyTitle", None),
+            "The quick brown fox jumps over the lazy dog",
+        )
+        .with_sibling_hints();
+        sut.set_display_info(&DisplaySize::new(21, 3));
+        sut.set_siblings(Siblings {
+            previous: Some("Time"),
+            next: Some("Temperature"),
+        });
+        assert_eq!(
+            sut.lines(),
+            vec![
+                "The quick brown fox",
+                "jumps over the lazy",
+                "◄ Time | Temperatu..."
+            ]
+        );
+        assert_eq!(sut.scroll_position().unwrap().to_string(), "1/2");
+        sut.dispatch(Interaction::Action);
+        assert_eq!(sut.lines(), vec!["dog", "", "◄ Time | Temperatu..."]);
+
+        sut.set_siblings(Siblings {
+            previous: None,
+            next: Some("Time"),
+        });
+        assert_eq!(sut.lines(), vec!["dog", "", "Time ►"]);
+
+        // a single row shows the text
+        sut.set_display_info(&DisplaySize::new(21, 1));
+        assert_eq!(sut.lines(), vec!["The quick brown fox"]);
+    }
+
+    #[test]
+    fn scroll_long_title() {
+        let mut sut = TextPage::new(BasicPage::new("Temperature", None), "21")
+            .with_marquee(Marquee::new(2, 0));
+        assert_eq!(sut.visible_title(), "Temperature");
+        sut.set_display_info(&DisplaySize::new(8, 1));
+        assert_eq!(sut.visible_title(), "Temperat");
+        sut.update(None).unwrap();
+        assert_eq!(sut.visible_title(), "mperatur");
+        sut.update(None).unwrap();
+        assert_eq!(sut.visible_title(), "perature");
+    }
 }
 
 mod startup_page {
 
     use super::super::*;
 
+    #[test]
+    fn check_translated_title() {
+        static TRANSLATIONS: Translations = Translations::new(
+            &["English", "Deutsch"],
+            &[
+                &["Back", "Ok", "Startup", "Shutdown"],
+                &["Zurück", "Ok", "Start", "Ende"],
+            ],
+        );
+        let sut = StartupPage::new("Welcome", 2).with_translations(&TRANSLATIONS);
+        assert_eq!(sut.title(), "Startup");
+        TRANSLATIONS.set_language(1).unwrap();
+        assert_eq!(sut.title(), "Start");
+    }
+
     #[test]
     fn check_title_and_content() {
         let sut = StartupPage::new("MyContent", 2);
@@ -59,6 +155,20 @@ mod shutdown_page {
     fn check_lifetime() {
         let mut sut = ShutdownPage::new("MyContent", 2);
         assert_eq!(sut.update(None).unwrap(), PageNavigation::SystemStop);
-        assert!(sut.update(None).is_err());
+        assert_eq!(sut.update(None).unwrap(), PageNavigation::Terminate);
+    }
+
+    #[test]
+    fn cancel_by_interaction() {
+        let mut sut = ShutdownPage::new("MyContent", 2).with_cancel(Interaction::Back);
+        assert_eq!(
+            sut.dispatch(Interaction::Action),
+            PageNavigation::SystemStop
+        );
+        assert_eq!(sut.update(None).unwrap(), PageNavigation::SystemStop);
+        assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Update);
+        // the next shutdown has the full lifetime
+        assert_eq!(sut.update(None).unwrap(), PageNavigation::SystemStop);
+        assert_eq!(sut.update(None).unwrap(), PageNavigation::Terminate);
     }
 }
@@ -0,0 +1,244 @@
+use super::super::{
+    graphics::GraphicsDisplay, truncate, DateTime, DisplayInfo, Interaction, PageBaseInterface,
+    PageError, PageInteractionInterface, PageInterface, PageNavigation, TimeSource,
+};
+use super::basic::BasicPage;
+
+use embedded_graphics::{
+    mono_font::MonoTextStyleBuilder,
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
+};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// A page showing a month with today highlighted, e.g. on devices scheduling alarms
+///
+/// Requires the `graphics` feature. The month is drawn as grid of weeks
+/// starting with Monday below a row of the weekdays; today is drawn in the
+/// highlight color. The title bar shows the month and the year. If the grid
+/// does not fit the display, today's date is shown as text instead.
+///
+/// The date of today is read from the time source with every update.
+///
+/// * previous, next - show the previous or next month
+/// * action - shows the month of today again
+/// * back - leaves the page with up-navigation
+/// * home - leaves the page with home-navigation
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, CalendarPage};
+///     use embedded_multi_page_hmi::{DateTime, Interaction, PageBaseInterface};
+///     use embedded_multi_page_hmi::PageInteractionInterface;
+///
+///     let rtc = || DateTime::new(2024, 12, 24, 18, 0, 0);
+///     let mut page = CalendarPage::new(BasicPage::new("Calendar", None), Box::new(rtc));
+///     page.update(None).unwrap();
+///     page.dispatch(Interaction::Next);
+///     assert_eq!(page.month(), (2025, 1));
+///     assert_eq!(page.heading(), "January 2025");
+/// ```
+pub struct CalendarPage<'a> {
+    pub basic: BasicPage,
+    time: Box<dyn TimeSource + 'a>,
+    today: DateTime,
+    offset: i32,
+    months: &'static [&'static str; 12],
+    weekdays: &'static [&'static str; 7],
+}
+
+impl<'a> CalendarPage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page, e.g. shown in menus
+    /// * `time`: - The local time, e.g. of a real time clock
+    pub fn new(basic: BasicPage, time: Box<dyn TimeSource + 'a>) -> Self {
+        CalendarPage {
+            basic,
+            time,
+            today: DateTime::new(1970, 1, 1, 0, 0, 0),
+            offset: 0,
+            months: &MONTHS,
+            weekdays: &WEEKDAYS,
+        }
+    }
+
+    /// Replace the names of the months and weekdays, e.g. by translated names
+    ///
+    /// Arguments
+    ///
+    /// * `months`: - The names of the months starting with January
+    /// * `weekdays`: - The short names of the weekdays starting with Monday
+    pub fn with_names(
+        mut self,
+        months: &'static [&'static str; 12],
+        weekdays: &'static [&'static str; 7],
+    ) -> Self {
+        self.months = months;
+        self.weekdays = weekdays;
+        self
+    }
+
+    /// The month shown as year and month
+    pub fn month(&self) -> (u16, u8) {
+        let months = self.today.year as i32 * 12 + self.today.month as i32 - 1 + self.offset;
+        (
+            months.div_euclid(12) as u16,
+            months.rem_euclid(12) as u8 + 1,
+        )
+    }
+
+    /// The name of the month shown and the year, e.g. `May 2024`
+    pub fn heading(&self) -> String {
+        let (year, month) = self.month();
+        format!("{} {}", self.months[month as usize - 1], year)
+    }
+
+    /// The day of today if the month of today is shown
+    pub fn today(&self) -> Option<u8> {
+        match self.offset {
+            0 => Some(self.today.day),
+            _ => None,
+        }
+    }
+
+    /// The weeks of the month shown starting with Monday - days of other months are `None`
+    pub fn weeks(&self) -> Vec<[Option<u8>; 7]> {
+        let (year, month) = self.month();
+        let first = DateTime::new(year, month, 1, 0, 0, 0).weekday() as usize;
+        let days = DateTime::days_in_month(year, month) as usize;
+        let mut weeks = vec![[None; 7]; (first + days).div_ceil(7)];
+        for day in 1..=days {
+            let cell = first + day - 1;
+            weeks[cell / 7][cell % 7] = Some(day as u8);
+        }
+        weeks
+    }
+}
+
+impl PageBaseInterface for CalendarPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    /// Read the date of today
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.today = self.time.now();
+        Ok(PageNavigation::Update)
+    }
+
+    /// Show the month of today when entered again
+    fn on_leave(&mut self) {
+        self.offset = 0;
+    }
+}
+
+impl PageInteractionInterface for CalendarPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Next => {
+                self.offset += 1;
+                PageNavigation::Update
+            }
+            Interaction::Previous => {
+                self.offset -= 1;
+                PageNavigation::Update
+            }
+            Interaction::Action => {
+                self.offset = 0;
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+}
+
+impl<G: DrawTarget, C> PageInterface<GraphicsDisplay<G>, C> for CalendarPage<'_> {
+    fn display(&self, display_driver: &mut GraphicsDisplay<G>) {
+        let theme = *display_driver.theme();
+        let font = theme.font;
+        let top = theme.title_font.character_size.height;
+        let size = display_driver.target().bounding_box().size;
+        let weeks = self.weeks();
+        // the weekdays are shown in the first row
+        let rows = weeks.len() as u32 + 1;
+        let (width, height) = (size.width / 7, size.height.saturating_sub(top) / rows);
+        let text_width = 2 * (font.character_size.width + font.character_spacing);
+        if width < text_width || height < font.character_size.height {
+            let date = format!("{} {}", self.today.day, self.heading());
+            let cols = display_driver.cols();
+            return display_driver.update(self.title(), &truncate(&date, cols));
+        }
+
+        display_driver.update(&self.heading(), "");
+        let normal = MonoTextStyleBuilder::new()
+            .font(font)
+            .text_color(theme.foreground)
+            .build();
+        let highlighted = MonoTextStyleBuilder::new()
+            .font(font)
+            .text_color(theme.background)
+            .build();
+        let centered = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Top)
+            .build();
+        let target = display_driver.target_mut();
+        let cell = |column: usize, row: usize| {
+            Point::new(
+                (column as u32 * width) as i32,
+                (top + row as u32 * height) as i32,
+            )
+        };
+        let center = Point::new(width as i32 / 2, 0);
+        for (column, name) in self.weekdays.iter().enumerate() {
+            let _ = Text::with_text_style(name, cell(column, 0) + center, normal, centered)
+                .draw(target);
+        }
+        for (row, week) in weeks.iter().enumerate() {
+            for (column, day) in week.iter().enumerate() {
+                let day = match day {
+                    Some(day) => *day,
+                    None => continue,
+                };
+                let origin = cell(column, row + 1);
+                let style = match self.today() == Some(day) {
+                    true => {
+                        let _ = Rectangle::new(origin, Size::new(width, height))
+                            .into_styled(PrimitiveStyle::with_fill(theme.highlight))
+                            .draw(target);
+                        highlighted
+                    }
+                    false => normal,
+                };
+                let _ = Text::with_text_style(&day.to_string(), origin + center, style, centered)
+                    .draw(target);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,73 @@
+use super::*;
+use crate::graphics::Theme;
+use embedded_graphics::mock_display::MockDisplay;
+use embedded_graphics::mono_font::ascii::FONT_4X6;
+use embedded_graphics::pixelcolor::BinaryColor;
+
+fn page() -> CalendarPage<'static> {
+    let rtc = || DateTime::new(2024, 5, 15, 12, 0, 0);
+    let mut page = CalendarPage::new(BasicPage::new("Calendar", None), Box::new(rtc));
+    page.update(None).unwrap();
+    page
+}
+
+fn show(page: &CalendarPage<'_>, theme: Theme<BinaryColor>) -> MockDisplay<BinaryColor> {
+    let mut target = MockDisplay::new();
+    target.set_allow_overdraw(true);
+    let mut display = GraphicsDisplay::new(target, theme);
+    PageInterface::<_, ()>::display(page, &mut display);
+    display.target().clone()
+}
+
+#[test]
+fn weeks_of_the_month() {
+    let page = page();
+    assert_eq!(page.month(), (2024, 5));
+    assert_eq!(page.heading(), "May 2024");
+    assert_eq!(page.today(), Some(15));
+    let weeks = page.weeks();
+    assert_eq!(weeks.len(), 5);
+    assert_eq!(
+        weeks[0],
+        [None, None, Some(1), Some(2), Some(3), Some(4), Some(5)]
+    );
+    assert_eq!(weeks[4][4], Some(31));
+    assert_eq!(weeks[4][5], None);
+}
+
+#[test]
+fn browse_months() {
+    let mut page = page();
+    for _ in 0..5 {
+        assert_eq!(page.dispatch(Interaction::Previous), PageNavigation::Update);
+    }
+    assert_eq!(page.month(), (2023, 12));
+    assert_eq!(page.today(), None);
+    assert_eq!(page.weeks()[0][4], Some(1));
+    page.dispatch(Interaction::Action);
+    assert_eq!(page.month(), (2024, 5));
+
+    page.dispatch(Interaction::Next);
+    page.on_leave();
+    assert_eq!(page.month(), (2024, 5));
+    assert_eq!(page.dispatch(Interaction::Back), PageNavigation::Up);
+    assert_eq!(page.dispatch(Interaction::Home), PageNavigation::Home);
+}
+
+#[test]
+fn highlight_today() {
+    // 9 pixels per day and per week below the title bar of 6 pixels
+    let theme = Theme::default().with_fonts(&FONT_4X6, &FONT_4X6);
+    let target = show(&page(), theme);
+    // the 15th is the Wednesday of the third week
+    assert_eq!(target.get_pixel(Point::new(18, 33)), Some(BinaryColor::On));
+    assert_eq!(target.get_pixel(Point::new(26, 41)), Some(BinaryColor::On));
+    assert_eq!(target.get_pixel(Point::new(18, 42)), Some(BinaryColor::Off));
+}
+
+#[test]
+fn date_as_text_if_the_month_does_not_fit() {
+    // 6x10 font needs 12 pixels per day
+    let target = show(&page(), Theme::default());
+    assert_eq!(target.get_pixel(Point::new(18, 33)), Some(BinaryColor::Off));
+}
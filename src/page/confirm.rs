@@ -0,0 +1,97 @@
+use super::super::{truncate, Interaction, PageNavigation};
+
+/// The outcome of an interaction with a `Confirmation`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Answer {
+    /// Yes is selected and confirmed by action
+    Yes,
+    /// Not confirmed - the page navigation to return
+    Navigation(PageNavigation),
+}
+
+/// A yes/no question asked by pages before doing something, e.g. powering off
+///
+/// No is selected by default and again after the question is answered yes.
+///
+/// * previous, next - select no or yes
+/// * action - answers yes if yes is selected, leaves the page with up-navigation otherwise
+/// * back - leaves the page with up-navigation
+/// * home - leaves the page with home-navigation
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{Answer, Confirmation};
+///     use embedded_multi_page_hmi::{Interaction, PageNavigation};
+///
+///     let mut confirmation = Confirmation::new("Delete log?");
+///     assert_eq!(confirmation.lines(20), vec!["Delete log?", "[No] Yes"]);
+///     assert_eq!(
+///         confirmation.dispatch(Interaction::Next),
+///         Answer::Navigation(PageNavigation::Update)
+///     );
+///     assert_eq!(confirmation.dispatch(Interaction::Action), Answer::Yes);
+///     assert!(!confirmation.is_yes());
+/// ```
+pub struct Confirmation {
+    question: String,
+    yes: bool,
+}
+
+impl Confirmation {
+    /// Arguments
+    ///
+    /// * `question`: - The question asked
+    pub fn new(question: &str) -> Self {
+        Confirmation {
+            question: String::from(question),
+            yes: false,
+        }
+    }
+
+    /// Replace the question asked, e.g. by a translated text
+    pub fn set_question(&mut self, question: &str) {
+        self.question = String::from(question);
+    }
+
+    /// Yes is selected
+    pub fn is_yes(&self) -> bool {
+        self.yes
+    }
+
+    /// Select no, e.g. when the page asking is left
+    pub fn reset(&mut self) {
+        self.yes = false;
+    }
+
+    /// The question and the answer selected, each truncated to the columns given
+    pub fn lines(&self, cols: usize) -> Vec<String> {
+        let answer = match self.yes {
+            false => "[No] Yes",
+            true => "No [Yes]",
+        };
+        [self.question.as_str(), answer]
+            .iter()
+            .map(|line| truncate(line, cols).into_owned())
+            .collect()
+    }
+
+    /// Select or answer by an interaction
+    pub fn dispatch(&mut self, interaction: Interaction) -> Answer {
+        match interaction {
+            Interaction::Next | Interaction::Previous => {
+                self.yes = !self.yes;
+                Answer::Navigation(PageNavigation::Update)
+            }
+            Interaction::Action if self.yes => {
+                self.yes = false;
+                Answer::Yes
+            }
+            Interaction::Action | Interaction::Back => Answer::Navigation(PageNavigation::Up),
+            Interaction::Home => Answer::Navigation(PageNavigation::Home),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
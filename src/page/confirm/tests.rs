@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn answer_yes() {
+    let mut sut = Confirmation::new("Power off?");
+    assert_eq!(sut.lines(20), vec!["Power off?", "[No] Yes"]);
+    assert_eq!(
+        sut.dispatch(Interaction::Previous),
+        Answer::Navigation(PageNavigation::Update)
+    );
+    assert!(sut.is_yes());
+    assert_eq!(sut.lines(20)[1], "No [Yes]");
+    assert_eq!(sut.dispatch(Interaction::Action), Answer::Yes);
+    // asks again with no selected
+    assert!(!sut.is_yes());
+}
+
+#[test]
+fn answer_no() {
+    let mut sut = Confirmation::new("Power off?");
+    assert_eq!(
+        sut.dispatch(Interaction::Action),
+        Answer::Navigation(PageNavigation::Up)
+    );
+    sut.dispatch(Interaction::Next);
+    assert_eq!(
+        sut.dispatch(Interaction::Back),
+        Answer::Navigation(PageNavigation::Up)
+    );
+    assert_eq!(
+        sut.dispatch(Interaction::Home),
+        Answer::Navigation(PageNavigation::Home)
+    );
+    sut.reset();
+    assert_eq!(
+        sut.dispatch(Interaction::Action),
+        Answer::Navigation(PageNavigation::Up)
+    );
+}
+
+#[test]
+fn lines_fit_the_display() {
+    let mut sut = Confirmation::new("Power off?");
+    sut.set_question("Gerät ausschalten?");
+    assert_eq!(sut.lines(8), vec!["Gerät...", "[No] Yes"]);
+}
@@ -0,0 +1,210 @@
+use super::super::{
+    number::clock, Feedback, Interaction, PageBaseInterface, PageError, PageInteractionInterface,
+    PageNavigation,
+};
+use super::basic::BasicPage;
+
+/// The phases of the countdown
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    /// Not started - starts with the next update
+    Idle,
+    /// Counting down - with the timestamp the elapsed time is counted up to
+    Running(u32),
+    /// Paused by the user
+    Paused,
+}
+
+/// A page counting down a duration, e.g. a sterilization cycle or a tea timer
+///
+/// The countdown starts with the first update of the page shown and runs
+/// while the page is shown; leaving the page cancels it. The time is taken
+/// from a clock, i.e. it does not depend on the update rate.
+///
+/// Expired, the expiry callback is called, `Feedback::Alarm` is requested and
+/// the page navigates to the target navigation, `PageNavigation::Up` by default.
+///
+/// * action - pauses and resumes the countdown
+/// * back - cancels the countdown and leaves the page with up-navigation
+/// * home - cancels the countdown and leaves the page with home-navigation
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, CountdownPage};
+///     use embedded_multi_page_hmi::{PageBaseInterface, PageNavigation};
+///     use std::cell::Cell;
+///
+///     let now = Cell::new(0);
+///     let mut page = CountdownPage::new(BasicPage::new("Tea", None), 180, Box::new(|| now.get()))
+///         .with_target(PageNavigation::Home);
+///     page.update(None).unwrap();
+///     now.set(60_000);
+///     page.update(None).unwrap();
+///     assert_eq!(page.lines(), vec!["02:00"]);
+///     now.set(180_000);
+///     assert_eq!(page.update(None).unwrap(), PageNavigation::Home);
+/// ```
+pub struct CountdownPage<'a> {
+    pub basic: BasicPage,
+    duration: u32,
+    clock: Box<dyn FnMut() -> u32 + 'a>,
+    target: PageNavigation,
+    expiry: Option<Box<dyn FnMut() + 'a>>,
+    paused_text: String,
+    phase: Phase,
+    elapsed: u32,
+    expired: bool,
+}
+
+impl<'a> CountdownPage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `duration`: - Seconds to count down
+    /// * `clock`: - Milliseconds of a free running clock, e.g. a system tick counter
+    pub fn new(basic: BasicPage, duration: u32, clock: Box<dyn FnMut() -> u32 + 'a>) -> Self {
+        CountdownPage {
+            basic,
+            duration,
+            clock,
+            target: PageNavigation::Up,
+            expiry: None,
+            paused_text: String::from("Paused"),
+            phase: Phase::Idle,
+            elapsed: 0,
+            expired: false,
+        }
+    }
+
+    /// Navigate to the target once the countdown expired
+    pub fn with_target(mut self, target: PageNavigation) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Call the callback once the countdown expired, e.g. to switch off a heater
+    pub fn with_expiry(mut self, expiry: Box<dyn FnMut() + 'a>) -> Self {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    /// Replace the text shown while paused, e.g. by a translated text
+    pub fn with_paused_text(mut self, paused: &str) -> Self {
+        self.paused_text = String::from(paused);
+        self
+    }
+
+    /// The countdown is paused by the user
+    pub fn is_paused(&self) -> bool {
+        self.phase == Phase::Paused
+    }
+
+    /// Seconds left - rounded up, i.e. zero once expired
+    pub fn remaining(&self) -> u32 {
+        let left = (self.duration as u64 * 1000).saturating_sub(self.elapsed as u64);
+        left.div_ceil(1000) as u32
+    }
+
+    /// The time left and if the countdown is paused
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![format_remaining(self.remaining())];
+        if self.is_paused() {
+            lines.push(self.paused_text.clone());
+        }
+        lines
+    }
+
+    /// Count the time since the last count
+    fn count(&mut self) {
+        if let Phase::Running(since) = self.phase {
+            let now = (self.clock)();
+            self.elapsed = self.elapsed.saturating_add(now.wrapping_sub(since));
+            self.phase = Phase::Running(now);
+        }
+    }
+
+    /// Stop counting and start over with the next update
+    fn reset(&mut self) {
+        self.phase = Phase::Idle;
+        self.elapsed = 0;
+    }
+}
+
+/// Format seconds as `mm:ss` - or `h:mm:ss` from one hour on
+fn format_remaining(seconds: u32) -> String {
+    match seconds / 3600 {
+        0 => clock(&[seconds / 60, seconds % 60]),
+        hours => format!("{}:{}", hours, clock(&[seconds % 3600 / 60, seconds % 60])),
+    }
+}
+
+impl PageBaseInterface for CountdownPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    /// Start or count down - navigate to the target once expired
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        match self.phase {
+            Phase::Idle => self.phase = Phase::Running((self.clock)()),
+            Phase::Running(_) => self.count(),
+            Phase::Paused => (),
+        }
+        if self.remaining() > 0 {
+            return Ok(PageNavigation::Update);
+        }
+        self.reset();
+        self.expired = true;
+        if let Some(expiry) = &mut self.expiry {
+            expiry();
+        }
+        Ok(self.target)
+    }
+
+    /// Cancel the countdown
+    fn on_leave(&mut self) {
+        self.reset();
+    }
+
+    /// Alert that the countdown expired
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        match std::mem::replace(&mut self.expired, false) {
+            true => Some(Feedback::Alarm),
+            false => None,
+        }
+    }
+}
+
+impl PageInteractionInterface for CountdownPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Action => {
+                match self.phase {
+                    Phase::Running(_) => {
+                        self.count();
+                        self.phase = Phase::Paused;
+                    }
+                    Phase::Paused => self.phase = Phase::Running((self.clock)()),
+                    Phase::Idle => (),
+                }
+                PageNavigation::Update
+            }
+            Interaction::Back => {
+                self.reset();
+                PageNavigation::Up
+            }
+            Interaction::Home => {
+                self.reset();
+                PageNavigation::Home
+            }
+            Interaction::Next | Interaction::Previous => PageNavigation::Update,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+use super::*;
+use std::cell::Cell;
+
+fn page<'a>(now: &'a Cell<u32>, duration: u32) -> CountdownPage<'a> {
+    CountdownPage::new(
+        BasicPage::new("Timer", None),
+        duration,
+        Box::new(move || now.get()),
+    )
+}
+
+#[test]
+fn count_down_to_expiry() {
+    let now = Cell::new(5_000);
+    let expired = Cell::new(0);
+    let mut sut = page(&now, 90).with_expiry(Box::new(|| expired.set(expired.get() + 1)));
+    assert_eq!(sut.lines(), vec!["01:30"]);
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Update);
+    now.set(5_500);
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Update);
+    assert_eq!(sut.remaining(), 90);
+    now.set(94_001);
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Update);
+    assert_eq!(sut.lines(), vec!["00:01"]);
+    assert_eq!(sut.take_feedback(), None);
+
+    now.set(95_000);
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Up);
+    assert_eq!(expired.get(), 1);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Alarm));
+    assert_eq!(sut.take_feedback(), None);
+    // starts over
+    assert_eq!(sut.remaining(), 90);
+}
+
+#[test]
+fn pause_and_resume() {
+    let now = Cell::new(0);
+    let mut sut = page(&now, 60).with_paused_text("Pause");
+    sut.update(None).unwrap();
+    now.set(10_000);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    assert!(sut.is_paused());
+    assert_eq!(sut.lines(), vec!["00:50", "Pause"]);
+
+    // no time passes while paused
+    now.set(100_000);
+    sut.update(None).unwrap();
+    assert_eq!(sut.remaining(), 50);
+    sut.dispatch(Interaction::Action);
+    assert!(!sut.is_paused());
+    now.set(149_000);
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Update);
+    assert_eq!(sut.lines(), vec!["00:01"]);
+}
+
+#[test]
+fn cancel_countdown() {
+    let now = Cell::new(0);
+    let mut sut = page(&now, 60).with_target(PageNavigation::Home);
+    sut.update(None).unwrap();
+    now.set(30_000);
+    sut.update(None).unwrap();
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert_eq!(sut.remaining(), 60);
+
+    sut.update(None).unwrap();
+    now.set(60_000);
+    sut.update(None).unwrap();
+    sut.on_leave();
+    assert_eq!(sut.remaining(), 60);
+    assert_eq!(sut.take_feedback(), None);
+}
+
+#[test]
+fn clock_wraps_around() {
+    let now = Cell::new(u32::MAX - 999);
+    let mut sut = page(&now, 2);
+    sut.update(None).unwrap();
+    now.set(999);
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Update);
+    assert_eq!(sut.remaining(), 1);
+}
+
+#[test]
+fn format_hours() {
+    assert_eq!(format_remaining(59), "00:59");
+    assert_eq!(format_remaining(3599), "59:59");
+    assert_eq!(format_remaining(7384), "2:03:04");
+}
@@ -0,0 +1,106 @@
+use super::super::{
+    wrap, DisplayInfo, DisplaySize, Feedback, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation,
+};
+use super::basic::BasicPage;
+
+/// A text page whose text changes at runtime
+///
+/// The text is provided by a closure, e.g. reading the last measurement or
+/// error message. The text is fetched on every update of the page, i.e. status
+/// screens do not need a page type of their own.
+///
+/// If the display size is known, the text is wrapped into the rows of the display.
+/// Error or alarm pages request feedback whenever they get activated.
+/// A page requesting `Feedback::Alarm` is indicated as alarm page.
+pub struct DynamicTextPage<'a> {
+    pub basic: BasicPage,
+    pub text: String,
+    provider: Box<dyn FnMut() -> String + 'a>,
+    size: DisplaySize,
+    feedback: Option<Feedback>,
+    shown: bool,
+    requested: Option<Feedback>,
+}
+
+impl<'a> DynamicTextPage<'a> {
+    pub fn new(basic: BasicPage, provider: Box<dyn FnMut() -> String + 'a>) -> Self {
+        DynamicTextPage {
+            basic,
+            text: String::new(),
+            provider,
+            size: DisplaySize::default(),
+            feedback: None,
+            shown: false,
+            requested: None,
+        }
+    }
+
+    /// Request feedback whenever the page gets activated, e.g. `Feedback::Error`
+    pub fn with_feedback(mut self, feedback: Feedback) -> Self {
+        self.feedback = Some(feedback);
+        self
+    }
+
+    /// The lines of the text fitting the display
+    pub fn lines(&self) -> Vec<&str> {
+        wrap(&self.text, self.size.cols)
+            .into_iter()
+            .take(self.size.rows)
+            .collect()
+    }
+}
+
+impl PageBaseInterface for DynamicTextPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    /// A page requesting `Feedback::Alarm` is an alarm page
+    fn is_alarm(&self) -> bool {
+        self.feedback == Some(Feedback::Alarm)
+    }
+
+    fn on_leave(&mut self) {
+        self.shown = false;
+    }
+
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        self.requested.take()
+    }
+
+    /// Fetch the text and take care of the lifetime
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.text = (self.provider)();
+        if !self.shown {
+            self.shown = true;
+            self.requested = self.feedback;
+        }
+        match self.basic.lifetime {
+            Some(mut lifetime) => {
+                let mut result = PageNavigation::Update;
+                lifetime.increase_age();
+                if lifetime.is_over() {
+                    debug!("lifetime of page {} is over", self.title());
+                    lifetime.reset_age();
+                    result = lifetime.get_target();
+                }
+                self.basic.lifetime = Some(lifetime);
+                Ok(result)
+            }
+            None => Ok(PageNavigation::Update),
+        }
+    }
+}
+
+impl PageInteractionInterface for DynamicTextPage<'_> {}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,29 @@
+use super::*;
+use crate::PageLifetime;
+use std::cell::Cell;
+
+#[test]
+fn text_follows_provider() {
+    let measurement = Cell::new(21);
+    let mut sut = DynamicTextPage::new(
+        BasicPage::new("Temperature", None),
+        Box::new(|| format!("{} °C", measurement.get())),
+    );
+    assert_eq!(sut.title(), "Temperature");
+    assert_eq!(sut.text, "");
+    sut.update(None).unwrap();
+    assert_eq!(sut.text, "21 °C");
+    measurement.set(22);
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["22 °C"]);
+}
+
+#[test]
+fn check_lifetime() {
+    let mut sut = DynamicTextPage::new(
+        BasicPage::new("Error", Some(PageLifetime::new(PageNavigation::Home, 2))),
+        Box::new(|| String::from("Sensor failure")),
+    );
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Update);
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Home);
+}
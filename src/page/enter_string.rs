@@ -1,7 +1,4 @@
-#[allow(unused_imports)]
 use super::super::setting::Setting;
-
-use super::super::setting::CellSetting;
 use super::basic::BasicPage;
 
 use std::fmt::{Debug, Display};
@@ -10,7 +7,10 @@ use std::str::FromStr;
 #[allow(unused_imports)]
 use std::string::String;
 
-/// Page that allows to enter an ascii string composed of fixed set of characters.
+/// Page that allows to enter a string composed of fixed set of characters.
+///
+/// The allowed characters can be any UTF-8 characters, e.g. umlauts or the
+/// degree sign.
 ///
 /// Interaction is as follows:
 /// * entering the page -> previous collected characters are shown
@@ -21,6 +21,10 @@ use std::string::String;
 /// * action - appends the selected character to the so far collected characters
 /// * back - removes (most right character from the collected characters)
 /// * home - leaves the page with UP-navigation
+/// * characters entered directly, e.g. by a keypad, are appended if allowed
+///
+/// Navigating away while the entered value is not committed is vetoed, see
+/// `PageBaseInterface::can_leave()`.
 ///
 /// If previous button does not exist, next starts at the beginning after reaching
 /// the end.
@@ -34,9 +38,12 @@ pub struct EnterStringPage<'a, T> {
     max_chars: usize,
     pub buffer: String,
 
-    back: Option<&'static str>, // the Back menu entry in language
-    up: Option<&'static str>,   // the OK/Up/leave menu entry in language
-    value: &'a CellSetting<T>,  // the value to store
+    back: Option<&'static str>,       // the Back menu entry in language
+    up: Option<&'static str>,         // the OK/Up/leave menu entry in language
+    value: &'a dyn Setting<Item = T>, // the value to store
+    translations: Option<&'static Translations>,
+    committed: bool,
+    result: Option<T>,
 }
 
 impl<'a, T: Copy + FromStr + Display> EnterStringPage<'a, T>
@@ -48,9 +55,9 @@ where
         allowed_characters: &'static str,
         back: Option<&'static str>,
         up: Option<&'static str>,
-        value: &'a CellSetting<T>,
+        value: &'a dyn Setting<Item = T>,
     ) -> Self {
-        let mut max_chars = allowed_characters.len();
+        let mut max_chars = allowed_characters.chars().count();
         if back.is_some() {
             max_chars += 1;
         }
@@ -67,6 +74,37 @@ where
             up,
             max_chars,
             value,
+            translations: None,
+            committed: false,
+            result: None,
+        }
+    }
+
+    /// Show the back and the finish action in the active language
+    pub fn with_translations(mut self, translations: &'static Translations) -> Self {
+        self.translations = Some(translations);
+        self
+    }
+
+    /// A fixed action string in the active language
+    fn translate(&self, text: &'static str, id: TextId) -> &'static str {
+        match self.translations {
+            Some(translations) => translations.get(id),
+            None => text,
+        }
+    }
+
+    /// Number of allowed characters
+    fn allowed_len(&self) -> usize {
+        self.allowed_characters.chars().count()
+    }
+
+    /// The selected allowed character
+    fn selected_char(&self) -> &'static str {
+        let allowed = self.allowed_characters;
+        match allowed.char_indices().nth(self.current_char) {
+            Some((offset, c)) => &allowed[offset..offset + c.len_utf8()],
+            None => "",
         }
     }
 
@@ -75,10 +113,10 @@ where
         match self.up {
             None => false,
             Some(_) => {
-                if self.current_char >= self.allowed_characters.len() {
+                if self.current_char >= self.allowed_len() {
                     return match self.back {
                         None => true,
-                        Some(_) => self.current_char > self.allowed_characters.len(),
+                        Some(_) => self.current_char > self.allowed_len(),
                     };
                 }
                 false
@@ -90,7 +128,7 @@ where
     fn is_back(&self) -> bool {
         match self.back {
             None => false,
-            Some(_) => self.current_char == self.allowed_characters.len(),
+            Some(_) => self.current_char == self.allowed_len(),
         }
     }
 
@@ -105,26 +143,25 @@ where
     ///
     /// h2. Args
     ///
-
     pub fn action_string(&self) -> &'static str {
         if self.is_back() {
             if let Some(back) = self.back {
-                return back;
+                return self.translate(back, TextId::BACK);
             }
         }
         if self.is_finish() {
             if let Some(up) = self.up {
                 self.value.set_string(&self.buffer[..]);
-                return up;
+                return self.translate(up, TextId::OK);
             }
         }
-        &self.allowed_characters[self.current_char..self.current_char + 1]
+        self.selected_char()
     }
 }
 
 use super::super::*;
 
-impl<T: Copy + FromStr + Display> PageInteractionInterface for EnterStringPage<'_, T>
+impl<T: Copy + FromStr + Display + 'static> PageInteractionInterface for EnterStringPage<'_, T>
 where
     <T as FromStr>::Err: Debug,
 {
@@ -136,14 +173,11 @@ where
                     return PageNavigation::Update;
                 }
                 if self.is_finish() {
+                    self.committed = true;
+                    self.result = self.buffer.parse().ok();
                     return PageNavigation::Up;
                 }
-                self.buffer.push(
-                    self.allowed_characters
-                        .chars()
-                        .nth(self.current_char)
-                        .unwrap(),
-                );
+                self.buffer.push_str(self.selected_char());
                 PageNavigation::Update
             }
             Interaction::Back => {
@@ -152,6 +186,8 @@ where
             }
             Interaction::Home => {
                 self.value.set_string(&self.buffer[..]);
+                self.committed = true;
+                self.result = self.buffer.parse().ok();
                 PageNavigation::Up
             }
             Interaction::Next => {
@@ -173,9 +209,21 @@ where
             }
         }
     }
+
+    /// Append an allowed character and select it
+    fn dispatch_char(&mut self, c: char) -> PageNavigation {
+        if let Some(index) = self.allowed_characters.chars().position(|a| a == c) {
+            self.current_char = index;
+            self.buffer.push(c);
+        }
+        PageNavigation::Update
+    }
 }
 
-impl<T> PageBaseInterface for EnterStringPage<'_, T> {
+impl<T: Copy + FromStr + Display + 'static> PageBaseInterface for EnterStringPage<'_, T>
+where
+    <T as FromStr>::Err: Debug,
+{
     fn update<'a>(
         &mut self,
         _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
@@ -185,7 +233,44 @@ impl<T> PageBaseInterface for EnterStringPage<'_, T> {
     }
 
     fn title(&self) -> &str {
-        self.basic.title
+        &self.basic.title
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    /// Veto leaving while the entered value is not committed
+    fn can_leave(&self) -> Result<(), &str> {
+        // the entered text is compared as value, e.g. "04" is saved as 4
+        let entered = self
+            .buffer
+            .parse::<T>()
+            .ok()
+            .map(|value| format!("{}", value));
+        match self.committed || entered == Some(format!("{}", self.value.get())) {
+            true => Ok(()),
+            false => Err("Not saved"),
+        }
+    }
+
+    /// Show the value of the setting again, e.g. after it was changed remotely
+    fn reload(&mut self) {
+        self.buffer = format!("{}", self.value.get());
+        self.current_char = 0;
+    }
+
+    /// The value committed, e.g. for a form page opening the page
+    fn take_result(&mut self) -> Option<PageResult> {
+        self.result.take().map(PageResult::new)
+    }
+
+    /// Confirm storing the value
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        match std::mem::replace(&mut self.committed, false) {
+            true => Some(Feedback::Confirm),
+            false => None,
+        }
     }
 }
 
@@ -209,3 +209,125 @@ fn action_string() {
     sut.current_char = 5;
     assert_eq!(sut.action_string(), "Ok");
 }
+
+#[test]
+fn enter_multi_byte_characters() {
+    let value: CellSetting<i32> = Default::default();
+    let mut sut: EnterStringPage<i32> = EnterStringPage::<i32>::new(
+        BasicPage::new("MyTitle", None),
+        "äö°",
+        Some("Back"),
+        Some("Ok"),
+        &value,
+    );
+    assert_eq!(sut.max_chars, 5);
+    sut.buffer.clear();
+    assert_eq!(sut.action_string(), "ä");
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.action_string(), "ö");
+    sut.dispatch(Interaction::Action);
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.action_string(), "°");
+    sut.dispatch(Interaction::Action);
+    assert_eq!(&sut.buffer[..], "ö°");
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.action_string(), "Back");
+    sut.dispatch(Interaction::Action);
+    assert_eq!(&sut.buffer[..], "ö");
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.action_string(), "°");
+}
+
+#[test]
+fn translated_action_strings() {
+    static TRANSLATIONS: Translations = Translations::new(
+        &["English", "Deutsch"],
+        &[&["Back", "Ok"], &["Zurück", "Fertig"]],
+    );
+    let value: CellSetting<i32> = Default::default();
+    let mut sut: EnterStringPage<i32> = EnterStringPage::<i32>::new(
+        BasicPage::new("MyTitle", None),
+        "0",
+        Some("<<"),
+        Some("Return"),
+        &value,
+    )
+    .with_translations(&TRANSLATIONS);
+    TRANSLATIONS.set_language(1).unwrap();
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.action_string(), "Zurück");
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.action_string(), "Fertig");
+}
+
+#[test]
+fn enter_characters_directly() {
+    let value: CellSetting<i32> = Default::default();
+    let mut sut: EnterStringPage<i32> = EnterStringPage::<i32>::new(
+        BasicPage::new("MyTitle", None),
+        "0123456789",
+        Some("Back"),
+        Some("Ok"),
+        &value,
+    );
+    sut.buffer.clear();
+    assert_eq!(sut.dispatch_char('4'), PageNavigation::Update);
+    sut.dispatch_char('x');
+    sut.dispatch_char('2');
+    assert_eq!(&sut.buffer[..], "42");
+    assert_eq!(sut.action_string(), "2");
+}
+
+#[test]
+fn veto_leaving_uncommitted_value() {
+    let value: CellSetting<i32> = Default::default();
+    let mut sut: EnterStringPage<i32> = EnterStringPage::<i32>::new(
+        BasicPage::new("MyTitle", None),
+        "0123456789",
+        None,
+        Some("Ok"),
+        &value,
+    );
+    assert_eq!(sut.can_leave(), Ok(()));
+    sut.dispatch_char('4');
+    assert_eq!(sut.can_leave(), Err("Not saved"));
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Up);
+    assert_eq!(sut.can_leave(), Ok(()));
+    sut.take_feedback();
+    assert_eq!(sut.can_leave(), Ok(()));
+}
+
+#[test]
+fn confirm_committed_value() {
+    let value: CellSetting<i32> = Default::default();
+    let mut sut: EnterStringPage<i32> = EnterStringPage::<i32>::new(
+        BasicPage::new("MyTitle", None),
+        "01",
+        None,
+        Some("Ok"),
+        &value,
+    );
+    sut.dispatch(Interaction::Action);
+    assert_eq!(sut.take_feedback(), None);
+    sut.dispatch(Interaction::Next);
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Up);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Confirm));
+    assert_eq!(sut.take_feedback(), None);
+    let result = sut.take_result().unwrap();
+    assert_eq!(result.get::<i32>(), Some(&0));
+    assert!(sut.take_result().is_none());
+}
+
+#[test]
+fn reload_the_value() {
+    let value: CellSetting<i32> = Default::default();
+    value.set(12);
+    let mut sut: EnterStringPage<i32> =
+        EnterStringPage::<i32>::new(BasicPage::new("MyTitle", None), "0123", None, None, &value);
+    sut.dispatch(Interaction::Next);
+    value.set(321);
+    sut.reload();
+    assert_eq!(&sut.buffer[..], "321");
+    assert_eq!(sut.current_char, 0);
+}
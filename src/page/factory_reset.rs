@@ -0,0 +1,183 @@
+use super::super::{
+    truncate, DisplayInfo, DisplaySize, Feedback, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation, SettingsStore,
+};
+use super::basic::BasicPage;
+use super::confirm::{Answer, Confirmation};
+
+use std::cell::RefCell;
+
+/// The phases of the factory reset
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Phase {
+    /// Ask for confirmation
+    Confirm,
+    /// Confirmed - the progress is shown with the next update
+    Progress,
+    /// The progress is shown - the reset is done with the next update
+    Reset,
+}
+
+/// The factory reset of a device as one page to register, e.g. in a settings menu
+///
+/// The page asks to confirm the reset by a `Confirmation` first.
+/// Confirmed, it shows the progress with the next update. The update after
+/// wipes the settings store, calls the reset callback (e.g. to reset the
+/// settings in memory) and navigates to the startup page by
+/// `PageNavigation::SystemStart`. A successful reset requests
+/// `Feedback::Confirm`. If wiping the store fails, the update fails and the
+/// page asks again.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, FactoryResetFlow};
+///     use embedded_multi_page_hmi::{Interaction, PageBaseInterface, PageError};
+///     use embedded_multi_page_hmi::{PageInteractionInterface, PageNavigation, SettingsStore};
+///     use std::cell::RefCell;
+///
+///     struct Eeprom;
+///     impl SettingsStore for Eeprom {
+///         fn store(&mut self, _key: &str, _data: &[u8]) -> Result<(), PageError> { Ok(()) }
+///         fn load(&mut self, _key: &str) -> Option<Vec<u8>> { None }
+///         fn clear(&mut self) -> Result<(), PageError> { Ok(()) }
+///     }
+///
+///     let store = RefCell::new(Eeprom);
+///     let mut page = FactoryResetFlow::new(BasicPage::new("Factory reset", None), &store, Box::new(|| {}));
+///     page.dispatch(Interaction::Next);
+///     page.dispatch(Interaction::Action);
+///     assert_eq!(page.update(None).unwrap(), PageNavigation::Update);
+///     assert_eq!(page.update(None).unwrap(), PageNavigation::SystemStart);
+/// ```
+pub struct FactoryResetFlow<'a> {
+    pub basic: BasicPage,
+    store: &'a RefCell<dyn SettingsStore + 'a>,
+    reset: Box<dyn FnMut() + 'a>,
+    confirmation: Confirmation,
+    progress: String,
+    phase: Phase,
+    done: bool,
+    size: DisplaySize,
+}
+
+impl<'a> FactoryResetFlow<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `store`: - The settings store to wipe
+    /// * `reset`: - Called after the store is wiped
+    pub fn new(
+        basic: BasicPage,
+        store: &'a RefCell<dyn SettingsStore + 'a>,
+        reset: Box<dyn FnMut() + 'a>,
+    ) -> Self {
+        FactoryResetFlow {
+            basic,
+            store,
+            reset,
+            confirmation: Confirmation::new("Reset all settings?"),
+            progress: String::from("Resetting ..."),
+            phase: Phase::Confirm,
+            done: false,
+            size: DisplaySize::default(),
+        }
+    }
+
+    /// Replace the texts shown, e.g. by translated texts
+    ///
+    /// Arguments
+    ///
+    /// * `question`: - The question to confirm the reset
+    /// * `progress`: - Shown while resetting
+    pub fn with_texts(mut self, question: &str, progress: &str) -> Self {
+        self.confirmation.set_question(question);
+        self.progress = String::from(progress);
+        self
+    }
+
+    /// The reset is confirmed and done with one of the next updates
+    pub fn is_resetting(&self) -> bool {
+        matches!(self.phase, Phase::Progress | Phase::Reset)
+    }
+
+    /// The lines of the current phase fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        match self.phase {
+            Phase::Confirm => self.confirmation.lines(self.size.cols),
+            Phase::Progress | Phase::Reset => {
+                vec![truncate(&self.progress, self.size.cols).into_owned()]
+            }
+        }
+    }
+
+    /// Wipe the store and let the application reset
+    fn reset(&mut self) -> Result<PageNavigation, PageError> {
+        self.phase = Phase::Confirm;
+        self.store.borrow_mut().clear()?;
+        (self.reset)();
+        self.done = true;
+        Ok(PageNavigation::SystemStart)
+    }
+}
+
+impl PageBaseInterface for FactoryResetFlow<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    /// Reset if confirmed - after the progress is shown by the update before
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        match self.phase {
+            Phase::Progress => {
+                self.phase = Phase::Reset;
+                Ok(PageNavigation::Update)
+            }
+            Phase::Reset => self.reset(),
+            Phase::Confirm => Ok(PageNavigation::Update),
+        }
+    }
+
+    fn on_leave(&mut self) {
+        self.phase = Phase::Confirm;
+        self.confirmation.reset();
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    /// Confirm the reset done
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        match std::mem::replace(&mut self.done, false) {
+            true => Some(Feedback::Confirm),
+            false => None,
+        }
+    }
+}
+
+impl PageInteractionInterface for FactoryResetFlow<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        if self.phase != Phase::Confirm {
+            // the reset is not interrupted
+            return PageNavigation::Update;
+        }
+        match self.confirmation.dispatch(interaction) {
+            Answer::Yes => {
+                self.phase = Phase::Progress;
+                PageNavigation::Update
+            }
+            Answer::Navigation(navigation) => navigation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
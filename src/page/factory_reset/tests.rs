@@ -0,0 +1,97 @@
+use super::*;
+use std::cell::Cell;
+
+/// Store counting the wipes - fails if not writable
+struct StoreMock {
+    writable: bool,
+    cleared: usize,
+}
+
+impl SettingsStore for StoreMock {
+    fn store(&mut self, _key: &str, _data: &[u8]) -> Result<(), PageError> {
+        Ok(())
+    }
+
+    fn load(&mut self, _key: &str) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn clear(&mut self) -> Result<(), PageError> {
+        if !self.writable {
+            return Err(PageError);
+        }
+        self.cleared += 1;
+        Ok(())
+    }
+}
+
+fn store(writable: bool) -> RefCell<StoreMock> {
+    RefCell::new(StoreMock {
+        writable,
+        cleared: 0,
+    })
+}
+
+#[test]
+fn confirm_reset() {
+    let store = store(true);
+    let resets = Cell::new(0);
+    let mut sut = FactoryResetFlow::new(
+        BasicPage::new("Factory reset", None),
+        &store,
+        Box::new(|| resets.set(resets.get() + 1)),
+    );
+    assert_eq!(sut.title(), "Factory reset");
+    assert!(sut.is_setting());
+    assert_eq!(sut.lines(), vec!["Reset all settings?", "[No] Yes"]);
+    assert_eq!(sut.dispatch(Interaction::Previous), PageNavigation::Update);
+    assert_eq!(sut.lines()[1], "No [Yes]");
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    assert!(sut.is_resetting());
+    assert_eq!(sut.lines(), vec!["Resetting ..."]);
+
+    // the progress is shown before resetting
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Update);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Update);
+    assert_eq!((store.borrow().cleared, resets.get()), (0, 0));
+    assert_eq!(sut.take_feedback(), None);
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::SystemStart);
+    assert_eq!((store.borrow().cleared, resets.get()), (1, 1));
+    assert_eq!(sut.take_feedback(), Some(Feedback::Confirm));
+    assert_eq!(sut.take_feedback(), None);
+    assert!(!sut.is_resetting());
+}
+
+#[test]
+fn cancel_reset() {
+    let store = store(true);
+    let mut sut = FactoryResetFlow::new(BasicPage::new("Reset", None), &store, Box::new(|| {}))
+        .with_texts("Werkseinstellungen?", "Bitte warten");
+    sut.set_display_info(&DisplaySize::new(12, 2));
+    assert_eq!(sut.lines(), vec!["Werkseins...", "[No] Yes"]);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Up);
+    sut.dispatch(Interaction::Next);
+    sut.on_leave();
+    assert_eq!(sut.lines()[1], "[No] Yes");
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Home);
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Update);
+    assert_eq!(store.borrow().cleared, 0);
+}
+
+#[test]
+fn ask_again_if_wipe_fails() {
+    let store = store(false);
+    let resets = Cell::new(0);
+    let mut sut = FactoryResetFlow::new(
+        BasicPage::new("Reset", None),
+        &store,
+        Box::new(|| resets.set(resets.get() + 1)),
+    );
+    sut.dispatch(Interaction::Next);
+    sut.dispatch(Interaction::Action);
+    sut.update(None).unwrap();
+    assert!(sut.update(None).is_err());
+    assert_eq!(resets.get(), 0);
+    assert_eq!(sut.take_feedback(), None);
+    assert_eq!(sut.lines()[1], "[No] Yes");
+}
@@ -0,0 +1,282 @@
+use super::super::{
+    truncate, DisplayInfo, DisplaySize, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation, PageResult, ScrollPosition,
+};
+use super::basic::BasicPage;
+
+/// An entry of a directory listed by the `FileListPage`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirEntry {
+    pub name: String,
+    /// The entry is a directory, i.e. it can be descended into
+    pub is_dir: bool,
+}
+
+impl DirEntry {
+    pub fn file(name: &str) -> Self {
+        DirEntry {
+            name: String::from(name),
+            is_dir: false,
+        }
+    }
+
+    pub fn dir(name: &str) -> Self {
+        DirEntry {
+            name: String::from(name),
+            is_dir: true,
+        }
+    }
+}
+
+/// A file system the `FileListPage` browses, e.g. an SD card
+///
+/// Paths are absolute with `/` separating the directories, the root is `/`.
+/// Is implemented by the application, e.g. by opening the directories of the
+/// path with `embedded-sdmmc` and iterating over the entries.
+pub trait Directory {
+    /// The entries of the directory of the path
+    ///
+    /// Returns `Err(PageError)` if the directory cannot be read, e.g. if
+    /// there is no card - the page shows it as not readable.
+    fn entries(&mut self, path: &str) -> Result<Vec<DirEntry>, PageError>;
+}
+
+/// A page to select a file, e.g. a logo or a config file from an SD card
+///
+/// The entries of the directory shown are listed, directories followed by
+/// `/`; the selected entry is marked by `>` and the lines shown follow the
+/// selection. The directory is read when the page is entered or another
+/// directory is shown. A directory not readable, e.g. without card, is shown
+/// as a single line of text instead of the entries.
+///
+/// The directories are descended within the page: the page tree is fixed at
+/// registration, whereas the directories are only known at runtime.
+///
+/// Selecting a file navigates up and returns the path of the file as
+/// `PageResult` of a `String` to the parent page.
+///
+/// * previous, next - select the previous or next entry
+/// * action - descends into the selected directory or selects the file
+/// * back - shows the parent directory, leaves the page with up-navigation at the root
+/// * home - leaves the page with home-navigation
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, DirEntry, Directory, FileListPage};
+///     use embedded_multi_page_hmi::{Interaction, PageBaseInterface, PageError};
+///     use embedded_multi_page_hmi::{PageInteractionInterface, PageNavigation};
+///
+///     struct Card;
+///     impl Directory for Card {
+///         fn entries(&mut self, path: &str) -> Result<Vec<DirEntry>, PageError> {
+///             match path {
+///                 "/" => Ok(vec![DirEntry::dir("logos")]),
+///                 _ => Ok(vec![DirEntry::file("dark.bmp")]),
+///             }
+///         }
+///     }
+///
+///     let mut page = FileListPage::new(BasicPage::new("Logo", None), Box::new(Card));
+///     page.update(None).unwrap();
+///     page.dispatch(Interaction::Action);
+///     page.update(None).unwrap();
+///     assert_eq!(page.path(), "/logos");
+///     assert_eq!(page.dispatch(Interaction::Action), PageNavigation::Up);
+///     let path = page.take_result().unwrap();
+///     assert_eq!(path.get::<String>().unwrap(), "/logos/dark.bmp");
+/// ```
+pub struct FileListPage<'a> {
+    pub basic: BasicPage,
+    directory: Box<dyn Directory + 'a>,
+    path: String,
+    entries: Vec<DirEntry>,
+    readable: bool,
+    unreadable: &'static str,
+    stale: bool,
+    selected: usize,
+    size: DisplaySize,
+    chosen: Option<String>,
+}
+
+impl<'a> FileListPage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `directory`: - The file system browsed, starting at the root
+    pub fn new(basic: BasicPage, directory: Box<dyn Directory + 'a>) -> Self {
+        FileListPage {
+            basic,
+            directory,
+            path: String::from("/"),
+            entries: Vec::new(),
+            readable: true,
+            unreadable: "Not readable",
+            stale: true,
+            selected: 0,
+            size: DisplaySize::default(),
+            chosen: None,
+        }
+    }
+
+    /// Replace the text shown if the directory is not readable, e.g. by "No card"
+    pub fn with_unreadable(mut self, unreadable: &'static str) -> Self {
+        self.unreadable = unreadable;
+        self
+    }
+
+    /// The path of the directory shown
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The entries of the directory shown
+    pub fn entries(&self) -> &[DirEntry] {
+        &self.entries
+    }
+
+    /// The directory shown could be read with the last update
+    pub fn is_readable(&self) -> bool {
+        self.readable
+    }
+
+    /// The lines of the entries fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        if !self.readable {
+            return vec![truncate(self.unreadable, self.size.cols).into_owned()];
+        }
+        let position = self.position();
+        self.entries
+            .iter()
+            .enumerate()
+            .skip(position.first)
+            .take(position.shown)
+            .map(|(index, entry)| {
+                let line = format!(
+                    "{}{}{}",
+                    if index == self.selected { "> " } else { "  " },
+                    entry.name,
+                    if entry.is_dir { "/" } else { "" },
+                );
+                truncate(&line, self.size.cols).into_owned()
+            })
+            .collect()
+    }
+
+    fn position(&self) -> ScrollPosition {
+        let shown = self.size.rows.max(1);
+        let first = self.selected.saturating_sub(shown - 1);
+        ScrollPosition::new(first, shown, self.entries.len())
+    }
+
+    /// The path of an entry of the directory shown
+    fn join(&self, name: &str) -> String {
+        match self.path.as_str() {
+            "/" => format!("/{}", name),
+            path => format!("{}/{}", path, name),
+        }
+    }
+
+    /// Show another directory with the next update
+    fn show(&mut self, path: String) {
+        self.path = path;
+        self.stale = true;
+        self.selected = 0;
+    }
+}
+
+impl PageBaseInterface for FileListPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    /// Read the directory shown if it changed
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        if self.stale {
+            // a directory not readable is not read again with every update
+            self.stale = false;
+            let entries = self.directory.entries(&self.path);
+            self.readable = entries.is_ok();
+            self.entries = entries.unwrap_or_default();
+            self.selected = self.selected.min(self.entries.len().saturating_sub(1));
+        }
+        Ok(PageNavigation::Update)
+    }
+
+    /// Read the directory again, e.g. after another card was inserted
+    fn on_enter(&mut self, _param: Option<usize>, _result: Option<&PageResult>) {
+        self.stale = true;
+    }
+
+    fn reload(&mut self) {
+        self.stale = true;
+    }
+
+    fn take_result(&mut self) -> Option<PageResult> {
+        self.chosen.take().map(PageResult::new)
+    }
+
+    fn selected(&self) -> Option<usize> {
+        Some(self.selected + 1)
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index.max(1) - 1;
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        Some(self.position())
+    }
+}
+
+impl PageInteractionInterface for FileListPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        let count = self.entries.len().max(1);
+        match interaction {
+            Interaction::Action => match self.entries.get(self.selected) {
+                Some(entry) if entry.is_dir => {
+                    let path = self.join(&entry.name);
+                    self.show(path);
+                    PageNavigation::Update
+                }
+                Some(entry) => {
+                    self.chosen = Some(self.join(&entry.name));
+                    PageNavigation::Up
+                }
+                None => PageNavigation::Update,
+            },
+            Interaction::Next => {
+                self.selected = (self.selected + 1) % count;
+                PageNavigation::Update
+            }
+            Interaction::Previous => {
+                self.selected = (self.selected + count - 1) % count;
+                PageNavigation::Update
+            }
+            Interaction::Back => match self.path.rfind('/') {
+                Some(0) if self.path.len() == 1 => PageNavigation::Up,
+                Some(0) => {
+                    self.show(String::from("/"));
+                    PageNavigation::Update
+                }
+                Some(end) => {
+                    let parent = String::from(&self.path[..end]);
+                    self.show(parent);
+                    PageNavigation::Update
+                }
+                None => PageNavigation::Up,
+            },
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
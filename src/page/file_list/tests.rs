@@ -0,0 +1,134 @@
+use super::*;
+use std::cell::Cell;
+
+/// A card with a config file, a directory of logos and an empty directory
+struct Card<'a>(&'a Cell<usize>);
+
+impl Directory for Card<'_> {
+    fn entries(&mut self, path: &str) -> Result<Vec<DirEntry>, PageError> {
+        self.0.set(self.0.get() + 1);
+        match path {
+            "/" => Ok(vec![
+                DirEntry::dir("logos"),
+                DirEntry::dir("empty"),
+                DirEntry::file("config.txt"),
+            ]),
+            "/logos" => Ok(vec![
+                DirEntry::file("dark.bmp"),
+                DirEntry::file("light.bmp"),
+                DirEntry::dir("old"),
+            ]),
+            "/logos/old" => Ok(vec![DirEntry::file("v1.bmp")]),
+            "/empty" => Ok(Vec::new()),
+            _ => Err(PageError),
+        }
+    }
+}
+
+#[test]
+fn list_and_scroll_entries() {
+    let reads = Cell::new(0);
+    let mut sut = FileListPage::new(BasicPage::new("Files", None), Box::new(Card(&reads)));
+    sut.set_display_info(&DisplaySize::new(10, 2));
+    sut.update(None).unwrap();
+    sut.update(None).unwrap();
+    assert_eq!(reads.get(), 1);
+    assert_eq!(sut.lines(), vec!["> logos/", "  empty/"]);
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.lines(), vec!["  empty/", "> confi..."]);
+    assert_eq!(sut.scroll_position(), Some(ScrollPosition::new(1, 2, 3)));
+    assert_eq!(sut.selected(), Some(3));
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.selected(), Some(1));
+}
+
+#[test]
+fn descend_and_ascend_directories() {
+    let reads = Cell::new(0);
+    let mut sut = FileListPage::new(BasicPage::new("Files", None), Box::new(Card(&reads)));
+    sut.update(None).unwrap();
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    sut.update(None).unwrap();
+    assert_eq!(sut.path(), "/logos");
+    sut.select(3);
+    sut.dispatch(Interaction::Action);
+    sut.update(None).unwrap();
+    assert_eq!(sut.path(), "/logos/old");
+    assert_eq!(sut.entries(), &[DirEntry::file("v1.bmp")]);
+
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Update);
+    sut.update(None).unwrap();
+    assert_eq!(sut.path(), "/logos");
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Update);
+    sut.update(None).unwrap();
+    assert_eq!(sut.path(), "/");
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert!(sut.take_result().is_none());
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Home);
+}
+
+#[test]
+fn return_selected_path() {
+    let reads = Cell::new(0);
+    let mut sut = FileListPage::new(BasicPage::new("Files", None), Box::new(Card(&reads)));
+    sut.update(None).unwrap();
+    sut.select(2);
+    sut.dispatch(Interaction::Action);
+    sut.update(None).unwrap();
+    // nothing to select in an empty directory
+    assert_eq!(sut.lines(), Vec::<String>::new());
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    sut.dispatch(Interaction::Back);
+    sut.update(None).unwrap();
+    sut.select(3);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Up);
+    let result = sut.take_result().unwrap();
+    assert_eq!(result.get::<String>().unwrap(), "/config.txt");
+    assert!(sut.take_result().is_none());
+}
+
+#[test]
+fn read_again_when_entered() {
+    let reads = Cell::new(0);
+    let mut sut = FileListPage::new(BasicPage::new("Files", None), Box::new(Card(&reads)));
+    sut.update(None).unwrap();
+    sut.on_enter(None, None);
+    sut.update(None).unwrap();
+    assert_eq!(reads.get(), 2);
+    sut.reload();
+    sut.update(None).unwrap();
+    assert_eq!(reads.get(), 3);
+}
+
+#[test]
+fn directory_not_readable() {
+    struct NoCard;
+    impl Directory for NoCard {
+        fn entries(&mut self, _path: &str) -> Result<Vec<DirEntry>, PageError> {
+            Err(PageError)
+        }
+    }
+    let mut sut = FileListPage::new(BasicPage::new("Files", None), Box::new(NoCard))
+        .with_unreadable("No card");
+    assert_eq!(sut.update(None).unwrap(), PageNavigation::Update);
+    assert!(!sut.is_readable());
+    assert!(sut.entries().is_empty());
+    assert_eq!(sut.lines(), vec!["No card"]);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    assert_eq!(sut.dispatch(Interaction::Next), PageNavigation::Update);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+}
+
+#[test]
+fn directory_readable_again() {
+    let reads = Cell::new(0);
+    let mut sut = FileListPage::new(BasicPage::new("Files", None), Box::new(Card(&reads)));
+    sut.update(None).unwrap();
+    sut.show(String::from("/missing"));
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["Not readable"]);
+    sut.dispatch(Interaction::Back);
+    sut.update(None).unwrap();
+    assert!(sut.is_readable());
+    assert_eq!(sut.entries().len(), 3);
+}
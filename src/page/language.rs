@@ -0,0 +1,97 @@
+use super::super::{
+    Interaction, PageBaseInterface, PageError, PageInteractionInterface, PageNavigation, TextId,
+    Translations,
+};
+use super::basic::BasicPage;
+
+/// A page to select the language of the HMI
+///
+/// Next and previous select one of the languages, action activates the
+/// selected language. Since all pages look up their texts whenever they are
+/// updated, the whole HMI is redrawn in the activated language.
+///
+/// The title is the translated `TextId::LANGUAGE` text, or the title of the
+/// basic page if there is no such text.
+pub struct LanguageSelectPage {
+    pub basic: BasicPage,
+    translations: &'static Translations,
+    selected: usize,
+}
+
+impl LanguageSelectPage {
+    pub fn new(basic: BasicPage, translations: &'static Translations) -> Self {
+        LanguageSelectPage {
+            basic,
+            translations,
+            selected: translations.language(),
+        }
+    }
+
+    /// The names of all languages
+    pub fn languages(&self) -> &'static [&'static str] {
+        self.translations.languages()
+    }
+
+    /// The name of the selected language
+    pub fn selected_language(&self) -> &'static str {
+        self.languages().get(self.selected).copied().unwrap_or("")
+    }
+}
+
+impl PageBaseInterface for LanguageSelectPage {
+    fn title(&self) -> &str {
+        match self.translations.get(TextId::LANGUAGE) {
+            "" => &self.basic.title,
+            title => title,
+        }
+    }
+
+    /// Start selecting at the active language
+    fn update<'a>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        if self.selected >= self.languages().len() {
+            self.selected = self.translations.language();
+        }
+        Ok(PageNavigation::Update)
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    fn selected(&self) -> Option<usize> {
+        Some(self.selected + 1)
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index.max(1) - 1;
+    }
+}
+
+impl PageInteractionInterface for LanguageSelectPage {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        let count = self.languages().len().max(1);
+        match interaction {
+            Interaction::Action => {
+                // an unknown language is ignored, the active one is kept
+                let _ = self.translations.set_language(self.selected);
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+            Interaction::Next => {
+                self.selected = (self.selected + 1) % count;
+                PageNavigation::Update
+            }
+            Interaction::Previous => {
+                self.selected = (self.selected + count - 1) % count;
+                PageNavigation::Update
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,27 @@
+use super::*;
+
+static TRANSLATIONS: Translations = Translations::new(
+    &["English", "Deutsch", "Français"],
+    &[
+        &["Back", "Ok", "Startup", "Shutdown", "Language"],
+        &["Zurück", "Ok", "Start", "Ende", "Sprache"],
+        &["Retour", "Ok", "Démarrage", "Arrêt", "Langue"],
+    ],
+);
+
+#[test]
+fn select_and_activate_language() {
+    let mut sut = LanguageSelectPage::new(BasicPage::new("Lang", None), &TRANSLATIONS);
+    assert_eq!(sut.title(), "Language");
+    assert_eq!(sut.selected_language(), "English");
+    assert_eq!(sut.dispatch(Interaction::Previous), PageNavigation::Update);
+    assert_eq!(sut.selected_language(), "Français");
+    sut.dispatch(Interaction::Next);
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.selected_language(), "Deutsch");
+    assert_eq!(TRANSLATIONS.language(), 0);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    assert_eq!(TRANSLATIONS.language(), 1);
+    assert_eq!(sut.title(), "Sprache");
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+}
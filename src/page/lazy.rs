@@ -0,0 +1,214 @@
+use super::super::{
+    AccessLevel, DisplayInfo, DisplaySize, Feedback, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageInterface, PageNavigation, PageResult, ScrollPosition, Siblings,
+    SubPageItem,
+};
+use super::basic::BasicPage;
+
+/// A lazy page constructs the page it stands for not before it is needed
+///
+/// Memory-heavy pages (e.g. charts or file browsers) can be registered as lazy
+/// page. The page is constructed by the factory when it is updated the first
+/// time, i.e. when it gets navigated to, and dropped again when it is left.
+/// This trades CPU for RAM on constrained targets.
+///
+/// The title is known without constructing the page, i.e. menus can show it.
+/// Since the page does not exist before it is navigated to, it is not
+/// reported as menu page by `is_menu()` until then.
+pub struct LazyPage<'a, D, C = ()> {
+    pub basic: BasicPage,
+    factory: Box<dyn FnMut() -> Box<dyn PageInterface<D, C> + 'a> + 'a>,
+    page: Option<Box<dyn PageInterface<D, C> + 'a>>,
+    size: Option<DisplaySize>,
+}
+
+impl<'a, D, C> LazyPage<'a, D, C> {
+    pub fn new(
+        basic: BasicPage,
+        factory: Box<dyn FnMut() -> Box<dyn PageInterface<D, C> + 'a> + 'a>,
+    ) -> Self {
+        LazyPage {
+            basic,
+            factory,
+            page: None,
+            size: None,
+        }
+    }
+
+    /// The page is constructed at the moment
+    pub fn is_constructed(&self) -> bool {
+        self.page.is_some()
+    }
+
+    /// The page - constructed by the factory if not yet done
+    fn page(&mut self) -> &mut Box<dyn PageInterface<D, C> + 'a> {
+        let factory = &mut self.factory;
+        let size = &self.size;
+        self.page.get_or_insert_with(|| {
+            let mut page = factory();
+            if let Some(size) = size {
+                page.set_display_info(size);
+            }
+            page
+        })
+    }
+}
+
+impl<D, C> PageBaseInterface for LazyPage<'_, D, C> {
+    fn update<'b>(
+        &mut self,
+        title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.page().update(title_of_subpages)
+    }
+
+    fn update_with_subpages<'b>(
+        &mut self,
+        subpages: Option<Box<dyn Iterator<Item = SubPageItem<'b>> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.page().update_with_subpages(subpages)
+    }
+
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn short_text(&self) -> &str {
+        match &self.page {
+            None => &self.basic.title,
+            Some(page) => page.short_text(),
+        }
+    }
+
+    fn preview(&self) -> Option<&str> {
+        self.page.as_ref().and_then(|page| page.preview())
+    }
+
+    fn is_menu(&self) -> bool {
+        match &self.page {
+            None => false,
+            Some(page) => page.is_menu(),
+        }
+    }
+
+    fn is_setting(&self) -> bool {
+        self.page.as_ref().is_some_and(|page| page.is_setting())
+    }
+
+    fn is_alarm(&self) -> bool {
+        self.page.as_ref().is_some_and(|page| page.is_alarm())
+    }
+
+    fn is_locked(&self) -> bool {
+        self.page.as_ref().is_some_and(|page| page.is_locked())
+    }
+
+    fn selected(&self) -> Option<usize> {
+        self.page.as_ref().and_then(|page| page.selected())
+    }
+
+    fn select(&mut self, index: usize) {
+        if let Some(page) = &mut self.page {
+            page.select(index);
+        }
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        self.page.as_ref().and_then(|page| page.scroll_position())
+    }
+
+    fn can_leave(&self) -> Result<(), &str> {
+        match &self.page {
+            None => Ok(()),
+            Some(page) => page.can_leave(),
+        }
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = Some(DisplaySize::from_info(info));
+        if let Some(page) = &mut self.page {
+            page.set_display_info(info);
+        }
+    }
+
+    /// Construct the page - it is updated next
+    fn set_siblings(&mut self, siblings: Siblings<'_>) {
+        self.page().set_siblings(siblings);
+    }
+
+    /// Drop the page to free its memory
+    fn on_leave(&mut self) {
+        if let Some(mut page) = self.page.take() {
+            page.on_leave();
+        }
+    }
+
+    /// Construct the page, e.g. for a result of a sub page
+    fn on_enter(&mut self, param: Option<usize>, result: Option<&PageResult>) {
+        self.page().on_enter(param, result);
+    }
+
+    fn take_result(&mut self) -> Option<PageResult> {
+        self.page.as_mut().and_then(|page| page.take_result())
+    }
+
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        self.page.as_mut().and_then(|page| page.take_feedback())
+    }
+
+    fn take_access_level(&mut self) -> Option<AccessLevel> {
+        self.page.as_mut().and_then(|page| page.take_access_level())
+    }
+
+    fn on_shutdown(&mut self) {
+        if let Some(page) = &mut self.page {
+            page.on_shutdown();
+        }
+    }
+
+    /// Drop the page to construct it anew with the next update
+    fn reload(&mut self) {
+        self.on_leave();
+    }
+}
+
+impl<D, C> PageInteractionInterface for LazyPage<'_, D, C> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        self.page().dispatch(interaction)
+    }
+
+    fn dispatch_char(&mut self, c: char) -> PageNavigation {
+        self.page().dispatch_char(c)
+    }
+
+    fn dispatch_custom(&mut self, code: u8) -> PageNavigation {
+        self.page().dispatch_custom(code)
+    }
+}
+
+impl<D, C> PageInterface<D, C> for LazyPage<'_, D, C> {
+    fn display(&self, display_driver: &mut D) {
+        if let Some(page) = &self.page {
+            page.display(display_driver);
+        }
+    }
+
+    fn update_with_context<'b>(
+        &mut self,
+        context: &mut C,
+        subpages: Option<Box<dyn Iterator<Item = SubPageItem<'b>> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.page().update_with_context(context, subpages)
+    }
+
+    fn dispatch_with_context(
+        &mut self,
+        context: &mut C,
+        interaction: Interaction,
+    ) -> PageNavigation {
+        self.page().dispatch_with_context(context, interaction)
+    }
+}
+
+#[cfg(test)]
+mod tests;
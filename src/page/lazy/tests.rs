@@ -0,0 +1,101 @@
+use super::*;
+use std::cell::Cell;
+
+struct DisplayStub;
+
+struct HeavyPage;
+
+impl PageBaseInterface for HeavyPage {
+    fn title(&self) -> &str {
+        "Heavy"
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    fn is_alarm(&self) -> bool {
+        true
+    }
+
+    fn is_locked(&self) -> bool {
+        true
+    }
+
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        Some(Feedback::Confirm)
+    }
+
+    fn take_access_level(&mut self) -> Option<AccessLevel> {
+        Some(AccessLevel::Service)
+    }
+}
+
+impl PageInteractionInterface for HeavyPage {
+    fn dispatch_char(&mut self, c: char) -> PageNavigation {
+        match c {
+            '0' => PageNavigation::Home,
+            _ => PageNavigation::Update,
+        }
+    }
+
+    fn dispatch_custom(&mut self, code: u8) -> PageNavigation {
+        PageNavigation::NthSubpage(code as usize)
+    }
+}
+
+impl PageInterface<DisplayStub> for HeavyPage {
+    fn display(&self, _display_driver: &mut DisplayStub) {}
+}
+
+fn lazy_page(constructed: &Cell<usize>) -> LazyPage<'_, DisplayStub> {
+    LazyPage::new(
+        BasicPage::new("Chart", None),
+        Box::new(move || {
+            constructed.set(constructed.get() + 1);
+            Box::new(HeavyPage)
+        }),
+    )
+}
+
+#[test]
+fn title_without_construction() {
+    let constructed = Cell::new(0);
+    let sut = lazy_page(&constructed);
+    assert_eq!(sut.title(), "Chart");
+    assert!(!sut.is_constructed());
+    assert_eq!(constructed.get(), 0);
+}
+
+#[test]
+fn construct_on_update_and_drop_on_leave() {
+    let constructed = Cell::new(0);
+    let mut sut = lazy_page(&constructed);
+    sut.update(None).unwrap();
+    sut.update(None).unwrap();
+    assert!(sut.is_constructed());
+    assert_eq!(constructed.get(), 1);
+    sut.on_leave();
+    assert!(!sut.is_constructed());
+    assert_eq!(sut.dispatch(Interaction::Next), PageNavigation::Left);
+    assert_eq!(constructed.get(), 2);
+}
+
+#[test]
+fn forward_to_the_constructed_page() {
+    let constructed = Cell::new(0);
+    let mut sut = lazy_page(&constructed);
+    assert_eq!(sut.take_access_level(), None);
+    assert_eq!(sut.take_feedback(), None);
+    assert!(!sut.is_setting());
+    assert!(!sut.is_alarm());
+    assert!(!sut.is_locked());
+    sut.update(None).unwrap();
+    assert!(sut.is_setting());
+    assert!(sut.is_alarm());
+    assert!(sut.is_locked());
+    assert_eq!(sut.take_access_level(), Some(AccessLevel::Service));
+    assert_eq!(sut.take_feedback(), Some(Feedback::Confirm));
+    assert_eq!(sut.dispatch_char('0'), PageNavigation::Home);
+    assert_eq!(sut.dispatch_custom(2), PageNavigation::NthSubpage(2));
+}
@@ -0,0 +1,108 @@
+use super::super::{
+    truncate, DisplayInfo, DisplaySize, Interaction, LogBuffer, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation, ScrollPosition,
+};
+use super::basic::BasicPage;
+
+/// A page showing the latest lines of a log
+///
+/// Lets field technicians read the log on the device display, e.g. of devices
+/// without a serial port. The page follows the latest lines as long as it is
+/// not scrolled.
+///
+/// * previous - scrolls to older lines
+/// * next - scrolls to newer lines
+/// * action - returns to the latest lines
+/// * back - leaves the page with up-navigation
+/// * home - leaves the page with home-navigation
+pub struct LogPage<'a, const LINES: usize, const COLS: usize> {
+    pub basic: BasicPage,
+    log: &'a LogBuffer<LINES, COLS>,
+    lines: Vec<String>,
+    scrolled: usize,
+    size: DisplaySize,
+}
+
+impl<'a, const LINES: usize, const COLS: usize> LogPage<'a, LINES, COLS> {
+    pub fn new(basic: BasicPage, log: &'a LogBuffer<LINES, COLS>) -> Self {
+        LogPage {
+            basic,
+            log,
+            lines: Vec::new(),
+            scrolled: 0,
+            size: DisplaySize::default(),
+        }
+    }
+
+    /// The lines of the log fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        let end = self.lines.len() - self.scrolled.min(self.lines.len());
+        let start = end.saturating_sub(self.size.rows);
+        self.lines[start..end]
+            .iter()
+            .map(|line| truncate(line, self.size.cols).into_owned())
+            .collect()
+    }
+
+    /// Number of lines scrolled back from the latest line
+    fn max_scrolled(&self) -> usize {
+        self.lines.len().saturating_sub(self.size.rows)
+    }
+}
+
+impl<const LINES: usize, const COLS: usize> PageBaseInterface for LogPage<'_, LINES, COLS> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    /// Fetch the lines of the log - the lines shown stay while scrolled
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        if self.scrolled == 0 {
+            self.lines = self.log.lines();
+        }
+        Ok(PageNavigation::Update)
+    }
+
+    fn on_leave(&mut self) {
+        self.scrolled = 0;
+    }
+
+    /// The lines of the log shown
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        let total = self.lines.len();
+        let end = total - self.scrolled.min(total);
+        let shown = self.size.rows.min(total);
+        Some(ScrollPosition::new(end.saturating_sub(shown), shown, total))
+    }
+}
+
+impl<const LINES: usize, const COLS: usize> PageInteractionInterface for LogPage<'_, LINES, COLS> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Previous => {
+                self.scrolled = (self.scrolled + 1).min(self.max_scrolled());
+                PageNavigation::Update
+            }
+            Interaction::Next => {
+                self.scrolled = self.scrolled.saturating_sub(1);
+                PageNavigation::Update
+            }
+            Interaction::Action => {
+                self.scrolled = 0;
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,49 @@
+use super::*;
+use core::fmt::Write;
+
+fn log_page(log: &LogBuffer<8, 20>) -> LogPage<'_, 8, 20> {
+    let mut sut = LogPage::new(BasicPage::new("Log", None), log);
+    sut.set_display_info(&DisplaySize::new(6, 2));
+    sut
+}
+
+#[test]
+fn follow_latest_lines() {
+    let log = LogBuffer::new();
+    let mut sut = log_page(&log);
+    assert_eq!(sut.title(), "Log");
+    sut.update(None).unwrap();
+    assert!(sut.lines().is_empty());
+    writeln!(&log, "boot").unwrap();
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["boot"]);
+    writeln!(&log, "pump on").unwrap();
+    writeln!(&log, "valve 1").unwrap();
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["pum...", "val..."]);
+}
+
+#[test]
+fn scroll_back() {
+    let log = LogBuffer::new();
+    for i in 1..=4 {
+        writeln!(&log, "l{}", i).unwrap();
+    }
+    let mut sut = log_page(&log);
+    sut.update(None).unwrap();
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.lines(), vec!["l2", "l3"]);
+    sut.dispatch(Interaction::Previous);
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.lines(), vec!["l1", "l2"]);
+    // the lines shown stay while scrolled
+    writeln!(&log, "l5").unwrap();
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["l1", "l2"]);
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.lines(), vec!["l2", "l3"]);
+    sut.dispatch(Interaction::Action);
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["l4", "l5"]);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+}
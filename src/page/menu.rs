@@ -1,16 +1,27 @@
 use super::basic::BasicPage;
 use arrayvec::ArrayString;
+use std::borrow::Cow;
 
 /// A menu page organizes navigation to sub pages
 ///
 /// A menu pages lists the titles of the sub-pages and allows
 /// navigation to a specific sub-page
+///
+/// If the display size is known, the titles are limited to what fits the display.
+/// A selected title longer than the display width is truncated or optionally
+/// scrolls by a marquee.
 pub struct MenuPage<'a> {
     pub basic: BasicPage,
     selected: usize,
     max_items: usize,
+    subpages: usize,
     pub sub_titles: ArrayString<100>, // is public to be accessed from outside implementation of PageInterface trait
     back: Option<&'a str>,            // the Back menu entry in language
+    size: DisplaySize,
+    marquee: Option<Marquee>,
+    translations: Option<&'static Translations>,
+    position: ScrollPosition,
+    previews: bool,
 }
 
 impl<'a> MenuPage<'a> {
@@ -19,10 +30,115 @@ impl<'a> MenuPage<'a> {
             basic,
             selected: 1,
             max_items: 1,
+            subpages: 0,
             sub_titles: ArrayString::<100>::new(),
             back,
+            size: DisplaySize::default(),
+            marquee: None,
+            translations: None,
+            position: ScrollPosition::new(0, 1, 1),
+            previews: false,
+        }
+    }
+
+    /// Show the back menu entry in the active language
+    pub fn with_translations(mut self, translations: &'static Translations) -> Self {
+        self.translations = Some(translations);
+        self
+    }
+
+    /// The back menu entry in the active language
+    fn back_text(&self) -> Option<&'a str> {
+        match self.translations {
+            Some(translations) => self.back.map(|_| translations.get(TextId::BACK)),
+            None => self.back,
         }
     }
+
+    /// Scroll a selected title longer than the display width instead of truncating it
+    pub fn with_marquee(mut self, marquee: Marquee) -> Self {
+        self.marquee = Some(marquee);
+        self
+    }
+
+    /// Show the previews of the sub pages next to their titles, e.g. `Boiler 60.0`
+    ///
+    /// See `PageBaseInterface::preview()`.
+    pub fn with_previews(mut self) -> Self {
+        self.previews = true;
+        self
+    }
+
+    /// The sub page selected and the number of sub pages, e.g. `(3, 7)` shown as `3/7`
+    ///
+    /// Is known after the first update. None if the back entry is selected.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        match self.selected <= self.subpages {
+            true => Some((self.selected, self.subpages)),
+            false => None,
+        }
+    }
+
+    /// Lay out the entries of the sub pages followed by the back entry, if any
+    fn show_entries(&mut self, subpages: Vec<Cow<str>>) {
+        self.subpages = subpages.len();
+        // Optional back navigation menu entry is always placed at the end
+        let entries: Vec<&str> = subpages
+            .iter()
+            .map(AsRef::as_ref)
+            .chain(self.back_text())
+            .collect();
+        self.max_items = entries.len();
+        self.sub_titles.clear();
+
+        let selected = self.selected;
+        let capacity = self.size.chars();
+        let entry_width = |(index, title): (usize, &&str)| {
+            width(title) + if index + 1 == selected { 5 } else { 1 }
+        };
+        let mut first = 0;
+        while first + 1 < selected
+            && entries
+                .iter()
+                .enumerate()
+                .skip(first)
+                .take(selected - first)
+                .map(entry_width)
+                .sum::<usize>()
+                > capacity
+        {
+            first += 1;
+        }
+
+        let mut used = 0;
+        let mut shown = 0;
+        for (index, title) in entries.iter().enumerate().skip(first) {
+            let is_selected = index + 1 == selected;
+            let decoration = if is_selected { 5 } else { 1 };
+            let available = capacity.saturating_sub(used + decoration);
+            let title = match (is_selected, &mut self.marquee) {
+                (true, Some(marquee)) => {
+                    marquee.advance(title, available);
+                    Cow::Borrowed(marquee.window(title, available))
+                }
+                (true, None) => truncate(title, available),
+                (false, _) => Cow::Borrowed(*title),
+            };
+            used += width(&title) + decoration;
+            if used > capacity {
+                break;
+            }
+            let entry = match is_selected {
+                true => format!("[ {} ] ", title),
+                false => format!("{} ", title),
+            };
+            if self.sub_titles.try_push_str(&entry).is_err() {
+                break;
+            }
+            shown += 1;
+        }
+        self.position = ScrollPosition::new(first, shown, entries.len());
+    }
 }
 
 use super::super::*;
@@ -44,6 +160,9 @@ impl PageInteractionInterface for MenuPage<'_> {
             Interaction::Back => PageNavigation::Up,
             Interaction::Home => PageNavigation::Home,
             Interaction::Next => {
+                if let Some(marquee) = &mut self.marquee {
+                    marquee.reset();
+                }
                 self.selected += 1;
                 if self.selected > self.max_items {
                     self.selected = 1;
@@ -53,6 +172,9 @@ impl PageInteractionInterface for MenuPage<'_> {
             // if previous interaction is not available, this implementation is never called
             // but it does not hurt
             Interaction::Previous => {
+                if let Some(marquee) = &mut self.marquee {
+                    marquee.reset();
+                }
                 self.selected -= 1;
                 if self.selected == 0 {
                     self.selected = 1;
@@ -64,44 +186,65 @@ impl PageInteractionInterface for MenuPage<'_> {
 }
 
 impl PageBaseInterface for MenuPage<'_> {
+    /// Collect the titles of the sub pages
+    ///
+    /// The selected title is marked by brackets. If the titles do not fit the
+    /// display, the titles in front of the selected one are skipped, the
+    /// selected title is truncated if needed and titles behind are omitted.
     fn update<'a>(
         &mut self,
         title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
     ) -> Result<PageNavigation, PageError> {
         if let Some(title_iterator) = title_of_subpages {
-            self.max_items = 0;
-            self.sub_titles.clear();
-
-            for title in title_iterator {
-                self.max_items += 1;
-                if self.max_items == self.selected {
-                    self.sub_titles.push_str("[ ");
-                }
-                self.sub_titles.push_str(title);
-                if self.max_items == self.selected {
-                    self.sub_titles.push_str(" ]");
-                }
-                self.sub_titles.push(' ');
-            }
+            self.show_entries(title_iterator.map(Cow::Borrowed).collect());
+        }
+        Ok(PageNavigation::Update)
+    }
 
-            // Optional back navigation menu entry is always placed at the end
-            if let Some(back_text) = self.back {
-                self.max_items += 1;
-                if self.max_items == self.selected {
-                    self.sub_titles.push_str("[ ");
-                }
-                self.sub_titles.push_str(back_text);
-                if self.max_items == self.selected {
-                    self.sub_titles.push_str(" ]");
-                }
-                self.sub_titles.push(' ');
+    /// Collect the titles of the sub pages shown - with their previews if requested
+    fn update_with_subpages<'a>(
+        &mut self,
+        subpages: Option<Box<dyn Iterator<Item = SubPageItem<'a>> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        match (self.previews, subpages) {
+            (true, Some(subpages)) => {
+                let entries = subpages
+                    .filter(SubPageItem::is_shown)
+                    .map(|subpage| match subpage.preview {
+                        Some(preview) => Cow::Owned(format!("{} {}", subpage.title, preview)),
+                        None => Cow::Borrowed(subpage.title),
+                    })
+                    .collect();
+                self.show_entries(entries);
+                Ok(PageNavigation::Update)
             }
+            (_, subpages) => self.update(subpages.map(SubPageItem::titles)),
         }
-        Ok(PageNavigation::Update)
     }
 
     fn title(&self) -> &str {
-        self.basic.title
+        &self.basic.title
+    }
+
+    fn is_menu(&self) -> bool {
+        true
+    }
+
+    fn selected(&self) -> Option<usize> {
+        Some(self.selected)
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index.max(1);
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    /// The entries shown
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        Some(self.position)
     }
 }
 
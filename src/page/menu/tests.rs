@@ -14,7 +14,7 @@ fn check_title_and_init() {
 fn update_sub_titles_without_back_simulation() {
     let sub_titles = ["foo", "bar", "baz"];
     let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), None);
-    sut.update(Some(Box::new(sub_titles.iter().map(|p| *p))))
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
         .unwrap();
     assert_eq!(&sut.sub_titles[..], "[ foo ] bar baz ");
     assert_eq!(sut.max_items, 3);
@@ -24,17 +24,62 @@ fn update_sub_titles_without_back_simulation() {
 fn update_sub_titles_with_back_simulation() {
     let sub_titles = ["foo", "bar", "baz"];
     let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), Some("Back"));
-    sut.update(Some(Box::new(sub_titles.iter().map(|p| *p))))
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
         .unwrap();
     assert_eq!(&sut.sub_titles[..], "[ foo ] bar baz Back ");
     assert_eq!(sut.max_items, 4);
 }
 
+#[test]
+fn update_sub_titles_with_previews() {
+    let item = |title, index, preview| SubPageItem {
+        title,
+        id: PageId(index),
+        hidden: false,
+        disabled: false,
+        preview,
+    };
+    let subpages = vec![
+        item("Boiler", 1, Some("60.0")),
+        SubPageItem {
+            hidden: true,
+            ..item("Service", 2, None)
+        },
+        item("Info", 3, None),
+    ];
+    let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), Some("Back"));
+    sut.update_with_subpages(Some(Box::new(subpages.clone().into_iter())))
+        .unwrap();
+    assert_eq!(&sut.sub_titles[..], "[ Boiler ] Info Back ");
+
+    let mut sut = sut.with_previews();
+    sut.update_with_subpages(Some(Box::new(subpages.into_iter())))
+        .unwrap();
+    assert_eq!(&sut.sub_titles[..], "[ Boiler 60.0 ] Info Back ");
+    assert_eq!(sut.position(), Some((1, 2)));
+}
+
+#[test]
+fn position_of_the_selected_sub_page() {
+    let sub_titles = ["foo", "bar", "baz"];
+    let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), Some("Back"));
+    assert_eq!(sut.position(), None);
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
+        .unwrap();
+    assert_eq!(sut.position(), Some((1, 3)));
+    sut.dispatch(Interaction::Next);
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.position(), Some((3, 3)));
+    // the back entry
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.position(), None);
+}
+
 #[test]
 fn interaction_next() {
     let sub_titles = ["foo", "bar", "baz"];
     let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), Some("Back"));
-    sut.update(Some(Box::new(sub_titles.iter().map(|p| *p))))
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
         .unwrap();
     assert_eq!(sut.selected, 1);
     assert_eq!(PageNavigation::Update, sut.dispatch(Interaction::Next));
@@ -51,7 +96,7 @@ fn interaction_next() {
 fn interaction_previous() {
     let sub_titles = ["foo", "bar", "baz"];
     let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), Some("Back"));
-    sut.update(Some(Box::new(sub_titles.iter().map(|p| *p))))
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
         .unwrap();
     sut.selected = 4;
     assert_eq!(PageNavigation::Update, sut.dispatch(Interaction::Previous));
@@ -68,7 +113,7 @@ fn interaction_previous() {
 fn interaction_home() {
     let sub_titles = ["foo", "bar", "baz"];
     let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), Some("Back"));
-    sut.update(Some(Box::new(sub_titles.iter().map(|p| *p))))
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
         .unwrap();
     assert_eq!(PageNavigation::Home, sut.dispatch(Interaction::Home));
 }
@@ -77,7 +122,7 @@ fn interaction_home() {
 fn interaction_action_with_back_navigation() {
     let sub_titles = ["foo", "bar", "baz"];
     let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), Some("Back"));
-    sut.update(Some(Box::new(sub_titles.iter().map(|p| *p))))
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
         .unwrap();
     sut.selected = 4;
     assert_eq!(PageNavigation::Up, sut.dispatch(Interaction::Action));
@@ -87,7 +132,7 @@ fn interaction_action_with_back_navigation() {
 fn interaction_action_without_back_navigation() {
     let sub_titles = ["foo", "bar", "baz"];
     let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), None);
-    sut.update(Some(Box::new(sub_titles.iter().map(|p| *p))))
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
         .unwrap();
     sut.selected = 1;
     assert_eq!(
@@ -110,7 +155,67 @@ fn interaction_action_without_back_navigation() {
 fn interaction_up() {
     let sub_titles = ["foo", "bar", "baz"];
     let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), Some("Back"));
-    sut.update(Some(Box::new(sub_titles.iter().map(|p| *p))))
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
         .unwrap();
     assert_eq!(PageNavigation::Up, sut.dispatch(Interaction::Back));
 }
+
+#[test]
+fn update_sub_titles_fitting_the_display() {
+    let sub_titles = ["foo", "bar", "baz"];
+    let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), Some("Back"));
+    sut.set_display_info(&DisplaySize::new(12, 1));
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
+        .unwrap();
+    assert_eq!(&sut.sub_titles[..], "[ foo ] bar ");
+    assert_eq!(sut.max_items, 4);
+    assert_eq!(sut.scroll_position(), Some(ScrollPosition::new(0, 2, 4)));
+
+    sut.selected = 3;
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
+        .unwrap();
+    assert_eq!(&sut.sub_titles[..], "bar [ baz ] ");
+}
+
+#[test]
+fn update_truncates_selected_sub_title() {
+    let sub_titles = ["Settings", "Info"];
+    let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), None);
+    sut.set_display_info(&DisplaySize::new(10, 1));
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
+        .unwrap();
+    assert_eq!(&sut.sub_titles[..], "[ Se... ] ");
+}
+
+#[test]
+fn update_scrolls_selected_sub_title() {
+    let sub_titles = ["Settings", "Info"];
+    let mut sut =
+        MenuPage::new(BasicPage::new("MyTitle", None), None).with_marquee(Marquee::new(1, 0));
+    sut.set_display_info(&DisplaySize::new(10, 1));
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
+        .unwrap();
+    assert_eq!(&sut.sub_titles[..], "[ ettin ] ");
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
+        .unwrap();
+    assert_eq!(&sut.sub_titles[..], "[ tting ] ");
+
+    // a new selection scrolls from the beginning of the title again
+    sut.dispatch(Interaction::Previous);
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
+        .unwrap();
+    assert_eq!(&sut.sub_titles[..], "[ ettin ] ");
+}
+
+#[test]
+fn update_sub_titles_with_translated_back() {
+    static TRANSLATIONS: Translations =
+        Translations::new(&["English", "Deutsch"], &[&["Back"], &["Zurück"]]);
+    let sub_titles = ["foo"];
+    let mut sut = MenuPage::new(BasicPage::new("MyTitle", None), Some("Back"))
+        .with_translations(&TRANSLATIONS);
+    TRANSLATIONS.set_language(1).unwrap();
+    sut.update(Some(Box::new(sub_titles.iter().copied())))
+        .unwrap();
+    assert_eq!(&sut.sub_titles[..], "[ foo ] Zurück ");
+}
@@ -0,0 +1,224 @@
+use super::super::{
+    truncate, DisplayInfo, DisplaySize, Feedback, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation, PageResult, ScrollPosition, Setting,
+};
+use super::basic::BasicPage;
+
+/// Maximal number of items - one bit of the mask each
+const MAX_ITEMS: usize = 32;
+
+/// A page to check any number of items, e.g. the log channels or the sensors enabled
+///
+/// Every item is shown in a line with a check box, e.g. `[x] Sensor A`,
+/// followed by a final `Done` entry; the selected entry is marked by `>`.
+/// The items checked are a bitmask, the first item being the lowest bit,
+/// i.e. at most 32 items are shown.
+///
+/// The items are checked in the page only. Done commits the mask into the
+/// setting, requests `Feedback::Confirm` and leaves the page with
+/// up-navigation, returning the mask as `PageResult` of a `u32`. Leaving the
+/// page otherwise discards the changes.
+///
+/// * previous, next - select the previous or next entry
+/// * action - checks or unchecks the selected item, commits on done
+/// * back - leaves the page with up-navigation without commit
+/// * home - leaves the page with home-navigation without commit
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, MultiSelectPage};
+///     use embedded_multi_page_hmi::{CellSetting, Interaction, PageInteractionInterface};
+///     use embedded_multi_page_hmi::{PageNavigation, Setting};
+///
+///     let channels: CellSetting<u32> = Default::default();
+///     let mut page = MultiSelectPage::new(
+///         BasicPage::new("Log", None),
+///         &["Radio", "Sensors"],
+///         &channels,
+///     );
+///     page.dispatch(Interaction::Next);
+///     page.dispatch(Interaction::Action);
+///     assert_eq!(page.lines(), vec!["> [x] Sensors"]);
+///     page.dispatch(Interaction::Next);
+///     assert_eq!(page.dispatch(Interaction::Action), PageNavigation::Up);
+///     assert_eq!(channels.get(), 0b10);
+/// ```
+pub struct MultiSelectPage<'a> {
+    pub basic: BasicPage,
+    labels: &'static [&'static str],
+    done: &'static str,
+    value: &'a dyn Setting<Item = u32>,
+    mask: u32,
+    selected: usize,
+    size: DisplaySize,
+    committed: bool,
+    result: Option<u32>,
+}
+
+impl<'a> MultiSelectPage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `labels`: - The labels of the items in the order of the bits
+    /// * `value`: - The setting the mask of the checked items is committed into
+    pub fn new(
+        basic: BasicPage,
+        labels: &'static [&'static str],
+        value: &'a dyn Setting<Item = u32>,
+    ) -> Self {
+        MultiSelectPage {
+            basic,
+            labels: &labels[..labels.len().min(MAX_ITEMS)],
+            done: "Done",
+            value,
+            mask: value.get(),
+            selected: 0,
+            size: DisplaySize::default(),
+            committed: false,
+            result: None,
+        }
+    }
+
+    /// The text of the entry committing the mask - default is `Done`
+    pub fn with_done(mut self, done: &'static str) -> Self {
+        self.done = done;
+        self
+    }
+
+    /// The mask of the items checked so far
+    pub fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    /// The item of the index is checked
+    pub fn is_checked(&self, index: usize) -> bool {
+        index < self.labels.len() && self.mask & (1 << index) != 0
+    }
+
+    /// The lines of the entries fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        let position = self.position();
+        (0..self.count())
+            .skip(position.first)
+            .take(position.shown)
+            .map(|index| {
+                let marker = if index == self.selected { "> " } else { "  " };
+                let line = match self.labels.get(index) {
+                    Some(label) => {
+                        let check = if self.is_checked(index) { 'x' } else { ' ' };
+                        format!("{}[{}] {}", marker, check, label)
+                    }
+                    None => format!("{}{}", marker, self.done),
+                };
+                truncate(&line, self.size.cols).into_owned()
+            })
+            .collect()
+    }
+
+    /// Number of entries - the items and done
+    fn count(&self) -> usize {
+        self.labels.len() + 1
+    }
+
+    fn position(&self) -> ScrollPosition {
+        let shown = self.size.rows.max(1);
+        let first = self.selected.saturating_sub(shown - 1);
+        ScrollPosition::new(first, shown, self.count())
+    }
+
+    /// Start over with the mask of the setting at the first item
+    fn restore(&mut self) {
+        self.mask = self.value.get();
+        self.selected = 0;
+    }
+}
+
+impl PageBaseInterface for MultiSelectPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        Ok(PageNavigation::Update)
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    /// Discard the changes not committed
+    fn on_enter(&mut self, _param: Option<usize>, _result: Option<&PageResult>) {
+        self.restore();
+    }
+
+    /// Show the mask of the setting again, e.g. after it was changed remotely
+    fn reload(&mut self) {
+        self.restore();
+    }
+
+    /// The mask committed, e.g. for a form page opening the page
+    fn take_result(&mut self) -> Option<PageResult> {
+        self.result.take().map(PageResult::new)
+    }
+
+    /// Confirm storing the mask
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        match std::mem::replace(&mut self.committed, false) {
+            true => Some(Feedback::Confirm),
+            false => None,
+        }
+    }
+
+    fn selected(&self) -> Option<usize> {
+        Some(self.selected + 1)
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = (index.max(1) - 1).min(self.count() - 1);
+    }
+
+    fn scroll_position(&self) -> Option<ScrollPosition> {
+        Some(self.position())
+    }
+}
+
+impl PageInteractionInterface for MultiSelectPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        let count = self.count();
+        match interaction {
+            Interaction::Action => match self.selected < self.labels.len() {
+                true => {
+                    self.mask ^= 1 << self.selected;
+                    PageNavigation::Update
+                }
+                false => {
+                    self.value.set(self.mask);
+                    self.committed = true;
+                    self.result = Some(self.mask);
+                    PageNavigation::Up
+                }
+            },
+            Interaction::Next => {
+                self.selected = (self.selected + 1) % count;
+                PageNavigation::Update
+            }
+            Interaction::Previous => {
+                self.selected = (self.selected + count - 1) % count;
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
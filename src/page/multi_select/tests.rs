@@ -0,0 +1,60 @@
+use super::*;
+use crate::CellSetting;
+
+static LABELS: [&str; 3] = ["Radio", "Sensors", "Power"];
+
+#[test]
+fn toggle_items_and_commit_on_done() {
+    let channels: CellSetting<u32> = Default::default();
+    channels.set(0b100);
+    let mut sut = MultiSelectPage::new(BasicPage::new("Log", None), &LABELS, &channels);
+    assert!(sut.is_setting());
+    sut.set_display_info(&DisplaySize::new(20, 4));
+    assert!(sut.is_checked(2));
+    sut.dispatch(Interaction::Action);
+    sut.dispatch(Interaction::Next);
+    sut.dispatch(Interaction::Next);
+    sut.dispatch(Interaction::Action);
+    assert_eq!(sut.mask(), 0b001);
+    assert_eq!(channels.get(), 0b100);
+    assert_eq!(
+        sut.lines(),
+        vec!["  [x] Radio", "  [ ] Sensors", "> [ ] Power", "  Done"]
+    );
+    assert_eq!(sut.take_feedback(), None);
+    assert_eq!(sut.dispatch(Interaction::Previous), PageNavigation::Update);
+    sut.select(4);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Up);
+    assert_eq!(channels.get(), 0b001);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Confirm));
+    assert_eq!(sut.take_result().unwrap().get::<u32>(), Some(&0b001));
+}
+
+#[test]
+fn leaving_discards_changes() {
+    let channels: CellSetting<u32> = Default::default();
+    let mut sut = MultiSelectPage::new(BasicPage::new("Log", None), &LABELS, &channels);
+    sut.dispatch(Interaction::Action);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert_eq!(channels.get(), 0);
+    sut.on_enter(None, None);
+    assert_eq!(sut.mask(), 0);
+    channels.set(0b010);
+    sut.reload();
+    assert!(sut.is_checked(1));
+    assert!(!sut.is_checked(3));
+}
+
+#[test]
+fn scroll_entries_with_done_text() {
+    let channels: CellSetting<u32> = Default::default();
+    let mut sut = MultiSelectPage::new(BasicPage::new("Log", None), &LABELS, &channels)
+        .with_done("Save settings");
+    sut.set_display_info(&DisplaySize::new(10, 2));
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.selected(), Some(4));
+    assert_eq!(sut.lines(), vec!["  [ ] P...", "> Save ..."]);
+    assert_eq!(sut.scroll_position(), Some(ScrollPosition::new(2, 2, 4)));
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.selected(), Some(1));
+}
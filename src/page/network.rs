@@ -0,0 +1,217 @@
+use super::super::{
+    truncate, DisplayInfo, DisplaySize, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation,
+};
+use super::basic::BasicPage;
+
+#[cfg(feature = "graphics")]
+use super::super::{graphics::GraphicsDisplay, PageInterface};
+#[cfg(feature = "graphics")]
+use embedded_graphics::{
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+
+/// Weakest signal in dBm of one bar up to four bars
+const BARS: [i8; 4] = [-85, -75, -67, -55];
+
+/// The state of the network connection shown by the `NetworkInfoPage`
+///
+/// Only the connection state is mandatory; information not available, e.g.
+/// the SSID of a wired connection, is not shown.
+pub trait NetworkStatusProvider {
+    /// The device is connected, e.g. associated and configured
+    fn is_connected(&self) -> bool;
+
+    /// The name of the wireless network - default is none
+    fn ssid(&self) -> Option<&str> {
+        None
+    }
+
+    /// The received signal strength in dBm - default is none
+    fn rssi(&self) -> Option<i8> {
+        None
+    }
+
+    /// The IPv4 address - default is none
+    fn ip(&self) -> Option<[u8; 4]> {
+        None
+    }
+
+    /// The MAC address - default is none
+    fn mac(&self) -> Option<[u8; 6]> {
+        None
+    }
+}
+
+/// A page showing the state of the network connection, e.g. of a WiFi module
+///
+/// Shows the connection state, SSID, signal strength, IP and MAC address as
+/// provided by the `NetworkStatusProvider`. The state is fetched on every
+/// update. Character displays show the signal strength in dBm; graphical
+/// displays of the `graphics` feature draw it as bars in the title bar as
+/// well. Lines not fitting the display are paginated: the action
+/// interaction shows the next rows.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, NetworkInfoPage, NetworkStatusProvider};
+///     use embedded_multi_page_hmi::PageBaseInterface;
+///
+///     struct Wifi;
+///     impl NetworkStatusProvider for Wifi {
+///         fn is_connected(&self) -> bool { true }
+///         fn rssi(&self) -> Option<i8> { Some(-60) }
+///         fn ip(&self) -> Option<[u8; 4]> { Some([192, 168, 1, 20]) }
+///     }
+///
+///     let mut page = NetworkInfoPage::new(BasicPage::new("Network", None), Box::new(Wifi));
+///     page.update(None).unwrap();
+///     assert_eq!(page.bars(), 3);
+///     assert_eq!(page.lines(), vec!["Connected"]);
+/// ```
+pub struct NetworkInfoPage<'a> {
+    pub basic: BasicPage,
+    provider: Box<dyn NetworkStatusProvider + 'a>,
+    states: (&'static str, &'static str),
+    lines: Vec<String>,
+    bars: usize,
+    first_line: usize,
+    size: DisplaySize,
+}
+
+impl<'a> NetworkInfoPage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `provider`: - The state of the connection, e.g. of the network stack
+    pub fn new(basic: BasicPage, provider: Box<dyn NetworkStatusProvider + 'a>) -> Self {
+        NetworkInfoPage {
+            basic,
+            provider,
+            states: ("Connected", "Disconnected"),
+            lines: Vec::new(),
+            bars: 0,
+            first_line: 0,
+            size: DisplaySize::default(),
+        }
+    }
+
+    /// Replace the texts of the connection state, e.g. by translated texts
+    pub fn with_states(mut self, connected: &'static str, disconnected: &'static str) -> Self {
+        self.states = (connected, disconnected);
+        self
+    }
+
+    /// The signal strength as zero up to four bars - zero if disconnected
+    pub fn bars(&self) -> usize {
+        self.bars
+    }
+
+    /// The lines of the state fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        self.lines
+            .iter()
+            .skip(self.first_line)
+            .take(self.size.rows)
+            .map(|line| truncate(line, self.size.cols).into_owned())
+            .collect()
+    }
+
+    fn fetch(&self) -> Vec<String> {
+        let provider = &self.provider;
+        let mut lines = vec![String::from(match provider.is_connected() {
+            true => self.states.0,
+            false => self.states.1,
+        })];
+        if let Some(ssid) = provider.ssid() {
+            lines.push(format!("SSID {}", ssid));
+        }
+        if let Some(rssi) = provider.rssi() {
+            lines.push(format!("RSSI {} dBm", rssi));
+        }
+        if let Some([a, b, c, d]) = provider.ip() {
+            lines.push(format!("IP {}.{}.{}.{}", a, b, c, d));
+        }
+        if let Some(mac) = provider.mac() {
+            let octets: Vec<String> = mac.iter().map(|octet| format!("{:02X}", octet)).collect();
+            lines.push(format!("MAC {}", octets.join(":")));
+        }
+        lines
+    }
+}
+
+impl PageBaseInterface for NetworkInfoPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    /// Fetch the state
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.lines = self.fetch();
+        self.bars = match (self.provider.is_connected(), self.provider.rssi()) {
+            (true, Some(rssi)) => BARS.iter().filter(|weakest| rssi >= **weakest).count(),
+            _ => 0,
+        };
+        if self.first_line >= self.lines.len() {
+            self.first_line = 0;
+        }
+        Ok(PageNavigation::Update)
+    }
+
+    fn on_leave(&mut self) {
+        self.first_line = 0;
+    }
+}
+
+impl PageInteractionInterface for NetworkInfoPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Action => {
+                self.first_line += self.size.rows;
+                if self.first_line >= self.lines.len() {
+                    self.first_line = 0;
+                }
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+            Interaction::Next => PageNavigation::Left,
+            Interaction::Previous => PageNavigation::Right,
+        }
+    }
+}
+
+/// The lines below the title bar, the signal bars at the right of the title bar
+#[cfg(feature = "graphics")]
+impl<G: DrawTarget, C> PageInterface<GraphicsDisplay<G>, C> for NetworkInfoPage<'_> {
+    fn display(&self, display_driver: &mut GraphicsDisplay<G>) {
+        let theme = *display_driver.theme();
+        display_driver.update(self.title(), &self.lines().join("\n"));
+        let height = theme.title_font.character_size.height as i32;
+        let width = display_driver.target().bounding_box().size.width as i32;
+        // bars of 2 pixels with a gap of 1 pixel, growing to the right
+        let bottom = height - 2;
+        for bar in 0..BARS.len() as i32 {
+            let bar_height = match (bar as usize) < self.bars {
+                true => (bar + 1) * (height - 2) / BARS.len() as i32,
+                false => 1,
+            };
+            let origin = Point::new(width - 12 + 3 * bar, bottom - bar_height);
+            let _ = Rectangle::new(origin, Size::new(2, bar_height as u32))
+                .into_styled(PrimitiveStyle::with_fill(theme.background))
+                .draw(display_driver.target_mut());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,108 @@
+use super::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// A WiFi module with the signal strength set by the test - disconnected without
+struct Wifi(Rc<Cell<Option<i8>>>);
+
+impl NetworkStatusProvider for Wifi {
+    fn is_connected(&self) -> bool {
+        self.0.get().is_some()
+    }
+
+    fn ssid(&self) -> Option<&str> {
+        Some("HomeNet")
+    }
+
+    fn rssi(&self) -> Option<i8> {
+        self.0.get()
+    }
+
+    fn ip(&self) -> Option<[u8; 4]> {
+        self.0.get().map(|_| [10, 0, 0, 7])
+    }
+
+    fn mac(&self) -> Option<[u8; 6]> {
+        Some([0x02, 0, 0, 0x12, 0xab, 0x5f])
+    }
+}
+
+#[test]
+fn show_the_state_paginated() {
+    let rssi = Rc::new(Cell::new(Some(-70)));
+    let mut sut = NetworkInfoPage::new(
+        BasicPage::new("Network", None),
+        Box::new(Wifi(Rc::clone(&rssi))),
+    );
+    sut.set_display_info(&DisplaySize::new(20, 3));
+    sut.update(None).unwrap();
+    assert_eq!(
+        sut.lines(),
+        vec!["Connected", "SSID HomeNet", "RSSI -70 dBm"]
+    );
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    assert_eq!(sut.lines(), vec!["IP 10.0.0.7", "MAC 02:00:00:12:A..."]);
+
+    // the lines of the page shown are gone with the connection
+    rssi.set(None);
+    sut.update(None).unwrap();
+    assert_eq!(
+        sut.lines(),
+        vec!["Disconnected", "SSID HomeNet", "MAC 02:00:00:12:A..."]
+    );
+    assert_eq!(sut.dispatch(Interaction::Next), PageNavigation::Left);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+}
+
+#[test]
+fn signal_bars_by_strength() {
+    let rssi = Rc::new(Cell::new(None));
+    let mut sut = NetworkInfoPage::new(
+        BasicPage::new("Network", None),
+        Box::new(Wifi(Rc::clone(&rssi))),
+    )
+    .with_states("Online", "Offline");
+    for (strength, bars) in [
+        (-50, 4),
+        (-55, 4),
+        (-56, 3),
+        (-67, 3),
+        (-75, 2),
+        (-85, 1),
+        (-90, 0),
+    ] {
+        rssi.set(Some(strength));
+        sut.update(None).unwrap();
+        assert_eq!(sut.bars(), bars, "{} dBm", strength);
+    }
+    rssi.set(None);
+    sut.update(None).unwrap();
+    assert_eq!(sut.bars(), 0);
+    assert_eq!(sut.lines(), vec!["Offline"]);
+}
+
+#[cfg(feature = "graphics")]
+#[test]
+fn draw_signal_bars_in_the_title_bar() {
+    use crate::graphics::Theme;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::mono_font::ascii::FONT_4X6;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    let rssi = Rc::new(Cell::new(Some(-60)));
+    let mut sut = NetworkInfoPage::new(
+        BasicPage::new("Net", None),
+        Box::new(Wifi(Rc::clone(&rssi))),
+    );
+    sut.update(None).unwrap();
+    let mut target = MockDisplay::new();
+    target.set_allow_overdraw(true);
+    let theme = Theme::default().with_fonts(&FONT_4X6, &FONT_4X6);
+    let mut display = GraphicsDisplay::new(target, theme);
+    PageInterface::<_, ()>::display(&sut, &mut display);
+    // three of four bars - bars are cut out of the title bar of 6 pixels
+    let target = display.target();
+    assert_eq!(target.get_pixel(Point::new(58, 1)), Some(BinaryColor::Off));
+    assert_eq!(target.get_pixel(Point::new(61, 1)), Some(BinaryColor::On));
+    assert_eq!(target.get_pixel(Point::new(61, 3)), Some(BinaryColor::Off));
+}
@@ -0,0 +1,163 @@
+use super::super::{
+    AccessLevel, Feedback, Interaction, PageBaseInterface, PageError, PageInteractionInterface,
+    PageNavigation,
+};
+use super::basic::BasicPage;
+
+/// A page to raise the access level by entering a PIN
+///
+/// The digits of the PIN are entered one by one; the digits entered are
+/// shown masked, followed by the selected digit. Once as many digits as the
+/// PIN has are entered the PIN is checked: a correct PIN grants its access
+/// level, requests `Feedback::Confirm` and leaves the page with
+/// up-navigation. A wrong PIN requests `Feedback::Error` and starts over.
+///
+/// * next - selects the next digit
+/// * previous - selects the previous digit
+/// * action - appends the selected digit
+/// * back - removes the last digit, leaves the page with up-navigation if there is none
+/// * home - leaves the page with home-navigation
+/// * digits entered directly, e.g. by a keypad, are appended
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, PinPage};
+///     use embedded_multi_page_hmi::{AccessLevel, PageBaseInterface, PageInteractionInterface};
+///
+///     let mut page = PinPage::new(BasicPage::new("Service", None), 4)
+///         .with_pin("1234", AccessLevel::Installer)
+///         .with_pin("9876", AccessLevel::Service);
+///     "9876".chars().for_each(|c| {
+///         page.dispatch_char(c);
+///     });
+///     assert_eq!(page.take_access_level(), Some(AccessLevel::Service));
+/// ```
+pub struct PinPage {
+    pub basic: BasicPage,
+    length: usize,
+    pins: Vec<(&'static str, AccessLevel)>,
+    entered: String,
+    digit: u8,
+    granted: Option<AccessLevel>,
+    feedback: Option<Feedback>,
+}
+
+impl PinPage {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `length`: - The number of digits of the PINs
+    pub fn new(basic: BasicPage, length: usize) -> Self {
+        PinPage {
+            basic,
+            length: length.max(1),
+            pins: Vec::new(),
+            entered: String::new(),
+            digit: 0,
+            granted: None,
+            feedback: None,
+        }
+    }
+
+    /// Add a PIN granting an access level
+    ///
+    /// Arguments
+    ///
+    /// * `pin`: - The digits of the PIN
+    /// * `level`: - The access level granted
+    pub fn with_pin(mut self, pin: &'static str, level: AccessLevel) -> Self {
+        self.pins.push((pin, level));
+        self
+    }
+
+    /// The digits entered masked followed by the selected digit, e.g. `**7`
+    pub fn line(&self) -> String {
+        format!("{}{}", "*".repeat(self.entered.len()), self.digit)
+    }
+
+    /// Append a digit and check the PIN if complete
+    fn enter(&mut self, digit: char) -> PageNavigation {
+        self.entered.push(digit);
+        if self.entered.len() < self.length {
+            return PageNavigation::Update;
+        }
+        let granted = self
+            .pins
+            .iter()
+            .find(|(pin, _)| *pin == self.entered)
+            .map(|(_, level)| *level);
+        self.entered.clear();
+        self.digit = 0;
+        match granted {
+            Some(level) => {
+                self.granted = Some(level);
+                self.feedback = Some(Feedback::Confirm);
+                PageNavigation::Up
+            }
+            None => {
+                self.feedback = Some(Feedback::Error);
+                PageNavigation::Update
+            }
+        }
+    }
+}
+
+impl PageBaseInterface for PinPage {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn update<'a>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        Ok(PageNavigation::Update)
+    }
+
+    fn on_leave(&mut self) {
+        self.entered.clear();
+        self.digit = 0;
+    }
+
+    /// Confirm the correct or report the wrong PIN
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        self.feedback.take()
+    }
+
+    /// Grant the access level of the correct PIN
+    fn take_access_level(&mut self) -> Option<AccessLevel> {
+        self.granted.take()
+    }
+}
+
+impl PageInteractionInterface for PinPage {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Next => {
+                self.digit = (self.digit + 1) % 10;
+                PageNavigation::Update
+            }
+            Interaction::Previous => {
+                self.digit = (self.digit + 9) % 10;
+                PageNavigation::Update
+            }
+            Interaction::Action => self.enter(char::from(b'0' + self.digit)),
+            Interaction::Back => match self.entered.pop() {
+                Some(_) => PageNavigation::Update,
+                None => PageNavigation::Up,
+            },
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+
+    fn dispatch_char(&mut self, c: char) -> PageNavigation {
+        match c.is_ascii_digit() {
+            true => self.enter(c),
+            false => PageNavigation::Update,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
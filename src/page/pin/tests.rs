@@ -0,0 +1,67 @@
+use super::*;
+
+fn pin_page() -> PinPage {
+    PinPage::new(BasicPage::new("Service", None), 3)
+        .with_pin("120", AccessLevel::Installer)
+        .with_pin("999", AccessLevel::Service)
+}
+
+#[test]
+fn enter_pin_by_buttons() {
+    let mut sut = pin_page();
+    assert_eq!(sut.title(), "Service");
+    assert_eq!(sut.line(), "0");
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    sut.dispatch(Interaction::Next);
+    sut.dispatch(Interaction::Action);
+    assert_eq!(sut.line(), "**2");
+    sut.dispatch(Interaction::Previous);
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.take_access_level(), None);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Up);
+    assert_eq!(sut.take_access_level(), Some(AccessLevel::Installer));
+    assert_eq!(sut.take_feedback(), Some(Feedback::Confirm));
+    assert_eq!(sut.take_access_level(), None);
+    assert_eq!(sut.line(), "0");
+}
+
+#[test]
+fn enter_pin_by_keypad() {
+    let mut sut = pin_page();
+    sut.dispatch_char('1');
+    sut.dispatch_char('2');
+    assert_eq!(sut.dispatch_char('0'), PageNavigation::Up);
+    assert_eq!(sut.take_access_level(), Some(AccessLevel::Installer));
+}
+
+#[test]
+fn wrong_pin_starts_over() {
+    let mut sut = pin_page();
+    sut.dispatch_char('9');
+    sut.dispatch_char('x');
+    sut.dispatch_char('9');
+    assert_eq!(sut.dispatch_char('8'), PageNavigation::Update);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Error));
+    assert_eq!(sut.take_access_level(), None);
+    assert_eq!(sut.line(), "0");
+    // the digit previous selected wraps around
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.line(), "9");
+    sut.dispatch(Interaction::Action);
+    sut.dispatch(Interaction::Action);
+    sut.dispatch(Interaction::Action);
+    assert_eq!(sut.take_access_level(), Some(AccessLevel::Service));
+}
+
+#[test]
+fn remove_digits() {
+    let mut sut = pin_page();
+    sut.dispatch_char('1');
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Update);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    sut.dispatch_char('1');
+    sut.on_leave();
+    assert_eq!(sut.line(), "0");
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Home);
+}
@@ -0,0 +1,104 @@
+use super::super::{
+    DisplayInfo, DisplaySize, Interaction, PageBaseInterface, PageError, PageInteractionInterface,
+    PageNavigation,
+};
+use super::basic::BasicPage;
+use super::confirm::{Answer, Confirmation};
+
+/// A page asking to confirm powering off the device
+///
+/// The page asks to confirm by a `Confirmation` first. Confirmed, it
+/// navigates by `PageNavigation::SystemStop`, i.e. the page manager shows the
+/// shutdown page and runs the shutdown tasks.
+///
+/// Together with a long press of the `ChordInput` and the cancel interaction
+/// of the `ShutdownPage` it composes a power-off flow, without overriding the
+/// dispatch of the home page.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{
+///         page::{BasicPage, PowerOffPage},
+///         ChordEvent, ChordInput, Interaction, PageInteractionInterface, PageNavigation,
+///     };
+///
+///     // e.g. registered as sub page of the home page
+///     let mut page = PowerOffPage::new(BasicPage::new("Power off", None));
+///     // e.g. the buttons read every 10ms - holding home for 2s asks to power off
+///     let mut input = ChordInput::new().with_long_press(
+///         Interaction::Home,
+///         200,
+///         ChordEvent::Path(&["Power off"]),
+///     );
+///     let held = (0..200).filter_map(|_| input.sample(&[Interaction::Home])).last();
+///     assert_eq!(held, Some(ChordEvent::Path(&["Power off"])));
+///     // the long press does not home on release
+///     assert_eq!(input.sample(&[]), None);
+///
+///     page.dispatch(Interaction::Next);
+///     assert_eq!(page.dispatch(Interaction::Action), PageNavigation::SystemStop);
+/// ```
+pub struct PowerOffPage {
+    pub basic: BasicPage,
+    confirmation: Confirmation,
+    size: DisplaySize,
+}
+
+impl PowerOffPage {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    pub fn new(basic: BasicPage) -> Self {
+        PowerOffPage {
+            basic,
+            confirmation: Confirmation::new("Power off?"),
+            size: DisplaySize::default(),
+        }
+    }
+
+    /// Replace the question asked, e.g. by a translated text
+    pub fn with_question(mut self, question: &str) -> Self {
+        self.confirmation.set_question(question);
+        self
+    }
+
+    /// The lines of the question fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        self.confirmation.lines(self.size.cols)
+    }
+}
+
+impl PageBaseInterface for PowerOffPage {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        Ok(PageNavigation::Update)
+    }
+
+    /// Ask again with no selected
+    fn on_leave(&mut self) {
+        self.confirmation.reset();
+    }
+}
+
+impl PageInteractionInterface for PowerOffPage {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match self.confirmation.dispatch(interaction) {
+            Answer::Yes => PageNavigation::SystemStop,
+            Answer::Navigation(navigation) => navigation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,37 @@
+use super::*;
+use crate::DisplaySize;
+
+#[test]
+fn confirm_power_off() {
+    let mut sut = PowerOffPage::new(BasicPage::new("Power off", None));
+    assert_eq!(sut.title(), "Power off");
+    assert_eq!(sut.lines(), vec!["Power off?", "[No] Yes"]);
+    assert_eq!(sut.dispatch(Interaction::Previous), PageNavigation::Update);
+    assert_eq!(sut.lines()[1], "No [Yes]");
+    assert_eq!(
+        sut.dispatch(Interaction::Action),
+        PageNavigation::SystemStop
+    );
+    // asks again with no selected
+    assert_eq!(sut.lines()[1], "[No] Yes");
+}
+
+#[test]
+fn decline_power_off() {
+    let mut sut = PowerOffPage::new(BasicPage::new("Power off", None));
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Up);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Home);
+
+    sut.dispatch(Interaction::Next);
+    sut.on_leave();
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Up);
+}
+
+#[test]
+fn question_fits_the_display() {
+    let mut sut =
+        PowerOffPage::new(BasicPage::new("Aus", None)).with_question("Gerät ausschalten?");
+    sut.set_display_info(&DisplaySize::new(8, 2));
+    assert_eq!(sut.lines(), vec!["Gerät...", "[No] Yes"]);
+}
@@ -0,0 +1,157 @@
+use super::super::{
+    graphics::GraphicsDisplay, wrap, DisplayInfo, PageBaseInterface, PageError,
+    PageInteractionInterface, PageInterface, PageNavigation,
+};
+use super::basic::BasicPage;
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+use qrcodegen::{QrCode, QrCodeEcc};
+
+/// Light modules around the code - scanners need at least two
+const QUIET_ZONE: u32 = 2;
+
+/// A page showing a text as QR code, e.g. a pairing URL or WiFi credentials
+///
+/// Requires the `qr-code` feature. The code is scaled to the largest size
+/// fitting the display below the title and centered. Dark modules are drawn in
+/// the foreground color of the theme, light modules in the background color,
+/// i.e. on displays with lit content on a dark background the code is inverted
+/// unless the colors are swapped by `with_inverted()`. If the code does not
+/// fit the display the text is shown instead.
+///
+/// The text is optionally provided by a closure, e.g. reading the device id;
+/// it is fetched and encoded on every update of the page.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, QrCodePage};
+///
+///     let device_id = || String::from("HMI-0042");
+///     let page = QrCodePage::new(BasicPage::new("Pairing", None), "")
+///         .with_provider(Box::new(move || format!("https://example.com/pair/{}", device_id())));
+/// ```
+pub struct QrCodePage<'a> {
+    pub basic: BasicPage,
+    pub text: String,
+    provider: Option<Box<dyn FnMut() -> String + 'a>>,
+    code: Option<(String, QrCode)>,
+    inverted: bool,
+}
+
+impl<'a> QrCodePage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `text`: - The text encoded, e.g. a URL
+    pub fn new(basic: BasicPage, text: &str) -> Self {
+        QrCodePage {
+            basic,
+            text: String::from(text),
+            provider: None,
+            code: None,
+            inverted: false,
+        }
+    }
+
+    /// Fetch the text encoded on every update
+    pub fn with_provider(mut self, provider: Box<dyn FnMut() -> String + 'a>) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Draw dark modules in the background color, e.g. on OLEDs
+    pub fn with_inverted(mut self, inverted: bool) -> Self {
+        self.inverted = inverted;
+        self
+    }
+
+    /// The QR code of the text - none before the first update
+    pub fn code(&self) -> Option<&QrCode> {
+        self.code.as_ref().map(|(_, code)| code)
+    }
+}
+
+impl PageBaseInterface for QrCodePage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    /// Fetch and encode the text
+    ///
+    /// Returns `PageError` if the text is too long for a QR code.
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        if let Some(provider) = &mut self.provider {
+            self.text = provider();
+        }
+        if !matches!(&self.code, Some((text, _)) if *text == self.text) {
+            self.code = None;
+            let code = QrCode::encode_text(&self.text, QrCodeEcc::Medium).map_err(|_| PageError)?;
+            self.code = Some((self.text.clone(), code));
+        }
+        Ok(PageNavigation::Update)
+    }
+}
+
+impl PageInteractionInterface for QrCodePage<'_> {}
+
+impl QrCodePage<'_> {
+    /// Show the text wrapped into the rows of the display
+    fn display_text<G: DrawTarget>(&self, display_driver: &mut GraphicsDisplay<G>) {
+        let mut lines = wrap(&self.text, display_driver.cols());
+        lines.truncate(display_driver.rows());
+        display_driver.update(self.title(), &lines.join("\n"));
+    }
+}
+
+impl<G: DrawTarget, C> PageInterface<GraphicsDisplay<G>, C> for QrCodePage<'_> {
+    fn display(&self, display_driver: &mut GraphicsDisplay<G>) {
+        let theme = *display_driver.theme();
+        let top = theme.title_font.character_size.height;
+        let size = display_driver.target().bounding_box().size;
+        let height = size.height.saturating_sub(top);
+        let (code, modules, scale) = match self.code() {
+            Some(code) => {
+                let modules = code.size() as u32 + 2 * QUIET_ZONE;
+                (code, modules, size.width.min(height) / modules)
+            }
+            None => return self.display_text(display_driver),
+        };
+        if scale == 0 {
+            return self.display_text(display_driver);
+        }
+
+        display_driver.update(self.title(), "");
+        let (dark, light) = match self.inverted {
+            false => (theme.foreground, theme.background),
+            true => (theme.background, theme.foreground),
+        };
+        let origin = Point::new(
+            ((size.width - modules * scale) / 2) as i32,
+            (top + (height - modules * scale) / 2) as i32,
+        );
+        let target = display_driver.target_mut();
+        let _ = Rectangle::new(origin, Size::new_equal(modules * scale))
+            .into_styled(PrimitiveStyle::with_fill(light))
+            .draw(target);
+        for y in 0..code.size() {
+            for x in 0..code.size() {
+                if code.get_module(x, y) {
+                    let module = Point::new(x, y) + Point::new_equal(QUIET_ZONE as i32);
+                    let _ = Rectangle::new(origin + module * scale as i32, Size::new_equal(scale))
+                        .into_styled(PrimitiveStyle::with_fill(dark))
+                        .draw(target);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
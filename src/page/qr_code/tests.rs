@@ -0,0 +1,79 @@
+use super::*;
+use crate::graphics::Theme;
+use embedded_graphics::mock_display::MockDisplay;
+use embedded_graphics::pixelcolor::BinaryColor;
+
+fn display() -> GraphicsDisplay<MockDisplay<BinaryColor>> {
+    let mut target = MockDisplay::new();
+    target.set_allow_overdraw(true);
+    GraphicsDisplay::new(target, Theme::default())
+}
+
+fn show(page: &QrCodePage<'_>) -> GraphicsDisplay<MockDisplay<BinaryColor>> {
+    let mut display = display();
+    PageInterface::<_, ()>::display(page, &mut display);
+    display
+}
+
+#[test]
+fn encode_on_update() {
+    let mut page = QrCodePage::new(BasicPage::new("Pairing", None), "HMI");
+    assert!(page.code().is_none());
+    page.update(None).unwrap();
+    assert_eq!(page.code().unwrap().size(), 21);
+}
+
+#[test]
+fn encode_provided_text() {
+    let mut id = 0;
+    let mut page =
+        QrCodePage::new(BasicPage::new("Pairing", None), "").with_provider(Box::new(move || {
+            id += 1;
+            "X".repeat(id * 20)
+        }));
+    page.update(None).unwrap();
+    assert_eq!(page.text.len(), 20);
+    let version_1 = page.code().unwrap().size();
+    page.update(None).unwrap();
+    assert!(page.code().unwrap().size() > version_1);
+}
+
+#[test]
+fn text_too_long() {
+    let text = "X".repeat(8000);
+    let mut page = QrCodePage::new(BasicPage::new("Pairing", None), &text);
+    assert!(page.update(None).is_err());
+}
+
+#[test]
+fn scale_code_to_the_display() {
+    // 21 modules and the quiet zone scaled by 2 below the title, centered
+    let mut page = QrCodePage::new(BasicPage::new("Pairing", None), "HMI");
+    page.update(None).unwrap();
+    let display = show(&page);
+    let pixel = |x, y| display.target().get_pixel(Point::new(x, y));
+    assert_eq!(pixel(7, 12), Some(BinaryColor::Off));
+    // top left module of the finder pattern
+    assert_eq!(pixel(11, 16), Some(BinaryColor::On));
+    assert_eq!(pixel(12, 17), Some(BinaryColor::On));
+
+    let page = page.with_inverted(true);
+    let display = show(&page);
+    assert_eq!(
+        display.target().get_pixel(Point::new(11, 16)),
+        Some(BinaryColor::Off)
+    );
+}
+
+#[test]
+fn show_the_text_if_the_code_does_not_fit() {
+    let text = "x".repeat(200);
+    let mut page = QrCodePage::new(BasicPage::new("Pairing", None), &text);
+    page.update(None).unwrap();
+    let display = show(&page);
+    // the first character of the text in the first content row
+    let lit = (0..6).flat_map(|x| (10..20).map(move |y| Point::new(x, y)));
+    assert!(lit
+        .into_iter()
+        .any(|point| display.target().get_pixel(point) == Some(BinaryColor::On)));
+}
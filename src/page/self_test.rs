@@ -0,0 +1,212 @@
+use super::super::{
+    truncate, width, DisplayInfo, DisplaySize, Feedback, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation,
+};
+use super::basic::BasicPage;
+
+/// A named test of the self test
+struct SelfTest<'a> {
+    name: String,
+    test: Box<dyn FnMut() -> bool + 'a>,
+    passed: Option<bool>,
+}
+
+/// A page running a self test of the device, e.g. at end of line testing
+///
+/// Runs the tests one after the other, one test with every update, as soon
+/// as the page gets activated. The first line summarizes the results, the
+/// other lines show the results of the tests. When all tests are run the
+/// page requests `Feedback::Confirm` if all passed and `Feedback::Error`
+/// otherwise, and shows the first failed test.
+///
+/// * previous - scrolls to the previous tests
+/// * next - scrolls to the next tests
+/// * action - runs the tests again once all are run
+/// * back - leaves the page with up-navigation
+/// * home - leaves the page with home-navigation
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, SelfTestPage};
+///     use embedded_multi_page_hmi::PageBaseInterface;
+///
+///     let mut page = SelfTestPage::new(BasicPage::new("Self test", None))
+///         .with_test("Display", Box::new(|| true))
+///         .with_test("Flash", Box::new(|| false));
+///     page.update(None).unwrap();
+///     page.update(None).unwrap();
+///     assert!(page.is_complete());
+///     assert_eq!(page.passed(), 1);
+/// ```
+pub struct SelfTestPage<'a> {
+    pub basic: BasicPage,
+    tests: Vec<SelfTest<'a>>,
+    first_line: usize,
+    reported: bool,
+    size: DisplaySize,
+}
+
+impl<'a> SelfTestPage<'a> {
+    pub fn new(basic: BasicPage) -> Self {
+        SelfTestPage {
+            basic,
+            tests: Vec::new(),
+            first_line: 0,
+            reported: false,
+            size: DisplaySize::default(),
+        }
+    }
+
+    /// Add a test
+    ///
+    /// Arguments
+    ///
+    /// * `name`: - The name shown
+    /// * `test`: - The test returning whether it passed
+    pub fn with_test(mut self, name: &str, test: Box<dyn FnMut() -> bool + 'a>) -> Self {
+        self.tests.push(SelfTest {
+            name: String::from(name),
+            test,
+            passed: None,
+        });
+        self
+    }
+
+    /// All tests are run
+    pub fn is_complete(&self) -> bool {
+        self.tests.iter().all(|t| t.passed.is_some())
+    }
+
+    /// Number of tests passed so far
+    pub fn passed(&self) -> usize {
+        self.tests.iter().filter(|t| t.passed == Some(true)).count()
+    }
+
+    /// Number of tests failed so far
+    pub fn failed(&self) -> usize {
+        self.tests
+            .iter()
+            .filter(|t| t.passed == Some(false))
+            .count()
+    }
+
+    /// Forget the results to run all tests again
+    pub fn restart(&mut self) {
+        self.tests.iter_mut().for_each(|t| t.passed = None);
+        self.first_line = 0;
+        self.reported = false;
+    }
+
+    /// The summary and the results of the tests fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        let run = self.passed() + self.failed();
+        let summary = match (self.is_complete(), self.failed()) {
+            (false, _) => format!("Testing {}/{}", run + 1, self.tests.len()),
+            (true, 0) => format!("PASS {}/{}", run, self.tests.len()),
+            (true, _) => format!("FAIL {}/{}", self.passed(), self.tests.len()),
+        };
+        std::iter::once(summary)
+            .chain(
+                self.tests
+                    .iter()
+                    .skip(self.first_line)
+                    .take(self.size.rows.saturating_sub(1))
+                    .map(|t| self.result_line(t)),
+            )
+            .map(|line| truncate(&line, self.size.cols).into_owned())
+            .collect()
+    }
+
+    /// The name of the test followed by its result - the name is truncated if needed
+    fn result_line(&self, test: &SelfTest<'_>) -> String {
+        let result = match test.passed {
+            None => "...",
+            Some(true) => "PASS",
+            Some(false) => "FAIL",
+        };
+        let name_cols = self.size.cols.saturating_sub(width(result) + 1);
+        format!("{} {}", truncate(&test.name, name_cols), result)
+    }
+
+    /// Number of test lines fitting the display below the summary
+    fn test_rows(&self) -> usize {
+        self.size.rows.saturating_sub(1).max(1)
+    }
+
+    /// Show the test of the index at the bottom of the display
+    fn show(&mut self, index: usize) {
+        self.first_line = (index + 1).saturating_sub(self.test_rows());
+    }
+}
+
+impl PageBaseInterface for SelfTestPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    /// Run the next test
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        if let Some(index) = self.tests.iter().position(|t| t.passed.is_none()) {
+            let test = &mut self.tests[index];
+            test.passed = Some((test.test)());
+            self.show(index);
+            if self.is_complete() {
+                if let Some(failed) = self.tests.iter().position(|t| t.passed == Some(false)) {
+                    self.first_line = failed;
+                }
+            }
+        }
+        Ok(PageNavigation::Update)
+    }
+
+    fn on_leave(&mut self) {
+        self.restart();
+    }
+
+    /// Confirm the passed or report the failed self test once
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        if self.reported || !self.is_complete() {
+            return None;
+        }
+        self.reported = true;
+        match self.failed() {
+            0 => Some(Feedback::Confirm),
+            _ => Some(Feedback::Error),
+        }
+    }
+}
+
+impl PageInteractionInterface for SelfTestPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Previous => {
+                self.first_line = self.first_line.saturating_sub(1);
+                PageNavigation::Update
+            }
+            Interaction::Next => {
+                let last = self.tests.len().saturating_sub(self.test_rows());
+                self.first_line = (self.first_line + 1).min(last);
+                PageNavigation::Update
+            }
+            Interaction::Action => {
+                if self.is_complete() {
+                    self.restart();
+                }
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
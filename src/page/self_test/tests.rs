@@ -0,0 +1,83 @@
+use super::*;
+use std::cell::Cell;
+
+fn self_test_page<'a>(flash: &'a Cell<bool>) -> SelfTestPage<'a> {
+    let mut sut = SelfTestPage::new(BasicPage::new("Self test", None))
+        .with_test("Display", Box::new(|| true))
+        .with_test("Flash... ...", Box::new(move || flash.get()))
+        .with_test("Buttons", Box::new(|| true));
+    sut.set_display_info(&DisplaySize::new(12, 3));
+    sut
+}
+
+#[test]
+fn run_tests_on_updates() {
+    let flash = Cell::new(true);
+    let mut sut = self_test_page(&flash);
+    assert_eq!(sut.title(), "Self test");
+    assert_eq!(
+        sut.lines(),
+        vec!["Testing 1/3", "Display ...", "Flash... ..."]
+    );
+    sut.update(None).unwrap();
+    assert_eq!(
+        sut.lines(),
+        vec!["Testing 2/3", "Display PASS", "Flash... ..."]
+    );
+    assert_eq!(sut.take_feedback(), None);
+    sut.update(None).unwrap();
+    sut.update(None).unwrap();
+    assert!(sut.is_complete());
+    assert_eq!((sut.passed(), sut.failed()), (3, 0));
+    assert_eq!(
+        sut.lines(),
+        vec!["PASS 3/3", "Flas... PASS", "Buttons PASS"]
+    );
+    assert_eq!(sut.take_feedback(), Some(Feedback::Confirm));
+    assert_eq!(sut.take_feedback(), None);
+    // further updates do not run the tests again
+    sut.update(None).unwrap();
+    assert_eq!(sut.passed(), 3);
+}
+
+#[test]
+fn show_first_failed_test() {
+    let flash = Cell::new(false);
+    let mut sut = self_test_page(&flash);
+    (0..3).for_each(|_| {
+        sut.update(None).unwrap();
+    });
+    assert_eq!(
+        sut.lines(),
+        vec!["FAIL 2/3", "Flas... FAIL", "Buttons PASS"]
+    );
+    assert_eq!(sut.take_feedback(), Some(Feedback::Error));
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.lines()[1], "Display PASS");
+    sut.dispatch(Interaction::Next);
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.lines()[1], "Flas... FAIL");
+}
+
+#[test]
+fn run_tests_again() {
+    let flash = Cell::new(false);
+    let mut sut = self_test_page(&flash);
+    // action does not interrupt running tests
+    sut.update(None).unwrap();
+    sut.dispatch(Interaction::Action);
+    assert_eq!(sut.passed(), 1);
+    sut.update(None).unwrap();
+    sut.update(None).unwrap();
+    flash.set(true);
+    sut.dispatch(Interaction::Action);
+    assert!(!sut.is_complete());
+    (0..3).for_each(|_| {
+        sut.update(None).unwrap();
+    });
+    assert_eq!(sut.failed(), 0);
+    // leaving the page forgets the results
+    sut.on_leave();
+    assert_eq!(sut.passed(), 0);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+}
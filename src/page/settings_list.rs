@@ -0,0 +1,174 @@
+use super::super::{
+    truncate, DisplayInfo, DisplaySize, Feedback, Interaction, PageBaseInterface, PageError,
+    PageId, PageInteractionInterface, PageNavigation, SettingsRegistry,
+};
+use super::basic::BasicPage;
+
+/// A page listing all settings and info fields of a `SettingsRegistry`
+///
+/// Every entry is shown in a line with its name and value; the selected
+/// entry is marked by `>`. The list is generated from the registry, i.e. a
+/// setting added to the registry is listed without a page of its own.
+///
+/// Action on a setting navigates to the editor page by
+/// `PageNavigation::ToWith(editor, n)` with the position n of the setting
+/// among the editable settings of the registry, e.g. to a
+/// `GenericValueEditorPage` given the editable settings in the order of the
+/// registry. Read-only `InfoField`s are not edited: action on them is rejected
+/// with `Feedback::Error`.
+///
+/// * previous, next - select the previous or next entry
+/// * action - edits the selected setting
+/// * back - leaves the page with up-navigation
+/// * home - leaves the page with home-navigation
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, SettingsListPage};
+///     use embedded_multi_page_hmi::{CellSetting, Feedback, InfoField, Interaction, SettingsRegistry};
+///     use embedded_multi_page_hmi::{PageBaseInterface, PageInteractionInterface};
+///
+///     let volume: CellSetting<u8> = Default::default();
+///     let serial = InfoField::new(Box::new(|| String::from("SN-0042")));
+///     let registry = SettingsRegistry::new()
+///         .with_setting("Volume", &volume)
+///         .with_setting("Serial", &serial);
+///     let mut page = SettingsListPage::new(BasicPage::new("Settings", None), &registry);
+///     page.dispatch(Interaction::Next);
+///     assert_eq!(page.lines(), vec!["> Serial SN-0042"]);
+///     page.dispatch(Interaction::Action);
+///     assert_eq!(page.take_feedback(), Some(Feedback::Error));
+/// ```
+pub struct SettingsListPage<'a> {
+    pub basic: BasicPage,
+    registry: &'a SettingsRegistry<'a>,
+    editor: Option<PageId>,
+    selected: usize,
+    size: DisplaySize,
+    rejected: bool,
+}
+
+impl<'a> SettingsListPage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `registry`: - The settings and info fields listed
+    pub fn new(basic: BasicPage, registry: &'a SettingsRegistry<'a>) -> Self {
+        SettingsListPage {
+            basic,
+            registry,
+            editor: None,
+            selected: 0,
+            size: DisplaySize::default(),
+            rejected: false,
+        }
+    }
+
+    /// Edit the selected setting by the editor page
+    pub fn with_editor(mut self, editor: PageId) -> Self {
+        self.editor = Some(editor);
+        self
+    }
+
+    /// The lines of the entries fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        let rows = self.size.rows.max(1);
+        let first = self.selected.saturating_sub(rows - 1);
+        self.registry
+            .settings()
+            .enumerate()
+            .skip(first)
+            .take(rows)
+            .map(|(index, (name, setting))| {
+                let marker = if index == self.selected { "> " } else { "  " };
+                let line = format!("{}{} {}", marker, name, setting.text());
+                truncate(&line, self.size.cols).into_owned()
+            })
+            .collect()
+    }
+
+    fn count(&self) -> usize {
+        self.registry.settings().count()
+    }
+
+    /// The position of the selected setting among the editable settings
+    fn editable_position(&self) -> usize {
+        self.registry
+            .settings()
+            .take(self.selected)
+            .filter(|(_, setting)| !setting.is_read_only())
+            .count()
+    }
+}
+
+impl PageBaseInterface for SettingsListPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        Ok(PageNavigation::Update)
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    fn selected(&self) -> Option<usize> {
+        Some(self.selected + 1)
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = (index.max(1) - 1).min(self.count().saturating_sub(1));
+    }
+
+    /// Alert that an info field is not edited
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        match std::mem::replace(&mut self.rejected, false) {
+            true => Some(Feedback::Error),
+            false => None,
+        }
+    }
+}
+
+impl PageInteractionInterface for SettingsListPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        let count = self.count().max(1);
+        match interaction {
+            Interaction::Action => {
+                let setting = self.registry.settings().nth(self.selected);
+                match (setting, self.editor) {
+                    (Some((_, setting)), Some(editor)) if !setting.is_read_only() => {
+                        PageNavigation::ToWith(editor, self.editable_position())
+                    }
+                    (Some((_, setting)), _) if setting.is_read_only() => {
+                        self.rejected = true;
+                        PageNavigation::Update
+                    }
+                    _ => PageNavigation::Update,
+                }
+            }
+            Interaction::Next => {
+                self.selected = (self.selected + 1) % count;
+                PageNavigation::Update
+            }
+            Interaction::Previous => {
+                self.selected = (self.selected + count - 1) % count;
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
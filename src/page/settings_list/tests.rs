@@ -0,0 +1,79 @@
+use super::*;
+use crate::page::{GenericValueEditorPage, MenuPage};
+use crate::testing::FrameBufferDisplay;
+use crate::{CellSetting, InfoField, PageManager, Setting};
+
+#[test]
+fn list_settings_and_info_fields() {
+    let volume: CellSetting<u8> = Default::default();
+    let gain: CellSetting<f32> = Default::default();
+    let firmware = InfoField::new(Box::new(|| String::from("1.2.0")));
+    let registry = SettingsRegistry::new()
+        .with_setting("Volume", &volume)
+        .with_setting("Gain", &gain)
+        .with_setting("Firmware", &firmware);
+    let mut sut = SettingsListPage::new(BasicPage::new("Settings", None), &registry);
+    assert!(sut.is_setting());
+    sut.set_display_info(&DisplaySize::new(12, 2));
+    volume.set(7);
+    assert_eq!(sut.lines(), vec!["> Volume 7", "  Gain 0"]);
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.selected(), Some(3));
+    assert_eq!(sut.lines(), vec!["  Gain 0", "> Firmwar..."]);
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.selected(), Some(1));
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+}
+
+#[test]
+fn edit_settings_only() {
+    let volume: CellSetting<u8> = Default::default();
+    let serial = InfoField::new(Box::new(|| String::from("SN-0042")));
+    let registry = SettingsRegistry::new()
+        .with_setting("Serial", &serial)
+        .with_setting("Volume", &volume);
+    let editor = PageId(3);
+    let mut sut =
+        SettingsListPage::new(BasicPage::new("Settings", None), &registry).with_editor(editor);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Error));
+    assert_eq!(sut.take_feedback(), None);
+    sut.select(2);
+    assert_eq!(
+        sut.dispatch(Interaction::Action),
+        PageNavigation::ToWith(editor, 0)
+    );
+    assert_eq!(sut.take_feedback(), None);
+}
+
+#[test]
+fn edit_by_the_value_editor() {
+    let volume: CellSetting<u8> = Default::default();
+    let brightness: CellSetting<u8> = Default::default();
+    let serial = InfoField::new(Box::new(|| String::from("SN-0042")));
+    let registry = SettingsRegistry::new()
+        .with_setting("Serial", &serial)
+        .with_setting("Volume", &volume)
+        .with_setting("Brightness", &brightness);
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(FrameBufferDisplay::new(20, 4), Box::new(home));
+    let editor = m.register_sub(Box::new(
+        GenericValueEditorPage::new(BasicPage::new("Edit", None), "0123456789", None, None)
+            .with_setting("Volume", &volume)
+            .with_setting("Brightness", &brightness),
+    ));
+    m.register(Box::new(
+        SettingsListPage::new(BasicPage::new("Settings", None), &registry).with_editor(editor),
+    ));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+
+    // the third entry of the list is the second setting of the editor
+    m.dispatch_interaction(Interaction::Previous).unwrap();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    assert_eq!(m.active().title, "Brightness");
+    m.dispatch_char('7').unwrap();
+    m.dispatch_interaction(Interaction::Home).unwrap();
+    assert_eq!(brightness.get(), 7);
+    assert_eq!(volume.get(), 0);
+}
@@ -0,0 +1,296 @@
+use super::super::{
+    graphics::GraphicsDisplay, Feedback, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageInterface, PageNavigation, PageResult, Setting,
+};
+use super::basic::BasicPage;
+
+use embedded_graphics::{
+    mono_font::MonoTextStyleBuilder,
+    prelude::*,
+    primitives::{Line, PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
+};
+
+/// Half the height of the min and max markers and of the knob in pixels
+const MARKER: i32 = 3;
+
+/// A page editing a value of a range by a horizontal slider, e.g. a percentage
+///
+/// Requires the `graphics` feature. The slider is drawn as track between the
+/// min and the max marker with the knob at the value in the gauge color;
+/// the value is shown above the knob, the min and the max below the markers.
+///
+/// The value is changed in the page only. Action commits the value into the
+/// setting, requests `Feedback::Confirm` and leaves the page with
+/// up-navigation, returning the value as `PageResult` of an `i32`. Leaving
+/// the page otherwise discards the change.
+///
+/// Large ranges take long to edit step by step. With acceleration, pressing
+/// next or previous repeatedly changes the value by larger steps.
+///
+/// * previous, next - decrease or increase the value, limited by the range
+/// * action - commits the value
+/// * back - leaves the page with up-navigation without commit
+/// * home - leaves the page with home-navigation without commit
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, SliderPage};
+///     use embedded_multi_page_hmi::{CellSetting, Interaction, PageInteractionInterface};
+///     use embedded_multi_page_hmi::{PageNavigation, Setting};
+///
+///     let brightness: CellSetting<i32> = Default::default();
+///     // from the 4th press on in the same direction steps of 10
+///     let mut page = SliderPage::new(BasicPage::new("Brightness", None), &brightness, 0, 100)
+///         .with_acceleration(4, 10);
+///     for _ in 0..5 {
+///         page.dispatch(Interaction::Next);
+///     }
+///     assert_eq!(page.value(), 23);
+///     assert_eq!(page.dispatch(Interaction::Action), PageNavigation::Up);
+///     assert_eq!(brightness.get(), 23);
+/// ```
+pub struct SliderPage<'a> {
+    pub basic: BasicPage,
+    setting: &'a dyn Setting<Item = i32>,
+    min: i32,
+    max: i32,
+    step: i32,
+    suffix: &'static str,
+    acceleration: Vec<(usize, i32)>,
+    value: i32,
+    presses: usize,
+    increasing: bool,
+    committed: bool,
+    result: Option<i32>,
+}
+
+impl<'a> SliderPage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `setting`: - The setting the value is committed into
+    /// * `min`: - The lowest value, at the left end of the slider
+    /// * `max`: - The highest value, at the right end of the slider
+    pub fn new(basic: BasicPage, setting: &'a dyn Setting<Item = i32>, min: i32, max: i32) -> Self {
+        let max = max.max(min);
+        SliderPage {
+            basic,
+            setting,
+            min,
+            max,
+            step: 1,
+            suffix: "",
+            acceleration: Vec::new(),
+            value: setting.get().clamp(min, max),
+            presses: 0,
+            increasing: true,
+            committed: false,
+            result: None,
+        }
+    }
+
+    /// Change the value by the step with every press - default is one
+    pub fn with_step(mut self, step: i32) -> Self {
+        self.step = step.max(1);
+        self
+    }
+
+    /// Show the values followed by a unit, e.g. `%`
+    pub fn with_suffix(mut self, suffix: &'static str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Change the value by larger steps while pressing repeatedly
+    ///
+    /// Can be given several times; the largest step whose number of presses
+    /// is reached applies. Pressing the other direction or action starts
+    /// counting again.
+    ///
+    /// Arguments
+    ///
+    /// * `presses`: - Number of presses in the same direction from which on the step applies
+    /// * `step`: - The step the value is changed by
+    pub fn with_acceleration(mut self, presses: usize, step: i32) -> Self {
+        self.acceleration.push((presses, step));
+        self
+    }
+
+    /// The value shown, not committed yet
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// The lowest and the highest value
+    pub fn range(&self) -> (i32, i32) {
+        (self.min, self.max)
+    }
+
+    /// A value followed by the suffix, e.g. `50%`
+    fn text(&self, value: i32) -> String {
+        format!("{}{}", value, self.suffix)
+    }
+
+    /// Change the value by a press of next or previous
+    fn press(&mut self, increasing: bool) {
+        match self.increasing == increasing {
+            true => self.presses += 1,
+            false => self.presses = 1,
+        }
+        self.increasing = increasing;
+        let step = self
+            .acceleration
+            .iter()
+            .filter(|(presses, _)| self.presses >= *presses)
+            .map(|(_, step)| *step)
+            .fold(self.step, i32::max);
+        self.value = match increasing {
+            true => self.value.saturating_add(step),
+            false => self.value.saturating_sub(step),
+        }
+        .clamp(self.min, self.max);
+    }
+
+    /// The horizontal position of the knob between the left and the right end
+    fn knob(&self, left: i32, right: i32) -> i32 {
+        match self.max > self.min {
+            true => {
+                let part = (self.value - self.min) as i64 * (right - left) as i64;
+                left + (part / (self.max - self.min) as i64) as i32
+            }
+            false => left,
+        }
+    }
+
+    /// Start over with the value of the setting
+    fn restore(&mut self) {
+        self.value = self.setting.get().clamp(self.min, self.max);
+        self.presses = 0;
+    }
+}
+
+impl PageBaseInterface for SliderPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        Ok(PageNavigation::Update)
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    /// Discard the change not committed
+    fn on_enter(&mut self, _param: Option<usize>, _result: Option<&PageResult>) {
+        self.restore();
+    }
+
+    /// Show the value of the setting again, e.g. after it was changed remotely
+    fn reload(&mut self) {
+        self.restore();
+    }
+
+    /// The value committed, e.g. for a form page opening the page
+    fn take_result(&mut self) -> Option<PageResult> {
+        self.result.take().map(PageResult::new)
+    }
+
+    /// Confirm storing the value
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        match std::mem::replace(&mut self.committed, false) {
+            true => Some(Feedback::Confirm),
+            false => None,
+        }
+    }
+}
+
+impl PageInteractionInterface for SliderPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Next => {
+                self.press(true);
+                PageNavigation::Update
+            }
+            Interaction::Previous => {
+                self.press(false);
+                PageNavigation::Update
+            }
+            Interaction::Action => {
+                self.setting.set(self.value);
+                self.presses = 0;
+                self.committed = true;
+                self.result = Some(self.value);
+                PageNavigation::Up
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+}
+
+impl<G: DrawTarget, C> PageInterface<GraphicsDisplay<G>, C> for SliderPage<'_> {
+    fn display(&self, display_driver: &mut GraphicsDisplay<G>) {
+        let theme = *display_driver.theme();
+        let top = theme.title_font.character_size.height as i32;
+        let size = display_driver.target().bounding_box().size;
+        display_driver.update(self.title(), "");
+
+        let middle = top + (size.height as i32 - top) / 2;
+        let (left, right) = (1, size.width as i32 - 2);
+        let knob = self.knob(left, right);
+        let line = PrimitiveStyle::with_stroke(theme.foreground, 1);
+        let text = MonoTextStyleBuilder::new()
+            .font(theme.font)
+            .text_color(theme.foreground)
+            .build();
+        let below = |alignment| {
+            TextStyleBuilder::new()
+                .alignment(alignment)
+                .baseline(Baseline::Top)
+                .build()
+        };
+        let above = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Bottom)
+            .build();
+        let target = display_driver.target_mut();
+        let _ = Line::new(Point::new(left, middle), Point::new(right, middle))
+            .into_styled(line)
+            .draw(target);
+        for x in [left, right] {
+            let _ = Line::new(
+                Point::new(x, middle - MARKER),
+                Point::new(x, middle + MARKER),
+            )
+            .into_styled(line)
+            .draw(target);
+        }
+        let _ = Rectangle::new(
+            Point::new(knob - 1, middle - MARKER),
+            Size::new(3, 2 * MARKER as u32 + 1),
+        )
+        .into_styled(PrimitiveStyle::with_fill(theme.gauge))
+        .draw(target);
+
+        let value = Point::new(knob, middle - MARKER - 1);
+        let _ = Text::with_text_style(&self.text(self.value), value, text, above).draw(target);
+        let (min, max) = (
+            Point::new(left, middle + MARKER + 2),
+            Point::new(right, middle + MARKER + 2),
+        );
+        let _ = Text::with_text_style(&self.text(self.min), min, text, below(Alignment::Left))
+            .draw(target);
+        let _ = Text::with_text_style(&self.text(self.max), max, text, below(Alignment::Right))
+            .draw(target);
+    }
+}
+
+#[cfg(test)]
+mod tests;
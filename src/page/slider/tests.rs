@@ -0,0 +1,85 @@
+use super::*;
+use crate::graphics::Theme;
+use crate::CellSetting;
+use embedded_graphics::mock_display::MockDisplay;
+use embedded_graphics::mono_font::ascii::FONT_4X6;
+use embedded_graphics::pixelcolor::BinaryColor;
+
+fn show(page: &SliderPage<'_>) -> MockDisplay<BinaryColor> {
+    let mut target = MockDisplay::new();
+    target.set_allow_overdraw(true);
+    let theme = Theme::default().with_fonts(&FONT_4X6, &FONT_4X6);
+    let mut display = GraphicsDisplay::new(target, theme);
+    PageInterface::<_, ()>::display(page, &mut display);
+    display.target().clone()
+}
+
+#[test]
+fn change_within_range_and_commit() {
+    let volume: CellSetting<i32> = Default::default();
+    volume.set(8);
+    let mut sut = SliderPage::new(BasicPage::new("Volume", None), &volume, 0, 10).with_step(2);
+    assert!(sut.is_setting());
+    assert_eq!(sut.range(), (0, 10));
+    sut.dispatch(Interaction::Next);
+    sut.dispatch(Interaction::Next);
+    assert_eq!(sut.value(), 10);
+    assert_eq!(sut.dispatch(Interaction::Previous), PageNavigation::Update);
+    assert_eq!(sut.value(), 8);
+    assert_eq!(sut.take_feedback(), None);
+    assert_eq!(volume.get(), 8);
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Up);
+    assert_eq!(volume.get(), 6);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Confirm));
+    assert_eq!(sut.take_result().unwrap().get::<i32>(), Some(&6));
+}
+
+#[test]
+fn accelerate_pressing_repeatedly() {
+    let level: CellSetting<i32> = Default::default();
+    let mut sut = SliderPage::new(BasicPage::new("Level", None), &level, -500, 500)
+        .with_acceleration(3, 10)
+        .with_acceleration(5, 100);
+    for _ in 0..6 {
+        sut.dispatch(Interaction::Next);
+    }
+    // 1 + 1 + 10 + 10 + 100 + 100
+    assert_eq!(sut.value(), 222);
+    // reversing starts counting again
+    sut.dispatch(Interaction::Previous);
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.value(), 220);
+}
+
+#[test]
+fn leaving_discards_the_change() {
+    let level: CellSetting<i32> = Default::default();
+    level.set(150);
+    let mut sut = SliderPage::new(BasicPage::new("Level", None), &level, 0, 100);
+    assert_eq!(sut.value(), 100);
+    sut.dispatch(Interaction::Previous);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert_eq!(level.get(), 150);
+    level.set(30);
+    sut.on_enter(None, None);
+    assert_eq!(sut.value(), 30);
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Home);
+}
+
+#[test]
+fn draw_track_markers_and_knob() {
+    let percent: CellSetting<i32> = Default::default();
+    percent.set(50);
+    let sut = SliderPage::new(BasicPage::new("Percent", None), &percent, 0, 100).with_suffix("%");
+    let target = show(&sut);
+    // the track is in the middle below the title bar of 6 pixels
+    assert_eq!(target.get_pixel(Point::new(10, 35)), Some(BinaryColor::On));
+    assert_eq!(target.get_pixel(Point::new(10, 33)), Some(BinaryColor::Off));
+    // min and max markers at the ends
+    assert_eq!(target.get_pixel(Point::new(1, 33)), Some(BinaryColor::On));
+    assert_eq!(target.get_pixel(Point::new(62, 37)), Some(BinaryColor::On));
+    // the knob at half of the track
+    assert_eq!(target.get_pixel(Point::new(31, 33)), Some(BinaryColor::On));
+    assert_eq!(target.get_pixel(Point::new(20, 33)), Some(BinaryColor::Off));
+}
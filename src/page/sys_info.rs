@@ -0,0 +1,181 @@
+use super::super::{
+    number::clock, truncate, DisplayInfo, DisplaySize, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation,
+};
+use super::basic::BasicPage;
+
+/// Information about the system shown by the `SysInfoPage`
+///
+/// Only the version is mandatory; information not available on a device is
+/// not shown.
+pub trait SystemInfo {
+    /// The version of the firmware, e.g. `env!("CARGO_PKG_VERSION")`
+    fn version(&self) -> &str;
+
+    /// The git commit the firmware is built from - default is none
+    fn git_hash(&self) -> Option<&str> {
+        None
+    }
+
+    /// Seconds since start, e.g. from the tick source - default is none
+    fn uptime(&self) -> Option<u32> {
+        None
+    }
+
+    /// Free heap in bytes - default is none
+    fn free_heap(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Build metadata known at compile time
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::BuildInfo;
+///
+///     let info = BuildInfo::new(env!("CARGO_PKG_VERSION"), option_env!("GIT_HASH"));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub git_hash: Option<&'static str>,
+}
+
+impl BuildInfo {
+    pub fn new(version: &'static str, git_hash: Option<&'static str>) -> Self {
+        BuildInfo { version, git_hash }
+    }
+}
+
+impl SystemInfo for BuildInfo {
+    fn version(&self) -> &str {
+        self.version
+    }
+
+    fn git_hash(&self) -> Option<&str> {
+        self.git_hash
+    }
+}
+
+/// A page showing information about the system, e.g. as "About" page
+///
+/// Shows the version, git hash, uptime and free heap as provided by the
+/// `SystemInfo` and the number of pages of the HMI. The information is
+/// fetched on every update. Lines not fitting the display are paginated:
+/// the action interaction shows the next rows.
+pub struct SysInfoPage<'a> {
+    pub basic: BasicPage,
+    info: Box<dyn SystemInfo + 'a>,
+    page_count: Option<usize>,
+    lines: Vec<String>,
+    first_line: usize,
+    size: DisplaySize,
+}
+
+impl<'a> SysInfoPage<'a> {
+    pub fn new(basic: BasicPage, info: Box<dyn SystemInfo + 'a>) -> Self {
+        SysInfoPage {
+            basic,
+            info,
+            page_count: None,
+            lines: Vec::new(),
+            first_line: 0,
+            size: DisplaySize::default(),
+        }
+    }
+
+    /// Show the number of pages
+    ///
+    /// E.g. `PageManager::page_count()` after registering all pages, i.e. the
+    /// pages of all modes.
+    pub fn with_page_count(mut self, count: usize) -> Self {
+        self.page_count = Some(count);
+        self
+    }
+
+    /// The lines of the information fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        self.lines
+            .iter()
+            .skip(self.first_line)
+            .take(self.size.rows)
+            .map(|line| truncate(line, self.size.cols).into_owned())
+            .collect()
+    }
+
+    fn fetch(&self) -> Vec<String> {
+        let mut lines = vec![format!("Version {}", self.info.version())];
+        if let Some(hash) = self.info.git_hash() {
+            lines.push(format!("Git {}", hash));
+        }
+        if let Some(seconds) = self.info.uptime() {
+            lines.push(format!("Uptime {}", format_uptime(seconds)));
+        }
+        if let Some(bytes) = self.info.free_heap() {
+            lines.push(format!("Heap {} B", bytes));
+        }
+        if let Some(count) = self.page_count {
+            lines.push(format!("Pages {}", count));
+        }
+        lines
+    }
+}
+
+/// Format seconds as `[<days>d ]hh:mm:ss`
+fn format_uptime(seconds: u32) -> String {
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+    let time = clock(&[seconds / 3600, seconds % 3600 / 60, seconds % 60]);
+    match days {
+        0 => time,
+        days => format!("{}d {}", days, time),
+    }
+}
+
+impl PageBaseInterface for SysInfoPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    /// Fetch the information
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.lines = self.fetch();
+        if self.first_line >= self.lines.len() {
+            self.first_line = 0;
+        }
+        Ok(PageNavigation::Update)
+    }
+
+    fn on_leave(&mut self) {
+        self.first_line = 0;
+    }
+}
+
+impl PageInteractionInterface for SysInfoPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Action => {
+                self.first_line += self.size.rows;
+                if self.first_line >= self.lines.len() {
+                    self.first_line = 0;
+                }
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+            Interaction::Next => PageNavigation::Left,
+            Interaction::Previous => PageNavigation::Right,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,55 @@
+use super::*;
+use std::cell::Cell;
+
+struct DeviceInfo<'a> {
+    ticks: &'a Cell<u32>,
+}
+
+impl SystemInfo for DeviceInfo<'_> {
+    fn version(&self) -> &str {
+        "1.2.0"
+    }
+
+    fn uptime(&self) -> Option<u32> {
+        Some(self.ticks.get() / 100)
+    }
+
+    fn free_heap(&self) -> Option<usize> {
+        Some(2048)
+    }
+}
+
+#[test]
+fn show_build_info() {
+    let mut sut = SysInfoPage::new(
+        BasicPage::new("About", None),
+        Box::new(BuildInfo::new("0.3.1", Some("3f2a1c9"))),
+    )
+    .with_page_count(12);
+    sut.set_display_info(&DisplaySize::new(16, 4));
+    assert_eq!(sut.title(), "About");
+    sut.update(None).unwrap();
+    assert_eq!(
+        sut.lines(),
+        vec!["Version 0.3.1", "Git 3f2a1c9", "Pages 12"]
+    );
+}
+
+#[test]
+fn show_device_info() {
+    let ticks = Cell::new(0);
+    let mut sut = SysInfoPage::new(
+        BasicPage::new("About", None),
+        Box::new(DeviceInfo { ticks: &ticks }),
+    );
+    sut.set_display_info(&DisplaySize::new(20, 2));
+    ticks.set(9_378_400);
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["Version 1.2.0", "Uptime 1d 02:03:04"]);
+    sut.dispatch(Interaction::Action);
+    assert_eq!(sut.lines(), vec!["Heap 2048 B"]);
+    sut.dispatch(Interaction::Action);
+    ticks.set(6_100);
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["Version 1.2.0", "Uptime 00:01:01"]);
+}
@@ -0,0 +1,233 @@
+use super::super::{
+    graphics::GraphicsDisplay, Feedback, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageInterface, PageNavigation, PageResult, PointerInput,
+    SettingsStore, TouchCalibration,
+};
+use super::basic::BasicPage;
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Line, PrimitiveStyle},
+};
+
+use std::cell::RefCell;
+
+/// Half the length of the arms of the crosshair in pixels
+const ARM: i32 = 5;
+
+/// A page calibrating a touch panel, e.g. at the first start of a resistive touch HMI
+///
+/// Requires the `graphics` feature. Three crosshair targets are shown one
+/// after the other: near the top left, the right and the bottom. The raw
+/// position of the panel is read from the pointer input with every update;
+/// the readings while a target is touched are averaged and taken when the
+/// panel is released.
+///
+/// With the third target taken, the calibration is computed and saved to the
+/// settings store, `Feedback::Confirm` is requested and the page is left with
+/// up-navigation, returning the `TouchCalibration` as `PageResult`. Targets
+/// that do not result in a calibration, e.g. because the panel was touched
+/// at the same position every time, request `Feedback::Error` and the
+/// calibration starts over.
+///
+/// * action - starts over with the first target
+/// * back - leaves the page with up-navigation, the calibration is kept
+/// * home - leaves the page with home-navigation, the calibration is kept
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, TouchCalibrationPage};
+///     use embedded_multi_page_hmi::{PageBaseInterface, PageError, PageNavigation};
+///     use embedded_multi_page_hmi::{PointerInput, SettingsStore, TouchCalibration};
+///     use std::cell::RefCell;
+///
+///     struct Eeprom(Option<Vec<u8>>);
+///     impl SettingsStore for Eeprom {
+///         fn store(&mut self, _key: &str, data: &[u8]) -> Result<(), PageError> {
+///             self.0 = Some(data.to_vec());
+///             Ok(())
+///         }
+///         fn load(&mut self, _key: &str) -> Option<Vec<u8>> { self.0.clone() }
+///         fn clear(&mut self) -> Result<(), PageError> { Ok(()) }
+///     }
+///
+///     // the panel reads twice the pixels, touched at every target shown
+///     struct Panel(Vec<Option<(i32, i32)>>);
+///     impl PointerInput for Panel {
+///         fn raw(&mut self) -> Option<(i32, i32)> { self.0.pop().flatten() }
+///     }
+///     let panel = Panel(vec![None, Some((240, 432)), None, Some((432, 240)), None, Some((48, 48))]);
+///
+///     let store = RefCell::new(Eeprom(None));
+///     let mut page =
+///         TouchCalibrationPage::new(BasicPage::new("Touch", None), Box::new(panel), &store, 240, 240);
+///     assert_eq!(page.target(), Some((24, 24)));
+///     for _ in 0..5 {
+///         assert_eq!(page.update(None).unwrap(), PageNavigation::Update);
+///     }
+///     assert_eq!(page.update(None).unwrap(), PageNavigation::Up);
+///     let calibration = TouchCalibration::load(&mut *store.borrow_mut()).unwrap();
+///     assert_eq!(calibration.apply((200, 100)), (100, 50));
+/// ```
+pub struct TouchCalibrationPage<'a> {
+    pub basic: BasicPage,
+    pointer: Box<dyn PointerInput + 'a>,
+    store: &'a RefCell<dyn SettingsStore + 'a>,
+    targets: [(i32, i32); 3],
+    samples: Vec<(i32, i32)>,
+    touch: Option<((i64, i64), i64)>,
+    calibration: Option<TouchCalibration>,
+    feedback: Option<Feedback>,
+    result: Option<TouchCalibration>,
+}
+
+impl<'a> TouchCalibrationPage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `pointer`: - The touch panel calibrated
+    /// * `store`: - The settings store the calibration is saved to
+    /// * `width`: - The width of the display in pixels
+    /// * `height`: - The height of the display in pixels
+    pub fn new(
+        basic: BasicPage,
+        pointer: Box<dyn PointerInput + 'a>,
+        store: &'a RefCell<dyn SettingsStore + 'a>,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        let (width, height) = (width as i32, height as i32);
+        TouchCalibrationPage {
+            basic,
+            pointer,
+            store,
+            targets: [
+                (width / 10, height / 10),
+                (width * 9 / 10, height / 2),
+                (width / 2, height * 9 / 10),
+            ],
+            samples: Vec::new(),
+            touch: None,
+            calibration: None,
+            feedback: None,
+            result: None,
+        }
+    }
+
+    /// The pixel of the target to touch - `None` if all targets are taken
+    pub fn target(&self) -> Option<(i32, i32)> {
+        self.targets.get(self.samples.len()).copied()
+    }
+
+    /// The calibration computed last, if any
+    pub fn calibration(&self) -> Option<TouchCalibration> {
+        self.calibration
+    }
+
+    /// Start over with the first target
+    fn restart(&mut self) {
+        self.samples.clear();
+        self.touch = None;
+    }
+
+    /// Compute and save the calibration from the targets taken
+    fn calibrate(&mut self) -> Result<PageNavigation, PageError> {
+        let raw = [self.samples[0], self.samples[1], self.samples[2]];
+        self.restart();
+        match TouchCalibration::from_points(raw, self.targets) {
+            Some(calibration) => {
+                calibration.save(&mut *self.store.borrow_mut())?;
+                self.calibration = Some(calibration);
+                self.result = Some(calibration);
+                self.feedback = Some(Feedback::Confirm);
+                Ok(PageNavigation::Up)
+            }
+            None => {
+                warn!("touch calibration failed");
+                self.feedback = Some(Feedback::Error);
+                Ok(PageNavigation::Update)
+            }
+        }
+    }
+}
+
+impl PageBaseInterface for TouchCalibrationPage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    /// Read the touch panel - take the target when released
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        match (self.pointer.raw(), self.touch) {
+            (Some((x, y)), None) => self.touch = Some(((x as i64, y as i64), 1)),
+            (Some((x, y)), Some(((sum_x, sum_y), count))) => {
+                self.touch = Some(((sum_x + x as i64, sum_y + y as i64), count + 1))
+            }
+            (None, Some(((sum_x, sum_y), count))) => {
+                self.touch = None;
+                self.samples
+                    .push(((sum_x / count) as i32, (sum_y / count) as i32));
+                if self.target().is_none() {
+                    return self.calibrate();
+                }
+            }
+            (None, None) => (),
+        }
+        Ok(PageNavigation::Update)
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    fn on_enter(&mut self, _param: Option<usize>, _result: Option<&PageResult>) {
+        self.restart();
+    }
+
+    /// The calibration saved, e.g. for the application to apply it
+    fn take_result(&mut self) -> Option<PageResult> {
+        self.result.take().map(PageResult::new)
+    }
+
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        self.feedback.take()
+    }
+}
+
+impl PageInteractionInterface for TouchCalibrationPage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Action => {
+                self.restart();
+                PageNavigation::Update
+            }
+            Interaction::Next | Interaction::Previous => PageNavigation::Update,
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+}
+
+impl<G: DrawTarget, C> PageInterface<GraphicsDisplay<G>, C> for TouchCalibrationPage<'_> {
+    fn display(&self, display_driver: &mut GraphicsDisplay<G>) {
+        let theme = *display_driver.theme();
+        display_driver.update(self.title(), "");
+        if let Some((x, y)) = self.target() {
+            let style = PrimitiveStyle::with_stroke(theme.foreground, 1);
+            let target = display_driver.target_mut();
+            let _ = Line::new(Point::new(x - ARM, y), Point::new(x + ARM, y))
+                .into_styled(style)
+                .draw(target);
+            let _ = Line::new(Point::new(x, y - ARM), Point::new(x, y + ARM))
+                .into_styled(style)
+                .draw(target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,112 @@
+use super::*;
+use crate::graphics::Theme;
+use crate::testing::MemoryStore;
+use embedded_graphics::mock_display::MockDisplay;
+use embedded_graphics::mono_font::ascii::FONT_4X6;
+use embedded_graphics::pixelcolor::BinaryColor;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// A panel touched at the raw position set by the test
+struct Panel(Rc<Cell<Option<(i32, i32)>>>);
+
+impl PointerInput for Panel {
+    fn raw(&mut self) -> Option<(i32, i32)> {
+        self.0.get()
+    }
+}
+
+/// Touch at the raw positions one after the other - each read twice
+fn touch(
+    page: &mut TouchCalibrationPage<'_>,
+    panel: &Cell<Option<(i32, i32)>>,
+    raw: &[(i32, i32)],
+) -> PageNavigation {
+    let mut navigation = PageNavigation::Update;
+    for (x, y) in raw {
+        panel.set(Some((x - 2, y + 1)));
+        page.update(None).unwrap();
+        panel.set(Some((x + 2, y - 1)));
+        page.update(None).unwrap();
+        panel.set(None);
+        navigation = page.update(None).unwrap();
+    }
+    navigation
+}
+
+#[test]
+fn calibrate_by_three_targets() {
+    let panel = Rc::new(Cell::new(None));
+    let store = RefCell::new(MemoryStore::default());
+    let mut sut = TouchCalibrationPage::new(
+        BasicPage::new("Touch", None),
+        Box::new(Panel(Rc::clone(&panel))),
+        &store,
+        320,
+        240,
+    );
+    assert!(sut.is_setting());
+    assert_eq!(sut.target(), Some((32, 24)));
+    // raw readings are 10 times the pixels with an offset of 200
+    let navigation = touch(&mut sut, &panel, &[(520, 440), (3080, 1400)]);
+    assert_eq!(navigation, PageNavigation::Update);
+    assert_eq!(sut.target(), Some((160, 216)));
+    assert_eq!(touch(&mut sut, &panel, &[(1800, 2360)]), PageNavigation::Up);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Confirm));
+    let calibration = sut.calibration().unwrap();
+    assert_eq!(calibration.apply((1200, 700)), (100, 50));
+    assert_eq!(
+        TouchCalibration::load(&mut *store.borrow_mut()),
+        Some(calibration)
+    );
+    let result = sut.take_result().unwrap();
+    assert_eq!(result.get::<TouchCalibration>(), Some(&calibration));
+    assert_eq!(sut.target(), Some((32, 24)));
+}
+
+#[test]
+fn start_over_if_touched_at_one_position() {
+    let panel = Rc::new(Cell::new(None));
+    let store = RefCell::new(MemoryStore::default());
+    let mut sut = TouchCalibrationPage::new(
+        BasicPage::new("Touch", None),
+        Box::new(Panel(Rc::clone(&panel))),
+        &store,
+        320,
+        240,
+    );
+    let navigation = touch(&mut sut, &panel, &[(900, 900), (900, 900), (900, 900)]);
+    assert_eq!(navigation, PageNavigation::Update);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Error));
+    assert_eq!(sut.calibration(), None);
+    assert_eq!(TouchCalibration::load(&mut *store.borrow_mut()), None);
+
+    touch(&mut sut, &panel, &[(520, 440)]);
+    assert_eq!(sut.target(), Some((288, 120)));
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    assert_eq!(sut.target(), Some((32, 24)));
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+}
+
+#[test]
+fn draw_the_crosshair_of_the_target() {
+    let store = RefCell::new(MemoryStore::default());
+    let panel = Rc::new(Cell::new(None));
+    let sut = TouchCalibrationPage::new(
+        BasicPage::new("Touch", None),
+        Box::new(Panel(panel)),
+        &store,
+        64,
+        64,
+    );
+    let mut target = MockDisplay::new();
+    target.set_allow_overdraw(true);
+    let theme = Theme::default().with_fonts(&FONT_4X6, &FONT_4X6);
+    let mut display = GraphicsDisplay::new(target, theme);
+    PageInterface::<_, ()>::display(&sut, &mut display);
+    let target = display.target();
+    // the first target is at (6, 6)
+    assert_eq!(target.get_pixel(Point::new(1, 6)), Some(BinaryColor::On));
+    assert_eq!(target.get_pixel(Point::new(6, 11)), Some(BinaryColor::On));
+    assert_eq!(target.get_pixel(Point::new(8, 8)), Some(BinaryColor::Off));
+}
@@ -0,0 +1,136 @@
+use super::super::{
+    Interaction, PageBaseInterface, PageError, PageInteractionInterface, PageNavigation,
+    TemperatureUnit, UnitSystem, Units,
+};
+use super::basic::BasicPage;
+
+/// A page to select the units values are shown in
+///
+/// The page lists the system of units and the temperature unit; the selected
+/// line is marked by `>`. Since pages format their values whenever they are
+/// updated, the whole HMI shows the units switched to.
+///
+/// * previous, next - select the system of units or the temperature unit
+/// * action - switches the selected unit, e.g. from metric to imperial
+/// * back - leaves the page with up-navigation
+/// * home - leaves the page with home-navigation
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, UnitSelectPage};
+///     use embedded_multi_page_hmi::{Interaction, PageInteractionInterface, UnitSystem, Units};
+///
+///     static UNITS: Units = Units::new();
+///
+///     let mut page = UnitSelectPage::new(BasicPage::new("Units", None), &UNITS);
+///     page.dispatch(Interaction::Action);
+///     assert_eq!(UNITS.system(), UnitSystem::Imperial);
+///     assert_eq!(page.lines(), vec!["> Units imperial", "  Temperature °C"]);
+/// ```
+pub struct UnitSelectPage {
+    pub basic: BasicPage,
+    units: &'static Units,
+    labels: [&'static str; 2],
+    systems: [&'static str; 2],
+    selected: usize,
+}
+
+impl UnitSelectPage {
+    pub fn new(basic: BasicPage, units: &'static Units) -> Self {
+        UnitSelectPage {
+            basic,
+            units,
+            labels: ["Units", "Temperature"],
+            systems: ["metric", "imperial"],
+            selected: 0,
+        }
+    }
+
+    /// Replace the texts shown, e.g. by translated texts
+    ///
+    /// Arguments
+    ///
+    /// * `labels`: - The labels of the system of units and of the temperature unit
+    /// * `systems`: - The names of the metric and of the imperial system
+    pub fn with_texts(mut self, labels: [&'static str; 2], systems: [&'static str; 2]) -> Self {
+        self.labels = labels;
+        self.systems = systems;
+        self
+    }
+
+    /// The lines of the units with the selected line marked
+    pub fn lines(&self) -> Vec<String> {
+        let system = match self.units.system() {
+            UnitSystem::Metric => self.systems[0],
+            UnitSystem::Imperial => self.systems[1],
+        };
+        let temperature = match self.units.temperature() {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F",
+        };
+        [system, temperature]
+            .iter()
+            .zip(self.labels)
+            .enumerate()
+            .map(|(index, (unit, label))| {
+                let marker = if index == self.selected { "> " } else { "  " };
+                format!("{}{} {}", marker, label, unit)
+            })
+            .collect()
+    }
+}
+
+impl PageBaseInterface for UnitSelectPage {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn update<'a>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        Ok(PageNavigation::Update)
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    fn selected(&self) -> Option<usize> {
+        Some(self.selected + 1)
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = (index.max(1) - 1).min(1);
+    }
+}
+
+impl PageInteractionInterface for UnitSelectPage {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Action => {
+                match self.selected {
+                    0 => self.units.set_system(match self.units.system() {
+                        UnitSystem::Metric => UnitSystem::Imperial,
+                        UnitSystem::Imperial => UnitSystem::Metric,
+                    }),
+                    _ => self.units.set_temperature(match self.units.temperature() {
+                        TemperatureUnit::Celsius => TemperatureUnit::Fahrenheit,
+                        TemperatureUnit::Fahrenheit => TemperatureUnit::Celsius,
+                    }),
+                }
+                PageNavigation::Update
+            }
+            Interaction::Next | Interaction::Previous => {
+                self.selected = 1 - self.selected;
+                PageNavigation::Update
+            }
+            Interaction::Back => PageNavigation::Up,
+            Interaction::Home => PageNavigation::Home,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+fn switch_units() {
+    static UNITS: Units = Units::new();
+    let mut sut = UnitSelectPage::new(BasicPage::new("Units", None), &UNITS);
+    assert_eq!(sut.lines(), vec!["> Units metric", "  Temperature °C"]);
+    assert_eq!(sut.dispatch(Interaction::Next), PageNavigation::Update);
+    assert_eq!(sut.selected(), Some(2));
+    assert_eq!(sut.dispatch(Interaction::Action), PageNavigation::Update);
+    assert_eq!(UNITS.temperature(), TemperatureUnit::Fahrenheit);
+    assert_eq!(UNITS.system(), UnitSystem::Metric);
+    sut.dispatch(Interaction::Previous);
+    sut.dispatch(Interaction::Action);
+    assert_eq!(UNITS.system(), UnitSystem::Imperial);
+    assert_eq!(sut.lines(), vec!["> Units imperial", "  Temperature °F"]);
+    sut.dispatch(Interaction::Action);
+    assert_eq!(UNITS.system(), UnitSystem::Metric);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Home);
+}
+
+#[test]
+fn translated_texts() {
+    static UNITS: Units = Units::new();
+    let mut sut = UnitSelectPage::new(BasicPage::new("Einheiten", None), &UNITS)
+        .with_texts(["Einheiten", "Temperatur"], ["metrisch", "imperial"]);
+    sut.select(2);
+    assert_eq!(sut.lines(), vec!["  Einheiten metrisch", "> Temperatur °C"]);
+    sut.select(5);
+    assert_eq!(sut.selected(), Some(2));
+}
@@ -0,0 +1,165 @@
+use super::super::{
+    truncate, DisplayInfo, DisplaySize, Feedback, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation, UpdateProgress, UpdateStatus,
+};
+use super::basic::BasicPage;
+
+/// Maximum number of columns of the progress bar
+const BAR_COLS: usize = 20;
+
+/// A page showing the progress of a firmware update
+///
+/// The application activates the page when the update starts, e.g. by
+/// `PageManager::navigate_path()`. The progress and the stage text are
+/// reported by the bootloader or DFU task through the `UpdateProgress`
+/// handle and fetched with every update of the page.
+///
+/// While the update is running the page is locked: all interactions are
+/// ignored and the page manager holds back alarms, shortcuts, the auto cycle
+/// and navigations of the application. When the update is done the page
+/// shows whether it completed or failed, requests `Feedback::Confirm` or
+/// `Feedback::Error` and prompts to reboot:
+///
+/// * action - calls the reboot callback and navigates where it says
+///
+/// Without an update running the page can be left by back and home.
+pub struct UpdatePage<'a> {
+    pub basic: BasicPage,
+    progress: &'a UpdateProgress,
+    reboot: Box<dyn FnMut() -> PageNavigation + 'a>,
+    texts: [String; 4],
+    status: UpdateStatus,
+    stage: String,
+    feedback: Option<Feedback>,
+    size: DisplaySize,
+}
+
+impl<'a> UpdatePage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `progress`: - The progress reported by the bootloader or DFU task
+    /// * `reboot`: - Reboots the device, e.g. by a system reset
+    pub fn new(
+        basic: BasicPage,
+        progress: &'a UpdateProgress,
+        reboot: Box<dyn FnMut() -> PageNavigation + 'a>,
+    ) -> Self {
+        UpdatePage {
+            basic,
+            progress,
+            reboot,
+            texts: [
+                String::from("No update running"),
+                String::from("Update complete"),
+                String::from("Update failed"),
+                String::from("Action: reboot"),
+            ],
+            status: UpdateStatus::Idle,
+            stage: String::new(),
+            feedback: None,
+            size: DisplaySize::default(),
+        }
+    }
+
+    /// Replace the texts shown, e.g. by translated texts
+    ///
+    /// Arguments
+    ///
+    /// * `idle`: - Shown without an update running
+    /// * `completed`: - Shown when the update completed
+    /// * `failed`: - Shown when the update failed
+    /// * `reboot`: - The prompt to reboot
+    pub fn with_texts(mut self, idle: &str, completed: &str, failed: &str, reboot: &str) -> Self {
+        self.texts = [
+            String::from(idle),
+            String::from(completed),
+            String::from(failed),
+            String::from(reboot),
+        ];
+        self
+    }
+
+    /// The lines of the status fitting the display
+    pub fn lines(&self) -> Vec<String> {
+        let [idle, completed, failed, reboot] = &self.texts;
+        let lines = match self.status {
+            UpdateStatus::Idle => vec![idle.clone()],
+            UpdateStatus::Running(percent) => vec![self.stage.clone(), self.bar(percent)],
+            UpdateStatus::Completed => vec![completed.clone(), reboot.clone()],
+            UpdateStatus::Failed => vec![failed.clone(), self.stage.clone(), reboot.clone()],
+        };
+        lines
+            .iter()
+            .map(|line| truncate(line, self.size.cols).into_owned())
+            .collect()
+    }
+
+    /// A progress bar followed by the percentage, e.g. `#####----- 50%`
+    fn bar(&self, percent: u8) -> String {
+        let cols = self.size.cols.saturating_sub(5).min(BAR_COLS);
+        let filled = cols * percent as usize / 100;
+        format!(
+            "{}{} {:>3}%",
+            "#".repeat(filled),
+            "-".repeat(cols - filled),
+            percent
+        )
+        .trim_start()
+        .to_string()
+    }
+}
+
+impl PageBaseInterface for UpdatePage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    /// Fetch the progress of the update
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        let status = self.progress.status();
+        if status != self.status {
+            self.feedback = match status {
+                UpdateStatus::Completed => Some(Feedback::Confirm),
+                UpdateStatus::Failed => Some(Feedback::Error),
+                _ => None,
+            };
+        }
+        self.status = status;
+        self.stage = self.progress.stage();
+        Ok(PageNavigation::Update)
+    }
+
+    /// Not left while the update is running
+    fn is_locked(&self) -> bool {
+        matches!(self.progress.status(), UpdateStatus::Running(_))
+    }
+
+    /// Confirm the completed or report the failed update once
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        self.feedback.take()
+    }
+}
+
+impl PageInteractionInterface for UpdatePage<'_> {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match (self.progress.status(), interaction) {
+            (UpdateStatus::Idle, Interaction::Back) => PageNavigation::Up,
+            (UpdateStatus::Idle, Interaction::Home) => PageNavigation::Home,
+            (UpdateStatus::Completed, Interaction::Action)
+            | (UpdateStatus::Failed, Interaction::Action) => (self.reboot)(),
+            // the update is not interrupted
+            _ => PageNavigation::Update,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
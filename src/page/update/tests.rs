@@ -0,0 +1,93 @@
+use super::*;
+use std::cell::Cell;
+
+fn update_page<'a>(progress: &'a UpdateProgress, reboots: &'a Cell<usize>) -> UpdatePage<'a> {
+    let mut sut = UpdatePage::new(
+        BasicPage::new("Update", None),
+        progress,
+        Box::new(move || {
+            reboots.set(reboots.get() + 1);
+            PageNavigation::SystemStop
+        }),
+    );
+    sut.set_display_info(&DisplaySize::new(15, 3));
+    sut
+}
+
+#[test]
+fn show_progress() {
+    let progress = UpdateProgress::new();
+    let reboots = Cell::new(0);
+    let mut sut = update_page(&progress, &reboots);
+    assert_eq!(sut.title(), "Update");
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["No update ru..."]);
+    progress.start("Writing");
+    progress.set_progress(50);
+    assert_eq!(sut.lines(), vec!["No update ru..."]);
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["Writing", "#####-----  50%"]);
+    progress.set_progress(100);
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines()[1], "########## 100%");
+    assert_eq!(sut.take_feedback(), None);
+}
+
+#[test]
+fn lock_navigation_while_running() {
+    let progress = UpdateProgress::new();
+    let reboots = Cell::new(0);
+    let mut sut = update_page(&progress, &reboots);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Home);
+    assert!(!sut.is_locked());
+    progress.start("Erasing");
+    assert!(sut.is_locked());
+    for interaction in [
+        Interaction::Action,
+        Interaction::Back,
+        Interaction::Home,
+        Interaction::Next,
+        Interaction::Previous,
+    ]
+    .iter()
+    {
+        assert_eq!(sut.dispatch(*interaction), PageNavigation::Update);
+    }
+    assert_eq!(reboots.get(), 0);
+}
+
+#[test]
+fn prompt_to_reboot() {
+    let progress = UpdateProgress::new();
+    let reboots = Cell::new(0);
+    let mut sut = update_page(&progress, &reboots);
+    progress.start("Verifying");
+    sut.update(None).unwrap();
+    progress.complete();
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["Update complete", "Action: reboot"]);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Confirm));
+    sut.update(None).unwrap();
+    assert_eq!(sut.take_feedback(), None);
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Update);
+    assert_eq!(
+        sut.dispatch(Interaction::Action),
+        PageNavigation::SystemStop
+    );
+    assert_eq!(reboots.get(), 1);
+}
+
+#[test]
+fn show_failure() {
+    let progress = UpdateProgress::new();
+    let reboots = Cell::new(0);
+    let mut sut = update_page(&progress, &reboots).with_texts("Idle", "Done", "Failed", "Reboot?");
+    progress.start("Verifying");
+    progress.fail("Bad signature");
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["Failed", "Bad signature", "Reboot?"]);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Error));
+    sut.dispatch(Interaction::Action);
+    assert_eq!(reboots.get(), 1);
+}
@@ -0,0 +1,207 @@
+use super::super::{
+    truncate, DisplayInfo, DisplaySize, NumberFormat, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation, Quantity, Units,
+};
+use super::basic::BasicPage;
+
+#[cfg(feature = "graphics")]
+use super::super::{graphics::GraphicsDisplay, PageInterface};
+#[cfg(feature = "graphics")]
+use embedded_graphics::{
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+
+/// Maximal columns of the gauge bar
+const GAUGE_COLS: usize = 20;
+
+/// A page showing a measured value in the units selected, e.g. a temperature
+///
+/// The value is read in its metric unit from a closure with every update and
+/// formatted by the `Units`, i.e. switching the units, e.g. by the
+/// `UnitSelectPage`, takes effect with the next update. With a range, the
+/// page is a gauge showing where the value is in the range by a bar.
+/// Graphical displays of the `graphics` feature draw the bar in the gauge
+/// color of the theme - in the alert color if the value is out of range.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::page::{BasicPage, ValuePage};
+///     use embedded_multi_page_hmi::{PageBaseInterface, Quantity, TemperatureUnit, Units};
+///
+///     static UNITS: Units = Units::new();
+///
+///     let mut page =
+///         ValuePage::new(BasicPage::new("Boiler", None), Quantity::Temperature, &UNITS, Box::new(|| 60.0))
+///             .with_range(20.0, 80.0);
+///     page.update(None).unwrap();
+///     assert_eq!(page.lines(), vec!["60.0 °C", "#############-------"]);
+///     UNITS.set_temperature(TemperatureUnit::Fahrenheit);
+///     assert_eq!(page.lines()[0], "140.0 °F");
+/// ```
+pub struct ValuePage<'a> {
+    pub basic: BasicPage,
+    quantity: Quantity,
+    units: &'static Units,
+    provider: Box<dyn FnMut() -> f32 + 'a>,
+    format: NumberFormat,
+    range: Option<(f32, f32)>,
+    value: f32,
+    short: String,
+    size: DisplaySize,
+}
+
+impl<'a> ValuePage<'a> {
+    /// Arguments
+    ///
+    /// * `basic`: - The title of the page
+    /// * `quantity`: - What the value measures
+    /// * `units`: - The units the value is shown in
+    /// * `provider`: - The value in the metric unit, e.g. of a sensor
+    pub fn new(
+        basic: BasicPage,
+        quantity: Quantity,
+        units: &'static Units,
+        provider: Box<dyn FnMut() -> f32 + 'a>,
+    ) -> Self {
+        ValuePage {
+            basic,
+            quantity,
+            units,
+            provider,
+            format: NumberFormat::new().with_decimals(1),
+            range: None,
+            value: 0.0,
+            short: String::new(),
+            size: DisplaySize::default(),
+        }
+    }
+
+    /// Show the digits after the decimal point - default is one
+    pub fn with_decimals(mut self, decimals: usize) -> Self {
+        self.format = self.format.with_decimals(decimals);
+        self
+    }
+
+    /// Format the number by a format, e.g. aligned to a width
+    pub fn with_format(mut self, format: NumberFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Show the value as gauge in the range - given in the metric unit
+    pub fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// The value last read in the metric unit
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// The value in the units selected and the gauge, if any
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![self
+            .units
+            .format_with(self.quantity, self.value, self.format)];
+        if let Some((min, max)) = self.range {
+            lines.push(self.gauge(min, max));
+        }
+        lines
+            .iter()
+            .map(|line| truncate(line, self.size.cols).into_owned())
+            .collect()
+    }
+
+    /// A bar filled by the part of the range up to the value, e.g. `#####-----`
+    fn gauge(&self, min: f32, max: f32) -> String {
+        let cols = self.size.cols.min(GAUGE_COLS);
+        let filled = (cols as f32 * self.part(min, max)).round() as usize;
+        format!("{}{}", "#".repeat(filled), "-".repeat(cols - filled))
+    }
+
+    /// The part of the range up to the value, from 0 to 1
+    fn part(&self, min: f32, max: f32) -> f32 {
+        match max > min {
+            true => ((self.value - min) / (max - min)).clamp(0.0, 1.0),
+            false => 0.0,
+        }
+    }
+}
+
+impl PageBaseInterface for ValuePage<'_> {
+    fn title(&self) -> &str {
+        &self.basic.title
+    }
+
+    /// The value last read without its unit, e.g. `60.0`
+    fn short_text(&self) -> &str {
+        &self.short
+    }
+
+    /// The value last read, once the page was shown
+    fn preview(&self) -> Option<&str> {
+        match self.short.is_empty() {
+            true => None,
+            false => Some(&self.short),
+        }
+    }
+
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.size = DisplaySize::from_info(info);
+    }
+
+    /// Read the value
+    fn update<'b>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.value = (self.provider)();
+        let text = self
+            .units
+            .format_with(self.quantity, self.value, self.format);
+        let suffix = self.units.suffix(self.quantity);
+        self.short = String::from(text.trim_end_matches(suffix).trim_end());
+        Ok(PageNavigation::Update)
+    }
+}
+
+impl PageInteractionInterface for ValuePage<'_> {}
+
+/// The value below the title bar, the gauge as bar below the value
+#[cfg(feature = "graphics")]
+impl<G: DrawTarget, C> PageInterface<GraphicsDisplay<G>, C> for ValuePage<'_> {
+    fn display(&self, display_driver: &mut GraphicsDisplay<G>) {
+        let theme = *display_driver.theme();
+        let lines = self.lines();
+        display_driver.update(self.title(), &lines[0]);
+        let (min, max) = match self.range {
+            Some(range) => range,
+            None => return,
+        };
+        let line_height = theme.font.character_size.height;
+        let top = (theme.title_font.character_size.height + line_height + 1) as i32;
+        let width = display_driver.target().bounding_box().size.width;
+        let outline = Rectangle::new(Point::new(0, top), Size::new(width, line_height - 2));
+        let target = display_driver.target_mut();
+        let _ = outline
+            .into_styled(PrimitiveStyle::with_stroke(theme.foreground, 1))
+            .draw(target);
+        let color = match (min..=max).contains(&self.value) {
+            true => theme.gauge,
+            false => theme.alert,
+        };
+        let filled = (width.saturating_sub(2) as f32 * self.part(min, max)).round() as u32;
+        let _ = Rectangle::new(
+            Point::new(1, top + 1),
+            Size::new(filled, line_height.saturating_sub(4)),
+        )
+        .into_styled(PrimitiveStyle::with_fill(color))
+        .draw(target);
+    }
+}
+
+#[cfg(test)]
+mod tests;
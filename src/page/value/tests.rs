@@ -0,0 +1,102 @@
+use super::*;
+use crate::{TemperatureUnit, UnitSystem};
+use std::cell::Cell;
+
+#[test]
+fn value_follows_units() {
+    static UNITS: Units = Units::new();
+    let pressure = Cell::new(1.5);
+    let mut sut = ValuePage::new(
+        BasicPage::new("Pressure", None),
+        Quantity::Pressure,
+        &UNITS,
+        Box::new(|| pressure.get()),
+    )
+    .with_decimals(2);
+    sut.update(None).unwrap();
+    assert_eq!(sut.value(), 1.5);
+    assert_eq!(sut.lines(), vec!["1.50 bar"]);
+    assert_eq!(sut.short_text(), "1.50");
+    UNITS.set_system(UnitSystem::Imperial);
+    pressure.set(2.0);
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["29.01 psi"]);
+    assert_eq!(sut.short_text(), "29.01");
+    // the temperature unit does not matter
+    UNITS.set_temperature(TemperatureUnit::Fahrenheit);
+    assert_eq!(sut.lines(), vec!["29.01 psi"]);
+}
+
+#[test]
+fn gauge_fits_display() {
+    static UNITS: Units = Units::new();
+    let temperature = Cell::new(-10.0);
+    let mut sut = ValuePage::new(
+        BasicPage::new("Outside", None),
+        Quantity::Temperature,
+        &UNITS,
+        Box::new(|| temperature.get()),
+    )
+    .with_decimals(0)
+    .with_range(0.0, 40.0);
+    sut.set_display_info(&DisplaySize { cols: 8, rows: 2 });
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["-10 °C", "--------"]);
+    temperature.set(10.0);
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["10 °C", "##------"]);
+    temperature.set(50.0);
+    sut.update(None).unwrap();
+    UNITS.set_temperature(TemperatureUnit::Fahrenheit);
+    assert_eq!(sut.lines(), vec!["122 °F", "########"]);
+}
+
+#[test]
+fn align_value() {
+    static UNITS: Units = Units::new();
+    let mut sut = ValuePage::new(
+        BasicPage::new("Tank", None),
+        Quantity::Volume,
+        &UNITS,
+        Box::new(|| 1234.5),
+    )
+    .with_format(NumberFormat::new().with_width(8).with_separator(','));
+    sut.update(None).unwrap();
+    assert_eq!(sut.lines(), vec!["   1,235 l"]);
+}
+
+#[cfg(feature = "graphics")]
+#[test]
+fn draw_gauge_in_color() {
+    use crate::graphics::{GraphicsDisplay, Theme};
+    use crate::PageInterface;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::{Rgb565, RgbColor};
+
+    static UNITS: Units = Units::new();
+    let temperature = Cell::new(10.0);
+    let mut sut = ValuePage::new(
+        BasicPage::new("Boiler", None),
+        Quantity::Temperature,
+        &UNITS,
+        Box::new(|| temperature.get()),
+    )
+    .with_range(0.0, 40.0);
+    sut.update(None).unwrap();
+    let mut target = MockDisplay::new();
+    target.set_allow_overdraw(true);
+    let mut display = GraphicsDisplay::new(target, Theme::<Rgb565>::dark());
+    PageInterface::<_, ()>::display(&sut, &mut display);
+    // the bar is below the title bar and the value, each 10 pixels high
+    let pixel = |display: &GraphicsDisplay<MockDisplay<Rgb565>>, x| {
+        display.target().get_pixel(Point::new(x, 24))
+    };
+    assert_eq!(pixel(&display, 0), Some(Rgb565::WHITE));
+    assert_eq!(pixel(&display, 16), Some(Rgb565::GREEN));
+    assert_eq!(pixel(&display, 17), Some(Rgb565::BLACK));
+
+    temperature.set(50.0);
+    sut.update(None).unwrap();
+    PageInterface::<_, ()>::display(&sut, &mut display);
+    assert_eq!(pixel(&display, 62), Some(Rgb565::RED));
+}
@@ -0,0 +1,200 @@
+use super::super::{
+    setting::Setting, Feedback, Interaction, PageBaseInterface, PageError,
+    PageInteractionInterface, PageNavigation, PageResult, Translations,
+};
+use super::basic::BasicPage;
+use super::enter_string::EnterStringPage;
+
+use std::borrow::Cow;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+/// One page editing many settings - bound to a setting when navigated to
+///
+/// Instead of one `EnterStringPage` per setting, a single editor page is
+/// registered for all settings of a type. It is navigated to by
+/// `PageNavigation::ToWith(editor, n)` and edits the n-th setting, counted
+/// from zero in the order of `with_setting()` calls. Only the setting edited
+/// takes RAM for the text entered; it is dropped when the page is left.
+///
+/// Editing is the same as with the `EnterStringPage`. A page that is not
+/// bound to a setting, e.g. entered without parameter, shows its own title and
+/// navigates up on any interaction.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{
+///         page::{BasicPage, GenericValueEditorPage}, CellSetting, PageBaseInterface,
+///     };
+///
+///     let volume: CellSetting<u8> = Default::default();
+///     let brightness: CellSetting<u8> = Default::default();
+///     let mut editor =
+///         GenericValueEditorPage::new(BasicPage::new("Edit", None), "0123456789", None, Some("Ok"))
+///             .with_setting("Volume", &volume)
+///             .with_setting("Brightness", &brightness);
+///     // what `PageNavigation::ToWith(editor_id, 1)` does
+///     editor.on_enter(Some(1), None);
+///     assert_eq!(editor.title(), "Brightness");
+/// ```
+pub struct GenericValueEditorPage<'a, T> {
+    pub basic: BasicPage,
+    allowed_characters: &'static str,
+    back: Option<&'static str>,
+    up: Option<&'static str>,
+    translations: Option<&'static Translations>,
+    settings: Vec<(Cow<'static, str>, &'a dyn Setting<Item = T>)>,
+    editor: Option<EnterStringPage<'a, T>>,
+}
+
+impl<'a, T: Copy + FromStr + Display + 'static> GenericValueEditorPage<'a, T>
+where
+    <T as FromStr>::Err: Debug,
+{
+    /// Arguments
+    ///
+    /// * `basic`: - The title shown while no setting is bound
+    /// * `allowed_characters`: - The characters the values are composed of
+    /// * `back`: - The action entry to remove the last character, if any
+    /// * `up`: - The action entry to commit the value, if any
+    pub fn new(
+        basic: BasicPage,
+        allowed_characters: &'static str,
+        back: Option<&'static str>,
+        up: Option<&'static str>,
+    ) -> Self {
+        GenericValueEditorPage {
+            basic,
+            allowed_characters,
+            back,
+            up,
+            translations: None,
+            settings: Vec::new(),
+            editor: None,
+        }
+    }
+
+    /// Add a setting edited with the parameter of its position
+    ///
+    /// Arguments
+    ///
+    /// * `title`: - The title shown while the setting is edited
+    /// * `setting`: - The setting edited
+    pub fn with_setting(
+        mut self,
+        title: impl Into<Cow<'static, str>>,
+        setting: &'a dyn Setting<Item = T>,
+    ) -> Self {
+        self.settings.push((title.into(), setting));
+        self
+    }
+
+    /// Show the back and the finish action in the active language
+    pub fn with_translations(mut self, translations: &'static Translations) -> Self {
+        self.translations = Some(translations);
+        self
+    }
+
+    /// The editor of the bound setting - none if no setting is bound
+    pub fn editor(&self) -> Option<&EnterStringPage<'a, T>> {
+        self.editor.as_ref()
+    }
+
+    /// Bind the n-th setting
+    fn bind(&mut self, index: usize) {
+        self.editor = self.settings.get(index).map(|(title, setting)| {
+            let basic = BasicPage::new(title.clone(), None);
+            let editor =
+                EnterStringPage::new(basic, self.allowed_characters, self.back, self.up, *setting);
+            match self.translations {
+                Some(translations) => editor.with_translations(translations),
+                None => editor,
+            }
+        });
+    }
+}
+
+impl<T: Copy + FromStr + Display + 'static> PageBaseInterface for GenericValueEditorPage<'_, T>
+where
+    <T as FromStr>::Err: Debug,
+{
+    fn update<'b>(
+        &mut self,
+        title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+    ) -> Result<PageNavigation, PageError> {
+        match &mut self.editor {
+            Some(editor) => editor.update(title_of_subpages),
+            None => Ok(PageNavigation::Update),
+        }
+    }
+
+    fn title(&self) -> &str {
+        match &self.editor {
+            Some(editor) => editor.title(),
+            None => &self.basic.title,
+        }
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    /// Bind the setting of the parameter - keep the setting bound without one
+    fn on_enter(&mut self, param: Option<usize>, _result: Option<&PageResult>) {
+        if let Some(index) = param {
+            self.bind(index);
+        }
+    }
+
+    /// Drop the editor to free its memory
+    fn on_leave(&mut self) {
+        self.editor = None;
+    }
+
+    fn can_leave(&self) -> Result<(), &str> {
+        match &self.editor {
+            Some(editor) => editor.can_leave(),
+            None => Ok(()),
+        }
+    }
+
+    fn reload(&mut self) {
+        if let Some(editor) = &mut self.editor {
+            editor.reload();
+        }
+    }
+
+    fn take_result(&mut self) -> Option<PageResult> {
+        self.editor.as_mut().and_then(|editor| editor.take_result())
+    }
+
+    fn take_feedback(&mut self) -> Option<Feedback> {
+        self.editor
+            .as_mut()
+            .and_then(|editor| editor.take_feedback())
+    }
+}
+
+impl<T: Copy + FromStr + Display + 'static> PageInteractionInterface
+    for GenericValueEditorPage<'_, T>
+where
+    <T as FromStr>::Err: Debug,
+{
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match &mut self.editor {
+            Some(editor) => editor.dispatch(interaction),
+            None => PageNavigation::Up,
+        }
+    }
+
+    fn dispatch_char(&mut self, c: char) -> PageNavigation {
+        match &mut self.editor {
+            Some(editor) => editor.dispatch_char(c),
+            None => PageNavigation::Update,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
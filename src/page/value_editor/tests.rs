@@ -0,0 +1,47 @@
+use super::super::super::CellSetting;
+use super::*;
+
+#[test]
+fn bind_setting_of_parameter() {
+    let volume: CellSetting<u8> = Default::default();
+    let brightness: CellSetting<u8> = Default::default();
+    brightness.set(7);
+    let mut sut =
+        GenericValueEditorPage::new(BasicPage::new("Edit", None), "0123456789", None, None)
+            .with_setting("Volume", &volume)
+            .with_setting("Brightness", &brightness);
+    assert_eq!(sut.title(), "Edit");
+    assert!(sut.editor().is_none());
+    assert_eq!(sut.dispatch(Interaction::Next), PageNavigation::Up);
+
+    sut.on_enter(Some(1), None);
+    assert_eq!(sut.title(), "Brightness");
+    assert_eq!(sut.editor().unwrap().buffer, "7");
+    // entered again without parameter, e.g. from a sub page
+    sut.on_enter(None, None);
+    assert_eq!(sut.title(), "Brightness");
+
+    sut.on_enter(Some(2), None);
+    assert_eq!(sut.title(), "Edit");
+}
+
+#[test]
+fn edit_the_bound_setting() {
+    let volume: CellSetting<u8> = Default::default();
+    let brightness: CellSetting<u8> = Default::default();
+    let mut sut =
+        GenericValueEditorPage::new(BasicPage::new("Edit", None), "0123456789", None, None)
+            .with_setting("Volume", &volume)
+            .with_setting("Brightness", &brightness);
+    sut.on_enter(Some(0), None);
+    sut.dispatch(Interaction::Back);
+    sut.dispatch_char('4');
+    assert_eq!(sut.can_leave(), Err("Not saved"));
+    assert_eq!(sut.dispatch(Interaction::Home), PageNavigation::Up);
+    assert_eq!(sut.take_feedback(), Some(Feedback::Confirm));
+    assert_eq!(sut.take_result().unwrap().get::<u8>(), Some(&4));
+    sut.on_leave();
+    assert!(sut.editor().is_none());
+    assert_eq!(volume.get(), 4);
+    assert_eq!(brightness.get(), 0);
+}
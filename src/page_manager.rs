@@ -1,5 +1,7 @@
 use super::*;
-use core::mem;
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
 
 /// The PageManager is responsible for switching among pages while
 /// pages do not know about other pages.
@@ -9,6 +11,11 @@ use core::mem;
 /// Startup and Shutdown pages are purely information pages and not
 /// activated only by SystemStartup and SystemShutdown events.
 ///
+/// The PageManager is not `Send`, since pages may share data with the
+/// application via `Rc` or `Cell`. It is owned and driven by a single task.
+/// Inputs detected in interrupt service routines or other tasks are
+/// handed over by an `InteractionQueue`.
+///
 /// h2. Example
 ///
 /// ```ignore
@@ -40,16 +47,19 @@ use core::mem;
 ///      }
 /// }
 /// ```
-
+//
 // h2. Implementation Note
 //
 // There is only one page active at a time, that dispatches events
-// (stored in page variable). other pages are activate by updating links
-// (in respective directions).
+// (its index is stored in active variable). All pages are stored in
+// registration order, i.e. the index of a page is its id. Other pages are
+// activated by following links (indices in respective directions); pages are
+// never moved by navigation.
 //
 // * Tree structures of pages are modeled by left, right, up and down links.
-// * Tree root is where both up and left links are empty.
-// * From the active page, all other pages can be navigated to
+// * Left links point to the next page of a level, right links to the previous one.
+// * Up links point to the parent page, down links to the first sub page.
+// * The home page (index 0) is the first page of the top level.
 //
 // h3. Example Structure
 //
@@ -64,34 +74,360 @@ use core::mem;
 // * `b, c` - are pages on the same level like `a` reachable via right link of `a`
 // * `d, e, f`- are sub-pages of `a` reachable via down link of `a`
 //
-pub struct PageManager<'a, D> {
+pub struct PageManager<'a, D, C = ()> {
     display: D,
-    page: Box<dyn PageInterface<D> + 'a>,
-    left: Link<Box<dyn PageInterface<D> + 'a>>,
-    right: Link<Box<dyn PageInterface<D> + 'a>>,
-    up: Link<Box<dyn PageInterface<D> + 'a>>,
-    down: Link<Box<dyn PageInterface<D> + 'a>>,
-    startup: Option<Box<dyn PageInterface<D> + 'a>>,
-    shutdown: Option<Box<dyn PageInterface<D> + 'a>>,
+    pages: Vec<Node<Box<dyn PageInterface<D, C> + 'a>>>,
+    active: usize,
+    modes: Vec<Mode>,
+    mode: usize,
+    startup: Option<Box<dyn PageInterface<D, C> + 'a>>,
+    shutdown: Option<Box<dyn PageInterface<D, C> + 'a>>,
     state: PageManagerState,
+    top_level_policy: NavigationPolicy,
+    sub_level_policy: NavigationPolicy,
+    bounced: Option<PageNavigation>,
+    changed: bool,
+    context: C,
+    display_size: Option<DisplaySize>,
+    version: usize,
+    subpages: Vec<SubPage>,
+    subpages_of: Option<(PageId, usize)>,
+    siblings: (Option<String>, Option<String>),
+    siblings_of: Option<(PageId, usize, Option<PageNavigation>)>,
+    quiet_time: Option<u32>,
+    clock: Option<Box<dyn FnMut() -> u32 + 'a>>,
+    last_event: Option<(InputEvent, u32)>,
+    feedback: Option<Box<dyn FeedbackSink + 'a>>,
+    indicator: Option<Box<dyn IndicatorSink + 'a>>,
+    indication: Option<Indication>,
+    audio: Option<Box<dyn AudioRenderer + 'a>>,
+    announced: Option<(PageId, Option<usize>)>,
+    auto_cycle: Option<AutoCycle>,
+    shortcuts: Vec<Box<dyn FnMut(InputEvent) -> Option<ChordEvent> + 'a>>,
+    middleware: Vec<Box<dyn NavigationMiddleware + 'a>>,
+    access_level: AccessLevel,
+    usage: Option<Box<dyn UsageObserver + 'a>>,
+    entered_at: Option<u32>,
+    heartbeat: Option<Box<dyn Heartbeat + 'a>>,
+    maintenance: Option<(u32, Box<dyn DisplayMaintenance<D> + 'a>)>,
+    last_maintenance: Option<u32>,
+    shutdown_tasks: Vec<(Box<dyn ShutdownTask + 'a>, bool)>,
+    shutdown_expired: bool,
+    batching: bool,
+    frame_interval: Option<u32>,
+    last_frame: Option<u32>,
+    transition_frames: u8,
+    transition: Option<(Transition, u8)>,
+    show_transition: Option<fn(&mut D, Option<(Transition, u8)>)>,
+    full_refresh_interval: u32,
+    page_changes: u32,
+    shown: Option<Shown>,
+    show_refresh: Option<fn(&mut D, Refresh)>,
+    vetoed: Option<PageNavigation>,
+    result: Option<PageResult>,
+    alarms: Option<Alarms<'a>>,
+    fired: VecDeque<AlarmId>,
+}
+
+/// A sub page of the active page as passed to its update
+struct SubPage {
+    title: String,
+    id: PageId,
+    hidden: bool,
+    disabled: bool,
+    preview: Option<String>,
+}
+
+/// The alarms of the application raised by the page manager
+struct Alarms<'a> {
+    scheduler: &'a RefCell<AlarmScheduler>,
+    time: Box<dyn TimeSource + 'a>,
+    page: Option<PageId>,
+}
+
+/// Rotation through the top level pages while there is no user interaction
+struct AutoCycle {
+    interval: u32,
+    grace: u32,
+    idle: u32,
+    interacted: bool,
+}
+
+/// The active page of the page tree as reported by `PageManager::active()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActivePage<'b> {
+    /// The title of the active page
+    pub title: &'b str,
+    /// The abbreviated representation of the active page, see `PageBaseInterface::short_text()`
+    pub short_text: &'b str,
+    /// The id of the active page
+    pub id: PageId,
+    /// The last dispatched navigation activated another page
+    pub changed: bool,
+}
+
+/// Behavior when navigating beyond the last page of a level
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeBehavior {
+    /// Continue with the page at the other end of the level.
+    Wrap,
+    /// Stay at the last page.
+    Clamp,
+    /// Reverse the direction, i.e. go back page by page until the other end is reached.
+    Bounce,
+}
+
+/// Edge behavior for left and right navigation on a level of pages
+///
+/// The default is to wrap around when navigating left and to stop at the
+/// edge when navigating right.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NavigationPolicy {
+    pub left: EdgeBehavior,
+    pub right: EdgeBehavior,
+}
+
+impl NavigationPolicy {
+    pub fn new(left: EdgeBehavior, right: EdgeBehavior) -> Self {
+        NavigationPolicy { left, right }
+    }
+}
+
+impl Default for NavigationPolicy {
+    fn default() -> Self {
+        NavigationPolicy::new(EdgeBehavior::Wrap, EdgeBehavior::Clamp)
+    }
+}
+
+/// The access level required for a page or granted to the user
+///
+/// Levels are ordered, i.e. the service level grants access to all pages.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AccessLevel {
+    /// Everybody using the device
+    #[default]
+    User,
+    /// The installer commissioning the device
+    Installer,
+    /// The service technician
+    Service,
+}
+
+/// Observe or rewrite navigations before they are executed
+///
+/// Middleware registered by `PageManager::register_middleware()` sees every
+/// navigation dispatched, e.g. to log or count page changes, to deny pages
+/// by access rights, or to veto leaving a page while a process runs.
+///
+/// Closures taking the navigation and the active page are middleware.
+pub trait NavigationMiddleware {
+    /// Return the navigation to execute
+    ///
+    /// Arguments
+    ///
+    /// * `navigation`: - The navigation requested, possibly rewritten by other middleware
+    /// * `active`: - The active page
+    ///
+    /// Returns the navigation unchanged to observe it only, another navigation
+    /// to rewrite it, or `PageNavigation::Update` to veto it.
+    fn navigate(&mut self, navigation: PageNavigation, active: ActivePage<'_>) -> PageNavigation;
 }
 
-type Link<T> = Option<Box<Node<T>>>;
+impl<F> NavigationMiddleware for F
+where
+    F: FnMut(PageNavigation, ActivePage<'_>) -> PageNavigation,
+{
+    fn navigate(&mut self, navigation: PageNavigation, active: ActivePage<'_>) -> PageNavigation {
+        self(navigation, active)
+    }
+}
 
+/// Observe which pages users actually visit
+///
+/// The observer registered by `PageManager::set_usage_observer()` is told
+/// about every change of the active page of the page tree, e.g. to count
+/// page views or to measure how long pages are looked at.
+pub trait UsageObserver {
+    /// A page got activated - default is to ignore it
+    ///
+    /// Arguments
+    ///
+    /// * `id`: - The id of the page
+    /// * `title`: - The title of the page
+    fn page_entered(&mut self, _id: PageId, _title: &str) {}
+
+    /// A page got deactivated - default is to ignore it
+    ///
+    /// Arguments
+    ///
+    /// * `id`: - The id of the page
+    /// * `title`: - The title of the page
+    /// * `duration`: - Milliseconds the page was active, if a clock is set by `set_clock()`
+    fn page_left(&mut self, _id: PageId, _title: &str, _duration: Option<u32>) {}
+}
+
+/// Sign of life of the HMI, e.g. to feed a watchdog
+///
+/// The heartbeat registered by `PageManager::set_heartbeat()` beats with
+/// every successful `dispatch()` (including the dispatch of interactions)
+/// and `update()`, i.e. only while the HMI loop is alive. A failing dispatch
+/// or update does not beat.
+///
+/// Closures are heartbeats.
+pub trait Heartbeat {
+    fn beat(&mut self);
+}
+
+impl<F: FnMut()> Heartbeat for F {
+    fn beat(&mut self) {
+        self()
+    }
+}
+
+/// Periodic maintenance of the display, e.g. toggling the VCOM of a Sharp memory display
+///
+/// The maintenance registered by `PageManager::set_maintenance()` runs with
+/// the periodic updates, i.e. `dispatch(PageNavigation::Update)`, also while
+/// nothing is rendered.
+///
+/// Closures taking the display are maintenances.
+pub trait DisplayMaintenance<D> {
+    /// Arguments
+    ///
+    /// * `display`: - The display of the page manager
+    fn maintain(&mut self, display: &mut D);
+}
+
+impl<D, F: FnMut(&mut D)> DisplayMaintenance<D> for F {
+    fn maintain(&mut self, display: &mut D) {
+        self(display)
+    }
+}
+
+/// A task to complete before the HMI is shut down, e.g. to commit settings or stop a motor
+///
+/// Tasks registered by `PageManager::register_shutdown_task()` are polled
+/// with every dispatch of `PageNavigation::SystemStop` until they report
+/// completion. The shutdown page stays active until all tasks are done, even
+/// beyond its lifetime.
+///
+/// Closures returning whether they are done are shutdown tasks.
+pub trait ShutdownTask {
+    /// Continue the task - returns true once it is done
+    fn poll(&mut self) -> bool;
+}
+
+impl<F: FnMut() -> bool> ShutdownTask for F {
+    fn poll(&mut self) -> bool {
+        self()
+    }
+}
+
+/// Index of the home page, i.e. the first page of the top level
+const HOME: usize = 0;
+
+/// Identifier of a mode, i.e. a page tree of its own, see `PageManager::add_mode()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ModeId(usize);
+
+impl ModeId {
+    /// The mode of the page tree the PageManager was constructed with
+    pub const DEFAULT: ModeId = ModeId(0);
+}
+
+/// The page tree of a mode given by the indices of its root and its home page
+#[derive(Clone, Copy)]
+struct Mode {
+    root: usize,
+    home: usize,
+}
+
+impl Mode {
+    fn new(root: usize) -> Self {
+        Mode { root, home: root }
+    }
+}
+
+/// A page of the page tree linked to its neighbours by their indices
 struct Node<T> {
     page: T,
-    left: Link<T>,
-    right: Link<T>,
-    down: Link<T>,
-    up: Link<T>,
+    left: Option<usize>,
+    right: Option<usize>,
+    down: Option<usize>,
+    up: Option<usize>,
+    access: AccessLevel,
+    hidden: bool,
+}
+
+impl<T> Node<T> {
+    fn new(page: T, up: Option<usize>) -> Self {
+        Node {
+            page,
+            left: None,
+            right: None,
+            down: None,
+            up,
+            access: AccessLevel::User,
+            hidden: false,
+        }
+    }
 }
 
-enum PageManagerState {
+/// A page of the page tree as yielded by `PageManager::tree_iter()`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageTreeItem<'b> {
+    /// The title of the page
+    pub title: &'b str,
+    /// The level of the page in the tree; the top level has depth 0
+    pub depth: usize,
+    /// The id of the page
+    pub id: PageId,
+    /// The page is a menu page
+    pub is_menu: bool,
+    /// The page is hidden, see `PageManager::set_page_hidden()`
+    pub hidden: bool,
+    /// The access level the page is restricted to, see `PageManager::set_page_access()`
+    pub access: AccessLevel,
+}
+
+impl PageTreeItem<'_> {
+    /// The page is listed at the access level, i.e. navigated to by the user
+    pub(crate) fn is_listed(&self, access_level: AccessLevel) -> bool {
+        !self.hidden && self.access <= access_level
+    }
+}
+
+/// Key of the HMI state in the settings store
+const STATE_KEY: &str = "hmi.state";
+
+pub(crate) enum PageManagerState {
     Startup,
     Operational,
     Shutdown,
 }
 
+/// Dispatch an input to a page - interactions are dispatched with the context
+fn input_to_page<D, C>(
+    page: &mut (dyn PageInterface<D, C> + '_),
+    context: &mut C,
+    input: InputEvent,
+) -> PageNavigation {
+    match input {
+        InputEvent::Primary(interaction) => page.dispatch_with_context(context, interaction),
+        InputEvent::Char(c) => page.dispatch_char(c),
+        InputEvent::Custom(code) => page.dispatch_custom(code),
+    }
+}
+
+/// What a frame shows, to tell page changes from updates of the page shown
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Shown {
+    Startup,
+    Page(usize),
+    Shutdown,
+}
+
 impl<'a, D> PageManager<'a, D> {
     /// PageManager Constructor
     ///
@@ -101,65 +437,1208 @@ impl<'a, D> PageManager<'a, D> {
     ///   The display data structure and the logic attached makes the rendered
     ///   output appear on some output facility viewable by a human.
     /// * `home`: The "home" page. There must be at least one page. Other pages
-    ///    are added by register_* calls.
+    ///   are added by register_* calls.
     pub fn new(display: D, home: Box<dyn PageInterface<D> + 'a>) -> Self {
-        PageManager::<D> {
+        PageManager::with_context(display, home, ())
+    }
+}
+
+impl<'a, D, C> PageManager<'a, D, C> {
+    /// PageManager Constructor with an application context
+    ///
+    /// The context is handed to all pages on update and interaction dispatch,
+    /// i.e. pages implementing `update_with_context()` or `dispatch_with_context()`
+    /// can access sensors, actuators or application data through it.
+    ///
+    /// Arguments
+    ///
+    /// * `display`: The display data structure where all output is rendered to
+    /// * `home`: The "home" page.
+    /// * `context`: The application context shared by all pages
+    pub fn with_context(display: D, home: Box<dyn PageInterface<D, C> + 'a>, context: C) -> Self {
+        PageManager {
             display,
-            page: home,
-            left: None,
-            right: None,
-            up: None,
-            down: None,
+            pages: vec![Node::new(home, None)],
+            active: HOME,
+            modes: vec![Mode::new(HOME)],
+            mode: 0,
             startup: None,
             shutdown: None,
             state: PageManagerState::Startup,
+            top_level_policy: NavigationPolicy::default(),
+            sub_level_policy: NavigationPolicy::default(),
+            bounced: None,
+            changed: false,
+            context,
+            display_size: None,
+            version: 0,
+            subpages: Vec::new(),
+            subpages_of: None,
+            siblings: (None, None),
+            siblings_of: None,
+            quiet_time: None,
+            clock: None,
+            last_event: None,
+            feedback: None,
+            indicator: None,
+            indication: None,
+            audio: None,
+            announced: None,
+            auto_cycle: None,
+            shortcuts: Vec::new(),
+            middleware: Vec::new(),
+            access_level: AccessLevel::User,
+            usage: None,
+            entered_at: None,
+            heartbeat: None,
+            maintenance: None,
+            last_maintenance: None,
+            shutdown_tasks: Vec::new(),
+            shutdown_expired: false,
+            batching: false,
+            frame_interval: None,
+            last_frame: None,
+            transition_frames: 0,
+            transition: None,
+            show_transition: None,
+            full_refresh_interval: 1,
+            page_changes: 0,
+            shown: None,
+            show_refresh: None,
+            vetoed: None,
+            result: None,
+            alarms: None,
+            fired: VecDeque::new(),
         }
     }
 
+    /// The application context shared by all pages
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// The application context shared by all pages - mutable
+    pub fn context_mut(&mut self) -> &mut C {
+        &mut self.context
+    }
+
+    /// The display all output is rendered to
+    pub fn display(&self) -> &D {
+        &self.display
+    }
+
+    /// The display all output is rendered to - mutable
+    pub fn display_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Let pages adapt their content to the display
+    ///
+    /// The display capabilities are passed to every page before it gets updated.
+    ///
+    /// Arguments
+    ///
+    /// * `info`: - The capabilities of the display, e.g. the display itself
+    pub fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.display_size = Some(DisplaySize::from_info(info));
+    }
+
     /// Update the content of the active page on the display
     ///
     /// Potentially initiate a page change before displaying, since the
     /// update responsibility is the responsibility of the specific active page
     pub fn update(&mut self) -> Result<(), PageError> {
-        // menu pages need submenu titles
-        let iter = Box::new(SubPageIterator {
-            left: self.down.as_deref(),
+        if let Err(e) = self.update_active() {
+            warn!(
+                "update failed at page {}",
+                self.pages[self.active].page.title()
+            );
+            return Err(e);
+        }
+        self.beat();
+        Ok(())
+    }
+
+    fn update_active(&mut self) -> Result<(), PageError> {
+        if let Some(size) = &self.display_size {
+            self.pages[self.active].page.set_display_info(size);
+        }
+        // menu pages need submenu titles - they are only collected again
+        // if another page is active or the page tree has changed
+        let subpages_of = Some((PageId(self.active), self.version));
+        if self.subpages_of != subpages_of {
+            self.subpages.clear();
+            let mut index = self.pages[self.active].down;
+            while let Some(i) = index {
+                let node = &self.pages[i];
+                self.subpages.push(SubPage {
+                    title: String::from(node.page.title()),
+                    id: PageId(i),
+                    hidden: node.hidden,
+                    disabled: node.access > self.access_level,
+                    preview: None,
+                });
+                index = node.left;
+            }
+            self.subpages_of = subpages_of;
+        }
+        let siblings_of = Some((PageId(self.active), self.version, self.bounced));
+        if self.siblings_of != siblings_of {
+            self.siblings = (
+                self.sibling(Self::navigate_right),
+                self.sibling(Self::navigate_left),
+            );
+            self.siblings_of = siblings_of;
+        }
+        self.pages[self.active].page.set_siblings(Siblings {
+            previous: self.siblings.0.as_deref(),
+            next: self.siblings.1.as_deref(),
+        });
+        // previews change with every update, e.g. the value of a setting
+        for subpage in self.subpages.iter_mut() {
+            match (
+                self.pages[subpage.id.0].page.preview(),
+                &mut subpage.preview,
+            ) {
+                (None, preview) => *preview = None,
+                (Some(text), Some(preview)) => {
+                    preview.clear();
+                    preview.push_str(text);
+                }
+                (Some(text), preview) => *preview = Some(String::from(text)),
+            }
+        }
+        let subpages = self.subpages.iter().map(|subpage| SubPageItem {
+            title: &subpage.title,
+            id: subpage.id,
+            hidden: subpage.hidden,
+            disabled: subpage.disabled,
+            preview: subpage.preview.as_deref(),
         });
-        let navigation = self.page.update(Some(Box::new(iter.map(|p| p.title()))))?;
+        let navigation = self.pages[self.active]
+            .page
+            .update_with_context(&mut self.context, Some(Box::new(subpages)))?;
 
         // in case the page requires another page to navigate this needs to be performed
         if navigation != PageNavigation::Update {
             self.dispatch(navigation)?;
         }
 
-        self.page.display(&mut self.display);
+        if self.frame_due() {
+            self.advance_transition();
+            self.hint_refresh(Shown::Page(self.active));
+            self.pages[self.active].page.display(&mut self.display);
+        }
+        // e.g. pages completing some work with the update
+        self.take_feedback_of(PageId(self.active));
         Ok(())
     }
 
-    /// Register a new page
+    /// The active page of the page tree
+    ///
+    /// Allows the application to react on page changes after a dispatch,
+    /// e.g. to log page views or enable outputs.
+    /// Startup and shutdown pages are not part of the page tree, i.e. the
+    /// page reported while they are shown is the page that will be active after
+    /// startup or was active before shutdown.
+    pub fn active(&self) -> ActivePage<'_> {
+        ActivePage {
+            title: self.pages[self.active].page.title(),
+            short_text: self.pages[self.active].page.short_text(),
+            id: PageId(self.active),
+            changed: self.changed,
+        }
+    }
+
+    /// The title of the active page of the page tree, see `active()`
+    pub fn active_title(&self) -> &str {
+        self.pages[self.active].page.title()
+    }
+
+    /// The id of the active page of the page tree, see `active()`
+    pub fn active_id(&self) -> PageId {
+        PageId(self.active)
+    }
+
+    /// The level of the active page in the tree; the top level has depth 0
+    pub fn active_depth(&self) -> usize {
+        let mut depth = 0;
+        let mut index = self.active;
+        while let Some(up) = self.pages[index].up {
+            depth += 1;
+            index = up;
+        }
+        depth
+    }
+
+    /// The active page of the page tree is a menu page
+    pub fn active_is_menu(&self) -> bool {
+        self.pages[self.active].page.is_menu()
+    }
+
+    /// Number of sub pages of the active page
+    ///
+    /// Hidden sub pages and sub pages of a higher access level are not
+    /// counted, i.e. it is the number of entries of the active menu page
+    /// without the back entry.
+    pub fn subpage_count(&self) -> usize {
+        self.sub_iter().count()
+    }
+
+    /// The sub page selected by the active menu page, counting from one
+    ///
+    /// Allows to show the position in the menu, e.g. `3/7`, with the
+    /// `subpage_count()`. None if the active page is no menu page or the
+    /// back entry of the menu is selected.
+    pub fn selected_subpage_index(&self) -> Option<usize> {
+        let page = &self.pages[self.active].page;
+        match page.is_menu() {
+            true => page
+                .selected()
+                .filter(|index| (1..=self.subpage_count()).contains(index)),
+            false => None,
+        }
+    }
+
+    /// Why the active page vetoed leaving it - none if it did not
+    ///
+    /// A page with uncommitted changes vetoes navigating away from it, see
+    /// `PageBaseInterface::can_leave()`. The navigation is blocked with error
+    /// feedback; the application shows the message, e.g. in a status line.
+    /// Repeating the navigation confirms leaving the page, discarding the
+    /// changes. Rotation by `set_auto_cycle()` never leaves such a page.
+    pub fn leave_veto(&self) -> Option<&str> {
+        self.vetoed?;
+        self.pages[self.active].page.can_leave().err()
+    }
+
+    /// The result the sub page left last returned to the active page
+    ///
+    /// Is kept until the next page change; the active page got it by
+    /// `PageBaseInterface::on_enter()` already. None if the active page was
+    /// not entered by navigating up from a sub page or the sub page returned
+    /// no result, see `PageBaseInterface::take_result()`.
+    pub fn result(&self) -> Option<&PageResult> {
+        self.result.as_ref()
+    }
+
+    /// Navigation path from the root level to the active page
+    ///
+    /// The iterator yields the titles of all pages up the hierarchy starting
+    /// at the top level page and ending with the title of the active page.
+    /// E.g. a header could render it as "Settings > Network > IP".
+    pub fn breadcrumb(&self) -> impl Iterator<Item = &str> + '_ {
+        self.path()
+            .into_iter()
+            .map(move |index| self.pages[index].page.title())
+    }
+
+    /// Iterate over all pages of the page tree
+    ///
+    /// The tree is walked depth first, i.e. every page is followed by its
+    /// sub pages before the next page on the same level is yielded.
+    /// Startup and shutdown pages are not part of the page tree. Only the
+    /// page tree of the active mode is iterated, see `add_mode()`.
+    pub fn tree_iter(&self) -> impl Iterator<Item = PageTreeItem<'_>> + '_ {
+        let mut items = Vec::new();
+        self.collect_list(&mut items, Some(self.modes[self.mode].root), 0);
+        items.into_iter()
+    }
+
+    /// Total number of pages registered
+    ///
+    /// Pages of all modes are counted, unlike by `tree_iter()` which walks
+    /// the page tree of the active mode only. Startup and shutdown pages are
+    /// not counted.
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Number of pages of the page tree of the active mode - found by their up and right links
+    #[cfg(any(test, feature = "testing"))]
+    pub(crate) fn mode_page_count(&self) -> usize {
+        (0..self.pages.len())
+            .filter(|index| self.mode_of(*index) == self.mode)
+            .count()
+    }
+
+    /// Collect the pages of a level starting at the given page and all pages below
+    fn collect_list<'b>(
+        &'b self,
+        items: &mut Vec<PageTreeItem<'b>>,
+        index: Option<usize>,
+        depth: usize,
+    ) {
+        let mut index = index;
+        while let Some(i) = index {
+            let node = &self.pages[i];
+            items.push(PageTreeItem {
+                title: node.page.title(),
+                depth,
+                id: PageId(i),
+                is_menu: node.page.is_menu(),
+                hidden: node.hidden,
+                access: node.access,
+            });
+            self.collect_list(items, node.down, depth + 1);
+            index = node.left;
+        }
+    }
+
+    /// Indices of the pages from the top level down to the active page
+    fn path(&self) -> Vec<usize> {
+        self.path_of(self.active)
+    }
+
+    /// Indices of the pages from the top level down to the page given
+    fn path_of(&self, index: usize) -> Vec<usize> {
+        let mut path = vec![index];
+        while let Some(up) = self.pages[path[path.len() - 1]].up {
+            path.push(up);
+        }
+        path.reverse();
+        path
+    }
+
+    /// Register a new page
+    ///
+    /// The page is registered in the "left" direction of the
+    /// active page. The registered page will be the new active page.
+    ///
+    /// Arguments
+    ///
+    /// * `page` - The page to be registered and activated.
+    ///
+    /// Returns
+    ///
+    /// * The id of the registered page.
+    pub fn register(&mut self, page: Box<dyn PageInterface<D, C> + 'a>) -> PageId {
+        let id = self.new_id();
+        let active = self.active;
+        let mut node = Node::new(page, self.pages[active].up);
+        node.left = self.pages[active].left;
+        node.right = Some(active);
+        if let Some(left) = node.left {
+            self.pages[left].right = Some(id.0);
+        }
+        self.pages[active].left = Some(id.0);
+        self.pages.push(node);
+        self.active = id.0;
+        id
+    }
+
+    /// Register a new sub page
+    ///
+    /// The page is registered in the "down" direction of the
+    /// active page. The registered page will be the new active page.
+    /// Sub pages registered before become sub pages of the registered page.
+    ///
+    /// Arguments
+    ///
+    /// * `page`: - The page to be registered and activated.
+    ///
+    /// Returns
+    ///
+    /// * The id of the registered page.
+    pub fn register_sub(&mut self, page: Box<dyn PageInterface<D, C> + 'a>) -> PageId {
+        let id = self.new_id();
+        let active = self.active;
+        let mut node = Node::new(page, Some(active));
+        node.down = self.pages[active].down;
+        let mut sub = node.down;
+        while let Some(i) = sub {
+            self.pages[i].up = Some(id.0);
+            sub = self.pages[i].left;
+        }
+        self.pages[active].down = Some(id.0);
+        self.pages.push(node);
+        self.active = id.0;
+        id
+    }
+
+    /// Add a mode, i.e. a page tree of its own, e.g. a setup mode next to the run mode
+    ///
+    /// Each mode has its own home page and top level; left, right, up and
+    /// home navigation stay within the page tree of the active mode. The page
+    /// tree the `PageManager` was constructed with is the mode
+    /// `ModeId::DEFAULT`. The home page of the mode added becomes the active
+    /// page, i.e. the pages registered next are part of the mode. The active
+    /// mode is not changed, see `switch_mode()`.
+    ///
+    /// Arguments
+    ///
+    /// * `home`: - The home page of the mode
+    ///
+    /// Returns
+    ///
+    /// * The id of the mode.
+    pub fn add_mode(&mut self, home: Box<dyn PageInterface<D, C> + 'a>) -> ModeId {
+        let id = self.new_id();
+        self.pages.push(Node::new(home, None));
+        self.modes.push(Mode::new(id.0));
+        self.active = id.0;
+        ModeId(self.modes.len() - 1)
+    }
+
+    /// Switch to another mode and activate its home page
+    ///
+    /// The page tree and the home page are switched at once, i.e. the
+    /// active page is never a page of another mode than the active one. The
+    /// home page is activated like by `PageNavigation::Home`, i.e. the
+    /// active page is able to veto leaving it.
+    ///
+    /// Arguments
+    ///
+    /// * `mode`: - The id of the mode as returned by `add_mode()`
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if there is no such mode or its home page did not get
+    ///   activated, e.g. by a veto; the active mode is unchanged in this case.
+    pub fn switch_mode(&mut self, mode: ModeId) -> Result<(), PageError> {
+        if mode.0 >= self.modes.len() {
+            return Err(PageError);
+        }
+        let previous = self.mode;
+        self.mode = mode.0;
+        let result = self.dispatch_rotating(PageNavigation::Home, false);
+        if self.mode_of(self.active) != self.mode {
+            self.mode = previous;
+            return Err(PageError);
+        }
+        result.map(|_| ())
+    }
+
+    /// The id of the active mode
+    pub fn mode(&self) -> ModeId {
+        ModeId(self.mode)
+    }
+
+    /// Index of the mode of the page tree a page is part of
+    fn mode_of(&self, index: usize) -> usize {
+        let mut root = self.path_of(index)[0];
+        while let Some(right) = self.pages[root].right {
+            root = right;
+        }
+        self.modes
+            .iter()
+            .position(|mode| mode.root == root)
+            .unwrap_or(0)
+    }
+
+    /// The page is the root of the page tree of a mode
+    fn is_root(&self, index: usize) -> bool {
+        self.modes.iter().any(|mode| mode.root == index)
+    }
+
+    fn new_id(&mut self) -> PageId {
+        let id = PageId(self.pages.len());
+        self.version += 1;
+        id
+    }
+
+    /// Collect the titles of the sub pages again with the next update
+    ///
+    /// The titles of the sub pages passed to the active page are collected
+    /// only once after a page got activated or registered. If titles of
+    /// registered pages change at runtime, the change shows up with the next
+    /// page activation or after calling this function.
+    pub fn invalidate_titles(&mut self) {
+        self.version += 1;
+    }
+
+    /// Set the behavior at the edges of a level for left and right navigation
+    ///
+    /// Arguments
+    ///
+    /// * `top_level`: - The policy for the top level pages.
+    /// * `sub_level`: - The policy for all levels of sub pages.
+    pub fn set_navigation_policy(
+        &mut self,
+        top_level: NavigationPolicy,
+        sub_level: NavigationPolicy,
+    ) {
+        self.top_level_policy = top_level;
+        self.sub_level_policy = sub_level;
+        self.bounced = None;
+        self.siblings_of = None;
+    }
+
+    /// Drop repeated interactions within a quiet time
+    ///
+    /// Noisy switches report one press as several interactions. An
+    /// interaction is dropped if the same interaction was dispatched less
+    /// than `quiet_time` milliseconds before, whatever input driver reported
+    /// it. Only interactions with a timestamp are filtered, i.e. dispatched by
+    /// `dispatch_event()` or by `dispatch_interaction()` with a clock set.
+    ///
+    /// Arguments
+    ///
+    /// * `quiet_time`: - Milliseconds to wait for the same interaction again
+    pub fn set_debounce(&mut self, quiet_time: u32) {
+        self.quiet_time = Some(quiet_time);
+        self.last_event = None;
+    }
+
+    /// Register a global shortcut handler
+    ///
+    /// Shortcut handlers see every input before the active page does, in the
+    /// order of registration, e.g. to always jump to the alarm list by a
+    /// dedicated button. A handler returning an event consumes the input: the
+    /// event is dispatched instead, i.e. the active page sees the interaction
+    /// of a `ChordEvent::Interaction` only. Startup and shutdown pages are not
+    /// affected by shortcuts.
+    ///
+    /// Arguments
+    ///
+    /// * `handler`: - Returns the event to dispatch instead of the input or `None`
+    pub fn register_shortcut(
+        &mut self,
+        handler: Box<dyn FnMut(InputEvent) -> Option<ChordEvent> + 'a>,
+    ) {
+        self.shortcuts.push(handler);
+    }
+
+    /// Restrict a page to an access level
+    ///
+    /// Pages requiring a higher access level than the current one are skipped
+    /// by navigation and hidden from menus, and so are their sub pages. The
+    /// home page is always accessible.
+    ///
+    /// Arguments
+    ///
+    /// * `id`: - The id of the page as returned by registration
+    /// * `level`: - The access level required
+    pub fn set_page_access(&mut self, id: PageId, level: AccessLevel) {
+        if !self.is_root(id.0) {
+            if let Some(node) = self.pages.get_mut(id.0) {
+                node.access = level;
+                self.version += 1;
+            }
+        }
+    }
+
+    /// Hide a page, e.g. a service or calibration page, from the user
+    ///
+    /// Hidden pages are skipped by left and right navigation and left out of
+    /// menus, but their sub pages are not. They are reached programmatically
+    /// only, by `navigate_to()`, `navigate_path()` or `PageNavigation::ToWith`,
+    /// e.g. by a global shortcut or a button combination. The home page is
+    /// never hidden.
+    ///
+    /// Arguments
+    ///
+    /// * `id`: - The id of the page as returned by registration
+    /// * `hidden`: - The page is hidden
+    pub fn set_page_hidden(&mut self, id: PageId, hidden: bool) {
+        if !self.is_root(id.0) {
+            if let Some(node) = self.pages.get_mut(id.0) {
+                node.hidden = hidden;
+                self.version += 1;
+            }
+        }
+    }
+
+    /// Replace the home page, e.g. by a run screen while a process is active
+    ///
+    /// `PageNavigation::Home` and the fallbacks to home, e.g. after startup
+    /// or if the active page is not accessible anymore, activate the page
+    /// given instead of the page the `PageManager` was constructed with. A
+    /// home page not accessible at the access level of the user falls back
+    /// to the page the `PageManager` was constructed with. The active page
+    /// is not changed. The home page of the mode the page is part of is
+    /// replaced, see `add_mode()`.
+    ///
+    /// Arguments
+    ///
+    /// * `id`: - The id of the page as returned by registration, `PageId::HOME` to
+    ///   return to the page the `PageManager` was constructed with
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if there is no such page
+    pub fn set_home(&mut self, id: PageId) -> Result<(), PageError> {
+        if id.0 >= self.pages.len() {
+            return Err(PageError);
+        }
+        let mode = self.mode_of(id.0);
+        self.modes[mode].home = id.0;
+        Ok(())
+    }
+
+    /// The id of the current home page of the active mode, see `set_home()`
+    pub fn home(&self) -> PageId {
+        PageId(self.modes[self.mode].home)
+    }
+
+    /// Set the access level of the user, e.g. back to user level after timeout
+    ///
+    /// Usually the level is raised by a page, e.g. the `PinPage`, see
+    /// `PageBaseInterface::take_access_level()`. If the active page is not
+    /// accessible anymore, the home page is activated by
+    /// `PageNavigation::Home`; the page left cannot veto it.
+    ///
+    /// Arguments
+    ///
+    /// * `level`: - The access level granted
+    pub fn set_access_level(&mut self, level: AccessLevel) {
+        self.access_level = level;
+        self.version += 1;
+        if !self.is_active_accessible() {
+            // the page left and the home page entered are told like by navigation
+            if self
+                .dispatch_navigation(PageNavigation::Home, false)
+                .is_err()
+            {
+                warn!(
+                    "home failed at page {}",
+                    self.pages[self.active].page.title()
+                );
+            }
+        }
+    }
+
+    /// The active page and the pages above it are accessible with the current access level
+    fn is_active_accessible(&self) -> bool {
+        self.path().iter().all(|index| self.is_accessible(*index))
+    }
+
+    /// The access level of the user
+    pub fn access_level(&self) -> AccessLevel {
+        self.access_level
+    }
+
+    /// Signal every successful dispatch and update, e.g. to feed a watchdog
+    ///
+    /// Arguments
+    ///
+    /// * `heartbeat`: - Beats while the HMI is alive, e.g. a closure feeding the watchdog
+    pub fn set_heartbeat(&mut self, heartbeat: Box<dyn Heartbeat + 'a>) {
+        self.heartbeat = Some(heartbeat);
+    }
+
+    fn beat(&mut self) {
+        if let Some(heartbeat) = &mut self.heartbeat {
+            heartbeat.beat();
+        }
+    }
+
+    /// Maintain the display periodically, e.g. toggle the VCOM of a Sharp memory display
+    ///
+    /// The maintenance runs with a periodic update if `interval` milliseconds
+    /// passed since it ran last, measured by the clock set by `set_clock()`.
+    /// Without a clock it runs with every periodic update.
+    ///
+    /// Arguments
+    ///
+    /// * `interval`: - Milliseconds between two maintenances, e.g. 1000 for VCOM at 1Hz
+    /// * `maintenance`: - Maintains the display, e.g. a closure toggling the VCOM pin
+    pub fn set_maintenance(
+        &mut self,
+        interval: u32,
+        maintenance: Box<dyn DisplayMaintenance<D> + 'a>,
+    ) {
+        self.maintenance = Some((interval, maintenance));
+        self.last_maintenance = None;
+    }
+
+    /// Run the maintenance of the display if due
+    fn maintain(&mut self) {
+        let (interval, maintenance) = match &mut self.maintenance {
+            Some(maintenance) => maintenance,
+            None => return,
+        };
+        if let Some(clock) = &mut self.clock {
+            let now = clock();
+            match self.last_maintenance {
+                Some(at) if now.wrapping_sub(at) < *interval => return,
+                _ => self.last_maintenance = Some(now),
+            }
+        }
+        maintenance.maintain(&mut self.display);
+    }
+
+    /// Observe the pages visited
+    ///
+    /// Without an observer set, page changes are not tracked at all.
+    ///
+    /// Arguments
+    ///
+    /// * `observer`: - Is told about every change of the active page
+    pub fn set_usage_observer(&mut self, observer: Box<dyn UsageObserver + 'a>) {
+        self.usage = Some(observer);
+        self.entered_at = self.clock.as_mut().map(|clock| clock());
+    }
+
+    /// Tell the usage observer about a change of the active page
+    fn observe_page_change(&mut self, origin: PageId) {
+        let usage = match &mut self.usage {
+            None => return,
+            Some(usage) => usage,
+        };
+        let now = self.clock.as_mut().map(|clock| clock());
+        let duration = match (now, self.entered_at) {
+            (Some(now), Some(at)) => Some(now.wrapping_sub(at)),
+            _ => None,
+        };
+        self.entered_at = now;
+        usage.page_left(origin, self.pages[origin.0].page.title(), duration);
+        usage.page_entered(PageId(self.active), self.pages[self.active].page.title());
+    }
+
+    /// Register a middleware observing or rewriting all navigations
+    ///
+    /// Middleware runs in the order of registration, each one getting the
+    /// navigation returned by the one before. Periodic updates are passed as
+    /// well, the rotation of the auto cycle as `PageNavigation::Left`.
+    ///
+    /// Arguments
+    ///
+    /// * `middleware`: - The middleware, e.g. a closure
+    pub fn register_middleware(&mut self, middleware: Box<dyn NavigationMiddleware + 'a>) {
+        self.middleware.push(middleware);
+    }
+
+    /// Pass the navigation through all middleware
+    fn apply_middleware(&mut self, navigation: PageNavigation) -> PageNavigation {
+        if self.middleware.is_empty() {
+            return navigation;
+        }
+        let active = ActivePage {
+            title: self.pages[self.active].page.title(),
+            short_text: self.pages[self.active].page.short_text(),
+            id: PageId(self.active),
+            changed: self.changed,
+        };
+        self.middleware
+            .iter_mut()
+            .fold(navigation, |navigation, m| m.navigate(navigation, active))
+    }
+
+    /// Rotate through the top level pages while the user does not interact
+    ///
+    /// Typical for status displays: the next top level page is activated
+    /// every `interval` updates, i.e. `dispatch(PageNavigation::Update)`
+    /// calls, wrapping around to the first page whatever the navigation
+    /// policy. Any interaction pauses the rotation for `grace` updates.
+    /// While a sub page is active there is no rotation.
+    ///
+    /// Arguments
+    ///
+    /// * `interval`: - Number of updates to show each top level page
+    /// * `grace`: - Number of updates to pause after an interaction
+    pub fn set_auto_cycle(&mut self, interval: u32, grace: u32) {
+        self.auto_cycle = Some(AutoCycle {
+            interval: interval.max(1),
+            grace: grace.max(1),
+            idle: 0,
+            interacted: false,
+        });
+    }
+
+    /// Stop rotating through the top level pages
+    pub fn clear_auto_cycle(&mut self) {
+        self.auto_cycle = None;
+    }
+
+    /// Count an update - returns whether to rotate to the next top level page
+    fn auto_cycle_due(&mut self) -> bool {
+        let rotating = matches!(self.state, PageManagerState::Operational)
+            && self.pages[self.active].up.is_none();
+        match &mut self.auto_cycle {
+            Some(cycle) if rotating => {
+                cycle.idle += 1;
+                let limit = match cycle.interacted {
+                    true => cycle.grace,
+                    false => cycle.interval,
+                };
+                if cycle.idle < limit {
+                    return false;
+                }
+                cycle.idle = 0;
+                cycle.interacted = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Pause the rotation after an interaction
+    fn pause_auto_cycle(&mut self) {
+        if let Some(cycle) = &mut self.auto_cycle {
+            cycle.idle = 0;
+            cycle.interacted = true;
+        }
+    }
+
+    /// Limit the rate the display is updated at
+    ///
+    /// A page is not rendered if the last frame was rendered less than
+    /// `interval` milliseconds before, i.e. rapid updates, e.g. by a quickly
+    /// turned rotary encoder, are coalesced. The state skipped is rendered by
+    /// the next dispatch or update after the interval, e.g. the periodic
+    /// update. Requires the clock set by `set_clock()`.
+    ///
+    /// Arguments
+    ///
+    /// * `interval`: - Minimum milliseconds between two frames
+    pub fn set_frame_interval(&mut self, interval: u32) {
+        self.frame_interval = Some(interval);
+        self.last_frame = None;
+    }
+
+    /// Render a frame now - false while dispatching a burst or if the last frame is too recent
+    fn frame_due(&mut self) -> bool {
+        if self.batching {
+            return false;
+        }
+        let (interval, now) = match (self.frame_interval, self.clock.as_mut()) {
+            (Some(interval), Some(clock)) => (interval, clock()),
+            _ => return true,
+        };
+        match self.last_frame {
+            Some(at) if now.wrapping_sub(at) < interval => false,
+            _ => {
+                self.last_frame = Some(now);
+                true
+            }
+        }
+    }
+
+    /// Render the next frame of a running page transition
+    ///
+    /// Called periodically after a page change, e.g. every 20 milliseconds,
+    /// as long as frames are left. See `set_transition_frames()`.
+    ///
+    /// Returns
+    ///
+    /// * `true` if there are frames of the transition left
+    pub fn animate(&mut self) -> bool {
+        if self.transition.is_none() {
+            return false;
+        }
+        self.advance_transition();
+        self.display_shown();
+        self.transition.is_some()
+    }
+
+    /// Start a transition if the navigation changed the page
+    fn start_transition(&mut self, origin: PageId, transition: Transition) {
+        if self.active != origin.0 && self.transition_frames > 1 {
+            self.reset_transition();
+            self.transition = Some((transition, 1));
+        }
+    }
+
+    /// Tell the display the transition of the frame rendered next
+    fn advance_transition(&mut self) {
+        if let (Some((transition, frame)), Some(show)) = (self.transition, self.show_transition) {
+            let frames = self.transition_frames;
+            if frame < frames {
+                let progress = (frame as u32 * 100 / frames as u32) as u8;
+                show(&mut self.display, Some((transition, progress)));
+                self.transition = Some((transition, frame + 1));
+            } else {
+                self.reset_transition();
+            }
+        }
+    }
+
+    fn reset_transition(&mut self) {
+        if let (Some(_), Some(show)) = (self.transition.take(), self.show_transition) {
+            show(&mut self.display, None);
+        }
+    }
+
+    /// Refresh the next frame fully, e.g. after a theme switch or periodically
+    /// to remove the ghosting of an e-paper display
+    pub fn request_full_refresh(&mut self) {
+        self.shown = None;
+    }
+
+    /// Tell the display the refresh of the frame rendered next
+    fn hint_refresh(&mut self, shown: Shown) {
+        let show = match self.show_refresh {
+            Some(show) => show,
+            None => return,
+        };
+        let refresh = match self.shown {
+            Some(before) if before == shown => Refresh::Partial,
+            Some(_) if self.page_changes + 1 < self.full_refresh_interval => {
+                self.page_changes += 1;
+                Refresh::Partial
+            }
+            _ => {
+                self.page_changes = 0;
+                Refresh::Full
+            }
+        };
+        self.shown = Some(shown);
+        show(&mut self.display, refresh);
+    }
+
+    /// Timestamp inputs dispatched by `dispatch_interaction()` or `dispatch_input()`
+    ///
+    /// Arguments
+    ///
+    /// * `clock`: - Milliseconds of a free running clock, e.g. a system tick counter
+    pub fn set_clock(&mut self, clock: Box<dyn FnMut() -> u32 + 'a>) {
+        self.clock = Some(clock);
+    }
+
+    /// Raise the alarms of the application
+    ///
+    /// The scheduler is polled with every periodic update of the operational
+    /// HMI, i.e. by `dispatch(PageNavigation::Update)`. A fired alarm requests
+    /// `Feedback::Alarm`, is queued for the application, see `take_alarm()`,
+    /// and activates the alarm page, if any, by `PageNavigation::ToWith`
+    /// with the index of the alarm, e.g. to show which alarm fired.
+    /// Alarms are held back while the active page is locked or, if there is
+    /// an alarm page, while the active page vetoes leaving it, e.g. for an
+    /// edit not committed yet.
+    ///
+    /// Arguments
+    ///
+    /// * `scheduler`: - The alarms, e.g. shared with an `AlarmListPage`
+    /// * `time`: - The local time, e.g. of a real time clock
+    /// * `page`: - The page interrupting the user if an alarm fires
+    pub fn set_alarms(
+        &mut self,
+        scheduler: &'a RefCell<AlarmScheduler>,
+        time: Box<dyn TimeSource + 'a>,
+        page: Option<PageId>,
+    ) {
+        self.alarms = Some(Alarms {
+            scheduler,
+            time,
+            page,
+        });
+        self.fired.clear();
+    }
+
+    /// The oldest alarm fired that the application did not take yet
+    pub fn take_alarm(&mut self) -> Option<AlarmId> {
+        self.fired.pop_front()
+    }
+
+    /// Poll the alarms - returns the navigation to the alarm page if one fired
+    fn poll_alarms(&mut self) -> Option<PageNavigation> {
+        if !matches!(self.state, PageManagerState::Operational) || self.is_locked() {
+            return None;
+        }
+        let alarms = self.alarms.as_mut()?;
+        // the alarm page must not discard what the active page holds
+        if alarms.page.is_some() && self.pages[self.active].page.can_leave().is_err() {
+            return None;
+        }
+        let now = alarms.time.now();
+        let id = alarms.scheduler.borrow_mut().poll(now)?;
+        let page = alarms.page;
+        debug!("alarm {} fired", id.index());
+        self.fired.push_back(id);
+        self.give_feedback(Feedback::Alarm);
+        page.map(|page| PageNavigation::ToWith(page, id.index()))
+    }
+
+    /// Give audible or haptic feedback
+    ///
+    /// The sink gets a key click for every interaction dispatched, the
+    /// feedback requested by pages and an error if dispatching fails.
+    ///
+    /// Arguments
+    ///
+    /// * `sink`: - The feedback device, e.g. a buzzer
+    pub fn set_feedback(&mut self, sink: Box<dyn FeedbackSink + 'a>) {
+        self.feedback = Some(sink);
+    }
+
+    fn give_feedback(&mut self, feedback: Feedback) {
+        if let Some(sink) = &mut self.feedback {
+            sink.give(feedback);
+        }
+    }
+
+    /// Let the page navigated to know it is active, with the result of the sub page left
+    ///
+    /// A page navigated to with a parameter gets it even if it is active already.
+    fn enter_active(&mut self, origin: PageId, param: Option<usize>) {
+        if self.active == origin.0 && param.is_none() {
+            return;
+        }
+        self.result = match self.pages[origin.0].up == Some(self.active) {
+            true => self.pages[origin.0].page.take_result(),
+            false => None,
+        };
+        self.pages[self.active]
+            .page
+            .on_enter(param, self.result.as_ref());
+    }
+
+    /// The active page vetoes the navigation away from it
+    ///
+    /// The first navigation is blocked, a repetition confirms leaving.
+    fn leave_vetoed(&mut self, navigation: PageNavigation, rotate: bool) -> bool {
+        let leaving = rotate
+            || matches!(
+                navigation,
+                PageNavigation::Left
+                    | PageNavigation::Right
+                    | PageNavigation::Home
+                    | PageNavigation::Up
+                    | PageNavigation::NthSubpage(_)
+                    | PageNavigation::ToWith(_, _)
+                    | PageNavigation::To(_)
+            );
+        if !leaving {
+            return false;
+        }
+        // a page not accessible anymore is left in any case
+        if self.pages[self.active].page.can_leave().is_ok() || !self.is_active_accessible() {
+            self.vetoed = None;
+            return false;
+        }
+        if rotate {
+            return true;
+        }
+        if self.vetoed == Some(navigation) {
+            self.vetoed = None;
+            return false;
+        }
+        warn!(
+            "{:?} vetoed by page {}",
+            navigation,
+            self.pages[self.active].page.title()
+        );
+        self.vetoed = Some(navigation);
+        self.give_feedback(Feedback::Error);
+        true
+    }
+
+    /// Give the feedback requested by a page
+    fn take_feedback_of(&mut self, id: PageId) {
+        if let Some(feedback) = self.page_mut(id).and_then(|page| page.take_feedback()) {
+            self.give_feedback(feedback);
+        }
+    }
+
+    /// Mirror the state of the HMI by front panel LEDs
+    ///
+    /// The sink is invoked after dispatching whenever the indication changes.
+    ///
+    /// Arguments
+    ///
+    /// * `sink`: - The LEDs
+    pub fn set_indicator(&mut self, sink: Box<dyn IndicatorSink + 'a>) {
+        self.indicator = Some(sink);
+        self.indication = None;
+    }
+
+    /// The state of the HMI as shown by front panel LEDs
+    pub fn indication(&self) -> Indication {
+        match self.state {
+            PageManagerState::Startup => Indication {
+                startup: self.startup.is_some(),
+                ..Indication::default()
+            },
+            PageManagerState::Shutdown => Indication {
+                shutdown: self.shutdown.is_some(),
+                ..Indication::default()
+            },
+            PageManagerState::Operational => Indication {
+                settings: self
+                    .path()
+                    .into_iter()
+                    .any(|index| self.pages[index].page.is_setting()),
+                alarm: self.pages[self.active].page.is_alarm(),
+                ..Indication::default()
+            },
+        }
+    }
+
+    fn update_indicator(&mut self) {
+        if self.indicator.is_none() {
+            return;
+        }
+        let indication = self.indication();
+        if self.indication != Some(indication) {
+            self.indication = Some(indication);
+            if let Some(sink) = &mut self.indicator {
+                sink.indicate(indication);
+            }
+        }
+    }
+
+    /// Announce the pages shown and the items selected, e.g. by text-to-speech
     ///
-    /// The page is registered in the "left" direction of the
-    /// active page. The registered page will be the new active page.
+    /// The renderer is invoked after dispatching whenever another page is
+    /// shown or another item of the page is selected - with the title of
+    /// the page first, followed by the item selected, if any. Startup and
+    /// shutdown pages are not announced.
     ///
     /// Arguments
     ///
-    /// * `page` - The page to be registered and activated.
-    pub fn register(&mut self, page: Box<dyn PageInterface<D> + 'a>) {
-        self.push_left(page, None, None);
-        self.activate_left();
+    /// * `audio`: - The audio renderer, e.g. a closure
+    pub fn set_audio(&mut self, audio: Box<dyn AudioRenderer + 'a>) {
+        self.audio = Some(audio);
+        self.announced = None;
     }
 
-    /// Register a new sub page
+    fn announce(&mut self) {
+        if self.audio.is_none() || !matches!(self.state, PageManagerState::Operational) {
+            return;
+        }
+        let page = &self.pages[self.active].page;
+        let announced = (PageId(self.active), page.selected());
+        let last = self.announced.replace(announced);
+        if last == Some(announced) {
+            return;
+        }
+        let mut subpages = SubPageIterator {
+            pages: &self.pages,
+            left: self.pages[self.active].down,
+            access_level: self.access_level,
+        };
+        if let Some(audio) = &mut self.audio {
+            if last.map(|(id, _)| id) != Some(announced.0) {
+                audio.announce(Announcement::Page(page.title()));
+            }
+            if let Some(index) = announced.1 {
+                let text = match page.is_menu() && index > 0 {
+                    true => subpages.nth(index - 1).map(|p| p.title()),
+                    false => None,
+                };
+                audio.announce(Announcement::Selection { index, text });
+            }
+        }
+    }
+
+    /// Remove all pages and start over with a new home page
     ///
-    /// The page is registered in the "down" direction of the
-    /// active page. The registered page will be the new active page.
+    /// Allows to rebuild the page tree at runtime, e.g. after a language change.
+    /// Startup and shutdown pages are removed as well and need to be registered
+    /// again, and so do the alarms. Page ids are assigned from scratch.
+    /// The navigation policy, the application context and the display info are kept.
     ///
     /// Arguments
     ///
-    /// * `page`: - The page to be registered and activated.
-    pub fn register_sub(&mut self, page: Box<dyn PageInterface<D> + 'a>) {
-        self.push_down(page, None, None);
-        self.activate_down();
+    /// * `home`: The new "home" page.
+    pub fn clear(&mut self, home: Box<dyn PageInterface<D, C> + 'a>) {
+        self.pages.clear();
+        self.pages.push(Node::new(home, None));
+        self.active = HOME;
+        self.modes = vec![Mode::new(HOME)];
+        self.mode = 0;
+        self.startup = None;
+        self.shutdown = None;
+        self.state = PageManagerState::Startup;
+        self.bounced = None;
+        self.vetoed = None;
+        self.result = None;
+        self.alarms = None;
+        self.fired.clear();
+        self.changed = false;
+        self.announced = None;
+        self.version += 1;
     }
 
     /// Register a startup page
@@ -170,7 +1649,7 @@ impl<'a, D> PageManager<'a, D> {
     /// Arguments
     ///
     /// * `page`: - The page that should serve for startup.
-    pub fn register_startup(&mut self, page: Box<dyn PageInterface<D> + 'a>) {
+    pub fn register_startup(&mut self, page: Box<dyn PageInterface<D, C> + 'a>) {
         self.startup = Some(page);
     }
 
@@ -182,82 +1661,77 @@ impl<'a, D> PageManager<'a, D> {
     /// Arguments
     ///
     /// * `page`: - The page that should serve for startup.
-    pub fn register_shutdown(&mut self, page: Box<dyn PageInterface<D> + 'a>) {
+    pub fn register_shutdown(&mut self, page: Box<dyn PageInterface<D, C> + 'a>) {
         self.shutdown = Some(page);
     }
 
-    fn push_left(
-        &mut self,
-        page: Box<dyn PageInterface<D> + 'a>,
-        up: Link<Box<dyn PageInterface<D> + 'a>>,
-        down: Link<Box<dyn PageInterface<D> + 'a>>,
-    ) {
-        let new_node = Box::new(Node {
-            page,
-            left: self.left.take(),
-            right: None,
-            down,
-            up,
-        });
-        self.left = Some(new_node);
+    /// Register a task to complete before the HMI is shut down
+    ///
+    /// Arguments
+    ///
+    /// * `task`: - Is polled with every dispatch of `PageNavigation::SystemStop` until it is done
+    pub fn register_shutdown_task(&mut self, task: Box<dyn ShutdownTask + 'a>) {
+        self.shutdown_tasks.push((task, false));
     }
 
-    fn push_right(
-        &mut self,
-        page: Box<dyn PageInterface<D> + 'a>,
-        up: Link<Box<dyn PageInterface<D> + 'a>>,
-        down: Link<Box<dyn PageInterface<D> + 'a>>,
-    ) {
-        let new_node = Box::new(Node {
-            page,
-            left: None,
-            right: self.right.take(),
-            up,
-            down,
-        });
-        self.right = Some(new_node);
+    /// All shutdown tasks are done - true if there are none
+    pub fn shutdown_tasks_done(&self) -> bool {
+        self.shutdown_tasks.iter().all(|(_, done)| *done)
     }
 
-    fn pop_left(
-        &mut self,
-    ) -> Option<(
-        Box<dyn PageInterface<D> + 'a>,
-        Link<Box<dyn PageInterface<D> + 'a>>,
-        Link<Box<dyn PageInterface<D> + 'a>>,
-    )> {
-        self.left.take().map(|node| {
-            let mut node = node;
-            self.left = node.left;
-            (node.page, node.up.take(), node.down.take())
-        })
+    /// Tell all pages about the shutdown and start the shutdown tasks over
+    fn start_shutdown(&mut self) {
+        debug!("shutdown started");
+        self.shutdown_expired = false;
+        self.shutdown_tasks
+            .iter_mut()
+            .for_each(|(_, done)| *done = false);
+        self.pages
+            .iter_mut()
+            .for_each(|node| node.page.on_shutdown());
     }
 
-    fn pop_right(
-        &mut self,
-    ) -> Option<(
-        Box<dyn PageInterface<D> + 'a>,
-        Link<Box<dyn PageInterface<D> + 'a>>,
-        Link<Box<dyn PageInterface<D> + 'a>>,
-    )> {
-        self.right.take().map(|node| {
-            let mut node = node;
-            self.right = node.right;
-            (node.page, node.up.take(), node.down.take())
-        })
+    /// Poll the shutdown tasks not done yet
+    fn poll_shutdown_tasks(&mut self) -> bool {
+        self.shutdown_tasks
+            .iter_mut()
+            .filter(|(_, done)| !*done)
+            .for_each(|(task, done)| *done = task.poll());
+        self.shutdown_tasks_done()
+    }
+
+    /// The page is accessible with the current access level
+    fn is_accessible(&self, index: usize) -> bool {
+        self.pages[index].access <= self.access_level
+    }
+
+    /// The page is accessible and not hidden, i.e. navigated to by the user
+    fn is_listed(&self, index: usize) -> bool {
+        self.is_accessible(index) && !self.pages[index].hidden
+    }
+
+    /// The first listed page following the links from the page on
+    fn accessible(
+        &self,
+        mut index: Option<usize>,
+        link: fn(&Node<Box<dyn PageInterface<D, C> + 'a>>) -> Option<usize>,
+    ) -> Option<usize> {
+        while let Some(i) = index {
+            if self.is_listed(i) {
+                return Some(i);
+            }
+            index = link(&self.pages[i]);
+        }
+        None
     }
 
     /// Navigate to the left page
     /// If there is no left page it returns false and activate page is unchanged
     fn activate_left(&mut self) -> bool {
-        match self.pop_left() {
+        match self.accessible(self.pages[self.active].left, |node| node.left) {
             None => false,
-            Some((page, up, down)) => {
-                let page = mem::replace(&mut self.page, page);
-                let new_up = self.up.take();
-                let new_down = self.down.take();
-                self.push_right(page, new_up, new_down);
-                self.up = up;
-                self.down = down;
+            Some(index) => {
+                self.active = index;
                 true
             }
         }
@@ -266,94 +1740,132 @@ impl<'a, D> PageManager<'a, D> {
     /// Navigate to the right page
     /// If there is no right page it returns false and activate page is unchanged
     fn activate_right(&mut self) -> bool {
-        match self.pop_right() {
+        match self.accessible(self.pages[self.active].right, |node| node.right) {
             None => false,
-            Some((page, up, down)) => {
-                let page = mem::replace(&mut self.page, page);
-                let new_up = self.up.take();
-                let new_down = self.down.take();
-                self.push_left(page, new_up, new_down);
-                self.up = up;
-                self.down = down;
+            Some(index) => {
+                self.active = index;
                 true
             }
         }
     }
 
+    /// The most right page of the level of the active page - hidden or not
+    fn first_of_level(&self) -> usize {
+        match self.pages[self.active].up {
+            None => self.modes[self.mode_of(self.active)].root,
+            Some(up) => self.pages[up].down.unwrap_or(self.active),
+        }
+    }
+
     fn activate_most_right(&mut self) {
-        while self.activate_right() {}
+        let first = self.first_of_level();
+        self.active = self
+            .accessible(Some(first), |node| node.left)
+            .unwrap_or(first);
     }
 
-    fn push_down(
-        &mut self,
-        page: Box<dyn PageInterface<D> + 'a>,
-        left: Link<Box<dyn PageInterface<D> + 'a>>,
-        right: Link<Box<dyn PageInterface<D> + 'a>>,
-    ) {
-        let new_node = Box::new(Node {
-            page,
-            up: None,
-            down: self.down.take(),
-            left,
-            right,
-        });
-        self.down = Some(new_node);
+    fn activate_most_left(&mut self) {
+        while self.activate_left() {}
     }
 
-    fn push_up(
-        &mut self,
-        page: Box<dyn PageInterface<D> + 'a>,
-        left: Link<Box<dyn PageInterface<D> + 'a>>,
-        right: Link<Box<dyn PageInterface<D> + 'a>>,
-    ) {
-        let new_node = Box::new(Node {
-            page,
-            up: self.up.take(),
-            down: None,
-            left,
-            right,
-        });
-        self.up = Some(new_node);
+    /// Navigation policy of the level of the active page
+    fn policy(&self) -> NavigationPolicy {
+        match self.pages[self.active].up {
+            None => self.top_level_policy,
+            Some(_) => self.sub_level_policy,
+        }
     }
 
-    fn pop_down(
-        &mut self,
-    ) -> Option<(
-        Box<dyn PageInterface<D> + 'a>,
-        Link<Box<dyn PageInterface<D> + 'a>>,
-        Link<Box<dyn PageInterface<D> + 'a>>,
-    )> {
-        self.down.take().map(|node| {
-            let mut node = node;
-            self.down = node.down;
-            (node.page, node.left.take(), node.right.take())
-        })
-    }
-
-    fn pop_up(
-        &mut self,
-    ) -> Option<(
-        Box<dyn PageInterface<D> + 'a>,
-        Link<Box<dyn PageInterface<D> + 'a>>,
-        Link<Box<dyn PageInterface<D> + 'a>>,
-    )> {
-        self.up.take().map(|node| {
-            let mut node = node;
-            self.up = node.up;
-            (node.page, node.left.take(), node.right.take())
-        })
+    /// Navigate left respecting the navigation policy
+    fn navigate_left(&mut self) {
+        let behavior = self.policy().left;
+        if self.bounced == Some(PageNavigation::Left) && self.activate_right() {
+            return;
+        }
+        self.bounced = None;
+        if !self.activate_left() {
+            match behavior {
+                EdgeBehavior::Wrap => self.activate_most_right(),
+                EdgeBehavior::Clamp => (),
+                EdgeBehavior::Bounce => {
+                    self.bounced = Some(PageNavigation::Left);
+                    self.activate_right();
+                }
+            }
+        }
+    }
+
+    /// Navigate right respecting the navigation policy
+    fn navigate_right(&mut self) {
+        let behavior = self.policy().right;
+        if self.bounced == Some(PageNavigation::Right) && self.activate_left() {
+            return;
+        }
+        self.bounced = None;
+        if !self.activate_right() {
+            match behavior {
+                EdgeBehavior::Wrap => self.activate_most_left(),
+                EdgeBehavior::Clamp => (),
+                EdgeBehavior::Bounce => {
+                    self.bounced = Some(PageNavigation::Right);
+                    self.activate_left();
+                }
+            }
+        }
+    }
+
+    /// Title of the page a navigation would activate - none if it stays at the active page
+    fn sibling(&mut self, navigate: fn(&mut Self)) -> Option<String> {
+        self.activated_by(navigate)
+            .map(|sibling| String::from(self.pages[sibling].page.title()))
+    }
+
+    /// The page an activation would activate - none if it stays at the active page
+    fn activated_by(&mut self, activate: impl FnOnce(&mut Self)) -> Option<usize> {
+        let (active, bounced) = (self.active, self.bounced);
+        activate(self);
+        let activated = self.active;
+        (self.active, self.bounced) = (active, bounced);
+        (activated != active).then_some(activated)
+    }
+
+    /// Resolve `PageNavigation::FirstOf` to the first navigation leading to another page
+    fn resolve(&mut self, navigation: PageNavigation) -> PageNavigation {
+        let targets = match navigation {
+            PageNavigation::FirstOf(targets) => targets,
+            navigation => return navigation,
+        };
+        for target in targets {
+            let target = match self.resolve(*target) {
+                // none of the nested navigations leads away
+                PageNavigation::Update if *target != PageNavigation::Update => continue,
+                target => target,
+            };
+            let leads_away = match target {
+                PageNavigation::Left => self.activated_by(Self::navigate_left).is_some(),
+                PageNavigation::Right => self.activated_by(Self::navigate_right).is_some(),
+                PageNavigation::Home => self.activated_by(Self::activate_home).is_some(),
+                PageNavigation::Up => self.activated_by(|m| _ = m.activate_up()).is_some(),
+                PageNavigation::NthSubpage(_) => {
+                    self.activated_by(|m| _ = m.activate_down()).is_some()
+                }
+                PageNavigation::To(id) | PageNavigation::ToWith(id, _) => {
+                    id.0 < self.pages.len() && self.is_accessible(id.0) && id.0 != self.active
+                }
+                _ => true,
+            };
+            if leads_away {
+                return target;
+            }
+        }
+        PageNavigation::Update
     }
 
     fn activate_down(&mut self) -> bool {
-        match self.pop_down() {
+        match self.accessible(self.pages[self.active].down, |node| node.left) {
             None => false,
-            Some((page, left, right)) => {
-                let page = mem::replace(&mut self.page, page);
-                let new_left = self.left.take();
-                let new_right = self.right.take();
-                self.push_up(page, new_left, new_right);
-                self.left = left;
-                self.right = right;
+            Some(index) => {
+                self.active = index;
                 true
             }
         }
@@ -361,23 +1873,254 @@ impl<'a, D> PageManager<'a, D> {
 
     fn activate_up(&mut self) -> bool {
         self.activate_most_right();
-        match self.pop_up() {
+        match self.pages[self.active].up {
             None => false,
-            Some((page, left, right)) => {
-                let page = mem::replace(&mut self.page, page);
-                let new_left = self.left.take();
-                let new_right = self.right.take();
-                self.push_down(page, new_left, new_right);
-                self.left = left;
-                self.right = right;
+            Some(index) => {
+                self.active = index;
                 true
             }
         }
     }
 
+    /// Find a registered page of the page tree by its id
+    fn page_mut(&mut self, id: PageId) -> Option<&mut Box<dyn PageInterface<D, C> + 'a>> {
+        self.pages.get_mut(id.0).map(|node| &mut node.page)
+    }
+
+    /// Activate the current home page - the root of the page tree if it is not accessible
     fn activate_home(&mut self) {
-        while self.activate_up() {}
-        self.activate_most_right();
+        let Mode { root, home } = self.modes[self.mode];
+        self.active = match self.path_of(home).iter().all(|i| self.is_accessible(*i)) {
+            true => home,
+            false => root,
+        };
+    }
+
+    /// The page with the given title, hidden or not, among the page given and the pages left of it
+    fn find_title(&self, first: Option<usize>, title: &str) -> Option<usize> {
+        let mut index = first;
+        while let Some(i) = index {
+            if self.is_accessible(i) && self.pages[i].page.title() == title {
+                return Some(i);
+            }
+            index = self.pages[i].left;
+        }
+        None
+    }
+
+    /// The page given by a path of titles - the active page is not changed
+    fn find_path(&self, path: &[&str]) -> Option<usize> {
+        let root = self.modes[self.mode].root;
+        let mut titles = path.iter();
+        let mut index = match titles.next() {
+            None => root,
+            Some(title) => self
+                .find_title(Some(root), title)
+                // the path might start below the home page
+                .or_else(|| self.find_title(self.pages[root].down, title))?,
+        };
+        for title in titles {
+            index = self.find_title(self.pages[index].down, title)?;
+        }
+        Some(index)
+    }
+
+    /// Navigate to a page given by the path of titles
+    ///
+    /// The path is resolved level by level starting at the top level, i.e.
+    /// it has the same form as yielded by `breadcrumb()`.
+    /// Since all navigation starts at the home page, a path whose first title
+    /// is not found on the top level is resolved below the home page. E.g.
+    /// `&["Menu", "Sub-Menu", "Config-2"]` is the same as
+    /// `&["Home", "Menu", "Sub-Menu", "Config-2"]`.
+    /// The page is activated by `PageNavigation::To` like by `navigate_to()`.
+    ///
+    /// Arguments
+    ///
+    /// * `path`: - Titles of the pages to pass on the way to the target page.
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if the path cannot be resolved or the active page is locked;
+    ///   the active page is unchanged
+    ///   in this case.
+    pub fn navigate_path(&mut self, path: &[&str]) -> Result<(), PageError> {
+        if self.is_locked() {
+            return Err(PageError);
+        }
+        let index = self.find_path(path).ok_or(PageError)?;
+        self.dispatch_rotating(PageNavigation::To(PageId(index)), false)?;
+        Ok(())
+    }
+
+    /// Navigate to a page given by its id, e.g. a hidden service page
+    ///
+    /// The page is activated by `PageNavigation::To`, i.e. the page left and
+    /// the page entered are told and the active page can veto leaving.
+    ///
+    /// Arguments
+    ///
+    /// * `id`: - The id of the page as returned by registration
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if there is no such page, it is not accessible
+    ///   with the current access level or the active page is locked; the
+    ///   active page is unchanged in this case.
+    pub fn navigate_to(&mut self, id: PageId) -> Result<(), PageError> {
+        if id.0 >= self.pages.len() || !self.is_accessible(id.0) || self.is_locked() {
+            return Err(PageError);
+        }
+        self.dispatch_rotating(PageNavigation::To(id), false)?;
+        Ok(())
+    }
+
+    /// Ids and selected items of the pages from the top level down to the active page
+    fn path_selection(&self) -> Vec<(PageId, Option<usize>)> {
+        self.path()
+            .into_iter()
+            .map(|index| (PageId(index), self.pages[index].page.selected()))
+            .collect()
+    }
+
+    /// Save the active page and the selected items of the pages on the way to it
+    ///
+    /// Allows a device resuming from a watchdog reset or deep sleep to return
+    /// the user to where they were by `restore_state()`. The pages are saved
+    /// by their ids, i.e. the state fits as long as the pages are registered
+    /// in the same order - hidden pages included.
+    ///
+    /// Arguments
+    ///
+    /// * `store`: - The persistent store to save the state to
+    pub fn save_state(&self, store: &mut impl SettingsStore) -> Result<(), PageError> {
+        let mut data = Vec::new();
+        for (id, selected) in self.path_selection() {
+            data.extend_from_slice(&(id.0 as u16).to_le_bytes());
+            data.extend_from_slice(&(selected.unwrap_or(0) as u16).to_le_bytes());
+        }
+        store.store(STATE_KEY, &data)
+    }
+
+    /// Restore the active page and selected items saved by `save_state()`
+    ///
+    /// Is used instead of dispatching `PageNavigation::SystemStart` when
+    /// resuming, i.e. the startup page is skipped and the restored page is
+    /// updated and displayed. The pages on the way to it are updated before
+    /// their items are selected, e.g. a `LazyPage` is constructed. The page is
+    /// activated by `PageNavigation::To` like by `navigate_to()`.
+    ///
+    /// Arguments
+    ///
+    /// * `store`: - The persistent store to restore the state from
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if there is no saved state or it does not fit the
+    ///   page tree; the active page is unchanged in this case.
+    /// * `Err(PageError)` - if the update of a page fails or the active page
+    ///   vetoes leaving.
+    pub fn restore_state(&mut self, store: &mut impl SettingsStore) -> Result<(), PageError> {
+        let data = store.load(STATE_KEY).ok_or(PageError)?;
+        if data.is_empty() || data.len() % 4 != 0 {
+            return Err(PageError);
+        }
+        let (path, selection): (Vec<usize>, Vec<usize>) = data
+            .chunks(4)
+            .map(|c| {
+                (
+                    u16::from_le_bytes([c[0], c[1]]) as usize,
+                    u16::from_le_bytes([c[2], c[3]]) as usize,
+                )
+            })
+            .unzip();
+
+        let active = path[path.len() - 1];
+        if active >= self.pages.len()
+            || self.path_of(active) != path
+            || !path.iter().all(|i| self.is_accessible(*i))
+        {
+            return Err(PageError);
+        }
+        for (index, selected) in path.into_iter().zip(selection) {
+            if selected > 0 {
+                let page = &mut self.pages[index].page;
+                if let Some(size) = &self.display_size {
+                    page.set_display_info(size);
+                }
+                page.update_with_context(&mut self.context, None)?;
+                page.select(selected);
+            }
+        }
+        self.dispatch_rotating(PageNavigation::To(PageId(active)), false)?;
+        match self.active == active {
+            true => Ok(()),
+            false => Err(PageError),
+        }
+    }
+
+    /// Dispatch a burst of inputs but render only once at the end
+    ///
+    /// The inputs, e.g. interactions, are dispatched one after the other like by
+    /// `dispatch_input()`, only the display is not updated in between,
+    /// e.g. to keep a slow display up with a quickly turned rotary encoder.
+    /// Nothing is rendered if there are no inputs or a dispatch fails.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// static QUEUE: InteractionQueue<8> = InteractionQueue::new();
+    /// navigation = m.dispatch_all(core::iter::from_fn(|| QUEUE.pop()))?;
+    /// ```
+    ///
+    /// Arguments
+    ///
+    /// * `inputs`: - The inputs to dispatch
+    ///
+    /// Returns
+    ///
+    /// * The navigation returned by the last dispatch, `PageNavigation::Update` if there was none
+    pub fn dispatch_all<I>(&mut self, inputs: I) -> Result<PageNavigation, PageError>
+    where
+        I: IntoIterator,
+        I::Item: Into<InputEvent>,
+    {
+        let mut navigation = None;
+        self.batching = true;
+        let result = inputs.into_iter().try_for_each(|input| {
+            navigation = Some(self.dispatch_input(input.into())?);
+            Ok(())
+        });
+        self.batching = false;
+        result?;
+        match navigation {
+            None => Ok(PageNavigation::Update),
+            Some(navigation) => {
+                if self.frame_due() {
+                    self.advance_transition();
+                    self.display_shown();
+                }
+                Ok(navigation)
+            }
+        }
+    }
+
+    /// Render the page shown - the startup or shutdown page while starting or shutting down
+    fn display_shown(&mut self) {
+        self.hint_refresh(match self.state {
+            PageManagerState::Startup if self.startup.is_some() => Shown::Startup,
+            PageManagerState::Shutdown if self.shutdown.is_some() => Shown::Shutdown,
+            _ => Shown::Page(self.active),
+        });
+        let page = match self.state {
+            PageManagerState::Startup => self.startup.as_ref(),
+            PageManagerState::Shutdown => self.shutdown.as_ref(),
+            PageManagerState::Operational => None,
+        };
+        match page {
+            Some(page) => page.display(&mut self.display),
+            None => self.pages[self.active].page.display(&mut self.display),
+        }
     }
 
     /// Dispatch an interaction event
@@ -385,26 +2128,126 @@ impl<'a, D> PageManager<'a, D> {
     /// Let the active page process the interaction event and eventually turn
     /// The interaction event into a executed page navigation
     ///
+    /// If a clock is set, the interaction is timestamped and dispatched by
+    /// `dispatch_event()`.
+    ///
     /// Arguments
     ///
     /// * `interaction`: - The interaction event to dispatch
-
     pub fn dispatch_interaction(
         &mut self,
         interaction: Interaction,
     ) -> Result<PageNavigation, PageError> {
+        self.dispatch_input(InputEvent::Primary(interaction))
+    }
+
+    /// Dispatch an input - a primary interaction or a richer input
+    ///
+    /// The input is dispatched like an interaction by `dispatch_interaction()`,
+    /// i.e. it is debounced, clicks and can be taken by a shortcut. Pages see
+    /// characters by `dispatch_char()` and custom events by `dispatch_custom()`.
+    ///
+    /// Arguments
+    ///
+    /// * `input`: - The input to dispatch
+    pub fn dispatch_input(&mut self, input: InputEvent) -> Result<PageNavigation, PageError> {
+        match self.clock.as_mut().map(|clock| clock()) {
+            Some(timestamp) if self.is_bounce(input, timestamp) => Ok(PageNavigation::Update),
+            _ => self.dispatch_to_page(input),
+        }
+    }
+
+    /// Dispatch a character entered directly, e.g. by a keypad
+    ///
+    /// Arguments
+    ///
+    /// * `c`: - The character to dispatch by `dispatch_input()`
+    pub fn dispatch_char(&mut self, c: char) -> Result<PageNavigation, PageError> {
+        self.dispatch_input(InputEvent::Char(c))
+    }
+
+    /// Dispatch an event defined by the application, e.g. a function key
+    ///
+    /// Arguments
+    ///
+    /// * `code`: - The code of the event to dispatch by `dispatch_input()`
+    pub fn dispatch_custom(&mut self, code: u8) -> Result<PageNavigation, PageError> {
+        self.dispatch_input(InputEvent::Custom(code))
+    }
+
+    /// Dispatch an interaction with the time it was detected
+    ///
+    /// Like `dispatch_interaction()`, except that an interaction repeated
+    /// within the quiet time set by `set_debounce()` is dropped. A dropped
+    /// interaction is not dispatched at all and results in `PageNavigation::Update`.
+    ///
+    /// Arguments
+    ///
+    /// * `event`: - The interaction and its timestamp
+    pub fn dispatch_event(&mut self, event: InteractionEvent) -> Result<PageNavigation, PageError> {
+        let input = InputEvent::Primary(event.interaction);
+        if self.is_bounce(input, event.timestamp) {
+            return Ok(PageNavigation::Update);
+        }
+        self.dispatch_to_page(input)
+    }
+
+    /// The input repeats the last input within the quiet time
+    fn is_bounce(&mut self, input: InputEvent, timestamp: u32) -> bool {
+        let quiet_time = match self.quiet_time {
+            None => return false,
+            Some(quiet_time) => quiet_time,
+        };
+        match self.last_event.replace((input, timestamp)) {
+            Some((last, last_timestamp)) => {
+                last == input && timestamp.wrapping_sub(last_timestamp) < quiet_time
+            }
+            None => false,
+        }
+    }
+
+    fn dispatch_to_page(&mut self, input: InputEvent) -> Result<PageNavigation, PageError> {
+        self.give_feedback(Feedback::KeyClick);
+        let mut input = input;
+        if matches!(self.state, PageManagerState::Operational) && !self.is_locked() {
+            let shortcut = self.shortcuts.iter_mut().find_map(|handler| handler(input));
+            match shortcut {
+                Some(ChordEvent::Interaction(other)) => input = InputEvent::Primary(other),
+                Some(ChordEvent::Navigation(navigation)) => {
+                    self.pause_auto_cycle();
+                    return self.dispatch_rotating(navigation, false);
+                }
+                Some(ChordEvent::Path(path)) => {
+                    self.pause_auto_cycle();
+                    self.navigate_path(path)?;
+                    return Ok(PageNavigation::Update);
+                }
+                Some(ChordEvent::Page(id)) => {
+                    self.pause_auto_cycle();
+                    self.navigate_to(id)?;
+                    return Ok(PageNavigation::Update);
+                }
+                None => (),
+            }
+        }
+        let context = &mut self.context;
         let navigation = match self.state {
             PageManagerState::Startup => match &mut self.startup {
-                None => self.page.dispatch(interaction),
-                Some(x) => x.dispatch(interaction),
+                None => input_to_page(self.pages[self.active].page.as_mut(), context, input),
+                Some(x) => input_to_page(x.as_mut(), context, input),
             },
-            PageManagerState::Operational => self.page.dispatch(interaction),
+            PageManagerState::Operational => {
+                input_to_page(self.pages[self.active].page.as_mut(), context, input)
+            }
             PageManagerState::Shutdown => match &mut self.shutdown {
-                None => self.page.dispatch(interaction),
-                Some(x) => x.dispatch(interaction),
+                None => input_to_page(self.pages[self.active].page.as_mut(), context, input),
+                // the shutdown cannot be cancelled beyond the lifetime of the shutdown page
+                Some(_) if self.shutdown_expired => PageNavigation::SystemStop,
+                Some(x) => input_to_page(x.as_mut(), context, input),
             },
         };
-        self.dispatch(navigation)
+        self.pause_auto_cycle();
+        self.dispatch_rotating(navigation, false)
     }
 
     /// Dispatch a navigation event
@@ -416,109 +2259,305 @@ impl<'a, D> PageManager<'a, D> {
     ///
     /// * `navigation`: - The navigation event to dispatch
     pub fn dispatch(&mut self, navigation: PageNavigation) -> Result<PageNavigation, PageError> {
-        let mut navigation = navigation;
+        if navigation == PageNavigation::Update {
+            self.maintain();
+            if let Some(alarm) = self.poll_alarms() {
+                return self.dispatch_rotating(alarm, false);
+            }
+        }
+        let rotate = navigation == PageNavigation::Update && self.auto_cycle_due();
+        self.dispatch_rotating(navigation, rotate)
+    }
+
+    /// The active page of the operational HMI must not be left
+    fn is_locked(&self) -> bool {
+        matches!(self.state, PageManagerState::Operational)
+            && self.pages[self.active].page.is_locked()
+    }
+
+    /// Dispatch a navigation - an update rotates to the next top level page if requested
+    fn dispatch_rotating(
+        &mut self,
+        navigation: PageNavigation,
+        rotate: bool,
+    ) -> Result<PageNavigation, PageError> {
+        let navigation = match rotate {
+            true => PageNavigation::Left,
+            false => self.resolve(navigation),
+        };
+        let navigation = self.apply_middleware(navigation);
+        let rotate = rotate && navigation == PageNavigation::Left;
+        let navigation = match rotate {
+            true => PageNavigation::Update,
+            false => navigation,
+        };
+        // a locked page is neither left nor rotated away from
+        let (navigation, rotate) = match (self.is_locked(), navigation) {
+            (false, _) => (navigation, rotate),
+            (true, PageNavigation::Reload) => (PageNavigation::Reload, false),
+            (true, _) => (PageNavigation::Update, false),
+        };
+        let result = self.dispatch_navigation(navigation, rotate);
+        match result {
+            Ok(_) => self.beat(),
+            Err(_) => {
+                warn!(
+                    "{:?} failed at page {}",
+                    navigation,
+                    self.pages[self.active].page.title()
+                );
+                self.give_feedback(Feedback::Error)
+            }
+        }
+        self.update_indicator();
+        self.announce();
+        result
+    }
+
+    fn dispatch_navigation(
+        &mut self,
+        navigation: PageNavigation,
+        rotate: bool,
+    ) -> Result<PageNavigation, PageError> {
+        let origin = PageId(self.active);
+        trace!(
+            "{:?} at page {}",
+            navigation,
+            self.pages[self.active].page.title()
+        );
+        let (mut navigation, rotate) = match self.leave_vetoed(navigation, rotate) {
+            true => (PageNavigation::Update, false),
+            false => (navigation, rotate),
+        };
         match navigation {
             PageNavigation::SystemStart => {
+                self.reset_transition();
                 self.activate_home(); // reset the ordinary page structure to home in case there is no startup page
-                match &mut self.startup {
-                    Some(page) => {
-                        navigation = page.update(None)?;
+                let due = self.startup.is_some() && self.frame_due();
+                if due {
+                    self.hint_refresh(Shown::Startup);
+                }
+                if let Some(page) = &mut self.startup {
+                    if let Some(size) = &self.display_size {
+                        page.set_display_info(size);
+                    }
+                    navigation = page.update_with_context(&mut self.context, None)?;
+                    if due {
                         page.display(&mut self.display);
                     }
-                    None => (),
                 }
             }
-            PageNavigation::SystemStop => match &mut self.shutdown {
-                Some(page) => {
-                    navigation = page.update(None)?;
-                    page.display(&mut self.display);
+            PageNavigation::SystemStop => {
+                if !matches!(self.state, PageManagerState::Shutdown) {
+                    self.reset_transition();
+                    self.start_shutdown();
                 }
-                None => (),
-            },
-            PageNavigation::Left => {
-                // when navigating left, we turn around at the end; in case there is no previous navigation
-                if !self.activate_left() {
-                    self.activate_most_right();
+                let done = self.poll_shutdown_tasks();
+                let due = self.shutdown.is_some() && self.frame_due();
+                if due {
+                    self.hint_refresh(Shown::Shutdown);
+                }
+                if let Some(page) = &mut self.shutdown {
+                    if let Some(size) = &self.display_size {
+                        page.set_display_info(size);
+                    }
+                    if !self.shutdown_expired {
+                        navigation = page.update_with_context(&mut self.context, None)?;
+                        self.shutdown_expired = navigation == PageNavigation::Terminate;
+                    }
+                    if due {
+                        page.display(&mut self.display);
+                    }
                 }
-                self.update()?;
+                // the shutdown page stays beyond its lifetime until the shutdown tasks are done
+                let over = self.shutdown_expired || self.shutdown.is_none();
+                navigation = match (over, done) {
+                    (true, true) => PageNavigation::Terminate,
+                    (true, false) => PageNavigation::SystemStop,
+                    (false, _) => navigation,
+                };
+            }
+            PageNavigation::Terminate => {}
+            PageNavigation::Left => {
+                self.navigate_left();
+                self.start_transition(origin, Transition::Left);
+                self.enter_active(origin, None);
+                self.update_active()?;
                 navigation = PageNavigation::Update;
             }
             PageNavigation::Right => {
-                self.activate_right();
-                self.update()?;
+                self.navigate_right();
+                self.start_transition(origin, Transition::Right);
+                self.enter_active(origin, None);
+                self.update_active()?;
                 navigation = PageNavigation::Update;
             }
             PageNavigation::Home => {
                 self.activate_home();
-                self.update()?;
+                self.bounced = None;
+                self.start_transition(origin, Transition::Up);
+                self.enter_active(origin, None);
+                self.update_active()?;
                 navigation = PageNavigation::Update;
             }
             PageNavigation::Up => {
                 self.activate_up();
-                self.update()?;
+                self.bounced = None;
+                self.start_transition(origin, Transition::Up);
+                self.enter_active(origin, None);
+                self.update_active()?;
                 navigation = PageNavigation::Update;
             }
             PageNavigation::NthSubpage(index) => {
                 self.activate_down();
+                self.bounced = None;
                 let mut index: usize = index;
                 while index > 1 {
                     self.activate_left();
                     index -= 1;
                 }
-                self.update()?;
+                self.start_transition(origin, Transition::Down);
+                self.enter_active(origin, None);
+                self.update_active()?;
+                navigation = PageNavigation::Update;
+            }
+            PageNavigation::ToWith(id, _) | PageNavigation::To(id) => {
+                if id.0 >= self.pages.len() || !self.is_accessible(id.0) {
+                    return Err(PageError);
+                }
+                let param = match navigation {
+                    PageNavigation::ToWith(_, param) => Some(param),
+                    _ => None,
+                };
+                self.active = id.0;
+                self.mode = self.mode_of(id.0);
+                self.bounced = None;
+                self.start_transition(origin, Transition::Down);
+                self.enter_active(origin, param);
+                self.update_active()?;
+                navigation = PageNavigation::Update;
+            }
+            PageNavigation::Reload => {
+                self.pages[self.active].page.reload();
+                // the titles of the sub pages might have changed as well
+                self.invalidate_titles();
+                self.update_active()?;
                 navigation = PageNavigation::Update;
             }
-            PageNavigation::Update => {
-                self.update()?;
+            // `FirstOf` is resolved by `dispatch_rotating()` - unless returned by a middleware
+            PageNavigation::Update | PageNavigation::FirstOf(_) => {
+                if rotate {
+                    self.bounced = None;
+                    if !self.activate_left() {
+                        self.activate_most_right();
+                    }
+                    self.start_transition(origin, Transition::Left);
+                    self.enter_active(origin, None);
+                }
+                self.update_active()?;
             }
         };
 
         // update the internal state for Correct HMI interaction update
         match navigation {
             PageNavigation::SystemStart => self.state = PageManagerState::Startup,
-            PageNavigation::SystemStop => self.state = PageManagerState::Shutdown,
-            _ => self.state = PageManagerState::Operational,
+            PageNavigation::SystemStop | PageNavigation::Terminate => {
+                self.state = PageManagerState::Shutdown
+            }
+            _ => {
+                if let PageManagerState::Shutdown = self.state {
+                    debug!("shutdown cancelled");
+                }
+                self.state = PageManagerState::Operational
+            }
+        }
+        self.changed = PageId(self.active) != origin;
+        self.take_feedback_of(origin);
+        let level = self
+            .page_mut(origin)
+            .and_then(|page| page.take_access_level());
+        if self.changed {
+            debug!("page {} activated", self.pages[self.active].page.title());
+            self.vetoed = None;
+            self.observe_page_change(origin);
+            if let Some(page) = self.page_mut(origin) {
+                page.on_leave();
+            }
+            self.take_feedback_of(PageId(self.active));
+        }
+        // after the page change, since a lower level navigates home on its own
+        if let Some(level) = level {
+            self.set_access_level(level);
         }
 
         Ok(navigation)
     }
 }
 
-impl<'a, D> Drop for PageManager<'a, D> {
-    /// TODO - update to remove everything
-    fn drop(&mut self) {
-        // forward list
-        let mut cur_horizontal = self.left.take();
-        while let Some(mut boxed_node) = cur_horizontal {
-            cur_horizontal = boxed_node.left.take();
-        }
-        // backward list
-        let mut cur_horizontal = self.right.take();
-        while let Some(mut boxed_node) = cur_horizontal {
-            cur_horizontal = boxed_node.left.take();
-        }
+impl<'a, D: TransitionDisplay, C> PageManager<'a, D, C> {
+    /// Animate page changes by the display
+    ///
+    /// A page change by navigation is rendered over a few frames, e.g. the
+    /// new page slides in from the direction navigated to. The first frame is
+    /// rendered with the page change, the following ones by `animate()` or
+    /// by the next dispatches and updates. The last frame shows the new page
+    /// only. Transitions are disabled by default.
+    ///
+    /// Arguments
+    ///
+    /// * `frames`: - The number of frames of a transition, 0 or 1 disables transitions
+    pub fn set_transition_frames(&mut self, frames: u8) {
+        self.reset_transition();
+        self.transition_frames = frames;
+        self.show_transition = Some(D::set_transition);
+    }
+}
+
+impl<'a, D: RefreshDisplay, C> PageManager<'a, D, C> {
+    /// Hint the display to refresh fully or partially, e.g. an e-paper display
+    ///
+    /// The first frame and every n-th page change are full refreshes, the
+    /// other page changes and the updates of the page shown are partial
+    /// refreshes. See also `request_full_refresh()`.
+    ///
+    /// Arguments
+    ///
+    /// * `page_changes`: - Page changes per full refresh, 1 for a full refresh with every page change
+    pub fn set_full_refresh_interval(&mut self, page_changes: u32) {
+        self.full_refresh_interval = page_changes;
+        self.page_changes = 0;
+        self.shown = None;
+        self.show_refresh = Some(D::set_refresh);
     }
 }
 
 pub struct SubPageIterator<'a, P> {
-    left: Option<&'a Node<P>>,
+    pages: &'a [Node<P>],
+    left: Option<usize>,
+    access_level: AccessLevel,
 }
 
-impl<'a, D> PageManager<'a, D> {
-    pub fn sub_iter(&self) -> SubPageIterator<Box<dyn PageInterface<D> + 'a>> {
+impl<'a, D, C> PageManager<'a, D, C> {
+    pub fn sub_iter(&self) -> SubPageIterator<'_, Box<dyn PageInterface<D, C> + 'a>> {
         SubPageIterator {
-            left: self.down.as_deref(),
+            pages: &self.pages,
+            left: self.pages[self.active].down,
+            access_level: self.access_level,
         }
     }
 }
 
-impl<'a, D> Iterator for SubPageIterator<'a, Box<dyn PageInterface<D> + 'a>> {
-    type Item = &'a Box<dyn PageInterface<D> + 'a>;
+impl<'a, P> Iterator for SubPageIterator<'a, P> {
+    type Item = &'a P;
     fn next(&mut self) -> Option<Self::Item> {
-        self.left.map(|node| {
-            self.left = node.left.as_deref();
-            &node.page
-        })
+        while let Some(index) = self.left {
+            let node = &self.pages[index];
+            self.left = node.left;
+            if node.access <= self.access_level && !node.hidden {
+                return Some(&node.page);
+            }
+        }
+        None
     }
 }
 
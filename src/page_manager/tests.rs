@@ -1,7 +1,11 @@
+use crate::testing::MemoryStore;
 use crate::*;
 
 mod mocks {
 
+    use std::cell::Cell;
+    use std::rc::Rc;
+
     use super::super::SubPageIterator;
     use super::PageInterface;
     use super::*;
@@ -39,14 +43,14 @@ mod mocks {
             self.expected_updates.push(s.to_string());
         }
 
-        pub fn update(&mut self, s: &String) {
-            self.collected_updates.push(s.clone());
+        pub fn update(&mut self, s: &str) {
+            self.collected_updates.push(s.to_string());
         }
     }
 
     impl Drop for DisplayDriverMock {
         fn drop(&mut self) {
-            if self.expected_updates.len() > 0 {
+            if !self.expected_updates.is_empty() {
                 assert_eq!(
                     self.expected_updates, self.collected_updates,
                     "Testing {}",
@@ -68,7 +72,11 @@ mod mocks {
         }
     }
 
-    impl PageBaseInterface for PageMock {}
+    impl PageBaseInterface for PageMock {
+        fn title(&self) -> &str {
+            &self.message
+        }
+    }
 
     impl PageInteractionInterface for PageMock {}
 
@@ -82,6 +90,27 @@ mod mocks {
         fn display(&self, _display_driver: &mut DisplayDriverStub) {}
     }
 
+    impl PageInterface<DisplayDriverStub> for page::ShutdownPage {
+        fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+    }
+
+    /// Page that counts how often pages of its kind are dropped
+    pub struct DropCountingPage(pub Rc<Cell<usize>>);
+
+    impl Drop for DropCountingPage {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    impl PageBaseInterface for DropCountingPage {}
+
+    impl PageInteractionInterface for DropCountingPage {}
+
+    impl PageInterface<DisplayDriverStub> for DropCountingPage {
+        fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+    }
+
     pub fn check_page_iteration<'a>(
         context: &str,
         expected: Vec<String>,
@@ -96,6 +125,8 @@ mod mocks {
 
 use super::*;
 use mocks::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 
 #[test]
 fn update_page_manager() {
@@ -285,6 +316,60 @@ fn shutdown_navigation() {
     m.dispatch(PageNavigation::SystemStop).unwrap();
 }
 
+#[test]
+fn terminate_after_shutdown() {
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    assert_eq!(
+        m.dispatch(PageNavigation::SystemStop).unwrap(),
+        PageNavigation::Terminate
+    );
+
+    m.register_shutdown(Box::new(page::ShutdownPage::new("Bye", 2)));
+    m.dispatch(PageNavigation::SystemStart).unwrap();
+    assert_eq!(
+        m.dispatch(PageNavigation::SystemStop).unwrap(),
+        PageNavigation::SystemStop
+    );
+    assert_eq!(
+        m.dispatch(PageNavigation::SystemStop).unwrap(),
+        PageNavigation::Terminate
+    );
+}
+
+#[test]
+fn cancel_shutdown_within_lifetime() {
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.register(Box::new(PageMock::new("Second")));
+    m.register_shutdown(Box::new(
+        page::ShutdownPage::new("Bye", 3).with_cancel(Interaction::Back),
+    ));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    m.dispatch(PageNavigation::SystemStop).unwrap();
+    assert_eq!(
+        m.dispatch_interaction(Interaction::Action).unwrap(),
+        PageNavigation::SystemStop
+    );
+    assert_eq!(
+        m.dispatch_interaction(Interaction::Back).unwrap(),
+        PageNavigation::Update
+    );
+    assert!(matches!(m.state, PageManagerState::Operational));
+    assert_eq!(m.active().title, "Second");
+
+    // beyond the lifetime the shutdown cannot be cancelled
+    m.dispatch(PageNavigation::SystemStop).unwrap();
+    m.dispatch(PageNavigation::SystemStop).unwrap();
+    assert_eq!(
+        m.dispatch(PageNavigation::SystemStop).unwrap(),
+        PageNavigation::Terminate
+    );
+    assert_eq!(
+        m.dispatch_interaction(Interaction::Back).unwrap(),
+        PageNavigation::Terminate
+    );
+}
+
 #[test]
 fn home_two_pages_and_two_subpages_and_two_subsubpages_navigation() {
     let home = PageMock::new("Home");
@@ -408,3 +493,2313 @@ fn home_and_three_subpages() {
     m.dispatch(PageNavigation::NthSubpage(0)).unwrap();
     m.dispatch(PageNavigation::Home).unwrap();
 }
+
+#[test]
+fn breadcrumb_follows_navigation() {
+    let home = PageMock::new("Home");
+    let level_2_first = PageMock::new("level_2_first");
+    let level_2_second = PageMock::new("level_2_second");
+    let level_32_first = PageMock::new("level_32_first");
+    let level_32_second = PageMock::new("level_32_second");
+
+    let d = DisplayDriverStub {};
+    let mut m = PageManager::new(d, Box::new(home));
+    m.register_sub(Box::new(level_2_first));
+    m.register(Box::new(level_2_second));
+    m.register_sub(Box::new(level_32_first));
+    m.register(Box::new(level_32_second));
+
+    let path: Vec<&str> = m.breadcrumb().collect();
+    assert_eq!(path, vec!["Home", "level_2_second", "level_32_second"]);
+
+    m.dispatch(PageNavigation::Home).unwrap();
+    let path: Vec<&str> = m.breadcrumb().collect();
+    assert_eq!(path, vec!["Home"]);
+
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    let path: Vec<&str> = m.breadcrumb().collect();
+    assert_eq!(path, vec!["Home", "level_2_second", "level_32_first"]);
+
+    m.dispatch(PageNavigation::Up).unwrap();
+    m.dispatch(PageNavigation::Right).unwrap();
+    let path: Vec<&str> = m.breadcrumb().collect();
+    assert_eq!(path, vec!["Home", "level_2_first"]);
+}
+
+#[test]
+fn query_the_active_page() {
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    let settings = m.register_sub(Box::new(page::MenuPage::new(
+        page::BasicPage::new("Settings", None),
+        None,
+    )));
+    let network = m.register_sub(Box::new(page::DynamicTextPage::new(
+        page::BasicPage::new("Network", None),
+        Box::new(|| String::from("Connected")),
+    )));
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.active_title(), "Home");
+    assert_eq!(m.active_id(), PageId(0));
+    assert_eq!(m.active_depth(), 0);
+    assert!(m.active_is_menu());
+
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    assert_eq!(m.active_title(), "Settings");
+    assert_eq!(m.active_id(), settings);
+    assert_eq!(m.active_depth(), 1);
+    assert!(m.active_is_menu());
+
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    assert_eq!(m.active_title(), "Network");
+    assert_eq!(m.active_id(), network);
+    assert_eq!(m.active_depth(), 2);
+    assert!(!m.active_is_menu());
+    assert_eq!(m.active_title(), m.active().title);
+}
+
+#[test]
+fn query_the_sub_pages() {
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), Some("Back"));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    m.register_sub(Box::new(PageMock::new("Info")));
+    m.register(Box::new(PageMock::new("Network")));
+    let service = m.register(Box::new(PageMock::new("Service")));
+    m.register(Box::new(PageMock::new("About")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.subpage_count(), 4);
+    // pages of a higher access level are not counted
+    m.set_page_access(service, AccessLevel::Service);
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.subpage_count(), 3);
+    assert_eq!(m.selected_subpage_index(), Some(1));
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    assert_eq!(m.selected_subpage_index(), Some(3));
+    // the back entry
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    assert_eq!(m.selected_subpage_index(), None);
+
+    // a page without sub pages
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    assert_eq!(m.subpage_count(), 0);
+    assert_eq!(m.selected_subpage_index(), None);
+}
+
+#[test]
+fn navigate_path() {
+    let home = PageMock::new("Home");
+    let info = PageMock::new("Info");
+    let menu = PageMock::new("Menu");
+    let config_1 = PageMock::new("Config-1");
+    let sub_menu = PageMock::new("Sub-Menu");
+    let config_2 = PageMock::new("Config-2");
+    let config_3 = PageMock::new("Config-3");
+
+    let mut d = DisplayDriverMock::default("navigate path");
+    d.expect("Config-2");
+    d.expect("Config-3");
+    d.expect("Info");
+    d.expect("Home");
+    let mut m = PageManager::new(d, Box::new(home));
+    m.register(Box::new(info));
+    m.activate_home();
+    m.register_sub(Box::new(menu));
+    m.register_sub(Box::new(config_1));
+    m.register(Box::new(sub_menu));
+    m.register_sub(Box::new(config_2));
+    m.register(Box::new(config_3));
+    m.activate_home();
+
+    m.navigate_path(&["Menu", "Sub-Menu", "Config-2"]).unwrap();
+    m.navigate_path(&["Home", "Menu", "Sub-Menu", "Config-3"])
+        .unwrap();
+    let path: Vec<&str> = m.breadcrumb().collect();
+    assert_eq!(path, vec!["Home", "Menu", "Sub-Menu", "Config-3"]);
+
+    // unknown path leaves the active page untouched
+    assert!(m.navigate_path(&["Menu", "Unknown"]).is_err());
+    assert!(m.navigate_path(&["Info", "Menu"]).is_err());
+    let path: Vec<&str> = m.breadcrumb().collect();
+    assert_eq!(path, vec!["Home", "Menu", "Sub-Menu", "Config-3"]);
+
+    m.navigate_path(&["Info"]).unwrap();
+    m.navigate_path(&[]).unwrap();
+}
+
+#[test]
+fn tree_iter_walks_depth_first() {
+    let home = PageMock::new("Home");
+    let info = PageMock::new("Info");
+    let menu = PageMock::new("Menu");
+    let config_1 = PageMock::new("Config-1");
+    let sub_menu = PageMock::new("Sub-Menu");
+    let config_2 = PageMock::new("Config-2");
+    let config_3 = PageMock::new("Config-3");
+
+    let d = DisplayDriverStub {};
+    let mut m = PageManager::new(d, Box::new(home));
+    let info_id = m.register(Box::new(info));
+    m.activate_home();
+    m.register_sub(Box::new(menu));
+    m.register_sub(Box::new(config_1));
+    let sub_menu_id = m.register(Box::new(sub_menu));
+    m.register_sub(Box::new(config_2));
+    m.register(Box::new(config_3));
+
+    let expected = vec![
+        ("Home", 0),
+        ("Menu", 1),
+        ("Config-1", 2),
+        ("Sub-Menu", 2),
+        ("Config-2", 3),
+        ("Config-3", 3),
+        ("Info", 0),
+    ];
+    let check = |m: &PageManager<DisplayDriverStub>| {
+        let tree: Vec<(&str, usize)> = m.tree_iter().map(|p| (p.title, p.depth)).collect();
+        assert_eq!(tree, expected);
+    };
+    check(&m);
+    m.dispatch(PageNavigation::Right).unwrap();
+    check(&m);
+    m.dispatch(PageNavigation::Home).unwrap();
+    check(&m);
+    m.dispatch(PageNavigation::Left).unwrap();
+    check(&m);
+    m.navigate_path(&["Menu", "Config-1"]).unwrap();
+    check(&m);
+
+    assert_eq!(m.page_count(), 7);
+    let ids: Vec<PageId> = m.tree_iter().map(|p| p.id).collect();
+    assert_eq!(ids[0], PageId(0));
+    assert_eq!(ids[3], sub_menu_id);
+    assert_eq!(ids[6], info_id);
+}
+
+fn three_pages_with_policy(
+    context: &str,
+    top_level: NavigationPolicy,
+    sub_level: NavigationPolicy,
+) -> PageManager<'static, DisplayDriverMock> {
+    let mut m = PageManager::new(
+        DisplayDriverMock::default(context),
+        Box::new(PageMock::new("a")),
+    );
+    m.register(Box::new(PageMock::new("b")));
+    m.register(Box::new(PageMock::new("c")));
+    m.set_navigation_policy(top_level, sub_level);
+    m.activate_home();
+    m
+}
+
+fn navigate(m: &mut PageManager<DisplayDriverMock>, navigation: &str) {
+    for c in navigation.chars() {
+        let navigation = match c {
+            'l' => PageNavigation::Left,
+            _ => PageNavigation::Right,
+        };
+        m.dispatch(navigation).unwrap();
+    }
+}
+
+#[test]
+fn navigation_policy_wrap_right_clamp_left() {
+    let policy = NavigationPolicy::new(EdgeBehavior::Clamp, EdgeBehavior::Wrap);
+    let mut m = three_pages_with_policy("wrap right clamp left", policy, policy);
+    for page in expect("b c c c b a c b") {
+        m.display.expect(&page);
+    }
+    navigate(&mut m, "llllrrrr");
+}
+
+#[test]
+fn navigation_policy_bounce() {
+    let policy = NavigationPolicy::new(EdgeBehavior::Bounce, EdgeBehavior::Bounce);
+    let mut m = three_pages_with_policy("bounce", policy, NavigationPolicy::default());
+    for page in expect("b c b a b c b a b c") {
+        m.display.expect(&page);
+    }
+    navigate(&mut m, "lllllllrrr");
+}
+
+#[test]
+fn navigation_policy_top_and_sub_level() {
+    let clamp = NavigationPolicy::new(EdgeBehavior::Clamp, EdgeBehavior::Clamp);
+    let wrap = NavigationPolicy::new(EdgeBehavior::Wrap, EdgeBehavior::Wrap);
+    let mut m = three_pages_with_policy("top and sub level", clamp, wrap);
+    m.register_sub(Box::new(PageMock::new("a1")));
+    m.register(Box::new(PageMock::new("a2")));
+    m.activate_home();
+    for page in expect("a a a1 a2 a1 a2 a1") {
+        m.display.expect(&page);
+    }
+    navigate(&mut m, "r");
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    navigate(&mut m, "rrll");
+}
+
+#[test]
+fn active_page_after_dispatch() {
+    let d = DisplayDriverStub {};
+    let mut m = PageManager::new(d, Box::new(PageMock::new("Home")));
+    let foo = m.register(Box::new(PageMock::new("foo")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    let active = m.active();
+    assert_eq!(active.title, "Home");
+    assert_eq!(active.id, PageId(0));
+    assert!(active.changed);
+
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert!(!m.active().changed);
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    let active = m.active();
+    assert_eq!(active.title, "foo");
+    assert_eq!(active.id, foo);
+    assert!(active.changed);
+
+    // there is no page at the right of the top level
+    m.dispatch(PageNavigation::Right).unwrap();
+    m.dispatch(PageNavigation::Right).unwrap();
+    assert_eq!(m.active().title, "Home");
+    assert!(!m.active().changed);
+}
+
+// function keys are shortcuts to the sub pages
+struct ShortcutPage;
+
+impl PageBaseInterface for ShortcutPage {}
+
+impl PageInteractionInterface for ShortcutPage {
+    fn dispatch_custom(&mut self, code: u8) -> PageNavigation {
+        PageNavigation::NthSubpage(code as usize)
+    }
+}
+
+impl PageInterface<DisplayDriverStub> for ShortcutPage {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn dispatch_custom_events() {
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(ShortcutPage));
+    m.register_sub(Box::new(PageMock::new("first")));
+    let second = m.register(Box::new(PageMock::new("second")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch_custom(2).unwrap();
+    assert_eq!(m.active().id, second);
+
+    // ignored by pages not knowing the code
+    m.dispatch_custom(1).unwrap();
+    assert_eq!(m.active().id, second);
+}
+
+fn drop_counting_tree(drops: &Rc<Cell<usize>>) -> PageManager<'static, DisplayDriverStub> {
+    let page = || Box::new(DropCountingPage(drops.clone()));
+    let mut m = PageManager::new(DisplayDriverStub {}, page());
+    m.register(page());
+    m.register_sub(page());
+    m.register(page());
+    m.register_sub(page());
+    m.register(page());
+    m.activate_up();
+    m.register(page());
+    m.register_sub(page());
+    m.activate_home();
+    m.register_sub(page());
+    m.register(page());
+    m.register_startup(page());
+    m.register_shutdown(page());
+    m
+}
+
+#[test]
+fn drop_removes_all_pages() {
+    let drops = Rc::new(Cell::new(0));
+    let mut m = drop_counting_tree(&drops);
+    assert_eq!(m.page_count(), 10);
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    assert_eq!(m.breadcrumb().count(), 3);
+    drop(m);
+    assert_eq!(drops.get(), 12);
+}
+
+#[test]
+fn drop_deep_tree_without_recursion() {
+    let drops = Rc::new(Cell::new(0));
+    let mut m = PageManager::new(
+        DisplayDriverStub {},
+        Box::new(DropCountingPage(drops.clone())),
+    );
+    for _ in 0..100_000 {
+        m.register_sub(Box::new(DropCountingPage(drops.clone())));
+    }
+    m.activate_up();
+    drop(m);
+    assert_eq!(drops.get(), 100_001);
+}
+
+#[test]
+fn clear_page_tree() {
+    let drops = Rc::new(Cell::new(0));
+    let mut m = drop_counting_tree(&drops);
+    m.clear(Box::new(PageMock::new("New Home")));
+    assert_eq!(drops.get(), 12);
+    assert_eq!(m.page_count(), 1);
+    assert_eq!(m.active().id, PageId(0));
+    assert_eq!(m.register(Box::new(PageMock::new("foo"))), PageId(1));
+    let tree: Vec<&str> = m.tree_iter().map(|p| p.title).collect();
+    assert_eq!(tree, vec!["New Home", "foo"]);
+}
+
+/// Application context counting actions and providing a sensor value
+struct CounterContext {
+    actions: usize,
+    sensor: &'static str,
+}
+
+struct ContextPage {
+    text: String,
+}
+
+impl PageBaseInterface for ContextPage {}
+
+impl PageInteractionInterface for ContextPage {}
+
+impl PageInterface<DisplayDriverMock, CounterContext> for ContextPage {
+    fn display(&self, display_driver: &mut DisplayDriverMock) {
+        display_driver.update(&self.text);
+    }
+
+    fn update_with_context<'a>(
+        &mut self,
+        context: &mut CounterContext,
+        _subpages: Option<Box<dyn Iterator<Item = SubPageItem<'a>> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.text = format!("{}:{}", context.sensor, context.actions);
+        Ok(PageNavigation::Update)
+    }
+
+    fn dispatch_with_context(
+        &mut self,
+        context: &mut CounterContext,
+        interaction: Interaction,
+    ) -> PageNavigation {
+        if let Interaction::Action = interaction {
+            context.actions += 1;
+        }
+        PageNavigation::Update
+    }
+}
+
+#[test]
+fn pages_access_application_context() {
+    let mut d = DisplayDriverMock::default("context");
+    d.expect("hot:0");
+    d.expect("hot:1");
+    d.expect("cold:1");
+    let context = CounterContext {
+        actions: 0,
+        sensor: "hot",
+    };
+    let home = ContextPage {
+        text: String::new(),
+    };
+    let mut m = PageManager::with_context(d, Box::new(home), context);
+    m.update().unwrap();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    assert_eq!(m.context().actions, 1);
+    m.context_mut().sensor = "cold";
+    m.update().unwrap();
+}
+
+#[test]
+fn lazy_page_dropped_when_left() {
+    let constructed = Rc::new(Cell::new(0));
+    let drops = Rc::new(Cell::new(0));
+    let (c, d) = (constructed.clone(), drops.clone());
+    let lazy = page::LazyPage::new(
+        page::BasicPage::new("Lazy", None),
+        Box::new(move || {
+            c.set(c.get() + 1);
+            Box::new(DropCountingPage(d.clone()))
+        }),
+    );
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.register(Box::new(lazy));
+    assert_eq!(constructed.get(), 0);
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!((constructed.get(), drops.get()), (1, 0));
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!((constructed.get(), drops.get()), (1, 1));
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!((constructed.get(), drops.get()), (2, 1));
+}
+
+#[test]
+fn lazy_page_dropped_when_access_is_lowered() {
+    let constructed = Rc::new(Cell::new(0));
+    let drops = Rc::new(Cell::new(0));
+    let (c, d) = (constructed.clone(), drops.clone());
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    let service = m.register_sub(Box::new(page::LazyPage::new(
+        page::BasicPage::new("Service", None),
+        Box::new(move || {
+            c.set(c.get() + 1);
+            Box::new(DropCountingPage(d.clone()))
+        }),
+    )));
+    m.set_page_access(service, AccessLevel::Service);
+    m.set_access_level(AccessLevel::Service);
+    m.navigate_to(service).unwrap();
+    assert_eq!((constructed.get(), drops.get()), (1, 0));
+
+    m.set_access_level(AccessLevel::User);
+    assert_eq!((m.active().title, m.active().changed), ("Home", true));
+    assert_eq!((constructed.get(), drops.get()), (1, 1));
+}
+
+#[test]
+fn lazy_page_dropped_when_left_by_path() {
+    let constructed = Rc::new(Cell::new(0));
+    let drops = Rc::new(Cell::new(0));
+    let (c, d) = (constructed.clone(), drops.clone());
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.register_sub(Box::new(page::LazyPage::new(
+        page::BasicPage::new("Lazy", None),
+        Box::new(move || {
+            c.set(c.get() + 1);
+            Box::new(DropCountingPage(d.clone()))
+        }),
+    )));
+    m.register(Box::new(PageMock::new("Info")));
+    m.dispatch(PageNavigation::Home).unwrap();
+
+    m.navigate_path(&["Home", "Lazy"]).unwrap();
+    assert_eq!((m.active().title, m.active().changed), ("Lazy", true));
+    assert_eq!((constructed.get(), drops.get()), (1, 0));
+    m.navigate_path(&["Home", "Info"]).unwrap();
+    assert_eq!((m.active().title, m.active().changed), ("Info", true));
+    assert_eq!((constructed.get(), drops.get()), (1, 1));
+}
+
+impl PageInterface<DisplayDriverStub> for page::MenuPage<'_> {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+fn menu_tree(with_d: bool) -> PageManager<'static, DisplayDriverStub> {
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    m.register_sub(Box::new(PageMock::new("A")));
+    m.register(Box::new(PageMock::new("B")));
+    m.register_sub(Box::new(PageMock::new("C")));
+    if with_d {
+        m.register(Box::new(PageMock::new("D")));
+    }
+    m.dispatch(PageNavigation::Home).unwrap();
+    m
+}
+
+#[test]
+fn save_and_restore_state() {
+    let mut store = MemoryStore::default();
+    let mut m = menu_tree(true);
+    assert!(m.restore_state(&mut store).is_err());
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    m.save_state(&mut store).unwrap();
+
+    let mut m = menu_tree(true);
+    m.restore_state(&mut store).unwrap();
+    let path: Vec<&str> = m.breadcrumb().collect();
+    assert_eq!(path, vec!["Home", "B", "D"]);
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.pages[m.active].page.selected(), Some(2));
+
+    // a state not fitting the page tree leaves the active page untouched
+    let mut m = menu_tree(false);
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    assert!(m.restore_state(&mut store).is_err());
+    let path: Vec<&str> = m.breadcrumb().collect();
+    assert_eq!(path, vec!["Home", "A"]);
+}
+
+fn lazy_menu_tree() -> PageManager<'static, DisplayDriverStub> {
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    m.register_sub(Box::new(PageMock::new("A")));
+    m.register(Box::new(page::LazyPage::new(
+        page::BasicPage::new("Menu", None),
+        Box::new(|| {
+            Box::new(page::MenuPage::new(
+                page::BasicPage::new("Menu", None),
+                None,
+            ))
+        }),
+    )));
+    m.register_sub(Box::new(PageMock::new("B")));
+    m.register(Box::new(PageMock::new("C")));
+    m.register(Box::new(PageMock::new("D")));
+    m.set_page_hidden(PageId(5), true);
+    m.dispatch(PageNavigation::Home).unwrap();
+    m
+}
+
+#[test]
+fn restore_hidden_page_below_lazy_page() {
+    let mut store = MemoryStore::default();
+    let mut m = lazy_menu_tree();
+    m.navigate_path(&["Home", "Menu"]).unwrap();
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    m.navigate_to(PageId(5)).unwrap();
+    m.save_state(&mut store).unwrap();
+    // the lazy page is dropped with its selection when left to the hidden page
+    let data = store.0.get_mut(STATE_KEY).unwrap();
+    assert_eq!(data[6..8], [0, 0]);
+    data[6] = 2;
+
+    let mut m = lazy_menu_tree();
+    m.restore_state(&mut store).unwrap();
+    let path: Vec<&str> = m.breadcrumb().collect();
+    assert_eq!(path, vec!["Home", "Menu", "D"]);
+    m.dispatch(PageNavigation::Up).unwrap();
+    assert_eq!(m.pages[m.active].page.selected(), Some(2));
+}
+
+/// Page that reports the display columns it got told
+struct ColsPage(Rc<Cell<usize>>);
+
+impl PageBaseInterface for ColsPage {
+    fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.0.set(info.cols());
+    }
+}
+
+impl PageInteractionInterface for ColsPage {}
+
+impl PageInterface<DisplayDriverStub> for ColsPage {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn pages_get_display_info() {
+    let cols = Rc::new(Cell::new(0));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.register(Box::new(ColsPage(cols.clone())));
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(cols.get(), 0);
+    m.set_display_info(&DisplaySize::new(16, 2));
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(cols.get(), 16);
+}
+
+// the trace output of defmt takes the titles of the pages navigated
+#[cfg(not(feature = "defmt"))]
+#[test]
+fn sub_page_titles_are_cached() {
+    /// Page that counts how often its title is asked for
+    struct TitleCountingPage(Rc<Cell<usize>>);
+
+    impl PageBaseInterface for TitleCountingPage {
+        fn title(&self) -> &str {
+            self.0.set(self.0.get() + 1);
+            "Counting"
+        }
+    }
+
+    impl PageInteractionInterface for TitleCountingPage {}
+
+    impl PageInterface<DisplayDriverStub> for TitleCountingPage {
+        fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+    }
+
+    let calls = Rc::new(Cell::new(0));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.register_sub(Box::new(TitleCountingPage(calls.clone())));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(calls.get(), 1);
+
+    m.invalidate_titles();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(calls.get(), 2);
+
+    // registration changes the page tree
+    m.register(Box::new(PageMock::new("Info")));
+    m.dispatch(PageNavigation::Right).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn update_with_rich_sub_pages() {
+    /// Menu recording the sub pages it got
+    struct RecordingMenu(Rc<RefCell<Vec<String>>>);
+
+    impl PageBaseInterface for RecordingMenu {
+        fn update_with_subpages<'a>(
+            &mut self,
+            subpages: Option<Box<dyn Iterator<Item = SubPageItem<'a>> + 'a>>,
+        ) -> Result<PageNavigation, PageError> {
+            let mut recorded = self.0.borrow_mut();
+            recorded.clear();
+            for s in subpages.into_iter().flatten() {
+                recorded.push(format!(
+                    "{} {} {} {} {:?}",
+                    s.title,
+                    s.id.index(),
+                    s.hidden,
+                    s.disabled,
+                    s.preview
+                ));
+            }
+            Ok(PageNavigation::Update)
+        }
+
+        fn is_menu(&self) -> bool {
+            true
+        }
+    }
+
+    impl PageInteractionInterface for RecordingMenu {}
+
+    impl PageInterface<DisplayDriverStub> for RecordingMenu {
+        fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+    }
+
+    /// Page previewing the value of a setting
+    struct Brightness(Rc<Cell<u8>>, String);
+
+    impl PageBaseInterface for Brightness {
+        fn title(&self) -> &str {
+            "Brightness"
+        }
+
+        fn preview(&self) -> Option<&str> {
+            Some(&self.1)
+        }
+
+        fn update<'a>(
+            &mut self,
+            _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
+        ) -> Result<PageNavigation, PageError> {
+            self.1 = format!("{}%", self.0.get());
+            Ok(PageNavigation::Update)
+        }
+    }
+
+    impl PageInteractionInterface for Brightness {}
+
+    impl PageInterface<DisplayDriverStub> for Brightness {
+        fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+    }
+
+    let recorded = Rc::new(RefCell::new(Vec::new()));
+    let brightness = Rc::new(Cell::new(80));
+    let mut m = PageManager::new(
+        DisplayDriverStub,
+        Box::new(RecordingMenu(Rc::clone(&recorded))),
+    );
+    m.register_sub(Box::new(PageMock::new("Info")));
+    let service = m.register(Box::new(PageMock::new("Service")));
+    let calibration = m.register(Box::new(PageMock::new("Calibration")));
+    m.register(Box::new(Brightness(
+        Rc::clone(&brightness),
+        String::from("80%"),
+    )));
+    m.set_page_access(service, AccessLevel::Service);
+    m.set_page_hidden(calibration, true);
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(
+        *recorded.borrow(),
+        vec![
+            "Info 1 false false None",
+            "Service 2 false true None",
+            "Calibration 3 true false None",
+            "Brightness 4 false false Some(\"80%\")",
+        ]
+    );
+
+    // the preview follows the page
+    brightness.set(60);
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    m.dispatch(PageNavigation::Up).unwrap();
+    assert_eq!(
+        recorded.borrow()[3],
+        "Brightness 4 false false Some(\"60%\")"
+    );
+}
+
+#[test]
+fn titles_of_sub_pages_shown_for_pages_updated_by_titles() {
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    m.register_sub(Box::new(PageMock::new("Info")));
+    let service = m.register(Box::new(PageMock::new("Service")));
+    let calibration = m.register(Box::new(PageMock::new("Calibration")));
+    m.register(Box::new(PageMock::new("About")));
+    m.set_page_access(service, AccessLevel::Service);
+    m.set_page_hidden(calibration, true);
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.subpage_count(), 2);
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    assert_eq!(m.active_title(), "About");
+}
+
+fn level_of_pages() -> PageManager<'static, DisplayDriverStub> {
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.register(Box::new(PageMock::new("A")));
+    m.register(Box::new(PageMock::new("B")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m
+}
+
+#[test]
+fn debounce_events() {
+    let mut m = level_of_pages();
+    let next = |timestamp| InteractionEvent::new(Interaction::Next, timestamp);
+    // without debounce all events are dispatched
+    m.dispatch_event(next(0)).unwrap();
+    m.dispatch_event(next(1)).unwrap();
+    assert_eq!(m.active().title, "B");
+
+    m.set_debounce(50);
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch_event(next(100)).unwrap();
+    assert_eq!(m.active().title, "A");
+    // the quiet time starts again with every bounce
+    assert_eq!(m.dispatch_event(next(120)).unwrap(), PageNavigation::Update);
+    m.dispatch_event(next(160)).unwrap();
+    assert_eq!(m.active().title, "A");
+    m.dispatch_event(next(210)).unwrap();
+    assert_eq!(m.active().title, "B");
+    // other interactions are not filtered
+    let previous = InteractionEvent::new(Interaction::Previous, 211);
+    m.dispatch_event(previous).unwrap();
+    assert_eq!(m.active().title, "A");
+    m.dispatch_event(next(212)).unwrap();
+    assert_eq!(m.active().title, "B");
+}
+
+#[test]
+fn debounce_with_clock() {
+    let now = Rc::new(Cell::new(u32::MAX - 10));
+    let mut m = level_of_pages();
+    let clock = Rc::clone(&now);
+    m.set_clock(Box::new(move || clock.get()));
+    m.set_debounce(50);
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    assert_eq!(m.active().title, "A");
+    // the clock wraps around
+    now.set(20);
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    assert_eq!(m.active().title, "A");
+    now.set(100);
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    assert_eq!(m.active().title, "B");
+}
+
+#[test]
+fn auto_cycle_top_level_pages() {
+    let mut m = level_of_pages();
+    m.register_sub(Box::new(PageMock::new("Home 1")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.set_auto_cycle(3, 5);
+    let updates = |m: &mut PageManager<'static, DisplayDriverStub>, n| {
+        (0..n).for_each(|_| {
+            m.dispatch(PageNavigation::Update).unwrap();
+        })
+    };
+    updates(&mut m, 2);
+    assert_eq!(m.active().title, "Home");
+    updates(&mut m, 1);
+    assert_eq!((m.active().title, m.active().changed), ("A", true));
+    updates(&mut m, 6);
+    // wraps around whatever the navigation policy
+    assert_eq!(m.active().title, "Home");
+
+    // an interaction pauses the rotation for the grace period
+    updates(&mut m, 2);
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    updates(&mut m, 4);
+    assert_eq!(m.active().title, "Home");
+    updates(&mut m, 1);
+    assert_eq!(m.active().title, "A");
+    updates(&mut m, 3);
+    assert_eq!(m.active().title, "B");
+
+    // no rotation while a sub page is active
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    updates(&mut m, 10);
+    assert_eq!(m.active().title, "Home 1");
+
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.clear_auto_cycle();
+    updates(&mut m, 10);
+    assert_eq!(m.active().title, "Home");
+}
+
+#[test]
+fn global_shortcuts() {
+    let mut m = level_of_pages();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.register_sub(Box::new(PageMock::new("Alarms")));
+    m.register_shortcut(Box::new(|input| match input {
+        InputEvent::Primary(Interaction::Home) => Some(ChordEvent::Path(&["B", "Alarms"])),
+        InputEvent::Primary(Interaction::Previous) => {
+            Some(ChordEvent::Interaction(Interaction::Next))
+        }
+        _ => None,
+    }));
+    m.register_shortcut(Box::new(|input| match input {
+        InputEvent::Primary(Interaction::Home | Interaction::Action) => {
+            Some(ChordEvent::Navigation(PageNavigation::Home))
+        }
+        _ => None,
+    }));
+    m.dispatch(PageNavigation::Home).unwrap();
+
+    // the first handler consuming the interaction wins
+    m.dispatch_interaction(Interaction::Home).unwrap();
+    assert_eq!(m.active().title, "Alarms");
+    // not consumed interactions are seen by the page
+    m.dispatch_interaction(Interaction::Back).unwrap();
+    assert_eq!(m.active().title, "B");
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    assert_eq!(m.active().title, "Home");
+    // the page sees the interaction of the shortcut
+    m.dispatch_interaction(Interaction::Previous).unwrap();
+    assert_eq!(m.active().title, "A");
+}
+
+#[test]
+fn navigation_middleware() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let busy = Rc::new(Cell::new(false));
+    let mut m = level_of_pages();
+    let logged = Rc::clone(&log);
+    m.register_middleware(Box::new(move |navigation, active: ActivePage<'_>| {
+        logged
+            .borrow_mut()
+            .push(format!("{:?} {}", navigation, active.title));
+        match navigation {
+            PageNavigation::Home => PageNavigation::Left,
+            _ => navigation,
+        }
+    }));
+    let running = Rc::clone(&busy);
+    m.register_middleware(Box::new(move |navigation, _active: ActivePage<'_>| match (
+        running.get(),
+        navigation,
+    ) {
+        (true, PageNavigation::Left) | (true, PageNavigation::Right) => PageNavigation::Update,
+        _ => navigation,
+    }));
+
+    // middleware runs in the order of registration
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.active().title, "A");
+    busy.set(true);
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    assert_eq!(m.active().title, "A");
+    // rotation is vetoed as well
+    m.set_auto_cycle(1, 1);
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.active().title, "A");
+    busy.set(false);
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.active().title, "B");
+    assert_eq!(
+        log.borrow_mut().split_off(0),
+        vec!["Home Home", "Left A", "Left A", "Left A"]
+    );
+}
+
+impl PageInterface<DisplayDriverStub> for page::PinPage {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn access_levels() {
+    // Home - Settings - Service
+    //   |       |
+    //   |       Network - Calibration
+    //   PIN
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.register_sub(Box::new(
+        page::PinPage::new(page::BasicPage::new("PIN", None), 2)
+            .with_pin("42", AccessLevel::Service),
+    ));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.register(Box::new(PageMock::new("Settings")));
+    m.register_sub(Box::new(PageMock::new("Network")));
+    let calibration = m.register(Box::new(PageMock::new("Calibration")));
+    m.set_page_access(calibration, AccessLevel::Installer);
+    m.dispatch(PageNavigation::Up).unwrap();
+    let service = m.register(Box::new(PageMock::new("Service")));
+    m.set_page_access(service, AccessLevel::Service);
+    m.dispatch(PageNavigation::Home).unwrap();
+
+    // pages above the access level are skipped and hidden
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "Home");
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "Settings");
+    assert_eq!(
+        m.sub_iter().map(|p| p.title()).collect::<Vec<_>>(),
+        vec!["Network"]
+    );
+    assert!(m.navigate_path(&["Service"]).is_err());
+
+    // elevate the access level by the PIN page
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    m.dispatch_char('4').unwrap();
+    m.dispatch_char('2').unwrap();
+    assert_eq!(m.access_level(), AccessLevel::Service);
+    assert_eq!(m.active().title, "Home");
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "Service");
+    m.dispatch(PageNavigation::Right).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    assert_eq!(m.active().title, "Calibration");
+
+    // lowering the access level leaves pages not accessible anymore
+    m.set_access_level(AccessLevel::Installer);
+    assert_eq!(m.active().title, "Calibration");
+    m.set_access_level(AccessLevel::User);
+    assert_eq!(m.active().title, "Home");
+}
+
+#[test]
+fn hidden_pages_are_reached_programmatically() {
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    let service = m.register(Box::new(PageMock::new("Service")));
+    m.register_sub(Box::new(PageMock::new("Calibration")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.register(Box::new(PageMock::new("Settings")));
+    m.register_sub(Box::new(PageMock::new("Network")));
+    let factory = m.register(Box::new(PageMock::new("Factory")));
+    m.set_page_hidden(service, true);
+    m.set_page_hidden(factory, true);
+    m.set_page_hidden(PageId(0), true);
+    m.register_shortcut(Box::new(move |input| match input {
+        InputEvent::Custom(7) => Some(ChordEvent::Page(service)),
+        _ => None,
+    }));
+    m.dispatch(PageNavigation::Home).unwrap();
+
+    let factory_item = m.tree_iter().find(|p| p.id == factory).unwrap();
+    assert!(factory_item.hidden);
+    assert_eq!(factory_item.access, AccessLevel::User);
+
+    // hidden pages are skipped and left out of menus
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "Settings");
+    assert_eq!(
+        m.sub_iter().map(|p| p.title()).collect::<Vec<_>>(),
+        vec!["Network"]
+    );
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "Home");
+    m.dispatch(PageNavigation::Right).unwrap();
+    m.dispatch(PageNavigation::Right).unwrap();
+    assert_eq!(m.active().title, "Home");
+
+    // reachable by id, by path and by a shortcut
+    m.navigate_to(factory).unwrap();
+    assert_eq!(m.active().title, "Factory");
+    assert!(m.navigate_to(PageId(42)).is_err());
+    assert_eq!(m.active().title, "Factory");
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.navigate_path(&["Service", "Calibration"]).unwrap();
+    assert_eq!(m.active().title, "Calibration");
+    m.dispatch(PageNavigation::Up).unwrap();
+    assert_eq!(m.active().title, "Service");
+    // sub pages of hidden pages are not hidden
+    assert_eq!(
+        m.sub_iter().map(|p| p.title()).collect::<Vec<_>>(),
+        vec!["Calibration"]
+    );
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch_custom(7).unwrap();
+    assert_eq!(m.active().title, "Service");
+
+    // shown again when not hidden anymore
+    m.set_page_hidden(service, false);
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "Service");
+}
+
+/// Usage observer recording all page changes
+struct UsageRecorder(Rc<RefCell<Vec<String>>>);
+
+impl UsageObserver for UsageRecorder {
+    fn page_entered(&mut self, _id: PageId, title: &str) {
+        self.0.borrow_mut().push(format!("+{}", title));
+    }
+
+    fn page_left(&mut self, _id: PageId, title: &str, duration: Option<u32>) {
+        self.0
+            .borrow_mut()
+            .push(format!("-{} {:?}", title, duration));
+    }
+}
+
+#[test]
+fn observe_page_usage() {
+    let usage = Rc::new(RefCell::new(Vec::new()));
+    let mut m = level_of_pages();
+    m.set_usage_observer(Box::new(UsageRecorder(Rc::clone(&usage))));
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(usage.borrow_mut().split_off(0), vec!["-Home None", "+A"]);
+
+    // durations are measured by the clock
+    let now = Rc::new(Cell::new(1000));
+    let clock = Rc::clone(&now);
+    m.set_clock(Box::new(move || clock.get()));
+    m.set_usage_observer(Box::new(UsageRecorder(Rc::clone(&usage))));
+    now.set(3500);
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    now.set(4000);
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(
+        usage.borrow_mut().split_off(0),
+        vec!["-A Some(2500)", "+B", "-B Some(500)", "+Home"]
+    );
+}
+
+#[test]
+fn heartbeat_while_alive() {
+    let beats = Rc::new(Cell::new(0));
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    m.register(Box::new(FailingPage));
+    m.dispatch(PageNavigation::Home).unwrap();
+    let counter = Rc::clone(&beats);
+    m.set_heartbeat(Box::new(move || counter.set(counter.get() + 1)));
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.update().unwrap();
+    m.dispatch_interaction(Interaction::Back).unwrap();
+    assert_eq!(beats.get(), 3);
+    // a failing page stops the heartbeat
+    assert!(m.dispatch(PageNavigation::Left).is_err());
+    assert!(m.update().is_err());
+    assert_eq!(beats.get(), 3);
+}
+
+#[test]
+fn maintain_the_display_with_periodic_updates() {
+    let toggles = Rc::new(Cell::new(0));
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    let counter = Rc::clone(&toggles);
+    m.set_maintenance(
+        1000,
+        Box::new(move |_: &mut DisplayDriverStub| counter.set(counter.get() + 1)),
+    );
+    // without a clock with every periodic update
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    assert_eq!(toggles.get(), 2);
+
+    let now = Rc::new(Cell::new(500));
+    let clock = Rc::clone(&now);
+    m.set_clock(Box::new(move || clock.get()));
+    m.dispatch(PageNavigation::Update).unwrap();
+    now.set(1400);
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(toggles.get(), 3);
+    now.set(1500);
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(toggles.get(), 4);
+}
+
+/// Feedback sink recording all feedback given
+struct FeedbackRecorder(Rc<RefCell<Vec<Feedback>>>);
+
+impl FeedbackSink for FeedbackRecorder {
+    fn key_click(&mut self) {
+        self.0.borrow_mut().push(Feedback::KeyClick);
+    }
+
+    fn confirm(&mut self) {
+        self.0.borrow_mut().push(Feedback::Confirm);
+    }
+
+    fn error(&mut self) {
+        self.0.borrow_mut().push(Feedback::Error);
+    }
+
+    fn alarm(&mut self) {
+        self.0.borrow_mut().push(Feedback::Alarm);
+    }
+}
+
+impl PageInterface<DisplayDriverStub> for page::ActionPage<'_> {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+impl PageInterface<DisplayDriverStub> for page::DynamicTextPage<'_> {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn give_feedback() {
+    let given = Rc::new(RefCell::new(Vec::new()));
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    m.set_feedback(Box::new(FeedbackRecorder(Rc::clone(&given))));
+    m.register_sub(Box::new(page::ActionPage::new(
+        page::BasicPage::new("Reset", None),
+        Box::new(|| PageNavigation::Up),
+    )));
+    m.register(Box::new(
+        page::DynamicTextPage::new(
+            page::BasicPage::new("Failure", None),
+            Box::new(|| String::from("Sensor")),
+        )
+        .with_feedback(Feedback::Error),
+    ));
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert!(given.borrow().is_empty());
+
+    // enter the action page by the menu
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(
+        given.borrow_mut().split_off(0),
+        vec![Feedback::KeyClick, Feedback::Confirm]
+    );
+
+    // the error page requests feedback once it gets activated
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch_interaction(Interaction::Back).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    assert_eq!(
+        given.borrow_mut().split_off(0),
+        vec![Feedback::Error, Feedback::KeyClick, Feedback::Error]
+    );
+}
+
+impl PageInterface<DisplayDriverStub> for page::SelfTestPage<'_> {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn give_feedback_of_self_test() {
+    let given = Rc::new(RefCell::new(Vec::new()));
+    let home = page::SelfTestPage::new(page::BasicPage::new("Self test", None))
+        .with_test("Display", Box::new(|| true))
+        .with_test("Flash", Box::new(|| false));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    m.set_feedback(Box::new(FeedbackRecorder(Rc::clone(&given))));
+    m.update().unwrap();
+    assert!(given.borrow().is_empty());
+    // the failed self test is reported once by the update completing it
+    m.update().unwrap();
+    m.update().unwrap();
+    assert_eq!(given.borrow_mut().split_off(0), vec![Feedback::Error]);
+}
+
+#[test]
+fn announce_pages_and_selections() {
+    let announced = RefCell::new(Vec::new());
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), Some("Back"));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    m.register_startup(Box::new(page::StartupPage::new("Welcome", 2)));
+    m.register_sub(Box::new(page::DynamicTextPage::new(
+        page::BasicPage::new("Info", None),
+        Box::new(|| String::from("Version 1.0")),
+    )));
+    m.register(Box::new(page::DynamicTextPage::new(
+        page::BasicPage::new("Network", None),
+        Box::new(|| String::from("Connected")),
+    )));
+    m.set_audio(Box::new(|announcement: Announcement<'_>| {
+        announced.borrow_mut().push(format!("{:?}", announcement))
+    }));
+    // the startup page is not announced
+    m.dispatch(PageNavigation::SystemStart).unwrap();
+    assert!(announced.borrow().is_empty());
+
+    // the home page is shown once the startup page expired
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    // nothing changed, nothing announced
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    m.dispatch_interaction(Interaction::Back).unwrap();
+    drop(m);
+    assert_eq!(
+        announced.into_inner(),
+        vec![
+            "Page(\"Home\")",
+            "Selection { index: 1, text: Some(\"Info\") }",
+            "Selection { index: 2, text: Some(\"Network\") }",
+            // the back entry of the menu
+            "Selection { index: 3, text: None }",
+            "Selection { index: 1, text: Some(\"Info\") }",
+            "Page(\"Info\")",
+            "Page(\"Home\")",
+            "Selection { index: 1, text: Some(\"Info\") }",
+        ]
+    );
+}
+
+impl PageInterface<DisplayDriverStub> for page::FactoryResetFlow<'_> {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn factory_reset() {
+    let store = RefCell::new(MemoryStore::default());
+    let resets = Cell::new(0);
+    let given = Rc::new(RefCell::new(Vec::new()));
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    m.set_feedback(Box::new(FeedbackRecorder(Rc::clone(&given))));
+    m.register_startup(Box::new(page::StartupPage::new("Welcome", 0)));
+    m.register_sub(Box::new(page::FactoryResetFlow::new(
+        page::BasicPage::new("Factory reset", None),
+        &store,
+        Box::new(|| resets.set(resets.get() + 1)),
+    )));
+    m.save_state(&mut *store.borrow_mut()).unwrap();
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    assert_eq!(m.active().title, "Factory reset");
+
+    // no is selected by default
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    assert_eq!(m.active().title, "Home");
+    assert_eq!(resets.get(), 0);
+
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    assert_eq!(resets.get(), 0);
+    given.borrow_mut().clear();
+    m.update().unwrap();
+    assert_eq!(resets.get(), 1);
+    assert!(store.borrow_mut().load("hmi.state").is_none());
+    assert_eq!(m.active().title, "Home");
+    assert_eq!(given.borrow_mut().split_off(0), vec![Feedback::Confirm]);
+}
+
+/// Page whose update always fails
+struct FailingPage;
+
+impl PageBaseInterface for FailingPage {
+    fn update<'a>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        Err(PageError)
+    }
+}
+
+impl PageInteractionInterface for FailingPage {}
+
+impl PageInterface<DisplayDriverStub> for FailingPage {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn give_error_feedback_if_dispatch_fails() {
+    let given = Rc::new(RefCell::new(Vec::new()));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.set_feedback(Box::new(FeedbackRecorder(Rc::clone(&given))));
+    m.register_sub(Box::new(FailingPage));
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert!(m.dispatch(PageNavigation::NthSubpage(1)).is_err());
+    assert_eq!(*given.borrow(), vec![Feedback::Error]);
+}
+
+#[test]
+fn raise_alarm_page() {
+    let given = Rc::new(RefCell::new(Vec::new()));
+    let scheduler = RefCell::new(AlarmScheduler::new());
+    let tea = scheduler.borrow_mut().add("Tea", AlarmTime::Daily(16, 0));
+    let now = Rc::new(Cell::new(DateTime::new(2024, 5, 1, 15, 59, 0)));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.set_feedback(Box::new(FeedbackRecorder(Rc::clone(&given))));
+    let alarm = m.register(Box::new(PageMock::new("Alarm")));
+    let time = Rc::clone(&now);
+    m.set_alarms(&scheduler, Box::new(move || time.get()), Some(alarm));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.take_alarm(), None);
+
+    now.set(DateTime::new(2024, 5, 1, 16, 0, 0));
+    assert_eq!(
+        m.dispatch(PageNavigation::Update).unwrap(),
+        PageNavigation::Update
+    );
+    assert_eq!(m.active().id, alarm);
+    assert_eq!(m.take_alarm(), Some(tea));
+    assert_eq!(m.take_alarm(), None);
+    assert_eq!(*given.borrow(), vec![Feedback::Alarm]);
+}
+
+impl PageInterface<DisplayDriverStub> for page::UpdatePage<'_> {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn locked_page_holds_back_alarms() {
+    let progress = UpdateProgress::new();
+    let scheduler = RefCell::new(AlarmScheduler::new());
+    let tea = scheduler.borrow_mut().add("Tea", AlarmTime::Daily(16, 0));
+    let now = Rc::new(Cell::new(DateTime::new(2024, 5, 1, 15, 59, 0)));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    let alarm = m.register(Box::new(PageMock::new("Alarm")));
+    let update = m.register(Box::new(page::UpdatePage::new(
+        page::BasicPage::new("Update", None),
+        &progress,
+        Box::new(|| PageNavigation::Home),
+    )));
+    let time = Rc::clone(&now);
+    m.set_alarms(&scheduler, Box::new(move || time.get()), Some(alarm));
+    m.set_auto_cycle(1, 1);
+    m.register_shortcut(Box::new(move |input| match input {
+        InputEvent::Custom(1) => Some(ChordEvent::Page(alarm)),
+        _ => None,
+    }));
+    m.navigate_to(update).unwrap();
+
+    // the alarm fires in the middle of the update
+    progress.start("Writing");
+    now.set(DateTime::new(2024, 5, 1, 16, 0, 0));
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.active().id, update);
+    assert_eq!(m.take_alarm(), None);
+    m.dispatch_custom(1).unwrap();
+    m.dispatch_interaction(Interaction::Home).unwrap();
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert!(m.navigate_to(alarm).is_err());
+    assert!(m.navigate_path(&["Home"]).is_err());
+    assert_eq!(m.active().id, update);
+
+    // raised once the update is done
+    progress.complete();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.active().id, alarm);
+    assert_eq!(m.take_alarm(), Some(tea));
+}
+
+#[test]
+fn alarms_wait_for_the_page_to_be_left() {
+    let given = Rc::new(RefCell::new(Vec::new()));
+    let scheduler = RefCell::new(AlarmScheduler::new());
+    let stir = scheduler.borrow_mut().add("Stir", AlarmTime::Interval(1));
+    let now = Rc::new(Cell::new(DateTime::new(2024, 5, 1, 12, 0, 0)));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.set_feedback(Box::new(FeedbackRecorder(Rc::clone(&given))));
+    let alarm = m.register(Box::new(PageMock::new("Alarm")));
+    let edit = m.register(Box::new(EditPage { dirty: false }));
+    let time = Rc::clone(&now);
+    m.set_alarms(&scheduler, Box::new(move || time.get()), Some(alarm));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.navigate_to(edit).unwrap();
+    m.dispatch_char('x').unwrap();
+    given.borrow_mut().clear();
+
+    // the interval alarm fires twice, the edit not committed is kept
+    for second in 1..3 {
+        now.set(DateTime::new(2024, 5, 1, 12, 0, second));
+        m.dispatch(PageNavigation::Update).unwrap();
+        assert_eq!(m.active().id, edit);
+    }
+    assert_eq!(m.take_alarm(), None);
+    assert!(given.borrow().is_empty());
+
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    given.borrow_mut().clear();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.active().id, alarm);
+    assert_eq!(m.take_alarm(), Some(stir));
+    assert_eq!(given.borrow_mut().split_off(0), vec![Feedback::Alarm]);
+}
+
+#[test]
+fn alarms_wait_for_operational_hmi() {
+    let scheduler = RefCell::new(AlarmScheduler::new());
+    let stir = scheduler.borrow_mut().add("Stir", AlarmTime::Interval(1));
+    let now = Rc::new(Cell::new(DateTime::new(2024, 5, 1, 12, 0, 0)));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    let time = Rc::clone(&now);
+    m.set_alarms(&scheduler, Box::new(move || time.get()), None);
+    m.dispatch(PageNavigation::Update).unwrap();
+    now.set(DateTime::new(2024, 5, 1, 12, 0, 5));
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.take_alarm(), None);
+
+    // without alarm page the active page stays
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    now.set(DateTime::new(2024, 5, 1, 12, 0, 6));
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.take_alarm(), Some(stir));
+    assert_eq!(m.active().title, "Home");
+}
+
+/// Indicator sink recording all indications
+struct IndicatorRecorder(Rc<RefCell<Vec<Indication>>>);
+
+impl IndicatorSink for IndicatorRecorder {
+    fn indicate(&mut self, indication: Indication) {
+        self.0.borrow_mut().push(indication);
+    }
+}
+
+impl PageInterface<DisplayDriverStub> for page::StartupPage {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+impl<T> PageInterface<DisplayDriverStub> for page::EnterStringPage<'_, T>
+where
+    T: std::fmt::Display + std::str::FromStr + Copy + 'static,
+    <T as std::str::FromStr>::Err: std::fmt::Debug,
+{
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn indicate_hmi_state() {
+    let indicated = Rc::new(RefCell::new(Vec::new()));
+    let value: CellSetting<u8> = Default::default();
+    let home = page::MenuPage::new(page::BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(home));
+    m.set_indicator(Box::new(IndicatorRecorder(Rc::clone(&indicated))));
+    m.register_startup(Box::new(page::StartupPage::new("Welcome", 2)));
+    m.register_sub(Box::new(page::EnterStringPage::new(
+        page::BasicPage::new("Volume", None),
+        "0123456789",
+        None,
+        Some("Ok"),
+        &value,
+    )));
+    m.register(Box::new(
+        page::DynamicTextPage::new(
+            page::BasicPage::new("Overheat", None),
+            Box::new(String::new),
+        )
+        .with_feedback(Feedback::Alarm),
+    ));
+
+    let mut navigation = m.dispatch(PageNavigation::SystemStart).unwrap();
+    while navigation == PageNavigation::SystemStart {
+        navigation = m.dispatch(navigation).unwrap();
+    }
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Home).unwrap();
+
+    let off = Indication::default();
+    assert_eq!(
+        *indicated.borrow(),
+        vec![
+            Indication {
+                startup: true,
+                ..off
+            },
+            off,
+            Indication {
+                settings: true,
+                ..off
+            },
+            Indication { alarm: true, ..off },
+            off,
+        ]
+    );
+}
+
+#[cfg(feature = "log")]
+#[test]
+fn trace_navigation_by_log() {
+    static LOG: LogBuffer<256, 40> = LogBuffer::new();
+    log::set_logger(&LOG).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Traced home")));
+    m.register(Box::new(PageMock::new("Traced page")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+
+    // other tests trace as well
+    let lines = LOG.lines();
+    assert!(lines.contains(&String::from("T Left at page Traced home")));
+    assert!(lines.contains(&String::from("D page Traced page activated")));
+}
+
+#[test]
+fn graceful_shutdown_tasks() {
+    /// Page that counts how often it is told about the shutdown
+    struct ShutdownCountingPage(Rc<Cell<usize>>);
+
+    impl PageBaseInterface for ShutdownCountingPage {
+        fn on_shutdown(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    impl PageInteractionInterface for ShutdownCountingPage {}
+
+    impl PageInterface<DisplayDriverStub> for ShutdownCountingPage {
+        fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+    }
+
+    let told = Rc::new(Cell::new(0));
+    let polls = Rc::new(Cell::new(0));
+    let mut m = PageManager::new(
+        DisplayDriverStub,
+        Box::new(ShutdownCountingPage(told.clone())),
+    );
+    m.register(Box::new(ShutdownCountingPage(told.clone())));
+    m.register_shutdown(Box::new(page::ShutdownPage::new("Bye", 2)));
+    let counter = polls.clone();
+    m.register_shutdown_task(Box::new(move || {
+        counter.set(counter.get() + 1);
+        counter.get() % 4 == 0
+    }));
+
+    // the shutdown page stays beyond its lifetime of two updates
+    for _ in 0..3 {
+        assert_eq!(
+            m.dispatch(PageNavigation::SystemStop).unwrap(),
+            PageNavigation::SystemStop
+        );
+    }
+    assert_eq!(told.get(), 2);
+    assert!(!m.shutdown_tasks_done());
+    assert_eq!(
+        m.dispatch(PageNavigation::SystemStop).unwrap(),
+        PageNavigation::Terminate
+    );
+    assert!(m.shutdown_tasks_done());
+    assert_eq!(polls.get(), 4);
+
+    // a restart shuts down again
+    m.dispatch(PageNavigation::SystemStart).unwrap();
+    m.dispatch(PageNavigation::SystemStop).unwrap();
+    assert_eq!(told.get(), 4);
+    assert!(!m.shutdown_tasks_done());
+}
+
+#[test]
+fn reload_active_page() {
+    /// Page that counts how often it is reloaded
+    struct ReloadCountingPage(Rc<Cell<usize>>);
+
+    impl PageBaseInterface for ReloadCountingPage {
+        fn reload(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    impl PageInteractionInterface for ReloadCountingPage {}
+
+    impl PageInterface<DisplayDriverStub> for ReloadCountingPage {
+        fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+    }
+
+    let reloads = Rc::new(Cell::new(0));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.register(Box::new(ReloadCountingPage(reloads.clone())));
+    assert_eq!(
+        m.dispatch(PageNavigation::Reload).unwrap(),
+        PageNavigation::Update
+    );
+    assert_eq!(reloads.get(), 1);
+    assert_eq!(m.active().id.index(), 1);
+
+    // only the active page reloads
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Reload).unwrap();
+    assert_eq!(reloads.get(), 1);
+}
+
+#[test]
+fn dispatch_burst_of_interactions() {
+    let mut d = DisplayDriverMock::default("burst");
+    d.expect("A");
+    d.expect("B");
+    let mut m = PageManager::new(d, Box::new(PageMock::new("A")));
+    m.register(Box::new(PageMock::new("B")));
+    m.register(Box::new(PageMock::new("C")));
+    m.dispatch(PageNavigation::Home).unwrap();
+
+    // page mocks navigate left by next
+    let interactions = vec![Interaction::Next, Interaction::Next, Interaction::Previous];
+    assert_eq!(
+        m.dispatch_all(interactions).unwrap(),
+        PageNavigation::Update
+    );
+    assert_eq!(m.active().title, "B");
+    assert_eq!(
+        m.dispatch_all(None::<Interaction>).unwrap(),
+        PageNavigation::Update
+    );
+}
+
+#[test]
+fn limit_frame_rate() {
+    let mut d = DisplayDriverMock::default("frame rate");
+    d.expect("A");
+    d.expect("C");
+    let now = Rc::new(Cell::new(0));
+    let mut m = PageManager::new(d, Box::new(PageMock::new("A")));
+    m.register(Box::new(PageMock::new("B")));
+    m.register(Box::new(PageMock::new("C")));
+    let clock = now.clone();
+    m.set_clock(Box::new(move || clock.get()));
+    m.set_frame_interval(100);
+
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    now.set(60);
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "C");
+
+    // the page skipped is rendered with the next update
+    now.set(100);
+    m.dispatch(PageNavigation::Update).unwrap();
+}
+
+#[test]
+fn animate_page_changes() {
+    #[derive(Default)]
+    struct AnimatedDisplay {
+        transition: Option<(Transition, u8)>,
+        frames: Vec<String>,
+    }
+
+    impl TransitionDisplay for AnimatedDisplay {
+        fn set_transition(&mut self, transition: Option<(Transition, u8)>) {
+            self.transition = transition;
+        }
+    }
+
+    impl PageInterface<AnimatedDisplay> for PageMock {
+        fn display(&self, display_driver: &mut AnimatedDisplay) {
+            let frame = match display_driver.transition {
+                Some((transition, progress)) => {
+                    format!("{} {:?} {}", self.title(), transition, progress)
+                }
+                None => String::from(self.title()),
+            };
+            display_driver.frames.push(frame);
+        }
+    }
+
+    let mut m = PageManager::new(AnimatedDisplay::default(), Box::new(PageMock::new("A")));
+    m.register(Box::new(PageMock::new("B")));
+    m.register_sub(Box::new(PageMock::new("C")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.set_transition_frames(4);
+    assert!(!m.animate());
+
+    m.dispatch(PageNavigation::Left).unwrap();
+    while m.animate() {}
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    // a page change during a transition starts a new one
+    m.dispatch(PageNavigation::Up).unwrap();
+    while m.animate() {}
+    // without page change there is no transition
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(
+        m.display().frames,
+        [
+            "A",
+            "B Left 25",
+            "B Left 50",
+            "B Left 75",
+            "B",
+            "C Down 25",
+            "B Up 25",
+            "B Up 50",
+            "B Up 75",
+            "B",
+            "B"
+        ]
+    );
+
+    m.set_transition_frames(0);
+    m.dispatch(PageNavigation::Right).unwrap();
+    assert!(!m.animate());
+    assert_eq!(m.display().frames.last().unwrap(), "A");
+}
+
+#[test]
+fn hint_full_and_partial_refreshes() {
+    #[derive(Default)]
+    struct EPaper {
+        refresh: Option<Refresh>,
+        frames: Vec<String>,
+    }
+
+    impl RefreshDisplay for EPaper {
+        fn set_refresh(&mut self, refresh: Refresh) {
+            self.refresh = Some(refresh);
+        }
+    }
+
+    impl PageInterface<EPaper> for PageMock {
+        fn display(&self, display_driver: &mut EPaper) {
+            let refresh = display_driver.refresh.take();
+            display_driver
+                .frames
+                .push(format!("{} {:?}", self.title(), refresh));
+        }
+    }
+
+    let mut m = PageManager::new(EPaper::default(), Box::new(PageMock::new("A")));
+    m.register(Box::new(PageMock::new("B")));
+    m.set_full_refresh_interval(2);
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Right).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.request_full_refresh();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(
+        m.display().frames,
+        [
+            "A Some(Full)",
+            "A Some(Partial)",
+            "B Some(Partial)",
+            "A Some(Full)",
+            "B Some(Partial)",
+            "B Some(Full)"
+        ]
+    );
+}
+
+/// A page that is dirty after a character is entered until the action commits it
+struct EditPage {
+    dirty: bool,
+}
+
+impl PageBaseInterface for EditPage {
+    fn title(&self) -> &str {
+        "Edit"
+    }
+
+    fn can_leave(&self) -> Result<(), &str> {
+        match self.dirty {
+            true => Err("Not saved"),
+            false => Ok(()),
+        }
+    }
+}
+
+impl PageInteractionInterface for EditPage {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Action => self.dirty = false,
+            Interaction::Back => return PageNavigation::Up,
+            _ => {}
+        }
+        PageNavigation::Update
+    }
+
+    fn dispatch_char(&mut self, _c: char) -> PageNavigation {
+        self.dirty = true;
+        PageNavigation::Update
+    }
+}
+
+impl PageInterface<DisplayDriverStub> for EditPage {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn dispatch_char_like_an_interaction() {
+    let given = Rc::new(RefCell::new(Vec::new()));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.set_feedback(Box::new(FeedbackRecorder(Rc::clone(&given))));
+    m.register_sub(Box::new(page::LazyPage::new(
+        page::BasicPage::new("PIN", None),
+        Box::new(|| {
+            Box::new(
+                page::PinPage::new(page::BasicPage::new("PIN", None), 2)
+                    .with_pin("42", AccessLevel::Service),
+            )
+        }),
+    )));
+    m.register_shortcut(Box::new(|input| match input {
+        InputEvent::Char('*') => Some(ChordEvent::Navigation(PageNavigation::Home)),
+        _ => None,
+    }));
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+
+    // the lazy page takes the characters, each of them clicks
+    m.dispatch_char('4').unwrap();
+    m.dispatch_char('2').unwrap();
+    assert_eq!(m.access_level(), AccessLevel::Service);
+    assert_eq!(m.active().title, "Home");
+    assert_eq!(
+        given.borrow_mut().split_off(0),
+        vec![Feedback::KeyClick, Feedback::KeyClick, Feedback::Confirm]
+    );
+
+    // a shortcut can take a character
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    m.dispatch_char('*').unwrap();
+    assert_eq!(m.active().title, "Home");
+}
+
+#[test]
+fn debounce_inputs() {
+    let now = Rc::new(Cell::new(0));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(ShortcutPage));
+    let clock = Rc::clone(&now);
+    m.set_clock(Box::new(move || clock.get()));
+    m.set_debounce(50);
+    m.register_sub(Box::new(
+        page::PinPage::new(page::BasicPage::new("PIN", None), 2)
+            .with_pin("11", AccessLevel::Service),
+    ));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch_input(InputEvent::Custom(1)).unwrap();
+    assert_eq!(m.active().title, "PIN");
+
+    // a repeated character within the quiet time is a bounce
+    m.dispatch_input(InputEvent::Char('1')).unwrap();
+    now.set(10);
+    m.dispatch_input(InputEvent::Char('1')).unwrap();
+    assert_eq!(m.access_level(), AccessLevel::User);
+    now.set(100);
+    m.dispatch_input(InputEvent::Char('1')).unwrap();
+    assert_eq!(m.access_level(), AccessLevel::Service);
+}
+
+#[test]
+fn dirty_page_vetoes_leaving() {
+    let given = Rc::new(RefCell::new(Vec::new()));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.set_feedback(Box::new(FeedbackRecorder(Rc::clone(&given))));
+    let edit = m.register_sub(Box::new(EditPage { dirty: false }));
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    m.dispatch_char('1').unwrap();
+    assert_eq!(m.leave_veto(), None);
+
+    m.dispatch_interaction(Interaction::Back).unwrap();
+    assert_eq!(m.active().id, edit);
+    assert_eq!(m.leave_veto(), Some("Not saved"));
+    assert_eq!(given.borrow().last(), Some(&Feedback::Error));
+    // updates keep the veto, repeating the navigation confirms leaving
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.leave_veto(), Some("Not saved"));
+    m.dispatch_interaction(Interaction::Back).unwrap();
+    assert_eq!(m.active().title, "Home");
+    assert_eq!(m.leave_veto(), None);
+}
+
+#[test]
+fn committed_page_is_left() {
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.register_sub(Box::new(EditPage { dirty: true }));
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.leave_veto(), Some("Not saved"));
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    assert_eq!(m.leave_veto(), None);
+    m.dispatch_interaction(Interaction::Back).unwrap();
+    assert_eq!(m.active().title, "Home");
+}
+
+/// A parent page keeping the value returned by its sub page
+struct FormPage {
+    value: Rc<Cell<Option<i32>>>,
+}
+
+impl PageBaseInterface for FormPage {
+    fn title(&self) -> &str {
+        "Form"
+    }
+
+    fn on_enter(&mut self, _param: Option<usize>, result: Option<&PageResult>) {
+        if let Some(value) = result.and_then(|result| result.get::<i32>()) {
+            self.value.set(Some(*value));
+        }
+    }
+}
+
+impl PageInteractionInterface for FormPage {}
+
+impl PageInterface<DisplayDriverStub> for FormPage {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn sub_page_returns_result_to_parent() {
+    let value = Rc::new(Cell::new(None));
+    let setting: CellSetting<i32> = Default::default();
+    let form = FormPage {
+        value: Rc::clone(&value),
+    };
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(form));
+    m.register_sub(Box::new(page::EnterStringPage::new(
+        page::BasicPage::new("Count", None),
+        "0123456789",
+        None,
+        None,
+        &setting,
+    )));
+    m.register(Box::new(PageMock::new("Other")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    m.dispatch_char('7').unwrap();
+    m.dispatch_interaction(Interaction::Home).unwrap();
+    assert_eq!(m.active().title, "Form");
+    assert_eq!(value.get(), Some(7));
+    assert_eq!(m.result().and_then(|r| r.get::<i32>()), Some(&7));
+    assert!(m.result().unwrap().get::<u8>().is_none());
+
+    // no result without committing, and none for pages on the same level
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    assert!(m.result().is_none());
+    m.dispatch(PageNavigation::Up).unwrap();
+    assert!(m.result().is_none());
+}
+
+/// A page keeping the parameters it was entered with
+struct ParamPage {
+    params: Rc<RefCell<Vec<Option<usize>>>>,
+}
+
+impl PageBaseInterface for ParamPage {
+    fn title(&self) -> &str {
+        "Editor"
+    }
+
+    fn on_enter(&mut self, param: Option<usize>, _result: Option<&PageResult>) {
+        self.params.borrow_mut().push(param);
+    }
+}
+
+impl PageInteractionInterface for ParamPage {}
+
+impl PageInterface<DisplayDriverStub> for ParamPage {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn navigate_to_page_with_parameter() {
+    let params = Rc::new(RefCell::new(Vec::new()));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    m.register_sub(Box::new(PageMock::new("Settings")));
+    let editor = m.register_sub(Box::new(ParamPage {
+        params: Rc::clone(&params),
+    }));
+    m.dispatch(PageNavigation::Home).unwrap();
+
+    m.dispatch(PageNavigation::ToWith(editor, 2)).unwrap();
+    assert_eq!(m.active().id, editor);
+    // the active page gets a new parameter as well
+    m.dispatch(PageNavigation::ToWith(editor, 3)).unwrap();
+    m.dispatch(PageNavigation::Up).unwrap();
+    assert_eq!(m.active().title, "Settings");
+    assert_eq!(*params.borrow(), vec![Some(2), Some(3)]);
+
+    assert!(m.dispatch(PageNavigation::ToWith(PageId(9), 2)).is_err());
+    m.set_page_access(editor, AccessLevel::Service);
+    assert!(m.dispatch(PageNavigation::ToWith(editor, 2)).is_err());
+    assert_eq!(m.active().title, "Settings");
+}
+
+#[test]
+fn navigate_to_like_to_navigation() {
+    let params = Rc::new(RefCell::new(Vec::new()));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    let edit = m.register_sub(Box::new(EditPage { dirty: false }));
+    let target = m.register(Box::new(ParamPage {
+        params: Rc::clone(&params),
+    }));
+    m.dispatch(PageNavigation::Home).unwrap();
+
+    m.navigate_to(edit).unwrap();
+    assert_eq!((m.active().id, m.active().changed), (edit, true));
+    m.dispatch_char('x').unwrap();
+    // the page can veto leaving, a repetition confirms
+    m.navigate_to(target).unwrap();
+    assert_eq!((m.active().id, m.active().changed), (edit, false));
+    m.navigate_to(target).unwrap();
+    assert_eq!((m.active().id, m.active().changed), (target, true));
+    assert_eq!(*params.borrow(), vec![None]);
+}
+
+/// A page keeping the titles of the pages next to it
+struct SiblingsPage(&'static str, Rc<RefCell<String>>);
+
+impl PageBaseInterface for SiblingsPage {
+    fn title(&self) -> &str {
+        self.0
+    }
+
+    fn set_siblings(&mut self, siblings: Siblings<'_>) {
+        *self.1.borrow_mut() = siblings.to_string();
+    }
+}
+
+impl PageInteractionInterface for SiblingsPage {}
+
+impl PageInterface<DisplayDriverStub> for SiblingsPage {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn update_with_sibling_titles() {
+    let hint = Rc::new(RefCell::new(String::new()));
+    let page = |title| Box::new(SiblingsPage(title, Rc::clone(&hint)));
+    let mut m = PageManager::new(DisplayDriverStub, page("Time"));
+    m.register(page("Temperature"));
+    m.register(page("Humidity"));
+    m.dispatch(PageNavigation::Home).unwrap();
+    // home is at the edge the default policy does not wrap around
+    assert_eq!(*hint.borrow(), "Temperature ►");
+
+    let wrap = NavigationPolicy::new(EdgeBehavior::Wrap, EdgeBehavior::Wrap);
+    m.set_navigation_policy(wrap, wrap);
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(*hint.borrow(), "◄ Humidity | Temperature ►");
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(*hint.borrow(), "◄ Time | Humidity ►");
+
+    // the edges of the level without wrapping around
+    let clamp = NavigationPolicy::new(EdgeBehavior::Clamp, EdgeBehavior::Clamp);
+    m.set_navigation_policy(clamp, clamp);
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(*hint.borrow(), "◄ Time | Humidity ►");
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(*hint.borrow(), "◄ Temperature");
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(*hint.borrow(), "Temperature ►");
+    assert_eq!(m.active().title, "Time");
+
+    // a single page has no siblings
+    m.register_sub(page("Detail"));
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(*hint.borrow(), "");
+}
+
+#[test]
+fn replace_the_home_page() {
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Dashboard")));
+    let run = m.register(Box::new(PageMock::new("Run")));
+    m.register_sub(Box::new(PageMock::new("Progress")));
+    let service = m.register(Box::new(PageMock::new("Service")));
+    assert_eq!(m.home(), PageId::HOME);
+
+    // a process is active
+    m.set_home(run).unwrap();
+    assert_eq!(m.active().title, "Service");
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.active().id, run);
+    m.dispatch(PageNavigation::SystemStart).unwrap();
+    assert_eq!(m.active().id, run);
+    // pages are still found by their path from the root of the page tree
+    m.navigate_path(&["Run", "Progress"]).unwrap();
+    assert_eq!(m.active().title, "Progress");
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.active().id, run);
+
+    // a home page not accessible falls back to the root of the page tree
+    m.set_home(service).unwrap();
+    m.set_page_access(service, AccessLevel::Service);
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.active().title, "Dashboard");
+
+    // the process is done
+    m.set_home(PageId::HOME).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.active().title, "Dashboard");
+    assert!(m.set_home(PageId(9)).is_err());
+    assert_eq!(m.home(), PageId::HOME);
+}
+
+#[test]
+fn switch_between_modes() {
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Run")));
+    m.register(Box::new(PageMock::new("Trend")));
+    let setup = m.add_mode(Box::new(PageMock::new("Setup")));
+    m.register_sub(Box::new(PageMock::new("Heater")));
+    let network = m.register(Box::new(PageMock::new("Network")));
+    m.dispatch(PageNavigation::Up).unwrap();
+    m.register(Box::new(PageMock::new("Calibration")));
+    assert_eq!(m.mode(), ModeId::DEFAULT);
+
+    // the run mode is active after startup
+    m.dispatch(PageNavigation::SystemStart).unwrap();
+    assert_eq!(m.active().title, "Run");
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "Run");
+    let tree: Vec<&str> = m.tree_iter().map(|p| p.title).collect();
+    assert_eq!(tree, vec!["Run", "Trend"]);
+    // all modes count to the total number of pages
+    assert_eq!(m.page_count(), 6);
+
+    m.switch_mode(setup).unwrap();
+    assert_eq!(m.mode(), setup);
+    assert_eq!(m.active().title, "Setup");
+    assert!(m.active().changed);
+    let tree: Vec<&str> = m.tree_iter().map(|p| p.title).collect();
+    assert_eq!(tree, vec!["Setup", "Heater", "Network", "Calibration"]);
+    // navigation stays within the page tree of the mode
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "Calibration");
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(m.active().title, "Setup");
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    assert_eq!(m.active().id, network);
+    m.dispatch(PageNavigation::Up).unwrap();
+    assert_eq!(m.active().title, "Setup");
+    m.dispatch(PageNavigation::Up).unwrap();
+    assert_eq!(m.active().title, "Setup");
+    m.navigate_path(&["Setup", "Heater"]).unwrap();
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.active().title, "Setup");
+
+    // each mode has its own home page
+    m.set_home(network).unwrap();
+    m.switch_mode(ModeId::DEFAULT).unwrap();
+    assert_eq!(m.active().title, "Run");
+    assert_eq!(m.home(), PageId::HOME);
+    m.switch_mode(setup).unwrap();
+    assert_eq!(m.active().id, network);
+
+    // navigating to a page of another mode switches the mode
+    m.navigate_to(PageId::HOME).unwrap();
+    assert_eq!(m.mode(), ModeId::DEFAULT);
+    m.dispatch(PageNavigation::ToWith(network, 1)).unwrap();
+    assert_eq!(m.mode(), setup);
+    assert!(m.switch_mode(ModeId(5)).is_err());
+    assert_eq!(m.mode(), setup);
+}
+
+#[test]
+fn dirty_page_vetoes_switching_the_mode() {
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Run")));
+    let setup = m.add_mode(Box::new(PageMock::new("Setup")));
+    let edit = m.register_sub(Box::new(EditPage { dirty: false }));
+    m.switch_mode(setup).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    m.dispatch_char('1').unwrap();
+
+    assert!(m.switch_mode(ModeId::DEFAULT).is_err());
+    assert_eq!(m.mode(), setup);
+    assert_eq!(m.active().id, edit);
+    // repeating confirms leaving
+    m.switch_mode(ModeId::DEFAULT).unwrap();
+    assert_eq!(m.mode(), ModeId::DEFAULT);
+    assert_eq!(m.active().title, "Run");
+}
+
+/// A page navigating on when its lifetime is over
+struct TimedPage(&'static str, PageLifetime);
+
+impl PageBaseInterface for TimedPage {
+    fn title(&self) -> &str {
+        self.0
+    }
+
+    fn update<'a>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        self.1.increase_age();
+        if self.1.is_over() {
+            self.1.reset_age();
+            return Ok(self.1.get_target());
+        }
+        Ok(PageNavigation::Update)
+    }
+}
+
+impl PageInteractionInterface for TimedPage {}
+
+impl PageInterface<DisplayDriverStub> for TimedPage {
+    fn display(&self, _display_driver: &mut DisplayDriverStub) {}
+}
+
+#[test]
+fn slideshow_by_lifetime_fallbacks() {
+    const NEXT_OR_HOME: PageNavigation =
+        PageNavigation::FirstOf(&[PageNavigation::Left, PageNavigation::Home]);
+    let slide = |title| Box::new(TimedPage(title, PageLifetime::new(NEXT_OR_HOME, 2)));
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    let first = m.register(slide("Slide 1"));
+    m.register(slide("Slide 2"));
+    m.register(slide("Slide 3"));
+    let clamp = NavigationPolicy::new(EdgeBehavior::Clamp, EdgeBehavior::Clamp);
+    m.set_navigation_policy(clamp, clamp);
+
+    m.navigate_to(first).unwrap();
+    let mut shown = Vec::new();
+    for _ in 0..4 {
+        m.dispatch(PageNavigation::Update).unwrap();
+        shown.push(m.active().title.to_string());
+    }
+    // the last slide cannot go on to the left
+    assert_eq!(shown, vec!["Slide 2", "Slide 3", "Home", "Home"]);
+}
+
+#[test]
+fn timed_wizard_navigates_to_pages() {
+    let mut m = PageManager::new(DisplayDriverStub, Box::new(PageMock::new("Home")));
+    let done = m.register_sub(Box::new(PageMock::new("Done")));
+    m.register(Box::new(PageMock::new("Options")));
+    let welcome = m.register(Box::new(TimedPage(
+        "Welcome",
+        PageLifetime::new(PageNavigation::To(done), 2),
+    )));
+    m.navigate_to(welcome).unwrap();
+    m.dispatch(PageNavigation::Update).unwrap();
+    assert_eq!(m.active().id, done);
+    m.dispatch(PageNavigation::To(done)).unwrap();
+    assert!(!m.active().changed);
+
+    // pages not accessible are skipped as fallback
+    m.set_page_access(welcome, AccessLevel::Service);
+    const TARGETS: &[PageNavigation] = &[
+        PageNavigation::To(PageId(7)),
+        PageNavigation::FirstOf(&[PageNavigation::Right]),
+        PageNavigation::Up,
+    ];
+    assert!(m.dispatch(PageNavigation::To(welcome)).is_err());
+    m.dispatch(PageNavigation::FirstOf(TARGETS)).unwrap();
+    assert_eq!(m.active().title, "Home");
+    // none leads away
+    m.dispatch(PageNavigation::FirstOf(TARGETS)).unwrap();
+    assert_eq!(m.active().title, "Home");
+}
@@ -0,0 +1,103 @@
+use super::InputEvent;
+use core::cell::RefCell;
+use critical_section::Mutex;
+
+/// A fixed size queue to hand over inputs from other execution contexts
+///
+/// The `PageManager` is not `Send` on purpose: pages may share data with the
+/// application by `Rc`, `Cell` or `RefCell`, which must not cross execution
+/// contexts. The page manager is meant to be owned and driven by one single
+/// task. Interrupt service routines and other tasks detecting input hand
+/// over the interactions, characters or custom events by this queue, which is `Sync` and can live in a
+/// `static`.
+///
+/// The queue is guarded by a critical section (see `critical-section` crate).
+/// The target platform needs to provide a critical section implementation.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{InputEvent, Interaction, InteractionQueue};
+///     static QUEUE: InteractionQueue<4> = InteractionQueue::new();
+///
+///     // e.g. in the button interrupt service routine
+///     QUEUE.push(Interaction::Next).unwrap();
+///     // e.g. in the keypad task
+///     QUEUE.push(InputEvent::Char('7')).unwrap();
+///
+///     // in the HMI task
+///     while let Some(input) = QUEUE.pop() {
+///         // m.dispatch_input(input)
+///     }
+/// ```
+pub struct InteractionQueue<const N: usize> {
+    buffer: Mutex<RefCell<RingBuffer<N>>>,
+}
+
+struct RingBuffer<const N: usize> {
+    items: [Option<InputEvent>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> InteractionQueue<N> {
+    pub const fn new() -> Self {
+        InteractionQueue {
+            buffer: Mutex::new(RefCell::new(RingBuffer {
+                items: [None; N],
+                head: 0,
+                len: 0,
+            })),
+        }
+    }
+
+    /// Append an input, e.g. an interaction, at the end of the queue
+    ///
+    /// Returns the input as error if the queue is full.
+    pub fn push(&self, input: impl Into<InputEvent>) -> Result<(), InputEvent> {
+        let input = input.into();
+        critical_section::with(|cs| {
+            let mut buffer = self.buffer.borrow_ref_mut(cs);
+            if buffer.len == N {
+                return Err(input);
+            }
+            let index = (buffer.head + buffer.len) % N;
+            buffer.items[index] = Some(input);
+            buffer.len += 1;
+            Ok(())
+        })
+    }
+
+    /// Take the oldest input from the queue
+    pub fn pop(&self) -> Option<InputEvent> {
+        critical_section::with(|cs| {
+            let mut buffer = self.buffer.borrow_ref_mut(cs);
+            if buffer.len == 0 {
+                return None;
+            }
+            let head = buffer.head;
+            buffer.head = (head + 1) % N;
+            buffer.len -= 1;
+            buffer.items[head].take()
+        })
+    }
+
+    /// Number of inputs in the queue
+    pub fn len(&self) -> usize {
+        critical_section::with(|cs| self.buffer.borrow_ref(cs).len)
+    }
+
+    /// Check if there is no input in the queue
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<const N: usize> Default for InteractionQueue<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests;
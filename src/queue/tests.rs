@@ -0,0 +1,44 @@
+use super::*;
+use crate::Interaction;
+
+fn is_sync<T: Sync>(_: &T) {}
+
+#[test]
+fn queue_is_sync() {
+    static QUEUE: InteractionQueue<2> = InteractionQueue::new();
+    is_sync(&QUEUE);
+}
+
+#[test]
+fn push_and_pop_in_order() {
+    let sut: InteractionQueue<3> = InteractionQueue::new();
+    assert!(sut.is_empty());
+    assert!(sut.pop().is_none());
+    sut.push(Interaction::Next).unwrap();
+    sut.push(Interaction::Action).unwrap();
+    assert_eq!(sut.len(), 2);
+    assert_eq!(sut.pop(), Some(InputEvent::Primary(Interaction::Next)));
+    sut.push(InputEvent::Char('7')).unwrap();
+    sut.push(Interaction::Home).unwrap();
+    assert_eq!(
+        sut.push(Interaction::Previous),
+        Err(InputEvent::Primary(Interaction::Previous))
+    );
+    assert_eq!(sut.pop(), Some(InputEvent::Primary(Interaction::Action)));
+    assert_eq!(sut.pop(), Some(InputEvent::Char('7')));
+    assert_eq!(sut.pop(), Some(InputEvent::Primary(Interaction::Home)));
+    assert!(sut.pop().is_none());
+}
+
+#[test]
+fn push_from_other_thread() {
+    static QUEUE: InteractionQueue<8> = InteractionQueue::new();
+    std::thread::spawn(|| {
+        for _ in 0..4 {
+            QUEUE.push(Interaction::Next).unwrap();
+        }
+    })
+    .join()
+    .unwrap();
+    assert_eq!(QUEUE.len(), 4);
+}
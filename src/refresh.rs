@@ -0,0 +1,21 @@
+/// How the display refreshes the frame rendered next
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Refresh {
+    /// Refresh every pixel, e.g. flash an e-paper display to remove ghosting
+    #[default]
+    Full,
+    /// Refresh changed pixels only - fast, but e-paper displays ghost over time
+    Partial,
+}
+
+/// A display refreshing fully or partially, e.g. an e-paper display
+///
+/// The `PageManager` hints the refresh before a frame is rendered: full
+/// refreshes for the first frame and every few page changes, partial
+/// refreshes for all other frames, e.g. updates of the page shown.
+pub trait RefreshDisplay {
+    /// Arguments
+    ///
+    /// * `refresh`: - The refresh of the frame rendered next
+    fn set_refresh(&mut self, refresh: Refresh);
+}
@@ -0,0 +1,424 @@
+use super::{PageError, Setting, SettingsStore};
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A setting read and written as text, e.g. to export and import it
+///
+/// Every `Setting` of a value that can be displayed and parsed is a text
+/// setting.
+pub trait TextSetting {
+    /// The value as text
+    fn text(&self) -> String;
+
+    /// The text is a valid value of the setting
+    fn accepts(&self, text: &str) -> bool;
+
+    /// Set the value from the text
+    ///
+    /// Returns `Err(PageError)` and keeps the value if the text is not valid.
+    fn set_text(&self, text: &str) -> Result<(), PageError>;
+
+    /// The value is shown but not edited, e.g. the serial number - default is false
+    fn is_read_only(&self) -> bool {
+        false
+    }
+}
+
+impl<S: Setting> TextSetting for S
+where
+    S::Item: Display + FromStr,
+{
+    fn text(&self) -> String {
+        self.get().to_string()
+    }
+
+    fn accepts(&self, text: &str) -> bool {
+        text.parse::<S::Item>().is_ok() && self.is_valid(text)
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), PageError> {
+        match (text.parse::<S::Item>(), self.is_valid(text)) {
+            (Ok(value), true) => {
+                self.set(value);
+                Ok(())
+            }
+            _ => Err(PageError),
+        }
+    }
+}
+
+/// A read-only value of the registry, e.g. the serial number or the firmware version
+///
+/// Info fields are listed and exported along with the settings, but they
+/// are neither edited nor imported, stored or reset.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{InfoField, TextSetting};
+///
+///     let serial = InfoField::new(Box::new(|| String::from("SN-0042")));
+///     assert_eq!(serial.text(), "SN-0042");
+///     assert!(serial.is_read_only());
+///     assert!(serial.set_text("SN-0001").is_err());
+/// ```
+pub struct InfoField<'a> {
+    value: Box<dyn Fn() -> String + 'a>,
+}
+
+impl<'a> InfoField<'a> {
+    /// Arguments
+    ///
+    /// * `value`: - Provides the value, e.g. read from the calibration data
+    pub fn new(value: Box<dyn Fn() -> String + 'a>) -> Self {
+        InfoField { value }
+    }
+}
+
+impl TextSetting for InfoField<'_> {
+    fn text(&self) -> String {
+        (self.value)()
+    }
+
+    fn accepts(&self, _text: &str) -> bool {
+        false
+    }
+
+    fn set_text(&self, _text: &str) -> Result<(), PageError> {
+        Err(PageError)
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+}
+
+/// The key the settings are stored under
+const SETTINGS_KEY: &str = "hmi.settings";
+
+/// Bytes of the schema version and of the CRC ahead of the stored settings
+const HEADER: usize = 6;
+
+/// How the settings were loaded from a store by `SettingsRegistry::load()`
+///
+/// Unless the stored settings were loaded, the settings were reset to their
+/// defaults, e.g. to be shown to the user on a notification page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SettingsLoad {
+    /// The stored settings were loaded
+    Loaded,
+    /// No settings were stored, e.g. at the first start
+    Missing,
+    /// The stored settings are damaged, e.g. by a power loss while storing
+    Corrupted,
+    /// The settings were stored with an other schema version, e.g. by an older firmware
+    Outdated(u16),
+}
+
+/// The values of all settings of a registry by their names
+///
+/// With the `serde` feature, the snapshot is serialized as a map of the
+/// names to the values, e.g. a JSON object of strings.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct SettingsSnapshot {
+    values: BTreeMap<String, String>,
+}
+
+impl SettingsSnapshot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace the value of a setting
+    pub fn with_value(mut self, name: &str, value: &str) -> Self {
+        self.values.insert(String::from(name), String::from(value));
+        self
+    }
+
+    /// The value of a setting, if any
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    /// The names and the values ordered by the names
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+/// All settings of the application by name, e.g. to back them up and restore them
+///
+/// Settings are exported into a `SettingsSnapshot` and imported from one.
+/// An import is validated as a whole before any setting is changed, i.e.
+/// either all values of the snapshot are taken or none. Settings missing in
+/// the snapshot keep their values. Read-only `InfoField`s are listed along
+/// with the settings, e.g. by the `SettingsListPage`.
+///
+/// With the `serde` feature, the snapshot is exported to and imported from
+/// JSON, e.g. by a host tool, or postcard, e.g. over a serial port, for
+/// provisioning devices of a fleet.
+///
+/// The settings are persisted in a `SettingsStore` along with a schema version
+/// and a CRC by `save()`. Settings loaded by `load()` that are damaged or were
+/// stored with an other schema version are replaced by the defaults - the
+/// values the settings had when they were added to the registry.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{CellSetting, Setting, SettingsRegistry, SettingsSnapshot};
+///
+///     let volume: CellSetting<u8> = Default::default();
+///     let brightness: CellSetting<u8> = Default::default();
+///     let registry = SettingsRegistry::new()
+///         .with_setting("volume", &volume)
+///         .with_setting("brightness", &brightness);
+///     volume.set(7);
+///     let backup = registry.export();
+///     assert_eq!(backup.get("volume"), Some("7"));
+///
+///     let invalid = backup.clone().with_value("brightness", "bright");
+///     assert!(registry.import(&invalid).is_err());
+///     registry.import(&backup.with_value("brightness", "3")).unwrap();
+///     assert_eq!(brightness.get(), 3);
+/// ```
+#[derive(Default)]
+pub struct SettingsRegistry<'a> {
+    settings: Vec<(Cow<'static, str>, &'a dyn TextSetting)>,
+    defaults: SettingsSnapshot,
+    version: u16,
+}
+
+impl<'a> SettingsRegistry<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a setting - its value is the default of the setting
+    ///
+    /// Arguments
+    ///
+    /// * `name`: - The unique name the setting is exported by
+    /// * `setting`: - The setting
+    pub fn with_setting(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        setting: &'a dyn TextSetting,
+    ) -> Self {
+        let name = name.into();
+        if !setting.is_read_only() {
+            self.defaults = self.defaults.with_value(&name, &setting.text());
+        }
+        self.settings.push((name, setting));
+        self
+    }
+
+    /// The schema version of the settings stored - default is zero
+    ///
+    /// Is increased with every release changing the settings, e.g. by a
+    /// setting renamed, so settings of older releases are not loaded.
+    pub fn with_version(mut self, version: u16) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// The names of all settings in the order they were added
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.settings.iter().map(|(name, _)| name.as_ref())
+    }
+
+    /// The names and the settings in the order they were added
+    pub fn settings(&self) -> impl Iterator<Item = (&str, &'a dyn TextSetting)> {
+        self.settings
+            .iter()
+            .map(|(name, setting)| (name.as_ref(), *setting))
+    }
+
+    /// The setting of a name, if any
+    pub fn get(&self, name: &str) -> Option<&'a dyn TextSetting> {
+        self.settings
+            .iter()
+            .find(|(setting_name, _)| setting_name == name)
+            .map(|(_, setting)| *setting)
+    }
+
+    /// The values of all settings and info fields
+    pub fn export(&self) -> SettingsSnapshot {
+        self.snapshot(true)
+    }
+
+    /// The values of the settings - of the info fields too if requested
+    fn snapshot(&self, info: bool) -> SettingsSnapshot {
+        self.settings
+            .iter()
+            .filter(|(_, setting)| info || !setting.is_read_only())
+            .fold(SettingsSnapshot::new(), |snapshot, (name, setting)| {
+                snapshot.with_value(name, &setting.text())
+            })
+    }
+
+    /// Set the settings to the values of a snapshot - values of info fields are ignored
+    ///
+    /// Returns `Err(PageError)` and keeps all values if a setting of the
+    /// snapshot is not known or a value is not valid.
+    pub fn import(&self, snapshot: &SettingsSnapshot) -> Result<(), PageError> {
+        let valid = snapshot.iter().all(|(name, value)| {
+            self.get(name)
+                .is_some_and(|setting| setting.is_read_only() || setting.accepts(value))
+        });
+        if !valid {
+            return Err(PageError);
+        }
+        snapshot
+            .iter()
+            .filter_map(|(name, value)| Some((self.get(name)?, value)))
+            .filter(|(setting, _)| !setting.is_read_only())
+            .try_for_each(|(setting, value)| setting.set_text(value))
+    }
+
+    /// Set all settings to their defaults, e.g. for a factory reset
+    pub fn reset(&self) {
+        for (name, setting) in &self.settings {
+            if let Some(value) = self.defaults.get(name) {
+                // a default was a valid value when it was taken
+                let _ = setting.set_text(value);
+            }
+        }
+    }
+
+    /// Store all settings with the schema version and a CRC
+    ///
+    /// Arguments
+    ///
+    /// * `store`: - The persistent store to save the settings to
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if a name or a value is longer than 65535 bytes,
+    ///   nothing is stored then; or if the store fails.
+    pub fn save(&self, store: &mut impl SettingsStore) -> Result<(), PageError> {
+        let payload = encode(&self.snapshot(false))?;
+        let mut data = Vec::with_capacity(HEADER + payload.len());
+        data.extend_from_slice(&self.version.to_le_bytes());
+        data.extend_from_slice(&crc32(&payload).to_le_bytes());
+        data.extend_from_slice(&payload);
+        store.store(SETTINGS_KEY, &data)
+    }
+
+    /// Load the settings stored by `save()` - fall back to the defaults
+    ///
+    /// Settings that are missing, damaged, not valid or of an other schema
+    /// version are replaced by the defaults, which are stored instead.
+    ///
+    /// Arguments
+    ///
+    /// * `store`: - The persistent store to load the settings from
+    ///
+    /// Returns
+    ///
+    /// * How the settings were loaded, e.g. to notify the user about a reset.
+    /// * `Err(PageError)` - if the defaults cannot be stored.
+    pub fn load(&self, store: &mut impl SettingsStore) -> Result<SettingsLoad, PageError> {
+        let outcome = match store.load(SETTINGS_KEY) {
+            None => SettingsLoad::Missing,
+            Some(data) if data.len() < HEADER => SettingsLoad::Corrupted,
+            Some(data) => {
+                let version = u16::from_le_bytes([data[0], data[1]]);
+                let crc = u32::from_le_bytes([data[2], data[3], data[4], data[5]]);
+                let payload = &data[HEADER..];
+                match decode(payload) {
+                    _ if crc32(payload) != crc => SettingsLoad::Corrupted,
+                    _ if version != self.version => SettingsLoad::Outdated(version),
+                    Some(snapshot) if self.import(&snapshot).is_ok() => SettingsLoad::Loaded,
+                    _ => SettingsLoad::Corrupted,
+                }
+            }
+        };
+        if outcome != SettingsLoad::Loaded {
+            warn!("settings reset to defaults: {:?}", outcome);
+            self.reset();
+            self.save(store)?;
+        }
+        Ok(outcome)
+    }
+
+    /// Export all settings as JSON object of the names and the values
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, PageError> {
+        serde_json::to_string(&self.export()).map_err(|_| PageError)
+    }
+
+    /// Import the settings of a JSON object exported by `to_json()`
+    #[cfg(feature = "serde")]
+    pub fn import_json(&self, json: &str) -> Result<(), PageError> {
+        self.import(&serde_json::from_str(json).map_err(|_| PageError)?)
+    }
+
+    /// Export all settings in the compact postcard format
+    #[cfg(feature = "serde")]
+    pub fn to_postcard(&self) -> Result<Vec<u8>, PageError> {
+        postcard::to_allocvec(&self.export()).map_err(|_| PageError)
+    }
+
+    /// Import the settings exported by `to_postcard()`
+    #[cfg(feature = "serde")]
+    pub fn import_postcard(&self, bytes: &[u8]) -> Result<(), PageError> {
+        self.import(&postcard::from_bytes(bytes).map_err(|_| PageError)?)
+    }
+}
+
+/// Encode a snapshot as the length prefixed names and values
+///
+/// Returns `Err(PageError)` if a text does not fit the 16 bit length prefix.
+fn encode(snapshot: &SettingsSnapshot) -> Result<Vec<u8>, PageError> {
+    let mut data = Vec::new();
+    for text in snapshot.iter().flat_map(|(name, value)| [name, value]) {
+        let len = u16::try_from(text.len()).map_err(|_| PageError)?;
+        data.extend_from_slice(&len.to_le_bytes());
+        data.extend_from_slice(text.as_bytes());
+    }
+    Ok(data)
+}
+
+/// Decode a snapshot encoded by `encode()` - `None` if it is not well-formed
+fn decode(mut data: &[u8]) -> Option<SettingsSnapshot> {
+    let mut snapshot = SettingsSnapshot::new();
+    while !data.is_empty() {
+        let name = take_text(&mut data)?;
+        snapshot = snapshot.with_value(name, take_text(&mut data)?);
+    }
+    Some(snapshot)
+}
+
+/// Take a length prefixed text from the start of the data
+fn take_text<'d>(data: &mut &'d [u8]) -> Option<&'d str> {
+    let len = u16::from_le_bytes([*data.first()?, *data.get(1)?]) as usize;
+    let text = std::str::from_utf8(data.get(2..2 + len)?).ok()?;
+    *data = &data[2 + len..];
+    Some(text)
+}
+
+/// CRC-32 as of IEEE 802.3, e.g. of zip or ethernet
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| match crc & 1 {
+            1 => (crc >> 1) ^ 0xEDB8_8320,
+            _ => crc >> 1,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests;
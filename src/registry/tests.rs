@@ -0,0 +1,261 @@
+use super::*;
+use crate::testing::MemoryStore;
+use crate::CellSetting;
+use std::cell::{Cell, RefCell};
+
+/// A percentage - values above 100 are not valid
+#[derive(Default)]
+struct Percent(Cell<u8>);
+
+impl Setting for Percent {
+    type Item = u8;
+
+    fn set(&self, value: u8) {
+        self.0.set(value);
+    }
+
+    fn set_string(&self, value: &str) {
+        self.0.set(value.parse().unwrap());
+    }
+
+    fn get(&self) -> u8 {
+        self.0.get()
+    }
+
+    fn is_valid(&self, value: &str) -> bool {
+        value.parse::<u8>().is_ok_and(|value| value <= 100)
+    }
+}
+
+#[test]
+fn text_settings() {
+    let setting: CellSetting<f32> = Default::default();
+    setting.set(2.5);
+    assert_eq!(setting.text(), "2.5");
+    assert!(setting.set_text("x").is_err());
+    setting.set_text("-1").unwrap();
+    assert_eq!(setting.get(), -1.0);
+
+    let percent = Percent::default();
+    assert!(percent.accepts("100"));
+    assert!(!percent.accepts("101"));
+    assert!(percent.set_text("101").is_err());
+    assert_eq!(percent.get(), 0);
+}
+
+#[test]
+fn export_and_import() {
+    let volume: CellSetting<u8> = Default::default();
+    let level = Percent::default();
+    let sut = SettingsRegistry::new()
+        .with_setting("volume", &volume)
+        .with_setting("level", &level);
+    assert_eq!(sut.names().collect::<Vec<_>>(), vec!["volume", "level"]);
+    volume.set(3);
+    level.set(50);
+    let snapshot = sut.export();
+    assert_eq!(
+        snapshot.iter().collect::<Vec<_>>(),
+        vec![("level", "50"), ("volume", "3")]
+    );
+
+    volume.set(9);
+    sut.import(&SettingsSnapshot::new().with_value("level", "70"))
+        .unwrap();
+    assert_eq!((volume.get(), level.get()), (9, 70));
+    sut.import(&snapshot).unwrap();
+    assert_eq!((volume.get(), level.get()), (3, 50));
+}
+
+#[test]
+fn reject_invalid_imports() {
+    let volume: CellSetting<u8> = Default::default();
+    let level = Percent::default();
+    let sut = SettingsRegistry::new()
+        .with_setting("volume", &volume)
+        .with_setting("level", &level);
+    let invalid = SettingsSnapshot::new()
+        .with_value("volume", "5")
+        .with_value("level", "150");
+    assert!(sut.import(&invalid).is_err());
+    let unknown = SettingsSnapshot::new()
+        .with_value("volume", "5")
+        .with_value("contrast", "1");
+    assert!(sut.import(&unknown).is_err());
+    // nothing is taken from a snapshot rejected
+    assert_eq!((volume.get(), level.get()), (0, 0));
+}
+
+#[test]
+fn save_and_load() {
+    let mut store = MemoryStore::default();
+    let volume = CellSetting::default();
+    volume.set(5u8);
+    let level = Percent::default();
+    let sut = SettingsRegistry::new()
+        .with_setting("volume", &volume)
+        .with_setting("level", &level)
+        .with_version(2);
+    assert_eq!(sut.load(&mut store).unwrap(), SettingsLoad::Missing);
+    assert!(store.0.contains_key(SETTINGS_KEY));
+
+    volume.set(9);
+    level.set(42);
+    sut.save(&mut store).unwrap();
+    volume.set(1);
+    level.set(1);
+    assert_eq!(sut.load(&mut store).unwrap(), SettingsLoad::Loaded);
+    assert_eq!((volume.get(), level.get()), (9, 42));
+    sut.reset();
+    assert_eq!((volume.get(), level.get()), (5, 0));
+}
+
+#[test]
+fn fall_back_to_defaults() {
+    let mut store = MemoryStore::default();
+    let volume = CellSetting::default();
+    volume.set(5u8);
+    let sut = SettingsRegistry::new().with_setting("volume", &volume);
+    volume.set(9);
+    sut.save(&mut store).unwrap();
+
+    // a flipped bit of the value
+    let data = store.0.get_mut(SETTINGS_KEY).unwrap();
+    *data.last_mut().unwrap() ^= 0x01;
+    assert_eq!(sut.load(&mut store).unwrap(), SettingsLoad::Corrupted);
+    assert_eq!(volume.get(), 5);
+    // the defaults replace the damaged settings
+    assert_eq!(sut.load(&mut store).unwrap(), SettingsLoad::Loaded);
+
+    store.0.insert(SETTINGS_KEY.to_string(), vec![0, 0, 1]);
+    assert_eq!(sut.load(&mut store).unwrap(), SettingsLoad::Corrupted);
+
+    volume.set(9);
+    sut.save(&mut store).unwrap();
+    volume.set(3);
+    let newer = SettingsRegistry::new()
+        .with_setting("volume", &volume)
+        .with_version(1);
+    assert_eq!(newer.load(&mut store).unwrap(), SettingsLoad::Outdated(0));
+    assert_eq!(volume.get(), 3);
+}
+
+#[test]
+fn reject_valid_crc_of_invalid_settings() {
+    let mut store = MemoryStore::default();
+    let level = Percent::default();
+    let sut = SettingsRegistry::new().with_setting("level", &level);
+    let payload = encode(&SettingsSnapshot::new().with_value("level", "200")).unwrap();
+    let mut data = vec![0, 0];
+    data.extend_from_slice(&crc32(&payload).to_le_bytes());
+    data.extend_from_slice(&payload);
+    store.0.insert(SETTINGS_KEY.to_string(), data);
+    assert_eq!(sut.load(&mut store).unwrap(), SettingsLoad::Corrupted);
+    assert_eq!(level.get(), 0);
+}
+
+#[test]
+fn encode_settings() {
+    let snapshot = SettingsSnapshot::new()
+        .with_value("a", "1")
+        .with_value("b", "");
+    let data = encode(&snapshot).unwrap();
+    assert_eq!(data, [1, 0, b'a', 1, 0, b'1', 1, 0, b'b', 0, 0]);
+    assert_eq!(decode(&data), Some(snapshot));
+    assert_eq!(decode(&data[..data.len() - 1]), None);
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+}
+
+/// A free text - every text is valid
+#[derive(Default)]
+struct Text(RefCell<String>);
+
+impl TextSetting for Text {
+    fn text(&self) -> String {
+        self.0.borrow().clone()
+    }
+
+    fn accepts(&self, _text: &str) -> bool {
+        true
+    }
+
+    fn set_text(&self, text: &str) -> Result<(), PageError> {
+        *self.0.borrow_mut() = String::from(text);
+        Ok(())
+    }
+}
+
+#[test]
+fn reject_saving_too_long_texts() {
+    let mut store = MemoryStore::default();
+    let text = Text::default();
+    let sut = SettingsRegistry::new().with_setting("text", &text);
+    text.set_text(&"x".repeat(usize::from(u16::MAX))).unwrap();
+    sut.save(&mut store).unwrap();
+    let saved = store.load(SETTINGS_KEY).unwrap();
+
+    // one byte more does not fit the length prefix
+    text.set_text(&"x".repeat(usize::from(u16::MAX) + 1))
+        .unwrap();
+    assert!(sut.save(&mut store).is_err());
+    assert_eq!(store.load(SETTINGS_KEY).unwrap(), saved);
+
+    let volume: CellSetting<u8> = Default::default();
+    let sut = SettingsRegistry::new().with_setting("v".repeat(70_000), &volume);
+    assert!(sut.save(&mut store).is_err());
+}
+
+#[test]
+fn info_fields_are_read_only() {
+    let mut store = MemoryStore::default();
+    let volume: CellSetting<u8> = Default::default();
+    let serial = InfoField::new(Box::new(|| String::from("SN-0042")));
+    let sut = SettingsRegistry::new()
+        .with_setting("serial", &serial)
+        .with_setting("volume", &volume);
+    assert!(sut.get("serial").unwrap().is_read_only());
+    assert!(!sut.get("volume").unwrap().is_read_only());
+    let snapshot = sut.export();
+    assert_eq!(snapshot.get("serial"), Some("SN-0042"));
+
+    // a backup of another device is restored, its serial number is not
+    let other = snapshot
+        .with_value("serial", "SN-0001")
+        .with_value("volume", "4");
+    sut.import(&other).unwrap();
+    assert_eq!((serial.text().as_str(), volume.get()), ("SN-0042", 4));
+
+    sut.save(&mut store).unwrap();
+    let data = store.load(SETTINGS_KEY).unwrap();
+    assert_eq!(decode(&data[HEADER..]).unwrap().get("serial"), None);
+    sut.reset();
+    assert_eq!(volume.get(), 0);
+    assert_eq!(sut.load(&mut store).unwrap(), SettingsLoad::Loaded);
+    assert_eq!(volume.get(), 4);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_and_postcard() {
+    let volume: CellSetting<u8> = Default::default();
+    let gain: CellSetting<f32> = Default::default();
+    let sut = SettingsRegistry::new()
+        .with_setting("volume", &volume)
+        .with_setting("gain", &gain);
+    volume.set(4);
+    gain.set(0.5);
+    let json = sut.to_json().unwrap();
+    assert_eq!(json, r#"{"gain":"0.5","volume":"4"}"#);
+    let bytes = sut.to_postcard().unwrap();
+
+    volume.set(0);
+    sut.import_json(r#"{"volume":"8"}"#).unwrap();
+    assert_eq!(volume.get(), 8);
+    sut.import_postcard(&bytes).unwrap();
+    assert_eq!((volume.get(), gain.get()), (4, 0.5));
+
+    assert!(sut.import_json(r#"{"volume":"loud"}"#).is_err());
+    assert!(sut.import_json("[1, 2]").is_err());
+    assert!(sut.import_postcard(&bytes[..3]).is_err());
+    assert_eq!(volume.get(), 4);
+}
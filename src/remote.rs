@@ -0,0 +1,243 @@
+//! Remote control over a serial port
+//!
+//! Requires the `remote` feature. A tiny line based protocol over any
+//! `embedded-io` serial port, e.g. a UART, allows factory test rigs and
+//! headless debugging of deployed devices.
+//!
+//! Commands are received one per line; `\r` is ignored:
+//!
+//! | Command | Meaning |
+//! | ------- | ------- |
+//! | `action`, `next`, `previous`, `back`, `home` | Dispatch the interaction |
+//! | `char <c>` | Dispatch the character `c` entered directly, e.g. `char 7` |
+//! | `custom <n>` | Dispatch the custom event `n`, e.g. `custom 3` |
+//! | `nav left`, `nav right`, `nav up`, `nav home`, `nav update`, `nav reload` | Dispatch the navigation |
+//! | `nav sub <n>` | Dispatch `PageNavigation::NthSubpage(n)` |
+//!
+//! Frames mirror the rendered page text: a line `frame <title>`, one line per
+//! line of the content prefixed by `|` and a line `end`.
+//! Invalid commands are answered by a line `err <reason>`.
+//!
+//! The `Remote` is a display all pages of this crate render to. Devices with
+//! a display of their own mirror the page text by `Remote::send_frame()`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut m = PageManager::new(Remote::new(uart), Box::new(home));
+//! let mut navigation = m.dispatch(PageNavigation::SystemStart).unwrap();
+//! loop {
+//!     navigation = match m.display_mut().poll() {
+//!         Ok(Some(command)) => command.dispatch(&mut m),
+//!         _ => m.dispatch(navigation),
+//!     }
+//!     .unwrap();
+//! }
+//! ```
+
+use super::script::parse_interaction;
+use super::{Interaction, PageError, PageManager, PageNavigation, ScrollPosition};
+
+use arrayvec::ArrayVec;
+use embedded_io::{Read, ReadReady, Write};
+
+/// Maximum length of a command line
+pub const MAX_LINE: usize = 32;
+
+/// A command received by the remote control
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    /// Dispatch an interaction to the active page
+    Interaction(Interaction),
+    /// Dispatch a character entered directly to the active page
+    Char(char),
+    /// Dispatch an event defined by the application to the active page
+    Custom(u8),
+    /// Dispatch a navigation to the page manager
+    Navigation(PageNavigation),
+}
+
+impl Command {
+    /// Parse a command line
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if the line is not a valid command
+    pub fn parse(line: &str) -> Result<Self, PageError> {
+        let mut words = line.split_whitespace();
+        let command = match (words.next(), words.next(), words.next()) {
+            (Some("nav"), Some(navigation), None) => {
+                Command::Navigation(parse_navigation(navigation).ok_or(PageError)?)
+            }
+            (Some("nav"), Some("sub"), Some(index)) => {
+                let index = index.parse().map_err(|_| PageError)?;
+                Command::Navigation(PageNavigation::NthSubpage(index))
+            }
+            (Some("char"), Some(c), None) => Command::Char(parse_char(c)?),
+            (Some("custom"), Some(code), None) => {
+                Command::Custom(code.parse().map_err(|_| PageError)?)
+            }
+            (Some(interaction), None, None) => {
+                Command::Interaction(parse_interaction(interaction).ok_or(PageError)?)
+            }
+            _ => return Err(PageError),
+        };
+        match words.next() {
+            None => Ok(command),
+            Some(_) => Err(PageError),
+        }
+    }
+
+    /// Dispatch the command
+    ///
+    /// Arguments
+    ///
+    /// * `manager`: - The page manager to dispatch the command to
+    pub fn dispatch<D, C>(
+        self,
+        manager: &mut PageManager<'_, D, C>,
+    ) -> Result<PageNavigation, PageError> {
+        match self {
+            Command::Interaction(interaction) => manager.dispatch_interaction(interaction),
+            Command::Char(c) => manager.dispatch_char(c),
+            Command::Custom(code) => manager.dispatch_custom(code),
+            Command::Navigation(navigation) => manager.dispatch(navigation),
+        }
+    }
+}
+
+/// A word of exactly one character, e.g. of the command `char 7`
+fn parse_char(word: &str) -> Result<char, PageError> {
+    let mut chars = word.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(PageError),
+    }
+}
+
+fn parse_navigation(name: &str) -> Option<PageNavigation> {
+    match name {
+        "left" => Some(PageNavigation::Left),
+        "right" => Some(PageNavigation::Right),
+        "up" => Some(PageNavigation::Up),
+        "home" => Some(PageNavigation::Home),
+        "update" => Some(PageNavigation::Update),
+        "reload" => Some(PageNavigation::Reload),
+        _ => None,
+    }
+}
+
+/// A serial port that can be read without blocking
+pub trait SerialPort: Read + ReadReady + Write {}
+
+impl<S: Read + ReadReady + Write> SerialPort for S {}
+
+/// Remote control over a serial port
+pub struct Remote<S> {
+    port: S,
+    line: ArrayVec<u8, MAX_LINE>,
+    overflow: bool,
+}
+
+impl<S: SerialPort> Remote<S> {
+    pub fn new(port: S) -> Self {
+        Remote {
+            port,
+            line: ArrayVec::new(),
+            overflow: false,
+        }
+    }
+
+    /// Receive a command without blocking
+    ///
+    /// Reads the bytes available at the serial port until a command line is
+    /// complete. Invalid command lines are answered by an error line.
+    ///
+    /// Returns
+    ///
+    /// * `Ok(None)` - if no command is complete yet
+    /// * `Err(PageError)` - if the serial port fails
+    pub fn poll(&mut self) -> Result<Option<Command>, PageError> {
+        while self.port.read_ready().map_err(|_| PageError)? {
+            let mut byte = [0; 1];
+            if self.port.read(&mut byte).map_err(|_| PageError)? == 0 {
+                break;
+            }
+            match byte[0] {
+                b'\r' => (),
+                b'\n' => {
+                    let result = match (self.overflow, core::str::from_utf8(&self.line)) {
+                        (true, _) => Err("line too long"),
+                        (false, Ok(line)) if line.trim().is_empty() => Ok(None),
+                        (false, Ok(line)) => Command::parse(line)
+                            .map(Some)
+                            .map_err(|_| "unknown command"),
+                        (false, Err(_)) => Err("unknown command"),
+                    };
+                    self.line.clear();
+                    self.overflow = false;
+                    match result {
+                        Ok(None) => (),
+                        Ok(command) => return Ok(command),
+                        Err(reason) => self.send(&["err ", reason, "\n"])?,
+                    }
+                }
+                byte => {
+                    if self.line.try_push(byte).is_err() {
+                        self.overflow = true;
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Send the text of a rendered page
+    ///
+    /// Arguments
+    ///
+    /// * `title`: - The title of the page
+    /// * `message`: - The content; lines are separated by `\n`
+    pub fn send_frame(&mut self, title: &str, message: &str) -> Result<(), PageError> {
+        self.send(&["frame ", title, "\n"])?;
+        for line in message.lines() {
+            self.send(&["|", line, "\n"])?;
+        }
+        self.send(&["end\n"])
+    }
+
+    /// Show a page by sending its text - errors of the serial port are ignored
+    pub fn update(&mut self, title: &str, message: &str) {
+        let _ = self.send_frame(title, message);
+    }
+
+    /// Show a scrolling page - the title is sent as is to keep frames comparable
+    pub fn update_scrolled(
+        &mut self,
+        title: &str,
+        message: &str,
+        _position: Option<ScrollPosition>,
+    ) {
+        self.update(title, message);
+    }
+
+    /// The serial port
+    pub fn into_inner(self) -> S {
+        self.port
+    }
+
+    fn send(&mut self, parts: &[&str]) -> Result<(), PageError> {
+        for part in parts {
+            self.port
+                .write_all(part.as_bytes())
+                .map_err(|_| PageError)?;
+        }
+        Ok(())
+    }
+}
+
+// ** All pages of this crate render to the remote control
+impl_text_display!(<S: SerialPort> Remote<S>);
+
+#[cfg(test)]
+mod tests;
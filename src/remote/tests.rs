@@ -0,0 +1,136 @@
+use super::*;
+use crate::page::{BasicPage, MenuPage, TextPage};
+use std::collections::VecDeque;
+use std::convert::Infallible;
+
+/// Serial port with received bytes given and sent bytes collected
+#[derive(Default)]
+struct PortMock {
+    received: VecDeque<u8>,
+    sent: Vec<u8>,
+}
+
+impl PortMock {
+    fn receive(&mut self, text: &str) {
+        self.received.extend(text.bytes());
+    }
+
+    fn take_sent(&mut self) -> String {
+        String::from_utf8(std::mem::take(&mut self.sent)).unwrap()
+    }
+}
+
+impl embedded_io::ErrorType for PortMock {
+    type Error = Infallible;
+}
+
+impl Read for PortMock {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let mut count = 0;
+        while count < buf.len() {
+            match self.received.pop_front() {
+                Some(byte) => buf[count] = byte,
+                None => break,
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+impl ReadReady for PortMock {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.received.is_empty())
+    }
+}
+
+impl Write for PortMock {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.sent.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[test]
+fn parse_commands() {
+    assert_eq!(
+        Command::parse("next").ok(),
+        Some(Command::Interaction(Interaction::Next))
+    );
+    assert_eq!(
+        Command::parse(" nav  home ").ok(),
+        Some(Command::Navigation(PageNavigation::Home))
+    );
+    assert_eq!(
+        Command::parse("nav sub 2").ok(),
+        Some(Command::Navigation(PageNavigation::NthSubpage(2)))
+    );
+    assert_eq!(Command::parse("char 7").ok(), Some(Command::Char('7')));
+    assert!(Command::parse("char 42").is_err());
+    assert_eq!(Command::parse("custom 3").ok(), Some(Command::Custom(3)));
+    assert!(Command::parse("custom x").is_err());
+    assert!(Command::parse("nav sub").is_err());
+    assert!(Command::parse("nav sub two").is_err());
+    assert!(Command::parse("next page").is_err());
+    assert!(Command::parse("jump").is_err());
+}
+
+#[test]
+fn poll_commands_without_blocking() {
+    let mut remote = Remote::new(PortMock::default());
+    assert_eq!(remote.poll().unwrap(), None);
+    remote.port.receive("ne");
+    assert_eq!(remote.poll().unwrap(), None);
+    remote.port.receive("xt\r\n\nnav up\n");
+    assert_eq!(
+        remote.poll().unwrap(),
+        Some(Command::Interaction(Interaction::Next))
+    );
+    assert_eq!(
+        remote.poll().unwrap(),
+        Some(Command::Navigation(PageNavigation::Up))
+    );
+    assert_eq!(remote.poll().unwrap(), None);
+    assert_eq!(remote.port.take_sent(), "");
+}
+
+#[test]
+fn invalid_commands_are_answered() {
+    let mut remote = Remote::new(PortMock::default());
+    remote.port.receive("jump\n");
+    remote.port.receive(&"x".repeat(MAX_LINE + 1));
+    remote.port.receive("\nback\n");
+    assert_eq!(
+        remote.poll().unwrap(),
+        Some(Command::Interaction(Interaction::Back))
+    );
+    assert_eq!(
+        remote.port.take_sent(),
+        "err unknown command\nerr line too long\n"
+    );
+}
+
+#[test]
+fn remote_controlled_page_manager() {
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(Remote::new(PortMock::default()), Box::new(home));
+    m.register_sub(Box::new(TextPage::new(
+        BasicPage::new("Info", None),
+        "Line 1\nLine 2",
+    )));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.display_mut().port.take_sent();
+
+    m.display_mut().port.receive("action\n");
+    let command = m.display_mut().poll().unwrap().unwrap();
+    command.dispatch(&mut m).unwrap();
+    assert_eq!(m.active().title, "Info");
+    assert_eq!(
+        m.display_mut().port.take_sent(),
+        "frame Info\n|Line 1\n|Line 2\nend\n"
+    );
+}
@@ -0,0 +1,130 @@
+use super::Interaction;
+
+use std::iter::{repeat_n, RepeatN};
+
+/// Quarter steps by the previous and the current level of the pins A and B
+const TRANSITIONS: [i8; 16] = [0, -1, 1, 0, 1, 0, 0, -1, -1, 0, 0, 1, 0, 1, -1, 0];
+
+/// Input of a rotary encoder knob
+///
+/// Decodes the quadrature signals A and B of the knob. A detent turned
+/// clockwise, i.e. A leading B, is next, counter-clockwise previous; the push
+/// button of the knob is handled like any other button, usually as action.
+///
+/// Large numeric ranges take long to edit detent by detent. With acceleration
+/// a detent turned quickly after the previous one results in several
+/// interactions.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{Interaction, RotaryEncoder};
+///
+///     // detents within 50ms count 5 times, within 20ms 10 times
+///     let mut knob = RotaryEncoder::new()
+///         .with_acceleration(50, 5)
+///         .with_acceleration(20, 10);
+///     assert_eq!(knob.detent(true, 0).count(), 1);
+///     assert_eq!(knob.detent(true, 40).count(), 5);
+///     assert_eq!(knob.detent(true, 50).last(), Some(Interaction::Next));
+/// ```
+pub struct RotaryEncoder {
+    pins: u8,
+    quarters: i8,
+    steps_per_detent: i8,
+    acceleration: Vec<(u32, usize)>,
+    last_detent: Option<(bool, u32)>,
+}
+
+impl Default for RotaryEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RotaryEncoder {
+    /// A knob with 4 quarter steps per detent
+    pub fn new() -> Self {
+        RotaryEncoder {
+            pins: 0,
+            quarters: 0,
+            steps_per_detent: 4,
+            acceleration: Vec::new(),
+            last_detent: None,
+        }
+    }
+
+    /// Set the number of quarter steps per detent, e.g. 2 or 1 for cheap knobs
+    pub fn with_steps_per_detent(mut self, steps: u8) -> Self {
+        self.steps_per_detent = steps.clamp(1, 4) as i8;
+        self
+    }
+
+    /// Count quick detents several times
+    ///
+    /// Can be given several times; the highest count whose interval is not
+    /// exceeded applies. Detents reversing the direction always count once.
+    ///
+    /// Arguments
+    ///
+    /// * `interval`: - Maximum milliseconds since the previous detent
+    /// * `count`: - Number of interactions of the detent
+    pub fn with_acceleration(mut self, interval: u32, count: usize) -> Self {
+        self.acceleration.push((interval, count));
+        self
+    }
+
+    /// Handle the levels of the pins A and B
+    ///
+    /// Is called on every change of the pins, e.g. by the pin change interrupt,
+    /// or periodically faster than the knob can be turned.
+    ///
+    /// Arguments
+    ///
+    /// * `a`, `b`: - The levels of the pins
+    /// * `timestamp`: - Milliseconds of a free running clock
+    ///
+    /// Returns the interactions of a completed detent; none otherwise.
+    pub fn sample(&mut self, a: bool, b: bool, timestamp: u32) -> RepeatN<Interaction> {
+        let pins = (a as u8) << 1 | b as u8;
+        self.quarters += TRANSITIONS[(self.pins << 2 | pins) as usize];
+        self.pins = pins;
+        if self.quarters.abs() < self.steps_per_detent {
+            return repeat_n(Interaction::Next, 0);
+        }
+        let clockwise = self.quarters > 0;
+        self.quarters = 0;
+        self.detent(clockwise, timestamp)
+    }
+
+    /// Handle a detent decoded otherwise, e.g. by a quadrature decoder of a timer
+    ///
+    /// Arguments
+    ///
+    /// * `clockwise`: - The direction of the detent
+    /// * `timestamp`: - Milliseconds of a free running clock
+    ///
+    /// Returns the interactions of the detent.
+    pub fn detent(&mut self, clockwise: bool, timestamp: u32) -> RepeatN<Interaction> {
+        let count = match self.last_detent.replace((clockwise, timestamp)) {
+            Some((last, time)) if last == clockwise => {
+                let interval = timestamp.wrapping_sub(time);
+                self.acceleration
+                    .iter()
+                    .filter(|(max, _)| interval <= *max)
+                    .map(|(_, count)| *count)
+                    .max()
+                    .unwrap_or(1)
+            }
+            _ => 1,
+        };
+        let interaction = match clockwise {
+            true => Interaction::Next,
+            false => Interaction::Previous,
+        };
+        repeat_n(interaction, count)
+    }
+}
+
+#[cfg(test)]
+mod tests;
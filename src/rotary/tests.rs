@@ -0,0 +1,58 @@
+use super::*;
+
+/// Levels of A and B turning one detent clockwise
+const CLOCKWISE: [(bool, bool); 4] = [(true, false), (true, true), (false, true), (false, false)];
+
+fn turn(knob: &mut RotaryEncoder, levels: &[(bool, bool)], timestamp: u32) -> Vec<Interaction> {
+    levels
+        .iter()
+        .flat_map(|(a, b)| knob.sample(*a, *b, timestamp).collect::<Vec<_>>())
+        .collect()
+}
+
+#[test]
+fn decode_quadrature() {
+    let mut knob = RotaryEncoder::new();
+    assert_eq!(turn(&mut knob, &CLOCKWISE[..3], 0), vec![]);
+    assert_eq!(turn(&mut knob, &CLOCKWISE[3..], 0), vec![Interaction::Next]);
+    let mut counter_clockwise = CLOCKWISE;
+    counter_clockwise.reverse();
+    counter_clockwise.rotate_left(1);
+    assert_eq!(
+        turn(&mut knob, &counter_clockwise, 0),
+        vec![Interaction::Previous]
+    );
+}
+
+#[test]
+fn ignore_bouncing_pins() {
+    let mut knob = RotaryEncoder::new();
+    // A bounces before B follows
+    let levels = [(true, false), (false, false), (true, false), (true, true)];
+    assert_eq!(turn(&mut knob, &levels, 0), vec![]);
+    assert_eq!(turn(&mut knob, &CLOCKWISE[2..], 0), vec![Interaction::Next]);
+}
+
+#[test]
+fn half_steps_per_detent() {
+    let mut knob = RotaryEncoder::new().with_steps_per_detent(2);
+    assert_eq!(
+        turn(&mut knob, &CLOCKWISE, 0),
+        vec![Interaction::Next, Interaction::Next]
+    );
+}
+
+#[test]
+fn accelerate_quick_detents() {
+    let mut knob = RotaryEncoder::new()
+        .with_acceleration(50, 5)
+        .with_acceleration(20, 10);
+    let counts: Vec<_> = [0, 100, 140, 150, 160, 500]
+        .iter()
+        .map(|t| knob.detent(true, *t).count())
+        .collect();
+    assert_eq!(counts, vec![1, 1, 5, 10, 10, 1]);
+    // reversing the direction
+    assert_eq!(knob.detent(false, 510).count(), 1);
+    assert_eq!(knob.detent(false, 520).last(), Some(Interaction::Previous));
+}
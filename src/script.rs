@@ -0,0 +1,216 @@
+use super::{Interaction, PageError};
+use std::fmt::Write;
+
+/// An interaction of a script and the time to wait for it
+///
+/// Time is counted in ticks, i.e. iterations of the event loop without
+/// input. Page lifetimes are counted in the same unit.
+#[derive(Debug, Clone, Copy)]
+pub struct ScriptStep {
+    /// Number of ticks without input before the interaction
+    pub delay: u32,
+    /// The interaction
+    pub interaction: Interaction,
+}
+
+impl ScriptStep {
+    pub fn new(delay: u32, interaction: Interaction) -> Self {
+        ScriptStep { delay, interaction }
+    }
+}
+
+/// Input that plays back a script of interactions
+///
+/// Is used like any other input by the event loop: every call of `next()` is
+/// a tick; it returns `None` while waiting for the next interaction of the
+/// script and after the end of the script.
+///
+/// Scripts are text with one step per line: the delay in ticks followed by
+/// one of `action`, `next`, `previous`, `back` or `home`. Empty lines and
+/// lines starting with `#` are ignored. Scripts are recorded by `InputRecorder`,
+/// i.e. a bug report can contain the script that reproduces the bug.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::ScriptedInput;
+///
+///     let mut input = ScriptedInput::parse("# enter the menu\n0 action\n2 next\n").unwrap();
+///     let ticks: Vec<_> = (0..4).map(|_| input.next()).collect();
+///     assert!(matches!(ticks[..], [Some(_), None, None, Some(_)]));
+///     assert!(input.is_finished());
+/// ```
+pub struct ScriptedInput {
+    steps: Vec<ScriptStep>,
+    position: usize,
+    waited: u32,
+}
+
+impl ScriptedInput {
+    pub fn new(steps: Vec<ScriptStep>) -> Self {
+        ScriptedInput {
+            steps,
+            position: 0,
+            waited: 0,
+        }
+    }
+
+    /// Parse a script
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if a line is not a valid step
+    pub fn parse(script: &str) -> Result<Self, PageError> {
+        let mut steps = Vec::new();
+        for line in script.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut words = line.split_whitespace();
+            let delay = words.next().ok_or(PageError)?;
+            let delay = delay.parse().map_err(|_| PageError)?;
+            let interaction = words.next().ok_or(PageError)?;
+            let interaction = parse_interaction(interaction).ok_or(PageError)?;
+            if words.next().is_some() {
+                return Err(PageError);
+            }
+            steps.push(ScriptStep::new(delay, interaction));
+        }
+        Ok(ScriptedInput::new(steps))
+    }
+
+    /// The steps of the script
+    pub fn steps(&self) -> &[ScriptStep] {
+        &self.steps
+    }
+
+    /// All interactions of the script are played back
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.steps.len()
+    }
+
+    /// Play back the script from the start
+    pub fn rewind(&mut self) {
+        self.position = 0;
+        self.waited = 0;
+    }
+}
+
+impl Iterator for ScriptedInput {
+    type Item = Interaction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let step = self.steps.get(self.position)?;
+        if self.waited < step.delay {
+            self.waited += 1;
+            return None;
+        }
+        self.position += 1;
+        self.waited = 0;
+        Some(step.interaction)
+    }
+}
+
+/// Input that records the interactions of another input as script
+///
+/// Wraps the live input of the event loop. Every call of `next()` is a tick
+/// and is passed on to the live input.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{InputRecorder, Interaction};
+///
+///     let mut live = vec![None, Some(Interaction::Next), None, Some(Interaction::Action)]
+///         .into_iter();
+///     let mut input = InputRecorder::new(std::iter::from_fn(move || live.next().flatten()));
+///     for _ in 0..4 {
+///         input.next();
+///     }
+///     assert_eq!(input.script(), "1 next\n1 action\n");
+/// ```
+pub struct InputRecorder<I> {
+    input: I,
+    steps: Vec<ScriptStep>,
+    idle: u32,
+}
+
+impl<I: Iterator<Item = Interaction>> InputRecorder<I> {
+    pub fn new(input: I) -> Self {
+        InputRecorder {
+            input,
+            steps: Vec::new(),
+            idle: 0,
+        }
+    }
+
+    /// The recorded steps
+    pub fn steps(&self) -> &[ScriptStep] {
+        &self.steps
+    }
+
+    /// The recorded steps as script to be parsed by `ScriptedInput::parse()`
+    pub fn script(&self) -> String {
+        let mut script = String::new();
+        for step in &self.steps {
+            writeln!(
+                script,
+                "{} {}",
+                step.delay,
+                interaction_name(step.interaction)
+            )
+            .unwrap();
+        }
+        script
+    }
+
+    /// Play back the recorded steps
+    pub fn replay(&self) -> ScriptedInput {
+        ScriptedInput::new(self.steps.clone())
+    }
+
+    /// The live input
+    pub fn into_inner(self) -> I {
+        self.input
+    }
+}
+
+impl<I: Iterator<Item = Interaction>> Iterator for InputRecorder<I> {
+    type Item = Interaction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let interaction = self.input.next();
+        match interaction {
+            None => self.idle += 1,
+            Some(interaction) => {
+                self.steps.push(ScriptStep::new(self.idle, interaction));
+                self.idle = 0;
+            }
+        }
+        interaction
+    }
+}
+
+pub(crate) fn interaction_name(interaction: Interaction) -> &'static str {
+    match interaction {
+        Interaction::Action => "action",
+        Interaction::Next => "next",
+        Interaction::Previous => "previous",
+        Interaction::Back => "back",
+        Interaction::Home => "home",
+    }
+}
+
+pub(crate) fn parse_interaction(name: &str) -> Option<Interaction> {
+    match name {
+        "action" => Some(Interaction::Action),
+        "next" => Some(Interaction::Next),
+        "previous" => Some(Interaction::Previous),
+        "back" => Some(Interaction::Back),
+        "home" => Some(Interaction::Home),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests;
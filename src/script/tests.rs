@@ -0,0 +1,72 @@
+use super::*;
+use crate::page::{BasicPage, MenuPage, TextPage};
+use crate::testing::FrameBufferDisplay;
+use crate::{PageManager, PageNavigation};
+
+#[test]
+fn parse_script() {
+    let input = ScriptedInput::parse("# comment\n\n 3 next \n0 home\n").unwrap();
+    let steps: Vec<_> = input
+        .steps()
+        .iter()
+        .map(|s| (s.delay, interaction_name(s.interaction)))
+        .collect();
+    assert_eq!(steps, vec![(3, "next"), (0, "home")]);
+    assert!(ScriptedInput::parse("next").is_err());
+    assert!(ScriptedInput::parse("1 jump").is_err());
+    assert!(ScriptedInput::parse("1 next now").is_err());
+}
+
+#[test]
+fn play_back_with_delays() {
+    let mut input = ScriptedInput::parse("1 next\n0 back\n").unwrap();
+    let ticks: Vec<_> = (0..5).map(|_| input.next().map(interaction_name)).collect();
+    assert_eq!(ticks, vec![None, Some("next"), Some("back"), None, None]);
+    assert!(input.is_finished());
+    input.rewind();
+    assert!(!input.is_finished());
+}
+
+fn menu_tree() -> PageManager<'static, FrameBufferDisplay> {
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(FrameBufferDisplay::new(16, 2), Box::new(home));
+    m.register_sub(Box::new(TextPage::new(
+        BasicPage::new("Info", None),
+        "Info",
+    )));
+    m.register(Box::new(TextPage::new(
+        BasicPage::new("Help", None),
+        "Help",
+    )));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m
+}
+
+/// Drive the event loop for a number of ticks
+fn run(m: &mut PageManager<FrameBufferDisplay>, input: &mut impl Iterator<Item = Interaction>) {
+    let mut navigation = m.dispatch(PageNavigation::SystemStart).unwrap();
+    for _ in 0..10 {
+        navigation = match input.next() {
+            None => m.dispatch(navigation),
+            Some(interaction) => m.dispatch_interaction(interaction),
+        }
+        .unwrap();
+    }
+}
+
+#[test]
+fn replay_recorded_navigation() {
+    let live = ScriptedInput::parse("1 action\n2 next\n0 back\n1 next\n0 action\n").unwrap();
+    let mut recorder = InputRecorder::new(live);
+    let mut m = menu_tree();
+    run(&mut m, &mut recorder);
+    assert_eq!(m.active().title, "Help");
+    assert_eq!(
+        recorder.script(),
+        "1 action\n2 next\n0 back\n1 next\n0 action\n"
+    );
+
+    let mut replayed = menu_tree();
+    run(&mut replayed, &mut recorder.replay());
+    assert_eq!(replayed.display().frames(), m.display().frames());
+}
@@ -0,0 +1,324 @@
+//! Auxiliary 7-segment and 14-segment displays
+//!
+//! Devices often have a segment display next to the main LCD, e.g. four
+//! digits showing the measured value at a glance. Pages provide an
+//! abbreviated representation by `PageBaseInterface::short_text()`, e.g. the
+//! value without its unit; the `SegmentRenderer` shows the short text of the
+//! active page, i.e. the same page tree drives both displays.
+//!
+//! The text is encoded by the segments of the display: a decimal point or
+//! comma is merged into the digit before, numbers are right aligned and
+//! text is left aligned. Characters without a representation are blank.
+//!
+//! # Example
+//!
+//! ```
+//!     use embedded_multi_page_hmi::{
+//!         PageBaseInterface, PageError, PageInteractionInterface, PageInterface, PageManager,
+//!         PageNavigation, SegmentDisplay, SegmentKind, SegmentRenderer,
+//!     };
+//!
+//!     struct Lcd(String);
+//!
+//!     struct Pressure(String);
+//!     impl PageBaseInterface for Pressure {
+//!         fn title(&self) -> &str { "Pressure" }
+//!         fn short_text(&self) -> &str { &self.0 }
+//!     }
+//!     impl PageInteractionInterface for Pressure {}
+//!     impl PageInterface<Lcd> for Pressure {
+//!         fn display(&self, lcd: &mut Lcd) { lcd.0 = format!("{} mbar", self.0); }
+//!     }
+//!
+//!     /// Four digits of a TM1637 driver
+//!     struct Tm1637([u16; 4]);
+//!     impl SegmentDisplay for Tm1637 {
+//!         fn kind(&self) -> SegmentKind { SegmentKind::Seven }
+//!         fn digits(&self) -> usize { 4 }
+//!         fn show(&mut self, digits: &[u16]) -> Result<(), PageError> {
+//!             self.0.copy_from_slice(digits);
+//!             Ok(())
+//!         }
+//!     }
+//!
+//!     let home = Pressure(String::from("1.5"));
+//!     let mut m = PageManager::new(Lcd(String::new()), Box::new(home));
+//!     let mut segments = SegmentRenderer::new(Tm1637([0; 4]));
+//!     m.dispatch(PageNavigation::Update).unwrap();
+//!     segments.render(m.active().short_text).unwrap();
+//!     assert_eq!(m.display().0, "1.5 mbar");
+//!     // right aligned, the decimal point is part of the digit before
+//!     assert_eq!(segments.display().0, [0x00, 0x00, 0x86, 0x6d]);
+//! ```
+
+use super::PageError;
+
+/// Decimal point of 7-segment digits
+const SEVEN_DP: u16 = 1 << 7;
+
+/// Decimal point of 14-segment digits
+const FOURTEEN_DP: u16 = 1 << 14;
+
+/// The segments of the digits of a segment display
+///
+/// Segments are bits of a `u16`, segment `a` at the top is bit 0, continuing
+/// clockwise. The bits are the ones of common drivers, e.g. the TM1637 or
+/// MAX7219 in no-decode mode for 7 segments, the HT16K33 for 14 segments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentKind {
+    /// Bits `a` to `g` are 0 to 6, the decimal point is bit 7
+    Seven,
+    /// Bits `a` to `f` are 0 to 5, followed by the segments in the middle
+    /// `g1`, `g2`, `h`, `j`, `k`, `l`, `m`, `n`; the decimal point is bit 14
+    ///
+    /// `h`, `j` and `k` are the upper diagonal, vertical and diagonal segments
+    /// from left to right, `n`, `m` and `l` the lower ones.
+    Fourteen,
+}
+
+impl SegmentKind {
+    /// The segments lit to show a character - none if it has no representation
+    pub fn encode(&self, c: char) -> u16 {
+        match self {
+            SegmentKind::Seven => seven(c),
+            SegmentKind::Fourteen => fourteen(c),
+        }
+    }
+
+    /// The decimal point segment
+    pub fn decimal_point(&self) -> u16 {
+        match self {
+            SegmentKind::Seven => SEVEN_DP,
+            SegmentKind::Fourteen => FOURTEEN_DP,
+        }
+    }
+
+    /// Encode a text to the digits of a display
+    ///
+    /// Arguments
+    ///
+    /// * `text`: - The text to show, e.g. `21.5`
+    /// * `digits`: - The number of digits of the display
+    ///
+    /// Returns
+    ///
+    /// * exactly `digits` digits, the leftmost first; numbers are right
+    ///   aligned, text is left aligned and cut to the digits
+    pub fn encode_text(&self, text: &str, digits: usize) -> Vec<u16> {
+        let mut encoded: Vec<u16> = Vec::new();
+        let mut pointed = true;
+        for c in text.chars() {
+            match c {
+                '.' | ',' if !pointed => {
+                    if let Some(last) = encoded.last_mut() {
+                        *last |= self.decimal_point();
+                    }
+                    pointed = true;
+                }
+                '.' | ',' => encoded.push(self.decimal_point()),
+                c => {
+                    encoded.push(self.encode(c));
+                    pointed = false;
+                }
+            }
+        }
+        let number = text.trim().replace(',', ".").parse::<f32>().is_ok();
+        if number && encoded.len() < digits {
+            let mut aligned = vec![0; digits - encoded.len()];
+            aligned.append(&mut encoded);
+            return aligned;
+        }
+        encoded.resize(digits, 0);
+        encoded
+    }
+}
+
+/// A display of segment digits, e.g. a 4-digit 7-segment LED display
+pub trait SegmentDisplay {
+    /// The kind of the digits
+    fn kind(&self) -> SegmentKind;
+
+    /// The number of digits
+    fn digits(&self) -> usize;
+
+    /// Show the digits, the leftmost first, see `SegmentKind` for the bits
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if the display does not respond
+    fn show(&mut self, digits: &[u16]) -> Result<(), PageError>;
+}
+
+/// Renders short texts of pages to a segment display
+///
+/// Texts are only sent to the display if they changed since the last render.
+pub struct SegmentRenderer<S> {
+    display: S,
+    shown: Option<String>,
+}
+
+impl<S: SegmentDisplay> SegmentRenderer<S> {
+    /// Arguments
+    ///
+    /// * `display`: - The segment display
+    pub fn new(display: S) -> Self {
+        SegmentRenderer {
+            display,
+            shown: None,
+        }
+    }
+
+    /// Show a text, e.g. `PageManager::active().short_text`
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if the display does not respond; the text is
+    ///   sent again with the next render
+    pub fn render(&mut self, text: &str) -> Result<(), PageError> {
+        if self.shown.as_deref() == Some(text) {
+            return Ok(());
+        }
+        let digits = self.display.kind().encode_text(text, self.display.digits());
+        self.shown = None;
+        self.display.show(&digits)?;
+        self.shown = Some(String::from(text));
+        Ok(())
+    }
+
+    /// The segment display
+    pub fn display(&self) -> &S {
+        &self.display
+    }
+
+    /// The segment display - mutable, e.g. to set its brightness
+    pub fn display_mut(&mut self) -> &mut S {
+        &mut self.display
+    }
+
+    /// The segment display
+    pub fn into_inner(self) -> S {
+        self.display
+    }
+}
+
+/// 7-segment representation of a character
+fn seven(c: char) -> u16 {
+    match c {
+        '0' | 'O' => 0x3f,
+        '1' => 0x06,
+        '2' | 'Z' | 'z' => 0x5b,
+        '3' => 0x4f,
+        '4' => 0x66,
+        '5' | 'S' | 's' => 0x6d,
+        '6' => 0x7d,
+        '7' => 0x07,
+        '8' => 0x7f,
+        '9' | 'g' => 0x6f,
+        'A' | 'a' => 0x77,
+        'B' | 'b' => 0x7c,
+        'C' | '[' => 0x39,
+        'c' => 0x58,
+        'D' | 'd' => 0x5e,
+        'E' | 'e' => 0x79,
+        'F' | 'f' => 0x71,
+        'G' => 0x3d,
+        'H' | 'K' | 'k' | 'X' | 'x' => 0x76,
+        'h' => 0x74,
+        'I' => 0x30,
+        'i' => 0x10,
+        'J' | 'j' => 0x1e,
+        'L' | 'l' => 0x38,
+        'M' | 'm' | 'N' => 0x37,
+        'n' => 0x54,
+        'o' => 0x5c,
+        'P' | 'p' => 0x73,
+        'Q' | 'q' => 0x67,
+        'R' | 'r' => 0x50,
+        'T' | 't' => 0x78,
+        'U' | 'W' | 'w' => 0x3e,
+        'u' | 'V' | 'v' => 0x1c,
+        'Y' | 'y' => 0x6e,
+        ']' => 0x0f,
+        '-' => 0x40,
+        '_' => 0x08,
+        '=' => 0x48,
+        '°' => 0x63,
+        '"' => 0x22,
+        '\'' => 0x02,
+        _ => 0,
+    }
+}
+
+/// 14-segment representation of a character
+fn fourteen(c: char) -> u16 {
+    const A: u16 = 1;
+    const B: u16 = 1 << 1;
+    const C: u16 = 1 << 2;
+    const D: u16 = 1 << 3;
+    const E: u16 = 1 << 4;
+    const F: u16 = 1 << 5;
+    const G1: u16 = 1 << 6;
+    const G2: u16 = 1 << 7;
+    const H: u16 = 1 << 8;
+    const J: u16 = 1 << 9;
+    const K: u16 = 1 << 10;
+    const L: u16 = 1 << 11;
+    const M: u16 = 1 << 12;
+    const N: u16 = 1 << 13;
+    match c.to_ascii_uppercase() {
+        '0' => A | B | C | D | E | F | K | N,
+        '1' => B | C,
+        '2' => A | B | D | E | G1 | G2,
+        '3' => A | B | C | D | G2,
+        '4' => B | C | F | G1 | G2,
+        '5' => A | C | D | F | G1 | G2,
+        '6' => A | C | D | E | F | G1 | G2,
+        '7' => A | B | C,
+        '8' => A | B | C | D | E | F | G1 | G2,
+        '9' => A | B | C | D | F | G1 | G2,
+        'A' => A | B | C | E | F | G1 | G2,
+        'B' => A | B | C | D | G2 | J | M,
+        'C' => A | D | E | F,
+        'D' => A | B | C | D | J | M,
+        'E' => A | D | E | F | G1,
+        'F' => A | E | F | G1,
+        'G' => A | C | D | E | F | G2,
+        'H' => B | C | E | F | G1 | G2,
+        'I' => A | D | J | M,
+        'J' => B | C | D | E,
+        'K' => E | F | G1 | K | L,
+        'L' => D | E | F,
+        'M' => B | C | E | F | H | K,
+        'N' => B | C | E | F | H | L,
+        'O' => A | B | C | D | E | F,
+        'P' => A | B | E | F | G1 | G2,
+        'Q' => A | B | C | D | E | F | L,
+        'R' => A | B | E | F | G1 | G2 | L,
+        'S' => A | C | D | F | G1 | G2,
+        'T' => A | J | M,
+        'U' => B | C | D | E | F,
+        'V' => E | F | K | N,
+        'W' => B | C | E | F | L | N,
+        'X' => H | K | L | N,
+        'Y' => H | K | M,
+        'Z' => A | D | K | N,
+        '-' => G1 | G2,
+        '_' => D,
+        '=' => D | G1 | G2,
+        '+' => G1 | G2 | J | M,
+        '*' => G1 | G2 | H | J | K | L | M | N,
+        '/' => K | N,
+        '\\' => H | L,
+        '(' | '<' => K | L,
+        ')' | '>' => H | N,
+        '[' => A | D | E | F,
+        ']' => A | B | C | D,
+        '°' => A | B | F | G1 | G2,
+        '"' => B | F,
+        '\'' => J,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests;
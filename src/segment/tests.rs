@@ -0,0 +1,162 @@
+use super::*;
+use crate::page::{BasicPage, TextPage, ValuePage};
+use crate::{OnBoth, PageBaseInterface, PageInteractionInterface, PageInterface, PageManager};
+use crate::{PageNavigation, Quantity, Units};
+
+/// Segment display recording the digits shown
+struct SegmentMock {
+    kind: SegmentKind,
+    digits: Vec<u16>,
+    shown: usize,
+}
+
+impl SegmentMock {
+    fn new(kind: SegmentKind, digits: usize) -> Self {
+        SegmentMock {
+            kind,
+            digits: vec![0; digits],
+            shown: 0,
+        }
+    }
+}
+
+impl SegmentDisplay for SegmentMock {
+    fn kind(&self) -> SegmentKind {
+        self.kind
+    }
+
+    fn digits(&self) -> usize {
+        self.digits.len()
+    }
+
+    fn show(&mut self, digits: &[u16]) -> Result<(), PageError> {
+        self.digits.copy_from_slice(digits);
+        self.shown += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn encode_seven_segments() {
+    let kind = SegmentKind::Seven;
+    assert_eq!(kind.encode_text("0123", 4), vec![0x3f, 0x06, 0x5b, 0x4f]);
+    // numbers are right aligned, the point belongs to the digit before
+    assert_eq!(kind.encode_text("-2.5", 4), vec![0x00, 0x40, 0xdb, 0x6d]);
+    assert_eq!(kind.encode_text("1,5", 4), vec![0x00, 0x00, 0x86, 0x6d]);
+    // a leading point or two points take a digit of their own
+    assert_eq!(kind.encode_text(".5", 3), vec![0x00, 0x80, 0x6d]);
+    assert_eq!(kind.encode_text("1..", 3), vec![0x86, 0x80, 0x00]);
+    // text is left aligned and cut
+    assert_eq!(kind.encode_text("On", 4), vec![0x3f, 0x54, 0x00, 0x00]);
+    assert_eq!(kind.encode_text("Error", 4), vec![0x79, 0x50, 0x50, 0x5c]);
+    // unknown characters are blank
+    assert_eq!(kind.encode_text("#1", 2), vec![0x00, 0x06]);
+}
+
+#[test]
+fn encode_fourteen_segments() {
+    let kind = SegmentKind::Fourteen;
+    assert_eq!(kind.decimal_point(), 0x4000);
+    assert_eq!(kind.encode('0'), 0x243f);
+    assert_eq!(kind.encode('8'), 0x00ff);
+    // upper and lower case look alike
+    assert_eq!(kind.encode('m'), kind.encode('M'));
+    assert_eq!(kind.encode('X'), 0x2d00);
+    assert_eq!(
+        kind.encode_text("AUTO", 4),
+        vec![0x00f7, 0x003e, 0x1201, 0x003f]
+    );
+    assert_eq!(
+        kind.encode_text("9.5", 4),
+        vec![0x0000, 0x0000, 0x40ef, 0x00ed]
+    );
+}
+
+#[test]
+fn render_changed_texts_only() {
+    let mut sut = SegmentRenderer::new(SegmentMock::new(SegmentKind::Seven, 4));
+    sut.render("12").unwrap();
+    sut.render("12").unwrap();
+    assert_eq!(sut.display().shown, 1);
+    assert_eq!(sut.display().digits, vec![0x00, 0x00, 0x06, 0x5b]);
+    sut.render("13").unwrap();
+    assert_eq!(sut.display().shown, 2);
+    assert_eq!(sut.into_inner().digits, vec![0x00, 0x00, 0x06, 0x4f]);
+}
+
+/// Main display showing the content of pages
+struct Lcd(String);
+
+impl PageInterface<Lcd> for TextPage {
+    fn display(&self, lcd: &mut Lcd) {
+        lcd.0 = String::from(self.text);
+    }
+}
+
+impl PageInterface<Lcd> for ValuePage<'_> {
+    fn display(&self, lcd: &mut Lcd) {
+        lcd.0 = self.lines().join("\n");
+    }
+}
+
+/// A page with a short text of its own
+struct Mode;
+
+impl PageBaseInterface for Mode {
+    fn title(&self) -> &str {
+        "Mode"
+    }
+
+    fn short_text(&self) -> &str {
+        "Auto"
+    }
+}
+
+impl PageInteractionInterface for Mode {}
+
+impl PageInterface<Lcd> for Mode {
+    fn display(&self, lcd: &mut Lcd) {
+        lcd.0 = String::from("Automatic");
+    }
+}
+
+#[test]
+fn page_tree_drives_lcd_and_segments() {
+    static UNITS: Units = Units::new();
+    let home = TextPage::new(BasicPage::new("Home", None), "Hello");
+    let mut m = PageManager::new(Lcd(String::new()), Box::new(home));
+    m.register(Box::new(ValuePage::new(
+        BasicPage::new("Boiler", None),
+        Quantity::Temperature,
+        &UNITS,
+        Box::new(|| 60.0),
+    )));
+    m.register(Box::new(Mode));
+    let mut segments = SegmentRenderer::new(SegmentMock::new(SegmentKind::Fourteen, 4));
+
+    m.dispatch(PageNavigation::Update).unwrap();
+    segments.render(m.active().short_text).unwrap();
+    assert_eq!(m.display().0, "Automatic");
+    assert_eq!(segments.display().digits, digits_of("Auto"));
+
+    // the title is the short text by default
+    m.dispatch(PageNavigation::Left).unwrap();
+    segments.render(m.active().short_text).unwrap();
+    assert_eq!(m.display().0, "Hello");
+    assert_eq!(segments.display().digits, digits_of("Home"));
+
+    m.dispatch(PageNavigation::Left).unwrap();
+    segments.render(m.active().short_text).unwrap();
+    assert_eq!(m.display().0, "60.0 °C");
+    assert_eq!(segments.display().digits, digits_of(" 60.0"));
+}
+
+/// The 14-segment digits of a text
+fn digits_of(text: &str) -> Vec<u16> {
+    SegmentKind::Fourteen.encode_text(text, 4)
+}
+
+#[test]
+fn wrapped_pages_forward_the_short_text() {
+    assert_eq!(OnBoth(Mode).short_text(), "Auto");
+}
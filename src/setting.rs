@@ -1,3 +1,4 @@
+use super::PageError;
 use std::fmt::Debug;
 use std::{cell::Cell, default::Default, str::FromStr};
 
@@ -72,3 +73,18 @@ where
         self.0.set(v);
     }
 }
+
+/// A persistent store for settings and HMI state, e.g. flash, EEPROM or a file
+///
+/// Data is stored as bytes under a key. The encoding of the data is up to the
+/// user of the store.
+pub trait SettingsStore {
+    /// Store the data under the key - replaces data stored before
+    fn store(&mut self, key: &str, data: &[u8]) -> Result<(), PageError>;
+
+    /// Load the data stored under the key - `None` if nothing is stored
+    fn load(&mut self, key: &str) -> Option<Vec<u8>>;
+
+    /// Remove all data stored, e.g. for a factory reset
+    fn clear(&mut self) -> Result<(), PageError>;
+}
@@ -0,0 +1,224 @@
+//! Prototype page trees on the host in a terminal
+//!
+//! Requires the `sim-terminal` feature. All pages of this crate render to the
+//! `TerminalDisplay`; the title is shown in the first row, the content below.
+//!
+//! Keys are:
+//! * n, right arrow - next
+//! * p, left arrow - previous
+//! * space, enter - action
+//! * b, backspace - back
+//! * h, home - home
+//! * 0 to 9 - the digit entered directly
+//! * F1 to F12 - custom events 1 to 12
+//! * q, ctrl-c - quit the simulation
+//!
+//! # Example
+//!
+//! ```no_run
+//!     use embedded_multi_page_hmi::{
+//!         page::{BasicPage, TextPage},
+//!         simulator::{self, TerminalDisplay},
+//!         PageManager,
+//!     };
+//!     use std::time::Duration;
+//!
+//!     let home = TextPage::new(BasicPage::new("Home", None), "Hello");
+//!     let mut m = PageManager::new(TerminalDisplay::new(), Box::new(home));
+//!     simulator::run(&mut m, Duration::from_millis(500));
+//! ```
+
+use super::{
+    paginated_title, DisplayInfo, DisplaySize, InputEvent, Interaction, PageManager,
+    PageNavigation, ScrollPosition,
+};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    execute, style,
+    terminal::{self, disable_raw_mode, enable_raw_mode},
+};
+use std::io::stdout;
+use std::time::Duration;
+
+/// The terminal as display
+///
+/// The terminal is switched to raw mode while the display exists.
+pub struct TerminalDisplay(());
+
+impl TerminalDisplay {
+    pub fn new() -> Self {
+        enable_raw_mode().unwrap();
+        TerminalDisplay(())
+    }
+
+    /// Show a page
+    ///
+    /// Arguments
+    ///
+    /// * `title`: - The title shown highlighted in the first row
+    /// * `message`: - The content; lines are separated by `\n`
+    pub fn update(&mut self, title: &str, message: &str) {
+        let mut stdout = stdout();
+
+        execute!(
+            stdout,
+            terminal::Clear(terminal::ClearType::All),
+            cursor::Hide,
+            cursor::MoveTo(0, 0),
+            style::SetForegroundColor(style::Color::Black),
+            style::SetBackgroundColor(style::Color::Green),
+            style::Print(title.to_string()),
+            style::ResetColor,
+        )
+        .unwrap();
+        for (row, line) in message.lines().enumerate() {
+            execute!(
+                stdout,
+                cursor::MoveTo(0, row as u16 + 1),
+                style::Print(line.to_string())
+            )
+            .unwrap();
+        }
+    }
+
+    /// Show a page scrolling its content - the title is followed by the page shown, e.g. `Info 2/5`
+    pub fn update_scrolled(
+        &mut self,
+        title: &str,
+        message: &str,
+        position: Option<ScrollPosition>,
+    ) {
+        self.update(&paginated_title(title, position), message);
+    }
+}
+
+impl Default for TerminalDisplay {
+    fn default() -> Self {
+        TerminalDisplay::new()
+    }
+}
+
+impl Drop for TerminalDisplay {
+    fn drop(&mut self) {
+        let mut stdout = stdout();
+        execute!(
+            stdout,
+            terminal::Clear(terminal::ClearType::All),
+            cursor::MoveTo(0, 0),
+            cursor::Show,
+        )
+        .unwrap();
+        disable_raw_mode().unwrap();
+    }
+}
+
+/// The terminal size without the title row
+impl DisplayInfo for TerminalDisplay {
+    fn cols(&self) -> usize {
+        terminal_size().cols
+    }
+
+    fn rows(&self) -> usize {
+        terminal_size().rows
+    }
+}
+
+fn terminal_size() -> DisplaySize {
+    match terminal::size() {
+        Ok((cols, rows)) => DisplaySize::new(cols as usize, (rows as usize).saturating_sub(1)),
+        Err(_) => DisplaySize::new(80, 23),
+    }
+}
+
+/// Map a key event of the terminal to an interaction
+///
+/// Returns `None` if the key has no meaning.
+pub fn map_interaction(event: Event) -> Option<Interaction> {
+    match event {
+        Event::Key(KeyEvent { code, .. }) => match code {
+            KeyCode::Char('n') | KeyCode::Right => Some(Interaction::Next),
+            KeyCode::Char('p') | KeyCode::Left => Some(Interaction::Previous),
+            KeyCode::Char(' ') | KeyCode::Enter => Some(Interaction::Action),
+            KeyCode::Char('b') | KeyCode::Backspace => Some(Interaction::Back),
+            KeyCode::Char('h') | KeyCode::Home => Some(Interaction::Home),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Map a key event of the terminal to an input
+///
+/// Digits are entered directly, function keys are custom events; all other
+/// keys are mapped by `map_interaction()`. Returns `None` if the key has no
+/// meaning.
+pub fn map_input(event: Event) -> Option<InputEvent> {
+    match event {
+        Event::Key(KeyEvent {
+            code: KeyCode::Char(c),
+            ..
+        }) if c.is_ascii_digit() => Some(InputEvent::Char(c)),
+        Event::Key(KeyEvent {
+            code: KeyCode::F(n),
+            ..
+        }) => Some(InputEvent::Custom(n)),
+        _ => map_interaction(event).map(InputEvent::Primary),
+    }
+}
+
+/// The key event quits the simulation
+fn is_quit(event: &Event) -> bool {
+    match event {
+        Event::Key(KeyEvent { code, modifiers }) => {
+            *code == KeyCode::Char('q')
+                || (*code == KeyCode::Char('c') && modifiers.contains(KeyModifiers::CONTROL))
+        }
+        _ => false,
+    }
+}
+
+/// Run the HMI in the terminal
+///
+/// Starts the page manager by `PageNavigation::SystemStart` and dispatches
+/// inputs from the keyboard. Without input the navigation returned by
+/// the last dispatch is dispatched every `period`.
+/// The simulation ends after the shutdown, if a dispatch fails or if the
+/// quit key is pressed.
+///
+/// Arguments
+///
+/// * `manager`: - The page manager rendering to the terminal
+/// * `period`: - The time between updates of the active page
+pub fn run<C>(manager: &mut PageManager<'_, TerminalDisplay, C>, period: Duration) {
+    manager.set_display_info(&terminal_size());
+    let mut navigation = match manager.dispatch(PageNavigation::SystemStart) {
+        Ok(navigation) => navigation,
+        Err(_) => return,
+    };
+    loop {
+        let input = match event::poll(period) {
+            Ok(true) => event::read().ok(),
+            _ => None,
+        };
+        let result = match input {
+            Some(event) if is_quit(&event) => break,
+            Some(event) => match map_input(event) {
+                Some(input) => manager.dispatch_input(input),
+                None => continue,
+            },
+            None => manager.dispatch(navigation),
+        };
+        match result {
+            Err(_) | Ok(PageNavigation::Terminate) => break,
+            Ok(next) => navigation = next,
+        }
+    }
+}
+
+// ** All pages of this crate render to the terminal
+impl_text_display!(TerminalDisplay);
+
+#[cfg(test)]
+mod tests;
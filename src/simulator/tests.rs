@@ -0,0 +1,52 @@
+use super::*;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(code.into())
+}
+
+#[test]
+fn keys_map_to_interactions() {
+    assert!(matches!(
+        map_interaction(key(KeyCode::Char('n'))),
+        Some(Interaction::Next)
+    ));
+    assert!(matches!(
+        map_interaction(key(KeyCode::Left)),
+        Some(Interaction::Previous)
+    ));
+    assert!(matches!(
+        map_interaction(key(KeyCode::Enter)),
+        Some(Interaction::Action)
+    ));
+    assert!(matches!(
+        map_interaction(key(KeyCode::Backspace)),
+        Some(Interaction::Back)
+    ));
+    assert!(matches!(
+        map_interaction(key(KeyCode::Char('h'))),
+        Some(Interaction::Home)
+    ));
+    assert!(map_interaction(key(KeyCode::Char('x'))).is_none());
+}
+
+#[test]
+fn keys_map_to_inputs() {
+    assert_eq!(
+        map_input(key(KeyCode::Char('7'))),
+        Some(InputEvent::Char('7'))
+    );
+    assert_eq!(map_input(key(KeyCode::F(2))), Some(InputEvent::Custom(2)));
+    assert_eq!(
+        map_input(key(KeyCode::Char('n'))),
+        Some(InputEvent::Primary(Interaction::Next))
+    );
+    assert_eq!(map_input(key(KeyCode::Char('x'))), None);
+}
+
+#[test]
+fn quit_keys() {
+    assert!(is_quit(&key(KeyCode::Char('q'))));
+    let ctrl_c = Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+    assert!(is_quit(&ctrl_c));
+    assert!(!is_quit(&key(KeyCode::Char('c'))));
+}
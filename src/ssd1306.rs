@@ -0,0 +1,94 @@
+//! Turnkey SSD1306 OLED
+//!
+//! Requires the `ssd1306` feature. The most common hobbyist display, a
+//! monochrome 128x64 SSD1306 OLED on I2C, works with a single call: the
+//! display is initialized, switched to buffered graphics mode and wrapped by
+//! a `GraphicsDisplay` with the default theme. Other sizes and SPI displays
+//! are set up by `init()`.
+//!
+//! The pages are drawn to the buffer of the display; the changed part of the
+//! buffer is sent to the display by `flush()` after a dispatch.
+//!
+//! See `examples/ssd1306-i2c.rs` for a complete HMI with buttons on GPIO.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let display = ssd1306::init_i2c(i2c)?;
+//! let mut m = PageManager::new(display, Box::new(home));
+//! m.set_display_info(&DisplaySize::from_info(m.display()));
+//! m.dispatch(PageNavigation::SystemStart)?;
+//! m.display_mut().flush()?;
+//! ```
+
+use super::graphics::{GraphicsDisplay, Theme};
+use super::PageError;
+
+use ::ssd1306::{
+    mode::BufferedGraphicsMode,
+    prelude::{
+        DisplayConfig, DisplayRotation, DisplaySize, DisplaySize128x64, I2CInterface,
+        WriteOnlyDataCommand,
+    },
+    I2CDisplayInterface, Ssd1306,
+};
+use embedded_hal::i2c::I2c;
+
+/// A buffered SSD1306 OLED as display of the pages
+pub type Ssd1306Display<DI, SIZE> = GraphicsDisplay<Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>>;
+
+/// Initialize an SSD1306 OLED
+///
+/// The display is cleared and shows the pages in the default theme.
+///
+/// Arguments
+///
+/// * `interface`: - The I2C or SPI interface of the display
+/// * `size`: - The size of the display, e.g. `DisplaySize128x32`
+/// * `rotation`: - The mounting of the display
+///
+/// Returns
+///
+/// * `Err(PageError)` - if the display does not respond
+pub fn init<DI: WriteOnlyDataCommand, SIZE: DisplaySize>(
+    interface: DI,
+    size: SIZE,
+    rotation: DisplayRotation,
+) -> Result<Ssd1306Display<DI, SIZE>, PageError> {
+    let mut oled = Ssd1306::new(interface, size, rotation).into_buffered_graphics_mode();
+    oled.init().map_err(|_| PageError)?;
+    Ok(GraphicsDisplay::new(oled, Theme::default()))
+}
+
+/// Initialize a 128x64 SSD1306 OLED at the I2C address `0x3C`
+///
+/// Arguments
+///
+/// * `i2c`: - The I2C bus the display is connected to
+///
+/// Returns
+///
+/// * `Err(PageError)` - if the display does not respond
+pub fn init_i2c<I: I2c>(
+    i2c: I,
+) -> Result<Ssd1306Display<I2CInterface<I>, DisplaySize128x64>, PageError> {
+    init(
+        I2CDisplayInterface::new(i2c),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+}
+
+impl<DI: WriteOnlyDataCommand, SIZE: DisplaySize> Ssd1306Display<DI, SIZE> {
+    /// Send the changed part of the buffer to the display
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if the display does not respond
+    pub fn flush(&mut self) -> Result<(), PageError> {
+        self.target_mut().flush().map_err(|_| PageError)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,70 @@
+use super::*;
+use crate::page::{BasicPage, TextPage};
+use crate::{DisplaySize, PageManager, PageNavigation};
+use embedded_hal::i2c::{ErrorType, Operation};
+use std::cell::RefCell;
+use std::convert::Infallible;
+use std::rc::Rc;
+
+/// I2C bus collecting the bytes written per address
+#[derive(Default)]
+struct BusMock {
+    written: Rc<RefCell<Vec<(u8, Vec<u8>)>>>,
+}
+
+impl BusMock {
+    /// Number of data bytes written, i.e. pixels sent in bytes of 8 pixels
+    fn data(&self) -> usize {
+        self.written
+            .borrow()
+            .iter()
+            .filter(|(_, bytes)| bytes.first() == Some(&0x40))
+            .map(|(_, bytes)| bytes.len() - 1)
+            .sum()
+    }
+}
+
+impl ErrorType for BusMock {
+    type Error = Infallible;
+}
+
+impl I2c for BusMock {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for operation in operations {
+            if let Operation::Write(bytes) = operation {
+                self.written.borrow_mut().push((address, bytes.to_vec()));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn init_draw_and_flush_over_i2c() {
+    let bus = BusMock::default();
+    let display = init_i2c(BusMock {
+        written: Rc::clone(&bus.written),
+    })
+    .unwrap();
+    assert_eq!(DisplaySize::from_info(&display), DisplaySize::new(21, 5));
+    let home = TextPage::new(BasicPage::new("Home", None), "Hello");
+    let mut m = PageManager::new(display, Box::new(home));
+    m.dispatch(PageNavigation::Update).unwrap();
+    m.display_mut().flush().unwrap();
+    assert!(bus
+        .written
+        .borrow()
+        .iter()
+        .all(|(address, _)| *address == 0x3c));
+    // the whole buffer of 128x64 pixels is sent after the first page
+    assert_eq!(bus.data(), 128 * 64 / 8);
+
+    // nothing changed, nothing sent
+    bus.written.borrow_mut().clear();
+    m.display_mut().flush().unwrap();
+    assert_eq!(bus.data(), 0);
+}
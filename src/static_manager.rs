@@ -0,0 +1,687 @@
+use super::page_manager::PageManagerState;
+use super::*;
+use arrayvec::ArrayVec;
+
+/// Generate an enum of page types usable by the `StaticPageManager`
+///
+/// Every variant wraps one page type. The enum implements the page traits
+/// by delegating to the wrapped page, i.e. it is dispatched statically
+/// without virtual function tables.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{
+///         page_enum, page::{BasicPage, MenuPage, TextPage}, PageInterface,
+///         StaticPageManager,
+///     };
+///
+///     pub struct Display;
+///
+///     impl PageInterface<Display> for TextPage {
+///         fn display(&self, _display_driver: &mut Display) {}
+///     }
+///
+///     impl PageInterface<Display> for MenuPage<'_> {
+///         fn display(&self, _display_driver: &mut Display) {}
+///     }
+///
+///     page_enum! {
+///         pub enum Pages<'a> for Display {
+///             Text(TextPage),
+///             Menu(MenuPage<'a>),
+///         }
+///     }
+///
+///     let home = Pages::Menu(MenuPage::new(BasicPage::new("Home", None), None));
+///     let mut m: StaticPageManager<_, _, 4> = StaticPageManager::new(Display, home);
+///     let info = Pages::Text(TextPage::new(BasicPage::new("Info", None), "Text"));
+///     m.register_sub(info).unwrap();
+/// ```
+#[macro_export]
+macro_rules! page_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident $(<$lt:lifetime>)? for $display:ty {
+            $($variant:ident($page:ty)),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name $(<$lt>)? {
+            $($variant($page)),*
+        }
+
+        impl $(<$lt>)? $crate::PageBaseInterface for $name $(<$lt>)? {
+            fn update<'b>(
+                &mut self,
+                title_of_subpages: Option<Box<dyn Iterator<Item = &'b str> + 'b>>,
+            ) -> Result<$crate::PageNavigation, $crate::PageError> {
+                match self {
+                    $($name::$variant(page) => page.update(title_of_subpages),)*
+                }
+            }
+
+            fn update_with_subpages<'b>(
+                &mut self,
+                subpages: Option<Box<dyn Iterator<Item = $crate::SubPageItem<'b>> + 'b>>,
+            ) -> Result<$crate::PageNavigation, $crate::PageError> {
+                match self {
+                    $($name::$variant(page) => page.update_with_subpages(subpages),)*
+                }
+            }
+
+            fn title(&self) -> &str {
+                match self {
+                    $($name::$variant(page) => page.title(),)*
+                }
+            }
+
+            fn short_text(&self) -> &str {
+                match self {
+                    $($name::$variant(page) => page.short_text(),)*
+                }
+            }
+
+            fn preview(&self) -> Option<&str> {
+                match self {
+                    $($name::$variant(page) => page.preview(),)*
+                }
+            }
+
+            fn is_menu(&self) -> bool {
+                match self {
+                    $($name::$variant(page) => page.is_menu(),)*
+                }
+            }
+
+            fn is_setting(&self) -> bool {
+                match self {
+                    $($name::$variant(page) => page.is_setting(),)*
+                }
+            }
+
+            fn is_alarm(&self) -> bool {
+                match self {
+                    $($name::$variant(page) => page.is_alarm(),)*
+                }
+            }
+
+            fn is_locked(&self) -> bool {
+                match self {
+                    $($name::$variant(page) => page.is_locked(),)*
+                }
+            }
+
+            fn on_leave(&mut self) {
+                match self {
+                    $($name::$variant(page) => page.on_leave(),)*
+                }
+            }
+
+            fn on_enter(&mut self, param: Option<usize>, result: Option<&$crate::PageResult>) {
+                match self {
+                    $($name::$variant(page) => page.on_enter(param, result),)*
+                }
+            }
+
+            fn take_result(&mut self) -> Option<$crate::PageResult> {
+                match self {
+                    $($name::$variant(page) => page.take_result(),)*
+                }
+            }
+
+            fn can_leave(&self) -> Result<(), &str> {
+                match self {
+                    $($name::$variant(page) => page.can_leave(),)*
+                }
+            }
+
+            fn on_shutdown(&mut self) {
+                match self {
+                    $($name::$variant(page) => page.on_shutdown(),)*
+                }
+            }
+
+            fn reload(&mut self) {
+                match self {
+                    $($name::$variant(page) => page.reload(),)*
+                }
+            }
+
+            fn selected(&self) -> Option<usize> {
+                match self {
+                    $($name::$variant(page) => page.selected(),)*
+                }
+            }
+
+            fn select(&mut self, index: usize) {
+                match self {
+                    $($name::$variant(page) => page.select(index),)*
+                }
+            }
+
+            fn take_feedback(&mut self) -> Option<$crate::Feedback> {
+                match self {
+                    $($name::$variant(page) => page.take_feedback(),)*
+                }
+            }
+
+            fn take_access_level(&mut self) -> Option<$crate::AccessLevel> {
+                match self {
+                    $($name::$variant(page) => page.take_access_level(),)*
+                }
+            }
+
+            fn scroll_position(&self) -> Option<$crate::ScrollPosition> {
+                match self {
+                    $($name::$variant(page) => page.scroll_position(),)*
+                }
+            }
+
+            fn set_display_info(&mut self, info: &dyn $crate::DisplayInfo) {
+                match self {
+                    $($name::$variant(page) => page.set_display_info(info),)*
+                }
+            }
+
+            fn set_siblings(&mut self, siblings: $crate::Siblings<'_>) {
+                match self {
+                    $($name::$variant(page) => page.set_siblings(siblings),)*
+                }
+            }
+        }
+
+        impl $(<$lt>)? $crate::PageInteractionInterface for $name $(<$lt>)? {
+            fn dispatch(&mut self, interaction: $crate::Interaction) -> $crate::PageNavigation {
+                match self {
+                    $($name::$variant(page) => page.dispatch(interaction),)*
+                }
+            }
+
+            fn dispatch_char(&mut self, c: char) -> $crate::PageNavigation {
+                match self {
+                    $($name::$variant(page) => page.dispatch_char(c),)*
+                }
+            }
+
+            fn dispatch_custom(&mut self, code: u8) -> $crate::PageNavigation {
+                match self {
+                    $($name::$variant(page) => page.dispatch_custom(code),)*
+                }
+            }
+        }
+
+        impl $(<$lt>)? $crate::PageInterface<$display> for $name $(<$lt>)? {
+            fn display(&self, display_driver: &mut $display) {
+                match self {
+                    $($name::$variant(page) => page.display(display_driver),)*
+                }
+            }
+        }
+    };
+}
+
+/// Links of a page to its neighbours in the page tree by index
+#[derive(Clone, Copy)]
+struct Links {
+    registered: bool,
+    left: Option<usize>,
+    right: Option<usize>,
+    up: Option<usize>,
+    down: Option<usize>,
+}
+
+impl Links {
+    const UNREGISTERED: Links = Links {
+        registered: false,
+        left: None,
+        right: None,
+        up: None,
+        down: None,
+    };
+}
+
+/// A PageManager without heap allocated pages and dynamic dispatch
+///
+/// All pages are of the same type `P`, typically an enum of page types
+/// generated by the `page_enum!` macro. At most `N` pages can be registered.
+/// The memory used is known at compile time, which suits tiny
+/// micro controllers.
+///
+/// The pages are stored in a page storage `S`, which is any container that
+/// can be accessed as slice of pages, e.g. an array, a `heapless::Vec` or a
+/// caller provided arena `&mut [P]`. By default pages are moved into an
+/// `ArrayVec` by the `register*` functions. Pages that already live in a
+/// storage are linked into the page tree by their index in the storage via the
+/// `register*_index` functions.
+///
+/// Navigation and registration behave like the ones of `PageManager`.
+/// Only menu pages get the titles of their sub pages on update, which is the
+/// only heap allocation left.
+/// There is no application context, i.e. pages are updated and dispatched
+/// without one.
+pub struct StaticPageManager<D, P, const N: usize, S = ArrayVec<P, N>> {
+    display: D,
+    pages: S,
+    links: [Links; N],
+    home: usize,
+    active: usize,
+    startup: Option<P>,
+    shutdown: Option<P>,
+    state: PageManagerState,
+    display_size: Option<DisplaySize>,
+}
+
+impl<D, P: PageInterface<D>, const N: usize> StaticPageManager<D, P, N> {
+    /// StaticPageManager Constructor
+    ///
+    /// Arguments
+    ///
+    /// * `display`: The display data structure where all output is rendered to
+    /// * `home`: The "home" page. Other pages are added by register_* calls.
+    pub fn new(display: D, home: P) -> Self {
+        let mut pages = ArrayVec::new();
+        pages.push(home);
+        Self::with_storage(display, pages, 0).unwrap()
+    }
+
+    /// Register a new page
+    ///
+    /// The page is registered in the "left" direction of the
+    /// active page. The registered page will be the new active page.
+    ///
+    /// Returns
+    ///
+    /// * The id of the registered page or `Err(PageError)` if all `N` pages are in use.
+    pub fn register(&mut self, page: P) -> Result<PageId, PageError> {
+        let index = self.push(page)?;
+        self.register_index(index)
+    }
+
+    /// Register a new sub page
+    ///
+    /// The page is registered in the "down" direction of the
+    /// active page. The registered page will be the new active page.
+    /// Sub pages registered before become sub pages of the registered page.
+    ///
+    /// Returns
+    ///
+    /// * The id of the registered page or `Err(PageError)` if all `N` pages are in use.
+    pub fn register_sub(&mut self, page: P) -> Result<PageId, PageError> {
+        let index = self.push(page)?;
+        self.register_sub_index(index)
+    }
+
+    fn push(&mut self, page: P) -> Result<usize, PageError> {
+        self.pages.try_push(page).map_err(|_| PageError)?;
+        Ok(self.pages.len() - 1)
+    }
+}
+
+impl<D, P: PageInterface<D>, const N: usize, S: AsRef<[P]> + AsMut<[P]>>
+    StaticPageManager<D, P, N, S>
+{
+    /// StaticPageManager Constructor for pages living in a page storage
+    ///
+    /// Arguments
+    ///
+    /// * `display`: The display data structure where all output is rendered to
+    /// * `pages`: The page storage. Pages are linked into the page tree by the
+    ///   register*_index functions.
+    /// * `home`: Index of the "home" page in the page storage.
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` if the home page is not in the storage or beyond `N`.
+    pub fn with_storage(display: D, pages: S, home: usize) -> Result<Self, PageError> {
+        if home >= pages.as_ref().len() || home >= N {
+            return Err(PageError);
+        }
+        let mut links = [Links::UNREGISTERED; N];
+        links[home].registered = true;
+        Ok(StaticPageManager {
+            display,
+            pages,
+            links,
+            home,
+            active: home,
+            startup: None,
+            shutdown: None,
+            state: PageManagerState::Startup,
+            display_size: None,
+        })
+    }
+
+    /// The display all output is rendered to
+    pub fn display(&self) -> &D {
+        &self.display
+    }
+
+    /// The display all output is rendered to - mutable
+    pub fn display_mut(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    /// Register a page of the page storage
+    ///
+    /// The page is registered in the "left" direction of the
+    /// active page. The registered page will be the new active page.
+    ///
+    /// Arguments
+    ///
+    /// * `index` - Index of the page in the page storage.
+    ///
+    /// Returns
+    ///
+    /// * The id of the registered page or `Err(PageError)` if there is no such page
+    ///   or the page is already registered.
+    pub fn register_index(&mut self, index: usize) -> Result<PageId, PageError> {
+        self.check_unregistered(index)?;
+        let active = self.links[self.active];
+        self.links[index] = Links {
+            registered: true,
+            left: active.left,
+            right: Some(self.active),
+            up: active.up,
+            down: None,
+        };
+        if let Some(left) = active.left {
+            self.links[left].right = Some(index);
+        }
+        self.links[self.active].left = Some(index);
+        self.active = index;
+        Ok(PageId(index))
+    }
+
+    /// Register a page of the page storage as sub page
+    ///
+    /// The page is registered in the "down" direction of the
+    /// active page. The registered page will be the new active page.
+    /// Sub pages registered before become sub pages of the registered page.
+    ///
+    /// Arguments
+    ///
+    /// * `index` - Index of the page in the page storage.
+    ///
+    /// Returns
+    ///
+    /// * The id of the registered page or `Err(PageError)` if there is no such page
+    ///   or the page is already registered.
+    pub fn register_sub_index(&mut self, index: usize) -> Result<PageId, PageError> {
+        self.check_unregistered(index)?;
+        let down = self.links[self.active].down;
+        self.links[index] = Links {
+            registered: true,
+            left: None,
+            right: None,
+            up: Some(self.active),
+            down,
+        };
+        let mut sub = down;
+        while let Some(i) = sub {
+            self.links[i].up = Some(index);
+            sub = self.links[i].left;
+        }
+        self.links[self.active].down = Some(index);
+        self.active = index;
+        Ok(PageId(index))
+    }
+
+    fn check_unregistered(&self, index: usize) -> Result<(), PageError> {
+        if index >= self.pages.as_ref().len() || index >= N || self.links[index].registered {
+            return Err(PageError);
+        }
+        Ok(())
+    }
+
+    /// Register a startup page
+    ///
+    /// Multiple calls to this function overwrite the previously set startup page.
+    pub fn register_startup(&mut self, page: P) {
+        self.startup = Some(page);
+    }
+
+    /// Register a shutdown page
+    ///
+    /// Multiple calls to this function overwrite the previously set shutdown page.
+    pub fn register_shutdown(&mut self, page: P) {
+        self.shutdown = Some(page);
+    }
+
+    /// Id of the active page
+    pub fn active_id(&self) -> PageId {
+        PageId(self.active)
+    }
+
+    /// The index of the page a navigation activates
+    fn target(&self, navigation: PageNavigation) -> Result<usize, PageError> {
+        let links = self.links[self.active];
+        Ok(match navigation {
+            // when navigating left, we turn around at the end
+            PageNavigation::Left => match links.left {
+                Some(left) => left,
+                None => self.most_right(self.active),
+            },
+            PageNavigation::Right => links.right.unwrap_or(self.active),
+            PageNavigation::Home => self.home,
+            PageNavigation::Up => match links.up {
+                Some(up) => up,
+                None => self.most_right(self.active),
+            },
+            PageNavigation::NthSubpage(index) => {
+                let mut active = links.down.unwrap_or(self.active);
+                for _ in 1..index {
+                    match self.links[active].left {
+                        Some(left) => active = left,
+                        None => break,
+                    }
+                }
+                active
+            }
+            PageNavigation::ToWith(id, _) | PageNavigation::To(id) => match self.links.get(id.0) {
+                Some(links) if links.registered => id.0,
+                _ => return Err(PageError),
+            },
+            _ => self.active,
+        })
+    }
+
+    /// The first navigation of `PageNavigation::FirstOf` leading to another page
+    fn resolve(&self, targets: &[PageNavigation]) -> PageNavigation {
+        for target in targets {
+            let target = match *target {
+                PageNavigation::FirstOf(nested) => match self.resolve(nested) {
+                    // none of the nested navigations leads away
+                    PageNavigation::Update => continue,
+                    target => target,
+                },
+                target => target,
+            };
+            let leads_away = match target {
+                PageNavigation::Left
+                | PageNavigation::Right
+                | PageNavigation::Home
+                | PageNavigation::Up
+                | PageNavigation::NthSubpage(_)
+                | PageNavigation::ToWith(_, _)
+                | PageNavigation::To(_) => {
+                    matches!(self.target(target), Ok(index) if index != self.active)
+                }
+                _ => true,
+            };
+            if leads_away {
+                return target;
+            }
+        }
+        PageNavigation::Update
+    }
+
+    /// The first page on the level of the given page
+    fn most_right(&self, index: usize) -> usize {
+        let mut index = index;
+        while let Some(right) = self.links[index].right {
+            index = right;
+        }
+        index
+    }
+
+    /// Let pages adapt their content to the display
+    ///
+    /// The display capabilities are passed to every page before it gets updated.
+    pub fn set_display_info(&mut self, info: &dyn DisplayInfo) {
+        self.display_size = Some(DisplaySize::from_info(info));
+    }
+
+    /// Update the content of the active page on the display
+    ///
+    /// Potentially initiate a page change before displaying, since the
+    /// update responsibility is the responsibility of the specific active page
+    pub fn update(&mut self) -> Result<(), PageError> {
+        if let Some(size) = &self.display_size {
+            self.pages.as_mut()[self.active].set_display_info(size);
+        }
+        let (before, rest) = self.pages.as_mut().split_at_mut(self.active);
+        let (page, after) = rest.split_first_mut().unwrap();
+        let navigation = if page.is_menu() {
+            page.update(Some(Box::new(SubTitles {
+                before,
+                after,
+                active: self.active,
+                links: &self.links,
+                next: self.links[self.active].down,
+            })))?
+        } else {
+            page.update(None)?
+        };
+
+        // in case the page requires another page to navigate this needs to be performed
+        if navigation != PageNavigation::Update {
+            self.dispatch(navigation)?;
+        }
+
+        self.pages.as_mut()[self.active].display(&mut self.display);
+        Ok(())
+    }
+
+    /// Dispatch an interaction event
+    ///
+    /// Let the active page process the interaction event and eventually turn
+    /// The interaction event into a executed page navigation
+    pub fn dispatch_interaction(
+        &mut self,
+        interaction: Interaction,
+    ) -> Result<PageNavigation, PageError> {
+        let navigation = match self.state {
+            PageManagerState::Startup => match &mut self.startup {
+                None => self.pages.as_mut()[self.active].dispatch(interaction),
+                Some(x) => x.dispatch(interaction),
+            },
+            PageManagerState::Operational => self.pages.as_mut()[self.active].dispatch(interaction),
+            PageManagerState::Shutdown => match &mut self.shutdown {
+                None => self.pages.as_mut()[self.active].dispatch(interaction),
+                Some(x) => x.dispatch(interaction),
+            },
+        };
+        self.dispatch(navigation)
+    }
+
+    /// Dispatch a navigation event
+    ///
+    /// The event can cause a change of the active page or
+    /// lead to an update of the active page content.
+    pub fn dispatch(&mut self, navigation: PageNavigation) -> Result<PageNavigation, PageError> {
+        let origin = self.active;
+        let mut navigation = navigation;
+        match navigation {
+            PageNavigation::SystemStart => {
+                self.active = self.home;
+                if let Some(page) = &mut self.startup {
+                    if let Some(size) = &self.display_size {
+                        page.set_display_info(size);
+                    }
+                    navigation = page.update(None)?;
+                    page.display(&mut self.display);
+                }
+            }
+            PageNavigation::SystemStop => {
+                if !matches!(self.state, PageManagerState::Shutdown) {
+                    self.pages
+                        .as_mut()
+                        .iter_mut()
+                        .for_each(|page| page.on_shutdown());
+                }
+                match &mut self.shutdown {
+                    Some(page) => {
+                        if let Some(size) = &self.display_size {
+                            page.set_display_info(size);
+                        }
+                        navigation = page.update(None)?;
+                        page.display(&mut self.display);
+                    }
+                    None => navigation = PageNavigation::Terminate,
+                }
+            }
+            PageNavigation::Terminate => {}
+            PageNavigation::Update => {
+                self.update()?;
+            }
+            PageNavigation::Reload => {
+                self.pages.as_mut()[self.active].reload();
+                self.update()?;
+                navigation = PageNavigation::Update;
+            }
+            PageNavigation::FirstOf(targets) => {
+                return self.dispatch(self.resolve(targets));
+            }
+            _ => {
+                self.active = self.target(navigation)?;
+                if self.active != origin {
+                    self.pages.as_mut()[origin].on_leave();
+                }
+                if let PageNavigation::ToWith(_, param) = navigation {
+                    self.pages.as_mut()[self.active].on_enter(Some(param), None);
+                }
+                self.update()?;
+                navigation = PageNavigation::Update;
+            }
+        };
+
+        // update the internal state for Correct HMI interaction update
+        match navigation {
+            PageNavigation::SystemStart => self.state = PageManagerState::Startup,
+            PageNavigation::SystemStop | PageNavigation::Terminate => {
+                self.state = PageManagerState::Shutdown
+            }
+            _ => self.state = PageManagerState::Operational,
+        }
+
+        Ok(navigation)
+    }
+}
+
+/// Iterator over the titles of the sub pages of a page
+struct SubTitles<'b, P> {
+    before: &'b [P],
+    after: &'b [P],
+    active: usize,
+    links: &'b [Links],
+    next: Option<usize>,
+}
+
+impl<'b, P: PageBaseInterface> Iterator for SubTitles<'b, P> {
+    type Item = &'b str;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|index| {
+            self.next = self.links[index].left;
+            // a page is never a sub page of itself
+            if index < self.active {
+                self.before[index].title()
+            } else {
+                self.after[index - self.active - 1].title()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,261 @@
+use super::*;
+use crate::page::{BasicPage, MenuPage, PinPage, TextPage};
+
+pub struct DisplayMock(Vec<String>);
+
+impl PageInterface<DisplayMock> for TextPage {
+    fn display(&self, display_driver: &mut DisplayMock) {
+        display_driver.0.push(self.basic.title.to_string());
+    }
+}
+
+impl PageInterface<DisplayMock> for MenuPage<'_> {
+    fn display(&self, display_driver: &mut DisplayMock) {
+        display_driver.0.push(self.sub_titles.to_string());
+    }
+}
+
+impl PageInterface<DisplayMock> for PinPage {
+    fn display(&self, display_driver: &mut DisplayMock) {
+        display_driver.0.push(self.line());
+    }
+}
+
+/// An alarm raised by a setting out of range
+pub struct RangeAlarm;
+
+impl PageBaseInterface for RangeAlarm {
+    fn title(&self) -> &str {
+        "Out of range"
+    }
+
+    fn is_setting(&self) -> bool {
+        true
+    }
+
+    fn is_alarm(&self) -> bool {
+        true
+    }
+}
+
+// any function key acknowledges the alarm
+impl PageInteractionInterface for RangeAlarm {
+    fn dispatch_custom(&mut self, _code: u8) -> PageNavigation {
+        PageNavigation::Up
+    }
+}
+
+impl PageInterface<DisplayMock> for RangeAlarm {
+    fn display(&self, display_driver: &mut DisplayMock) {
+        display_driver.0.push(self.title().to_string());
+    }
+}
+
+page_enum! {
+    enum Pages<'a> for DisplayMock {
+        Text(TextPage),
+        Menu(MenuPage<'a>),
+        Pin(PinPage),
+        Alarm(RangeAlarm),
+    }
+}
+
+fn text(title: &'static str) -> Pages<'static> {
+    Pages::Text(TextPage::new(BasicPage::new(title, None), ""))
+}
+
+fn menu(title: &'static str) -> Pages<'static> {
+    Pages::Menu(MenuPage::new(BasicPage::new(title, None), Some("Back")))
+}
+
+fn tree() -> StaticPageManager<DisplayMock, Pages<'static>, 8> {
+    let mut m = StaticPageManager::new(DisplayMock(Vec::new()), menu("Home"));
+    m.register(text("Info")).unwrap();
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.register_sub(text("Config-1")).unwrap();
+    m.register(menu("Sub-Menu")).unwrap();
+    m.register_sub(text("Config-2")).unwrap();
+    m.register(text("Config-3")).unwrap();
+    m.display.0.clear();
+    m
+}
+
+#[test]
+fn page_enum_delegates() {
+    let mut sut = menu("Menu");
+    assert_eq!(sut.title(), "Menu");
+    assert!(sut.is_menu());
+    assert_eq!(sut.dispatch(Interaction::Back), PageNavigation::Up);
+    assert!(!text("Text").is_menu());
+    assert!(!text("Text").is_setting());
+    assert!(Pages::Alarm(RangeAlarm).is_setting());
+    assert!(Pages::Alarm(RangeAlarm).is_alarm());
+    assert_eq!(
+        Pages::Alarm(RangeAlarm).dispatch_custom(1),
+        PageNavigation::Up
+    );
+
+    let mut pin = Pages::Pin(
+        PinPage::new(BasicPage::new("PIN", None), 1).with_pin("0", AccessLevel::Service),
+    );
+    pin.dispatch(Interaction::Action);
+    assert_eq!(pin.take_access_level(), Some(AccessLevel::Service));
+    assert_eq!(pin.take_feedback(), Some(Feedback::Confirm));
+
+    let mut pin = Pages::Pin(
+        PinPage::new(BasicPage::new("PIN", None), 1).with_pin("7", AccessLevel::Installer),
+    );
+    assert_eq!(pin.dispatch_char('7'), PageNavigation::Up);
+    assert_eq!(pin.take_access_level(), Some(AccessLevel::Installer));
+}
+
+#[test]
+fn register_beyond_capacity() {
+    let mut m: StaticPageManager<_, _, 2> =
+        StaticPageManager::new(DisplayMock(Vec::new()), text("Home"));
+    assert_eq!(m.register(text("Info")).unwrap(), PageId(1));
+    assert!(m.register(text("More")).is_err());
+}
+
+#[test]
+fn navigation() {
+    let mut m = tree();
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Right).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    m.dispatch(PageNavigation::Up).unwrap();
+    m.dispatch(PageNavigation::Up).unwrap();
+    m.dispatch(PageNavigation::Up).unwrap();
+    assert_eq!(
+        m.display.0,
+        vec![
+            "[ Config-1 ] Sub-Menu Back ",
+            "Info",
+            "[ Config-1 ] Sub-Menu Back ",
+            "[ Config-1 ] Sub-Menu Back ",
+            "[ Config-2 ] Config-3 Back ",
+            "Config-2 [ Config-3 ] Back ",
+            "Config-3",
+            "Config-2",
+            "Config-2 [ Config-3 ] Back ",
+            "[ Config-1 ] Sub-Menu Back ",
+            "[ Config-1 ] Sub-Menu Back ",
+        ]
+    );
+    assert_eq!(m.active_id(), PageId(0));
+}
+
+#[test]
+fn pages_in_arena() {
+    let mut arena = [
+        text("Config-1"),
+        text("Info"),
+        menu("Home"),
+        text("Config-2"),
+    ];
+    let mut m: StaticPageManager<_, _, 4, _> =
+        StaticPageManager::with_storage(DisplayMock(Vec::new()), &mut arena[..], 2).unwrap();
+    assert_eq!(m.register_index(1).unwrap(), PageId(1));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.register_sub_index(0).unwrap();
+    m.dispatch(PageNavigation::Up).unwrap();
+    assert!(m.register_index(1).is_err(), "already registered");
+    assert!(m.register_index(4).is_err(), "not in arena");
+    m.dispatch(PageNavigation::NthSubpage(1)).unwrap();
+    m.register_index(3).unwrap();
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.dispatch(PageNavigation::NthSubpage(2)).unwrap();
+    m.dispatch(PageNavigation::Left).unwrap();
+    assert_eq!(
+        m.display.0,
+        vec![
+            "[ Back ] ",
+            "[ Config-1 ] Back ",
+            "Config-1",
+            "[ Config-1 ] Config-2 Back ",
+            "Config-2",
+            "Config-1",
+        ]
+    );
+}
+
+#[test]
+fn register_like_the_page_manager() {
+    // the second sub page of "Home" takes over the first as its sub page
+    let mut sut: StaticPageManager<_, _, 4> =
+        StaticPageManager::new(DisplayMock(Vec::new()), menu("Home"));
+    sut.register_sub(text("Config-1")).unwrap();
+    sut.dispatch(PageNavigation::Home).unwrap();
+    sut.register_sub(menu("Menu")).unwrap();
+    sut.register(text("Info")).unwrap();
+    let mut m = PageManager::new(DisplayMock(Vec::new()), Box::new(menu("Home")));
+    m.register_sub(Box::new(text("Config-1")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.register_sub(Box::new(menu("Menu")));
+    m.register(Box::new(text("Info")));
+
+    const NAVIGATIONS: &[PageNavigation] = &[
+        PageNavigation::Home,
+        PageNavigation::NthSubpage(1),
+        PageNavigation::NthSubpage(1),
+        PageNavigation::Up,
+        PageNavigation::Left,
+        PageNavigation::Left,
+        PageNavigation::Up,
+        PageNavigation::NthSubpage(2),
+    ];
+    for navigation in NAVIGATIONS {
+        sut.dispatch(*navigation).unwrap();
+        m.dispatch(*navigation).unwrap();
+        assert_eq!(sut.active_id(), m.active_id(), "{:?}", navigation);
+    }
+    assert_eq!(sut.active_id(), PageId(3));
+}
+
+#[test]
+fn pages_in_heapless_vec() {
+    let mut pages: heapless::Vec<Pages, 4> = heapless::Vec::new();
+    pages.push(menu("Home")).ok().unwrap();
+    pages.push(text("Config-1")).ok().unwrap();
+    let mut m: StaticPageManager<_, _, 4, _> =
+        StaticPageManager::with_storage(DisplayMock(Vec::new()), pages, 0).unwrap();
+    m.register_sub_index(1).unwrap();
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert_eq!(m.display.0, vec!["[ Config-1 ] Back "]);
+}
+
+#[test]
+fn navigate_to_page_with_parameter() {
+    let mut m = tree();
+    m.dispatch(PageNavigation::ToWith(PageId(4), 2)).unwrap();
+    assert_eq!(m.active_id(), PageId(4));
+    assert_eq!(m.display.0, vec!["Config-2"]);
+    assert!(m.dispatch(PageNavigation::ToWith(PageId(7), 2)).is_err());
+    assert_eq!(m.active_id(), PageId(4));
+}
+
+#[test]
+fn navigate_to_the_first_page_leading_away() {
+    let mut m = tree();
+    m.dispatch(PageNavigation::To(PageId(2))).unwrap();
+    assert_eq!(m.active_id(), PageId(2));
+    assert!(m.dispatch(PageNavigation::To(PageId(7))).is_err());
+
+    const TARGETS: &[PageNavigation] = &[
+        PageNavigation::To(PageId(7)),
+        PageNavigation::Right,
+        PageNavigation::FirstOf(&[PageNavigation::NthSubpage(1)]),
+        PageNavigation::Home,
+    ];
+    // "Config-1" has no right page and no sub pages
+    m.dispatch(PageNavigation::FirstOf(TARGETS)).unwrap();
+    assert_eq!(m.active_id(), PageId(0));
+    m.dispatch(PageNavigation::To(PageId(3))).unwrap();
+    m.dispatch(PageNavigation::FirstOf(&TARGETS[2..])).unwrap();
+    assert_eq!(m.active_id(), PageId(4));
+}
@@ -0,0 +1,97 @@
+use super::{InputEvent, PageError, PageManager, PageNavigation};
+
+use core::future::Future;
+use futures::future::{select, Either};
+use futures::{pin_mut, Stream, StreamExt};
+
+/// Drive a page manager by an async input stream and a periodic tick
+///
+/// Runs the event loop of the HMI: it dispatches `PageNavigation::SystemStart`,
+/// then every input of the input stream and the navigation returned by
+/// the last dispatch with every tick, i.e. the periodic update of the pages.
+///
+/// The runner is not bound to an async runtime: the tick is any future, e.g.
+/// `Timer::after_millis(500)` of `embassy-time` or a `Delay` of `futures-timer`.
+///
+/// The end of the input stream requests the shutdown by
+/// `PageNavigation::SystemStop`. The runner returns once the shutdown is
+/// over, i.e. with `PageNavigation::Terminate`.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{
+///         page::{BasicPage, TextPage}, Interaction, PageInterface, PageManager,
+///         PageManagerTask,
+///     };
+///     use futures::{executor::block_on, future::ready, stream};
+///
+///     struct Display;
+///     impl PageInterface<Display> for TextPage {
+///         fn display(&self, _display_driver: &mut Display) {}
+///     }
+///
+///     let home = TextPage::new(BasicPage::new("Home", None), "Hello");
+///     let mut m = PageManager::new(Display, Box::new(home));
+///     let input = stream::iter(vec![Interaction::Next, Interaction::Action]);
+///     block_on(PageManagerTask::new(&mut m).run(input, || ready(()))).unwrap();
+/// ```
+pub struct PageManagerTask<'m, 'a, D, C = ()> {
+    manager: &'m mut PageManager<'a, D, C>,
+}
+
+impl<'m, 'a, D, C> PageManagerTask<'m, 'a, D, C> {
+    pub fn new(manager: &'m mut PageManager<'a, D, C>) -> Self {
+        PageManagerTask { manager }
+    }
+
+    /// Run the event loop until the HMI is shut down
+    ///
+    /// Arguments
+    ///
+    /// * `input`: - The inputs, e.g. the interactions of a button task, the keys of a
+    ///   `Keypad` or the inputs of an `InteractionQueue`
+    /// * `tick`: - Returns a future completing with the next periodic update
+    ///
+    /// Returns
+    ///
+    /// * `Ok(())` - once the HMI is shut down
+    /// * `Err(PageError)` - if a page fails while the HMI is running
+    pub async fn run<S, T, F>(&mut self, input: S, mut tick: T) -> Result<(), PageError>
+    where
+        S: Stream + Unpin,
+        S::Item: Into<InputEvent>,
+        T: FnMut() -> F,
+        F: Future<Output = ()>,
+    {
+        let mut input = input.fuse();
+        let mut navigation = self.manager.dispatch(PageNavigation::SystemStart)?;
+        // the tick is kept across interactions, i.e. it is not restarted by input
+        let next_tick = tick();
+        pin_mut!(next_tick);
+        loop {
+            let result = if input.is_done() {
+                next_tick.as_mut().await;
+                next_tick.set(tick());
+                self.manager.dispatch(navigation)
+            } else {
+                // pending interactions take precedence over the tick
+                match select(input.next(), next_tick.as_mut()).await {
+                    Either::Left((Some(input), _)) => self.manager.dispatch_input(input.into()),
+                    Either::Left((None, _)) => self.manager.dispatch(PageNavigation::SystemStop),
+                    Either::Right(_) => {
+                        next_tick.set(tick());
+                        self.manager.dispatch(navigation)
+                    }
+                }
+            };
+            match result? {
+                PageNavigation::Terminate => return Ok(()),
+                next => navigation = next,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,149 @@
+use super::*;
+use crate::page::{ShutdownPage, StartupPage};
+use crate::{InputEvent, Interaction, PageBaseInterface, PageInteractionInterface, PageInterface};
+use core::task::Poll;
+use futures::{executor::block_on, future, future::ready, stream};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// Display recording the titles of the pages displayed
+#[derive(Default)]
+struct Recorder(Rc<RefCell<Vec<String>>>);
+
+/// Page failing on update while its flag is set
+struct Page(&'static str, bool);
+
+impl PageBaseInterface for Page {
+    fn title(&self) -> &str {
+        self.0
+    }
+
+    fn update<'a>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        match self.1 {
+            true => Err(PageError),
+            false => Ok(PageNavigation::Update),
+        }
+    }
+}
+
+impl PageInteractionInterface for Page {}
+
+impl PageInterface<Recorder> for Page {
+    fn display(&self, display_driver: &mut Recorder) {
+        display_driver.0.borrow_mut().push(String::from(self.0));
+    }
+}
+
+impl PageInterface<Recorder> for StartupPage {
+    fn display(&self, display_driver: &mut Recorder) {
+        display_driver
+            .0
+            .borrow_mut()
+            .push(String::from(self.title()));
+    }
+}
+
+impl PageInterface<Recorder> for ShutdownPage {
+    fn display(&self, display_driver: &mut Recorder) {
+        display_driver
+            .0
+            .borrow_mut()
+            .push(String::from(self.title()));
+    }
+}
+
+fn manager(shown: &Rc<RefCell<Vec<String>>>) -> PageManager<'static, Recorder> {
+    let mut m = PageManager::new(Recorder(Rc::clone(shown)), Box::new(Page("Home", false)));
+    m.register(Box::new(Page("A", false)));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.register_startup(Box::new(StartupPage::new("Welcome", 2)));
+    m
+}
+
+#[test]
+fn run_until_input_ends() {
+    let shown = Rc::new(RefCell::new(Vec::new()));
+    let mut m = manager(&shown);
+    shown.borrow_mut().clear();
+    let input = stream::iter(vec![Interaction::Next, Interaction::Next]);
+    block_on(PageManagerTask::new(&mut m).run(input, || ready(()))).unwrap();
+    let shown = shown.borrow();
+    assert_eq!(shown[0], "Startup");
+    assert!(shown.contains(&String::from("A")));
+}
+
+#[test]
+fn run_input_events() {
+    let shown = Rc::new(RefCell::new(Vec::new()));
+    let mut m = manager(&shown);
+    shown.borrow_mut().clear();
+    let input = stream::iter(vec![
+        InputEvent::Char('7'),
+        InputEvent::Primary(Interaction::Next),
+        InputEvent::Custom(1),
+    ]);
+    block_on(PageManagerTask::new(&mut m).run(input, || ready(()))).unwrap();
+    assert!(shown.borrow().contains(&String::from("A")));
+}
+
+#[test]
+fn run_shutdown_page() {
+    let shown = Rc::new(RefCell::new(Vec::new()));
+    let mut m = manager(&shown);
+    m.register_shutdown(Box::new(ShutdownPage::new("Bye", 3)));
+    shown.borrow_mut().clear();
+    block_on(PageManagerTask::new(&mut m).run(stream::empty::<InputEvent>(), || ready(())))
+        .unwrap();
+    assert_eq!(
+        shown
+            .borrow()
+            .iter()
+            .filter(|title| *title == "Shutdown")
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn return_page_errors() {
+    let shown = Rc::new(RefCell::new(Vec::new()));
+    let mut m = PageManager::new(Recorder(Rc::clone(&shown)), Box::new(Page("Home", true)));
+    let input = stream::iter(vec![Interaction::Action]);
+    assert!(block_on(PageManagerTask::new(&mut m).run(input, || ready(()))).is_err());
+}
+
+/// Ready on every second poll, i.e. pending once before
+fn every_second_poll<T>(
+    mut ready: impl FnMut() -> T,
+) -> impl FnMut(&mut core::task::Context<'_>) -> Poll<T> {
+    let mut polled = false;
+    move |cx| {
+        polled = !polled;
+        match polled {
+            true => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            false => Poll::Ready(ready()),
+        }
+    }
+}
+
+#[test]
+fn tick_is_not_starved_by_input() {
+    let shown = Rc::new(RefCell::new(Vec::new()));
+    let mut m = manager(&shown);
+    let mut interactions = 10;
+    let input = stream::poll_fn(every_second_poll(move || {
+        interactions -= 1;
+        (interactions > 0).then_some(Interaction::Next)
+    }));
+    let ticks = Cell::new(0);
+    let tick = || future::poll_fn(every_second_poll(|| ticks.set(ticks.get() + 1)));
+    block_on(PageManagerTask::new(&mut m).run(input, tick)).unwrap();
+    // the input arrives faster than the tick, the tick completes anyway
+    assert!(ticks.get() >= 4);
+}
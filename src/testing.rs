@@ -0,0 +1,235 @@
+//! Snapshot tests of what the user actually sees
+//!
+//! Requires the `testing` feature, e.g. as dev-dependency
+//! `embedded-multi-page-hmi = { version = "*", features = ["testing"] }`.
+//!
+//! The `FrameBufferDisplay` records every rendered page as a frame of ASCII
+//! text. All pages of this crate render to it; the title is shown in the
+//! first row, the content below. Frames are compared by `assert_frames!`
+//! either to expected frames or to a golden file.
+//!
+//! The `NavigationChecker` walks interactions against a `PageManager` and
+//! checks invariants of the navigation.
+//!
+//! The `MemoryStore` keeps settings in memory instead of persisting them.
+//!
+//! # Example
+//!
+//! ```
+//!     use embedded_multi_page_hmi::{
+//!         assert_frames,
+//!         page::{BasicPage, TextPage},
+//!         testing::FrameBufferDisplay,
+//!         PageManager, PageNavigation,
+//!     };
+//!
+//!     let home = TextPage::new(BasicPage::new("Home", None), "Hello");
+//!     let mut m = PageManager::new(FrameBufferDisplay::new(8, 2), Box::new(home));
+//!     m.dispatch(PageNavigation::Update).unwrap();
+//!     assert_frames!(m.display(), ["|Home    |\n|Hello   |"]);
+//! ```
+
+use super::{pad, paginated_title, DisplayInfo, PageError, ScrollPosition, SettingsStore};
+
+mod checker;
+
+pub use checker::{NavigationChecker, Step, Violation};
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Environment variable to (re-)write golden files instead of comparing them
+pub const UPDATE_GOLDEN: &str = "UPDATE_GOLDEN";
+
+/// A character display recording every rendered frame
+///
+/// A frame has `rows` rows of `cols` characters each. Every row is enclosed
+/// by `|` so trailing spaces are visible; rows are separated by `\n`.
+pub struct FrameBufferDisplay {
+    cols: usize,
+    rows: Vec<String>,
+    frames: Vec<String>,
+}
+
+impl FrameBufferDisplay {
+    /// Arguments
+    ///
+    /// * `cols`: - Number of characters per row
+    /// * `rows`: - Number of rows including the title row
+    pub fn new(cols: usize, rows: usize) -> Self {
+        FrameBufferDisplay {
+            cols,
+            rows: vec![String::new(); rows],
+            frames: Vec::new(),
+        }
+    }
+
+    /// Clear all rows of the frame being rendered
+    pub fn clear(&mut self) {
+        self.rows.iter_mut().for_each(String::clear);
+    }
+
+    /// Print a text to a row of the frame being rendered
+    ///
+    /// Text beyond the display is truncated.
+    pub fn print(&mut self, row: usize, text: &str) {
+        if let Some(line) = self.rows.get_mut(row) {
+            line.clear();
+            line.push_str(text);
+        }
+    }
+
+    /// Record the frame being rendered
+    pub fn flush(&mut self) {
+        let cols = self.cols;
+        let frame: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| format!("|{}|", pad(row, cols)))
+            .collect();
+        self.frames.push(frame.join("\n"));
+    }
+
+    /// Render and record a page
+    ///
+    /// Arguments
+    ///
+    /// * `title`: - The title shown in the first row
+    /// * `message`: - The content; lines are separated by `\n`
+    pub fn update(&mut self, title: &str, message: &str) {
+        self.clear();
+        self.print(0, title);
+        for (row, line) in message.lines().enumerate() {
+            self.print(row + 1, line);
+        }
+        self.flush();
+    }
+
+    /// Show a page scrolling its content - the title is followed by the page shown, e.g. `Info 2/5`
+    pub fn update_scrolled(
+        &mut self,
+        title: &str,
+        message: &str,
+        position: Option<ScrollPosition>,
+    ) {
+        self.update(&paginated_title(title, position), message);
+    }
+
+    /// All recorded frames
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// The last recorded frame
+    pub fn last_frame(&self) -> Option<&str> {
+        self.frames.last().map(String::as_str)
+    }
+
+    /// Remove all recorded frames, e.g. to only check frames of an interaction
+    pub fn take_frames(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.frames)
+    }
+}
+
+/// The display size without the title row
+impl DisplayInfo for FrameBufferDisplay {
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn rows(&self) -> usize {
+        self.rows.len().saturating_sub(1)
+    }
+}
+
+/// A settings store in memory, e.g. to save and restore settings in tests
+///
+/// The data stored by key is accessible, e.g. to check it or to corrupt it.
+#[derive(Default)]
+pub struct MemoryStore(pub HashMap<String, Vec<u8>>);
+
+impl SettingsStore for MemoryStore {
+    fn store(&mut self, key: &str, data: &[u8]) -> Result<(), PageError> {
+        self.0.insert(key.to_string(), data.to_vec());
+        Ok(())
+    }
+
+    fn load(&mut self, key: &str) -> Option<Vec<u8>> {
+        self.0.get(key).cloned()
+    }
+
+    fn clear(&mut self) -> Result<(), PageError> {
+        self.0.clear();
+        Ok(())
+    }
+}
+
+/// Compare recorded frames to expected frames
+///
+/// Is used by `assert_frames!`.
+#[track_caller]
+pub fn check_frames(frames: &[String], expected: &[&str]) {
+    if frames.len() == expected.len() && frames.iter().zip(expected).all(|(f, e)| f == e) {
+        return;
+    }
+    panic!(
+        "frames differ\n--- recorded:\n{}\n--- expected:\n{}\n",
+        frames.join("\n\n"),
+        expected.join("\n\n")
+    );
+}
+
+/// Compare recorded frames to a golden file
+///
+/// Frames are separated by an empty line in the file. If the file does not
+/// exist or the environment variable `UPDATE_GOLDEN` is set, the file is
+/// written instead.
+/// Is used by `assert_frames!`.
+#[track_caller]
+pub fn check_golden(frames: &[String], path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    let recorded = format!("{}\n", frames.join("\n\n"));
+    if !path.exists() || std::env::var_os(UPDATE_GOLDEN).is_some() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).unwrap();
+        }
+        fs::write(path, recorded).unwrap();
+        return;
+    }
+    let golden = fs::read_to_string(path).unwrap();
+    if golden != recorded {
+        panic!(
+            "frames differ from golden file {}\n--- recorded:\n{}--- golden:\n{}\
+             (set {} to update the golden file)",
+            path.display(),
+            recorded,
+            golden,
+            UPDATE_GOLDEN
+        );
+    }
+}
+
+/// Assert the frames recorded by a `FrameBufferDisplay`
+///
+/// * `assert_frames!(display, [frame, ...])` - compare to the expected frames
+/// * `assert_frames!(display, golden = "path")` - compare to a golden file,
+///   the path is relative to the package root of the caller
+#[macro_export]
+macro_rules! assert_frames {
+    ($display:expr, [$($frame:expr),* $(,)?]) => {
+        $crate::testing::check_frames($display.frames(), &[$($frame),*])
+    };
+    ($display:expr, golden = $path:expr) => {
+        $crate::testing::check_golden(
+            $display.frames(),
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path),
+        )
+    };
+}
+
+// ** All pages of this crate render to the frame buffer
+impl_text_display!(FrameBufferDisplay);
+
+#[cfg(test)]
+mod tests;
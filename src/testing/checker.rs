@@ -0,0 +1,244 @@
+use crate::{Interaction, PageManager, PageNavigation};
+
+use std::collections::HashSet;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// A step of a walk through the page tree
+#[derive(Debug, Clone, Copy)]
+pub enum Step {
+    /// An interaction dispatched to the active page
+    Interaction(Interaction),
+    /// A navigation dispatched to the page manager
+    Navigation(PageNavigation),
+}
+
+/// All steps a walk is composed of
+const STEPS: [Step; 11] = [
+    Step::Interaction(Interaction::Action),
+    Step::Interaction(Interaction::Next),
+    Step::Interaction(Interaction::Previous),
+    Step::Interaction(Interaction::Back),
+    Step::Interaction(Interaction::Home),
+    Step::Navigation(PageNavigation::Left),
+    Step::Navigation(PageNavigation::Right),
+    Step::Navigation(PageNavigation::Up),
+    Step::Navigation(PageNavigation::Home),
+    Step::Navigation(PageNavigation::NthSubpage(1)),
+    Step::Navigation(PageNavigation::NthSubpage(2)),
+];
+
+/// An invariant that does not hold after a walk
+#[derive(Debug, Clone)]
+pub struct Violation {
+    /// The steps of the walk starting at the home page
+    pub steps: Vec<Step>,
+    /// The invariant that does not hold
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} after steps {:?}", self.message, self.steps)
+    }
+}
+
+enum Walks {
+    Random {
+        seed: u64,
+        walks: usize,
+        steps: usize,
+    },
+    Exhaustive {
+        depth: usize,
+    },
+}
+
+/// Walks interactions and navigations against a `PageManager` and checks invariants
+///
+/// Every walk starts at the home page. After every step it is checked that
+///
+/// * dispatching neither panics nor fails,
+/// * the page tree is consistent, i.e. all pages are yielded once by
+///   `tree_iter()` and the breadcrumb ends with the active page,
+/// * `Up` after `NthSubpage` returns to the page the sub page was entered
+///   from, if that page has sub pages.
+///
+/// At the end of every walk the home page must be reachable by `Home`.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{
+///         page::{BasicPage, MenuPage, TextPage},
+///         testing::{FrameBufferDisplay, NavigationChecker},
+///         PageManager,
+///     };
+///
+///     let home = MenuPage::new(BasicPage::new("Home", None), None);
+///     let mut m = PageManager::new(FrameBufferDisplay::new(16, 2), Box::new(home));
+///     m.register_sub(Box::new(TextPage::new(BasicPage::new("Info", None), "Info")));
+///     m.register(Box::new(TextPage::new(BasicPage::new("Help", None), "Help")));
+///
+///     NavigationChecker::exhaustive(3).check(&mut m).unwrap();
+///     NavigationChecker::random(42, 100, 50).check(&mut m).unwrap();
+/// ```
+pub struct NavigationChecker {
+    walks: Walks,
+}
+
+impl NavigationChecker {
+    /// Walk random steps
+    ///
+    /// Arguments
+    ///
+    /// * `seed`: - Seed of the pseudo random steps; the same seed results in the same walks
+    /// * `walks`: - Number of walks
+    /// * `steps`: - Number of steps of every walk
+    pub fn random(seed: u64, walks: usize, steps: usize) -> Self {
+        NavigationChecker {
+            walks: Walks::Random { seed, walks, steps },
+        }
+    }
+
+    /// Walk all combinations of steps
+    ///
+    /// There are 11 different steps, i.e. `11^depth` walks.
+    ///
+    /// Arguments
+    ///
+    /// * `depth`: - Number of steps of every walk
+    pub fn exhaustive(depth: usize) -> Self {
+        NavigationChecker {
+            walks: Walks::Exhaustive { depth },
+        }
+    }
+
+    /// Check the invariants
+    ///
+    /// Returns
+    ///
+    /// * `Err(Violation)` - for the first walk an invariant does not hold
+    pub fn check<D, C>(&self, manager: &mut PageManager<'_, D, C>) -> Result<(), Violation> {
+        match self.walks {
+            Walks::Random { seed, walks, steps } => {
+                let mut state = seed ^ 0x9E37_79B9_7F4A_7C15;
+                for _ in 0..walks {
+                    let walk = (0..steps).map(|_| STEPS[random(&mut state) % STEPS.len()]);
+                    check_walk(manager, walk)?;
+                }
+            }
+            Walks::Exhaustive { depth } => {
+                let mut indices = vec![0; depth];
+                loop {
+                    check_walk(manager, indices.iter().map(|i| STEPS[*i]))?;
+                    // next combination - count up with STEPS.len() as base
+                    let mut digit = 0;
+                    while digit < depth && indices[digit] + 1 == STEPS.len() {
+                        indices[digit] = 0;
+                        digit += 1;
+                    }
+                    if digit == depth {
+                        break;
+                    }
+                    indices[digit] += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Pseudo random number by xorshift
+fn random(state: &mut u64) -> usize {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state as usize
+}
+
+fn check_walk<D, C>(
+    manager: &mut PageManager<'_, D, C>,
+    walk: impl Iterator<Item = Step>,
+) -> Result<(), Violation> {
+    let mut done = Vec::new();
+    let home = Step::Navigation(PageNavigation::Home);
+    check_step(manager, &mut done, home)?;
+    for step in walk {
+        check_step(manager, &mut done, step)?;
+    }
+    check_step(manager, &mut done, home)?;
+    if manager.active().id != manager.home() {
+        return Err(violation(done, "home page is not reachable"));
+    }
+    Ok(())
+}
+
+fn check_step<D, C>(
+    manager: &mut PageManager<'_, D, C>,
+    done: &mut Vec<Step>,
+    step: Step,
+) -> Result<(), Violation> {
+    let origin = manager.active().id;
+    let has_sub_pages = manager.sub_iter().next().is_some();
+    dispatch(manager, done, step)?;
+    check_tree(manager, done)?;
+
+    if let Step::Navigation(PageNavigation::NthSubpage(_)) = step {
+        if has_sub_pages {
+            dispatch(manager, done, Step::Navigation(PageNavigation::Up))?;
+            if manager.active().id != origin {
+                return Err(violation(
+                    done.clone(),
+                    "up after sub page does not return to origin",
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn dispatch<D, C>(
+    manager: &mut PageManager<'_, D, C>,
+    done: &mut Vec<Step>,
+    step: Step,
+) -> Result<(), Violation> {
+    done.push(step);
+    let result = catch_unwind(AssertUnwindSafe(|| match step {
+        Step::Interaction(interaction) => manager.dispatch_interaction(interaction),
+        Step::Navigation(navigation) => manager.dispatch(navigation),
+    }));
+    match result {
+        Err(_) => Err(violation(done.clone(), "dispatch panicked")),
+        Ok(Err(_)) => Err(violation(done.clone(), "dispatch failed")),
+        Ok(Ok(_)) => Ok(()),
+    }
+}
+
+fn check_tree<D, C>(manager: &PageManager<'_, D, C>, done: &[Step]) -> Result<(), Violation> {
+    let mut ids = HashSet::new();
+    if !manager.tree_iter().all(|page| ids.insert(page.id)) {
+        return Err(violation(
+            done.to_vec(),
+            "page is yielded twice by tree_iter",
+        ));
+    }
+    if ids.len() != manager.mode_page_count() {
+        return Err(violation(done.to_vec(), "page count does not match tree"));
+    }
+    let active = manager.active();
+    if !ids.contains(&active.id) || manager.breadcrumb().last() != Some(active.title) {
+        return Err(violation(done.to_vec(), "active page is not in tree"));
+    }
+    Ok(())
+}
+
+fn violation(steps: Vec<Step>, message: &str) -> Violation {
+    Violation {
+        steps,
+        message: String::from(message),
+    }
+}
+
+#[cfg(test)]
+mod tests;
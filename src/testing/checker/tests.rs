@@ -0,0 +1,118 @@
+use super::*;
+use crate::page::{BasicPage, MenuPage, TextPage};
+use crate::testing::FrameBufferDisplay;
+use crate::{PageBaseInterface, PageError, PageInteractionInterface, PageInterface};
+
+fn menu_tree() -> PageManager<'static, FrameBufferDisplay> {
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(FrameBufferDisplay::new(16, 2), Box::new(home));
+    m.register(Box::new(TextPage::new(BasicPage::new("About", None), "")));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.register_sub(Box::new(MenuPage::new(
+        BasicPage::new("Setup", None),
+        Some("Back"),
+    )));
+    m.register_sub(Box::new(TextPage::new(BasicPage::new("Time", None), "")));
+    m.register(Box::new(TextPage::new(BasicPage::new("Date", None), "")));
+    m.dispatch(PageNavigation::Up).unwrap();
+    m.register(Box::new(TextPage::new(BasicPage::new("Info", None), "")));
+    m
+}
+
+#[test]
+fn invariants_hold_for_menu_tree() {
+    let mut m = menu_tree();
+    NavigationChecker::exhaustive(3).check(&mut m).unwrap();
+    NavigationChecker::random(7, 200, 40).check(&mut m).unwrap();
+}
+
+#[test]
+fn invariants_hold_for_two_modes() {
+    let mut m = menu_tree();
+    m.add_mode(Box::new(TextPage::new(BasicPage::new("Setup", None), "")));
+    m.register(Box::new(TextPage::new(
+        BasicPage::new("Language", None),
+        "",
+    )));
+    NavigationChecker::exhaustive(3).check(&mut m).unwrap();
+}
+
+#[test]
+fn invariants_hold_for_home_set() {
+    let mut m = menu_tree();
+    let info = m.active().id;
+    m.set_home(info).unwrap();
+    NavigationChecker::exhaustive(3).check(&mut m).unwrap();
+
+    let setup = m.add_mode(Box::new(TextPage::new(BasicPage::new("Setup", None), "")));
+    m.register(Box::new(TextPage::new(
+        BasicPage::new("Language", None),
+        "",
+    )));
+    m.switch_mode(setup).unwrap();
+    NavigationChecker::exhaustive(3).check(&mut m).unwrap();
+}
+
+/// Page that fails on update or panics on action
+struct FaultyPage {
+    fail_update: bool,
+}
+
+impl PageBaseInterface for FaultyPage {
+    fn title(&self) -> &str {
+        "Faulty"
+    }
+
+    fn update<'a>(
+        &mut self,
+        _title_of_subpages: Option<Box<dyn Iterator<Item = &'a str> + 'a>>,
+    ) -> Result<PageNavigation, PageError> {
+        match self.fail_update {
+            true => Err(PageError),
+            false => Ok(PageNavigation::Update),
+        }
+    }
+}
+
+impl PageInteractionInterface for FaultyPage {
+    fn dispatch(&mut self, interaction: Interaction) -> PageNavigation {
+        match interaction {
+            Interaction::Action => panic!("not implemented"),
+            _ => PageNavigation::Home,
+        }
+    }
+}
+
+impl PageInterface<FrameBufferDisplay> for FaultyPage {
+    fn display(&self, _display_driver: &mut FrameBufferDisplay) {}
+}
+
+fn faulty_tree(fail_update: bool) -> PageManager<'static, FrameBufferDisplay> {
+    let home = TextPage::new(BasicPage::new("Home", None), "");
+    let mut m = PageManager::new(FrameBufferDisplay::new(16, 2), Box::new(home));
+    m.register(Box::new(FaultyPage { fail_update }));
+    m
+}
+
+#[test]
+fn failing_dispatch_is_a_violation() {
+    let mut m = faulty_tree(true);
+    let violation = NavigationChecker::exhaustive(1).check(&mut m).unwrap_err();
+    assert_eq!(
+        violation.to_string(),
+        "dispatch failed after steps [Navigation(Home), Interaction(Next)]"
+    );
+}
+
+#[test]
+fn panicking_dispatch_is_a_violation() {
+    let mut m = faulty_tree(false);
+    let violation = NavigationChecker::random(1, 10, 10)
+        .check(&mut m)
+        .unwrap_err();
+    assert_eq!(violation.message, "dispatch panicked");
+    assert!(matches!(
+        violation.steps[..],
+        [.., Step::Interaction(Interaction::Action)]
+    ));
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::page::{BasicPage, MenuPage, TextPage};
+use crate::{DisplaySize, Interaction, PageManager, PageNavigation};
+
+fn menu_tree() -> PageManager<'static, FrameBufferDisplay> {
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let mut m = PageManager::new(FrameBufferDisplay::new(16, 2), Box::new(home));
+    let size = DisplaySize::from_info(m.display());
+    m.set_display_info(&size);
+    m.register_sub(Box::new(TextPage::new(
+        BasicPage::new("Info", None),
+        "Some information",
+    )));
+    m.register(Box::new(TextPage::new(
+        BasicPage::new("Version", None),
+        "1.0",
+    )));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.display_mut().take_frames();
+    m
+}
+
+#[test]
+fn frames_are_padded_and_truncated() {
+    let mut d = FrameBufferDisplay::new(6, 3);
+    d.update("Title", "A long line\nB");
+    assert_eq!(d.last_frame(), Some("|Title |\n|A l...|\n|B     |"));
+    assert_eq!(DisplaySize::from_info(&d), DisplaySize::new(6, 2));
+}
+
+#[test]
+fn frames_of_menu_navigation() {
+    let mut m = menu_tree();
+    m.dispatch_interaction(Interaction::Next).unwrap();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    assert_frames!(
+        m.display(),
+        [
+            "|Home 2/2        |\n|[ Version ]     |",
+            "|Version         |\n|1.0             |",
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "frames differ")]
+fn differing_frames_panic() {
+    let mut m = menu_tree();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    assert_frames!(m.display(), ["|Home            |\n|                |"]);
+}
+
+#[test]
+fn frames_match_golden_file() {
+    let mut m = menu_tree();
+    m.dispatch_interaction(Interaction::Action).unwrap();
+    m.dispatch_interaction(Interaction::Back).unwrap();
+    assert_frames!(m.display(), golden = "src/testing/menu_navigation.golden");
+}
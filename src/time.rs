@@ -0,0 +1,90 @@
+/// A local date and time, e.g. read from a real time clock
+///
+/// Fields are ordered from year to second, i.e. date times compare in
+/// chronological order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DateTime {
+    pub year: u16,
+    /// One to twelve
+    pub month: u8,
+    /// Starts with one
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl DateTime {
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Self {
+        DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Days since 1970-01-01 - negative before
+    pub fn days(&self) -> i64 {
+        // days from civil, see http://howardhinnant.github.io/date_algorithms.html
+        let month = self.month as i64;
+        let year = self.year as i64 - (month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// Seconds since 1970-01-01 00:00:00
+    pub fn timestamp(&self) -> i64 {
+        self.days() * 86400 + self.seconds_of_day() as i64
+    }
+
+    /// Day of the week - zero is Monday
+    pub fn weekday(&self) -> u8 {
+        // 1970-01-01 was a Thursday
+        (self.days() + 3).rem_euclid(7) as u8
+    }
+
+    /// Number of days of the month of a year
+    ///
+    /// Arguments
+    ///
+    /// * `year`: - The year, e.g. to tell leap years
+    /// * `month`: - The month, one to twelve
+    pub fn days_in_month(year: u16, month: u8) -> u8 {
+        let leap =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        match month {
+            2 if leap => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Seconds since midnight
+    pub fn seconds_of_day(&self) -> u32 {
+        self.hour as u32 * 3600 + self.minute as u32 * 60 + self.second as u32
+    }
+}
+
+/// The source of the local time, e.g. a real time clock
+///
+/// Closures returning the date time are time sources.
+pub trait TimeSource {
+    fn now(&mut self) -> DateTime;
+}
+
+impl<F: FnMut() -> DateTime> TimeSource for F {
+    fn now(&mut self) -> DateTime {
+        self()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn timestamp_of_date_times() {
+    assert_eq!(DateTime::new(1970, 1, 1, 0, 0, 0).timestamp(), 0);
+    assert_eq!(DateTime::new(2000, 3, 1, 0, 0, 0).days(), 11017);
+    assert_eq!(
+        DateTime::new(2024, 2, 29, 12, 30, 15).timestamp(),
+        1_709_209_815
+    );
+    assert_eq!(DateTime::new(1969, 12, 31, 23, 59, 59).timestamp(), -1);
+}
+
+#[test]
+fn chronological_order() {
+    assert!(DateTime::new(2024, 1, 31, 23, 0, 0) < DateTime::new(2024, 2, 1, 0, 0, 0));
+    assert_eq!(DateTime::new(2024, 1, 1, 1, 2, 3).seconds_of_day(), 3723);
+}
+
+#[test]
+fn closure_as_time_source() {
+    let mut source = || DateTime::new(2024, 5, 1, 8, 0, 0);
+    assert_eq!(source.now().hour, 8);
+}
+
+#[test]
+fn weekday_and_days_of_month() {
+    assert_eq!(DateTime::new(1970, 1, 1, 0, 0, 0).weekday(), 3);
+    assert_eq!(DateTime::new(2024, 5, 1, 0, 0, 0).weekday(), 2);
+    assert_eq!(DateTime::new(2024, 5, 5, 0, 0, 0).weekday(), 6);
+    assert_eq!(DateTime::days_in_month(2024, 2), 29);
+    assert_eq!(DateTime::days_in_month(1900, 2), 28);
+    assert_eq!(DateTime::days_in_month(2000, 2), 29);
+    assert_eq!(DateTime::days_in_month(2023, 4), 30);
+    assert_eq!(DateTime::days_in_month(2023, 12), 31);
+}
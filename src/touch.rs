@@ -0,0 +1,131 @@
+use super::{PageError, SettingsStore};
+
+/// The key the touch calibration is stored under
+const CALIBRATION_KEY: &str = "hmi.touch";
+
+/// A touch panel, e.g. a resistive panel read by an XPT2046 controller
+///
+/// Is implemented by the application on top of the controller driver. The
+/// positions are raw readings, i.e. they are mapped to display pixels by a
+/// `TouchCalibration`.
+pub trait PointerInput {
+    /// The raw position touched - `None` if the panel is not touched
+    fn raw(&mut self) -> Option<(i32, i32)>;
+}
+
+/// The mapping of raw touch positions to display pixels
+///
+/// Raw readings of resistive panels are shifted, scaled, rotated and even
+/// sheared against the pixels of the display. The affine mapping corrects all
+/// of them; it is determined from three positions touched at known pixels,
+/// e.g. by the `TouchCalibrationPage`, and kept in the settings store.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::TouchCalibration;
+///
+///     // raw readings are 10 times the pixels with an offset of 100
+///     let calibration = TouchCalibration::from_points(
+///         [(300, 300), (2500, 1100), (1300, 2100)],
+///         [(20, 20), (240, 100), (120, 200)],
+///     )
+///     .unwrap();
+///     assert_eq!(calibration.apply((1100, 600)), (100, 50));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchCalibration {
+    /// `x = a * raw_x + b * raw_y + c` and `y = d * raw_x + e * raw_y + f`
+    coefficients: [f32; 6],
+}
+
+/// The raw positions are taken as pixels
+impl Default for TouchCalibration {
+    fn default() -> Self {
+        TouchCalibration {
+            coefficients: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+        }
+    }
+}
+
+impl TouchCalibration {
+    /// Determine the mapping from three positions
+    ///
+    /// Arguments
+    ///
+    /// * `raw`: - The raw positions read while touching the pixels
+    /// * `pixels`: - The pixels touched
+    ///
+    /// Returns
+    ///
+    /// * `None` if the raw positions are on a line, e.g. the same position
+    ///   was read for all pixels.
+    pub fn from_points(raw: [(i32, i32); 3], pixels: [(i32, i32); 3]) -> Option<Self> {
+        let [(x0, y0), (x1, y1), (x2, y2)] = raw.map(|(x, y)| (x as f64, y as f64));
+        let det = x0 * (y1 - y2) - y0 * (x1 - x2) + (x1 * y2 - x2 * y1);
+        if det.abs() < 1.0 {
+            return None;
+        }
+        // Cramer's rule of the rows [raw_x raw_y 1] for x and y each
+        let solve = |[v0, v1, v2]: [f64; 3]| {
+            [
+                (v0 * (y1 - y2) - y0 * (v1 - v2) + (v1 * y2 - v2 * y1)) / det,
+                (x0 * (v1 - v2) - v0 * (x1 - x2) + (x1 * v2 - x2 * v1)) / det,
+                (x0 * (y1 * v2 - y2 * v1) - y0 * (x1 * v2 - x2 * v1) + v0 * (x1 * y2 - x2 * y1))
+                    / det,
+            ]
+        };
+        let [a, b, c] = solve(pixels.map(|(x, _)| x as f64));
+        let [d, e, f] = solve(pixels.map(|(_, y)| y as f64));
+        Some(TouchCalibration {
+            coefficients: [a, b, c, d, e, f].map(|k| k as f32),
+        })
+    }
+
+    /// The pixel of a raw position
+    pub fn apply(&self, raw: (i32, i32)) -> (i32, i32) {
+        let [a, b, c, d, e, f] = self.coefficients;
+        let (x, y) = (raw.0 as f32, raw.1 as f32);
+        (
+            (a * x + b * y + c).round() as i32,
+            (d * x + e * y + f).round() as i32,
+        )
+    }
+
+    /// Store the calibration
+    ///
+    /// Arguments
+    ///
+    /// * `store`: - The persistent store to save the calibration to
+    pub fn save(&self, store: &mut (impl SettingsStore + ?Sized)) -> Result<(), PageError> {
+        let data: Vec<u8> = self
+            .coefficients
+            .iter()
+            .flat_map(|k| k.to_le_bytes())
+            .collect();
+        store.store(CALIBRATION_KEY, &data)
+    }
+
+    /// Load the calibration stored by `save()` - `None` if none or a damaged one is stored
+    ///
+    /// Arguments
+    ///
+    /// * `store`: - The persistent store to load the calibration from
+    pub fn load(store: &mut (impl SettingsStore + ?Sized)) -> Option<Self> {
+        let data = store.load(CALIBRATION_KEY)?;
+        if data.len() != 24 {
+            return None;
+        }
+        let mut coefficients = [0.0; 6];
+        for (k, bytes) in coefficients.iter_mut().zip(data.chunks_exact(4)) {
+            *k = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        match coefficients.iter().all(|k| k.is_finite()) {
+            true => Some(TouchCalibration { coefficients }),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,44 @@
+use super::*;
+use crate::testing::MemoryStore;
+
+#[test]
+fn map_swapped_and_mirrored_axes() {
+    // a panel mounted rotated: raw x runs down, raw y runs right to left
+    let calibration = TouchCalibration::from_points(
+        [(200, 3800), (2000, 400), (3800, 2100)],
+        [(24, 20), (216, 180), (120, 340)],
+    )
+    .unwrap();
+    assert_eq!(calibration.apply((200, 3800)), (24, 20));
+    assert_eq!(calibration.apply((3800, 2100)), (120, 340));
+    assert_eq!(calibration.apply((2000, 2100)), (120, 180));
+    assert_eq!(TouchCalibration::default().apply((7, 9)), (7, 9));
+}
+
+#[test]
+fn reject_positions_on_a_line() {
+    let pixels = [(10, 10), (100, 50), (50, 100)];
+    assert_eq!(TouchCalibration::from_points([(500, 500); 3], pixels), None);
+    assert_eq!(
+        TouchCalibration::from_points([(0, 0), (100, 100), (300, 300)], pixels),
+        None
+    );
+}
+
+#[test]
+fn save_and_load() {
+    let mut store = MemoryStore::default();
+    assert_eq!(TouchCalibration::load(&mut store), None);
+    let calibration = TouchCalibration::from_points(
+        [(300, 300), (2500, 1100), (1300, 2100)],
+        [(20, 20), (240, 100), (120, 200)],
+    )
+    .unwrap();
+    calibration.save(&mut store).unwrap();
+    assert_eq!(TouchCalibration::load(&mut store), Some(calibration));
+
+    store.store(CALIBRATION_KEY, &[0; 12]).unwrap();
+    assert_eq!(TouchCalibration::load(&mut store), None);
+    store.store(CALIBRATION_KEY, &[0xff; 24]).unwrap();
+    assert_eq!(TouchCalibration::load(&mut store), None);
+}
@@ -0,0 +1,41 @@
+//! Trace output of the HMI behavior
+//!
+//! With the `defmt` feature the output goes to `defmt`, e.g. over RTT; with
+//! the `log` feature to the `log` crate. Without any of both the arguments
+//! are not evaluated at all. The format strings are restricted to what both
+//! understand, i.e. `{}` for strings and numbers and `{:?}` for types
+//! implementing `Debug` and `defmt::Format`.
+
+macro_rules! hmi_log {
+    ($level:ident, $($arg:tt)+) => {{
+        #[cfg(feature = "defmt")]
+        defmt::$level!($($arg)+);
+        #[cfg(feature = "log")]
+        log::$level!($($arg)+);
+        #[cfg(not(any(feature = "defmt", feature = "log")))]
+        if false {
+            let _ = format_args!($($arg)+);
+        }
+    }};
+}
+
+/// Navigation events
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        hmi_log!(trace, $($arg)+)
+    };
+}
+
+/// Page activations and lifetime expirations
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        hmi_log!(debug, $($arg)+)
+    };
+}
+
+/// Errors of pages
+macro_rules! warn {
+    ($($arg:tt)+) => {
+        hmi_log!(warn, $($arg)+)
+    };
+}
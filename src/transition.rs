@@ -0,0 +1,26 @@
+/// The navigation changing the page, i.e. the direction of a transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    /// To the next page of the level - the new page comes in from the right
+    Left,
+    /// To the previous page of the level - the new page comes in from the left
+    Right,
+    /// Into a sub page - the new page comes in from the bottom
+    Down,
+    /// To the parent or the home page - the new page comes in from the top
+    Up,
+}
+
+/// A display animating page changes, e.g. a graphical display
+///
+/// When the `PageManager` changes the page it sets the transition before
+/// each frame of the transition is rendered. The display keeps what was shown
+/// before and composes it with the new page, e.g. by sliding it out. After
+/// the last frame the transition is reset.
+pub trait TransitionDisplay {
+    /// Arguments
+    ///
+    /// * `transition`: - The direction and the progress in percent of the
+    ///   frame rendered next, `None` if there is no transition (anymore)
+    fn set_transition(&mut self, transition: Option<(Transition, u8)>);
+}
@@ -0,0 +1,194 @@
+use super::NumberFormat;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// The system of units values are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UnitSystem {
+    Metric,
+    Imperial,
+}
+
+/// The unit temperatures are shown in - independent of the system of units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+}
+
+/// What a value measures - values are given in the metric unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Quantity {
+    /// Degree Celsius, shown in degree Fahrenheit by choice
+    Temperature,
+    /// Meter, shown in feet by the imperial system
+    Length,
+    /// Kilometer, shown in miles by the imperial system
+    Distance,
+    /// Kilogram, shown in pounds by the imperial system
+    Mass,
+    /// Liter, shown in US gallons by the imperial system
+    Volume,
+    /// Bar, shown in psi by the imperial system
+    Pressure,
+    /// Kilometer per hour, shown in miles per hour by the imperial system
+    Speed,
+}
+
+impl Quantity {
+    /// Factor from the metric to the imperial unit
+    fn factor(&self) -> f32 {
+        match self {
+            Quantity::Temperature => 1.8,
+            Quantity::Length => 3.28084,
+            Quantity::Distance | Quantity::Speed => 0.621371,
+            Quantity::Mass => 2.204623,
+            Quantity::Volume => 0.264172,
+            Quantity::Pressure => 14.503774,
+        }
+    }
+}
+
+/// The units values are shown in, selectable at runtime
+///
+/// The units can be placed in a `static`, so pages can refer to it for their
+/// whole lifetime, like the `Translations`. Pages format their values whenever
+/// they are updated or displayed, i.e. switching the units takes effect with
+/// the next update. The system of units and the temperature unit are chosen
+/// independently, e.g. metric units with degree Fahrenheit.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{Quantity, TemperatureUnit, UnitSystem, Units};
+///
+///     static UNITS: Units = Units::new();
+///
+///     assert_eq!(UNITS.format(Quantity::Temperature, 21.5, 1), "21.5 °C");
+///     UNITS.set_temperature(TemperatureUnit::Fahrenheit);
+///     assert_eq!(UNITS.format(Quantity::Temperature, 21.5, 1), "70.7 °F");
+///     UNITS.set_system(UnitSystem::Imperial);
+///     assert_eq!(UNITS.format(Quantity::Speed, 100.0, 0), "62 mph");
+/// ```
+pub struct Units {
+    imperial: AtomicBool,
+    fahrenheit: AtomicBool,
+}
+
+impl Default for Units {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Units {
+    /// Metric units with degree Celsius
+    pub const fn new() -> Self {
+        Units {
+            imperial: AtomicBool::new(false),
+            fahrenheit: AtomicBool::new(false),
+        }
+    }
+
+    pub fn system(&self) -> UnitSystem {
+        match self.imperial.load(Ordering::Relaxed) {
+            true => UnitSystem::Imperial,
+            false => UnitSystem::Metric,
+        }
+    }
+
+    pub fn set_system(&self, system: UnitSystem) {
+        self.imperial
+            .store(system == UnitSystem::Imperial, Ordering::Relaxed);
+    }
+
+    pub fn temperature(&self) -> TemperatureUnit {
+        match self.fahrenheit.load(Ordering::Relaxed) {
+            true => TemperatureUnit::Fahrenheit,
+            false => TemperatureUnit::Celsius,
+        }
+    }
+
+    pub fn set_temperature(&self, unit: TemperatureUnit) {
+        self.fahrenheit
+            .store(unit == TemperatureUnit::Fahrenheit, Ordering::Relaxed);
+    }
+
+    /// Whether the quantity is shown in the imperial or Fahrenheit unit
+    fn is_converted(&self, quantity: Quantity) -> bool {
+        match quantity {
+            Quantity::Temperature => self.temperature() == TemperatureUnit::Fahrenheit,
+            _ => self.system() == UnitSystem::Imperial,
+        }
+    }
+
+    /// Convert a value from the metric unit to the unit shown
+    ///
+    /// Arguments
+    ///
+    /// * `quantity`: - What the value measures
+    /// * `value`: - The value in the metric unit, e.g. degree Celsius
+    pub fn convert(&self, quantity: Quantity, value: f32) -> f32 {
+        match (self.is_converted(quantity), quantity) {
+            (false, _) => value,
+            (true, Quantity::Temperature) => value * quantity.factor() + 32.0,
+            (true, _) => value * quantity.factor(),
+        }
+    }
+
+    /// Convert a value from the unit shown back to the metric unit, e.g. a value entered
+    pub fn convert_back(&self, quantity: Quantity, value: f32) -> f32 {
+        match (self.is_converted(quantity), quantity) {
+            (false, _) => value,
+            (true, Quantity::Temperature) => (value - 32.0) / quantity.factor(),
+            (true, _) => value / quantity.factor(),
+        }
+    }
+
+    /// The symbol of the unit shown, e.g. `°C` or `mph`
+    pub fn suffix(&self, quantity: Quantity) -> &'static str {
+        let (metric, imperial) = match quantity {
+            Quantity::Temperature => ("°C", "°F"),
+            Quantity::Length => ("m", "ft"),
+            Quantity::Distance => ("km", "mi"),
+            Quantity::Mass => ("kg", "lb"),
+            Quantity::Volume => ("l", "gal"),
+            Quantity::Pressure => ("bar", "psi"),
+            Quantity::Speed => ("km/h", "mph"),
+        };
+        match self.is_converted(quantity) {
+            true => imperial,
+            false => metric,
+        }
+    }
+
+    /// Format a value in the unit shown followed by its symbol, e.g. `70.7 °F`
+    ///
+    /// Arguments
+    ///
+    /// * `quantity`: - What the value measures
+    /// * `value`: - The value in the metric unit
+    /// * `decimals`: - The digits shown after the decimal point
+    pub fn format(&self, quantity: Quantity, value: f32, decimals: usize) -> String {
+        self.format_with(quantity, value, NumberFormat::new().with_decimals(decimals))
+    }
+
+    /// Format a value in the unit shown by a number format, e.g. aligned to a width
+    ///
+    /// The value is rounded to the decimals of the format.
+    pub fn format_with(&self, quantity: Quantity, value: f32, format: NumberFormat) -> String {
+        let scale = 10f32.powi(format.decimals() as i32);
+        let scaled = (self.convert(quantity, value) * scale).round() as i64;
+        let mut text = String::new();
+        // a number wider than the buffer of the format is left out
+        let _ = format.push_to(scaled, &mut text);
+        text.push(' ');
+        text.push_str(self.suffix(quantity));
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn metric_by_default() {
+    let sut = Units::default();
+    assert_eq!(sut.system(), UnitSystem::Metric);
+    assert_eq!(sut.temperature(), TemperatureUnit::Celsius);
+    assert_eq!(sut.format(Quantity::Temperature, -3.26, 1), "-3.3 °C");
+    assert_eq!(sut.format(Quantity::Pressure, 2.5, 2), "2.50 bar");
+    assert_eq!(sut.convert(Quantity::Mass, 3.0), 3.0);
+}
+
+#[test]
+fn convert_to_imperial_units() {
+    let sut = Units::new();
+    sut.set_system(UnitSystem::Imperial);
+    assert_eq!(sut.format(Quantity::Length, 10.0, 1), "32.8 ft");
+    assert_eq!(sut.format(Quantity::Distance, 42.195, 1), "26.2 mi");
+    assert_eq!(sut.format(Quantity::Mass, 1.0, 2), "2.20 lb");
+    assert_eq!(sut.format(Quantity::Volume, 10.0, 2), "2.64 gal");
+    assert_eq!(sut.format(Quantity::Pressure, 1.0, 1), "14.5 psi");
+    assert_eq!(sut.format(Quantity::Speed, 50.0, 0), "31 mph");
+    // the temperature unit is chosen on its own
+    assert_eq!(sut.format(Quantity::Temperature, 20.0, 0), "20 °C");
+}
+
+#[test]
+fn convert_temperatures() {
+    let sut = Units::new();
+    sut.set_temperature(TemperatureUnit::Fahrenheit);
+    assert_eq!(sut.format(Quantity::Temperature, 100.0, 0), "212 °F");
+    assert_eq!(sut.format(Quantity::Temperature, -40.0, 0), "-40 °F");
+    assert_eq!(sut.suffix(Quantity::Speed), "km/h");
+    assert!((sut.convert_back(Quantity::Temperature, 32.0)).abs() < 1e-4);
+}
+
+#[test]
+fn convert_back_and_forth() {
+    let sut = Units::new();
+    sut.set_system(UnitSystem::Imperial);
+    let psi = sut.convert(Quantity::Pressure, 2.0);
+    assert!((sut.convert_back(Quantity::Pressure, psi) - 2.0).abs() < 1e-4);
+    sut.set_system(UnitSystem::Metric);
+    assert_eq!(sut.convert_back(Quantity::Pressure, 2.0), 2.0);
+}
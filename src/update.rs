@@ -0,0 +1,144 @@
+use arrayvec::ArrayString;
+use core::cell::RefCell;
+use critical_section::Mutex;
+
+/// Maximum length of a stage text in bytes - longer texts are truncated
+pub const STAGE_LEN: usize = 32;
+
+/// The status of a firmware update
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UpdateStatus {
+    /// No update started
+    Idle,
+    /// The update is running - with the progress in percent
+    Running(u8),
+    /// The update is done successfully
+    Completed,
+    /// The update failed
+    Failed,
+}
+
+struct Progress {
+    status: UpdateStatus,
+    stage: ArrayString<STAGE_LEN>,
+}
+
+/// The progress of a firmware update shown by the `UpdatePage`
+///
+/// The bootloader or DFU task reports the progress by this handle, e.g. from
+/// another task or an interrupt service routine. Like the `InteractionQueue`,
+/// the handle is guarded by a critical section and can live in a `static`.
+///
+/// # Example
+///
+/// ```
+///     use embedded_multi_page_hmi::{UpdateProgress, UpdateStatus};
+///     static UPDATE: UpdateProgress = UpdateProgress::new();
+///
+///     // in the DFU task
+///     UPDATE.start("Erasing");
+///     UPDATE.set_stage("Writing");
+///     UPDATE.set_progress(40);
+///     assert_eq!(UPDATE.status(), UpdateStatus::Running(40));
+///     UPDATE.complete();
+/// ```
+pub struct UpdateProgress {
+    progress: Mutex<RefCell<Progress>>,
+}
+
+impl Default for UpdateProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Replace the stage text - truncated to the capacity
+fn replace(stage: &mut ArrayString<STAGE_LEN>, text: &str) {
+    stage.clear();
+    for c in text.chars() {
+        if stage.try_push(c).is_err() {
+            break;
+        }
+    }
+}
+
+impl UpdateProgress {
+    pub const fn new() -> Self {
+        UpdateProgress {
+            progress: Mutex::new(RefCell::new(Progress {
+                status: UpdateStatus::Idle,
+                stage: ArrayString::new_const(),
+            })),
+        }
+    }
+
+    /// Start the update with a progress of zero percent
+    ///
+    /// Arguments
+    ///
+    /// * `stage`: - The text of the first stage, e.g. "Erasing"
+    pub fn start(&self, stage: &str) {
+        self.modify(UpdateStatus::Running(0), Some(stage));
+    }
+
+    /// Set the progress of the running update
+    ///
+    /// Arguments
+    ///
+    /// * `percent`: - The progress, values above 100 are taken as 100
+    pub fn set_progress(&self, percent: u8) {
+        critical_section::with(|cs| {
+            let mut progress = self.progress.borrow_ref_mut(cs);
+            if let UpdateStatus::Running(_) = progress.status {
+                progress.status = UpdateStatus::Running(percent.min(100));
+            }
+        })
+    }
+
+    /// Set the text of the current stage, e.g. "Verifying"
+    pub fn set_stage(&self, stage: &str) {
+        critical_section::with(|cs| replace(&mut self.progress.borrow_ref_mut(cs).stage, stage))
+    }
+
+    /// The update is done successfully
+    pub fn complete(&self) {
+        self.modify(UpdateStatus::Completed, None);
+    }
+
+    /// The update failed
+    ///
+    /// Arguments
+    ///
+    /// * `reason`: - Shown instead of the stage, e.g. "Invalid signature"
+    pub fn fail(&self, reason: &str) {
+        self.modify(UpdateStatus::Failed, Some(reason));
+    }
+
+    /// Forget about the update, e.g. after rebooting without reset
+    pub fn reset(&self) {
+        self.modify(UpdateStatus::Idle, Some(""));
+    }
+
+    /// The status of the update
+    pub fn status(&self) -> UpdateStatus {
+        critical_section::with(|cs| self.progress.borrow_ref(cs).status)
+    }
+
+    /// The text of the current stage or the reason of the failure
+    pub fn stage(&self) -> String {
+        critical_section::with(|cs| String::from(self.progress.borrow_ref(cs).stage.as_str()))
+    }
+
+    fn modify(&self, status: UpdateStatus, stage: Option<&str>) {
+        critical_section::with(|cs| {
+            let mut progress = self.progress.borrow_ref_mut(cs);
+            progress.status = status;
+            if let Some(stage) = stage {
+                replace(&mut progress.stage, stage);
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,41 @@
+use super::*;
+
+#[test]
+fn report_progress() {
+    let sut = UpdateProgress::new();
+    assert_eq!(sut.status(), UpdateStatus::Idle);
+    // no progress without an update running
+    sut.set_progress(20);
+    assert_eq!(sut.status(), UpdateStatus::Idle);
+    sut.start("Erasing");
+    assert_eq!(
+        (sut.status(), sut.stage()),
+        (UpdateStatus::Running(0), String::from("Erasing"))
+    );
+    sut.set_progress(120);
+    sut.set_stage("Writing");
+    assert_eq!(
+        (sut.status(), sut.stage()),
+        (UpdateStatus::Running(100), String::from("Writing"))
+    );
+    sut.complete();
+    assert_eq!(
+        (sut.status(), sut.stage()),
+        (UpdateStatus::Completed, String::from("Writing"))
+    );
+    sut.reset();
+    assert_eq!(
+        (sut.status(), sut.stage()),
+        (UpdateStatus::Idle, String::new())
+    );
+}
+
+#[test]
+fn truncate_stage() {
+    let sut = UpdateProgress::default();
+    sut.start("Erasing");
+    sut.fail("Signatur der Firmware ungültig - bitte erneut versuchen");
+    assert_eq!(sut.status(), UpdateStatus::Failed);
+    assert_eq!(sut.stage(), "Signatur der Firmware ungültig ");
+    assert!(sut.stage().len() <= STAGE_LEN);
+}
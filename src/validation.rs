@@ -0,0 +1,59 @@
+use super::*;
+
+/// Structural problem of a page tree as found by `PageManager::validate()`
+///
+/// A missing home page or cycles of pages are not reported, since the
+/// `PageManager` prevents both by construction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Diagnostic {
+    /// The page has sub pages but is not a menu.
+    /// The sub pages cannot be reached by user interaction.
+    UnreachableSubpages(PageId),
+    /// The page is a menu without sub pages listed, e.g. with hidden sub pages only.
+    /// Any `PageNavigation::NthSubpage` the menu returns is out of range.
+    EmptyMenu(PageId),
+    /// A previous page on the same level has the same title.
+    /// Navigation by path cannot reach this page.
+    DuplicateTitle(PageId),
+}
+
+impl<'a, D, C> PageManager<'a, D, C> {
+    /// Check the page tree for structural problems
+    ///
+    /// Useful in host side tests to check the page tree an application builds
+    /// before flashing it. Sub pages are counted if they are listed at the
+    /// current access level, i.e. hidden sub pages do not make a menu.
+    ///
+    /// Returns
+    ///
+    /// * List of problems found - empty if the page tree is fine.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let pages: Vec<PageTreeItem> = self.tree_iter().collect();
+        let mut diagnostics = Vec::new();
+        for (index, page) in pages.iter().enumerate() {
+            let subpages = pages[index + 1..]
+                .iter()
+                .take_while(|p| p.depth > page.depth)
+                .filter(|p| p.depth == page.depth + 1 && p.is_listed(self.access_level()))
+                .count();
+            if page.is_menu && subpages == 0 {
+                diagnostics.push(Diagnostic::EmptyMenu(page.id));
+            }
+            if !page.is_menu && subpages > 0 {
+                diagnostics.push(Diagnostic::UnreachableSubpages(page.id));
+            }
+            let duplicate = pages[..index]
+                .iter()
+                .rev()
+                .take_while(|p| p.depth >= page.depth)
+                .any(|p| p.depth == page.depth && p.title == page.title);
+            if duplicate {
+                diagnostics.push(Diagnostic::DuplicateTitle(page.id));
+            }
+        }
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+use super::*;
+use crate::page::{BasicPage, MenuPage, TextPage};
+
+struct DisplayStub;
+
+impl PageInterface<DisplayStub> for TextPage {
+    fn display(&self, _display_driver: &mut DisplayStub) {}
+}
+
+impl PageInterface<DisplayStub> for MenuPage<'_> {
+    fn display(&self, _display_driver: &mut DisplayStub) {}
+}
+
+fn text(title: &'static str) -> Box<TextPage> {
+    Box::new(TextPage::new(BasicPage::new(title, None), ""))
+}
+
+fn menu(title: &'static str) -> Box<MenuPage<'static>> {
+    Box::new(MenuPage::new(BasicPage::new(title, None), None))
+}
+
+#[test]
+fn valid_tree() {
+    let mut m = PageManager::new(DisplayStub, menu("Home"));
+    m.register_sub(text("Config-1"));
+    m.register(text("Config-2"));
+    assert_eq!(m.validate(), vec![]);
+}
+
+#[test]
+fn unreachable_subpages() {
+    let mut m = PageManager::new(DisplayStub, text("Home"));
+    m.register_sub(text("Config-1"));
+    assert_eq!(
+        m.validate(),
+        vec![Diagnostic::UnreachableSubpages(PageId(0))]
+    );
+}
+
+#[test]
+fn empty_menu() {
+    let mut m = PageManager::new(DisplayStub, menu("Home"));
+    m.register_sub(menu("Menu"));
+    assert_eq!(m.validate(), vec![Diagnostic::EmptyMenu(PageId(1))]);
+}
+
+#[test]
+fn menu_with_hidden_subpages_only() {
+    let mut m = PageManager::new(DisplayStub, menu("Home"));
+    let debug = m.register_sub(text("Debug"));
+    let service = m.register(text("Service"));
+    m.set_page_hidden(debug, true);
+    m.set_page_access(service, AccessLevel::Service);
+    assert_eq!(m.validate(), vec![Diagnostic::EmptyMenu(PageId(0))]);
+    m.set_access_level(AccessLevel::Service);
+    assert_eq!(m.validate(), vec![]);
+}
+
+#[test]
+fn duplicate_titles() {
+    let mut m = PageManager::new(DisplayStub, menu("Home"));
+    m.register_sub(text("Config"));
+    m.register(text("Config"));
+    let info = m.register(text("Info"));
+    m.dispatch(PageNavigation::Home).unwrap();
+    m.register(text("Config")); // same title on a different level is fine
+    m.register(text("Info"));
+    m.register(text("Info"));
+    assert_eq!(
+        m.validate(),
+        vec![
+            Diagnostic::DuplicateTitle(PageId(2)),
+            Diagnostic::DuplicateTitle(PageId(6))
+        ]
+    );
+    assert_eq!(info, PageId(3));
+}
@@ -0,0 +1,248 @@
+//! VT100 terminal over a serial port as display and keyboard
+//!
+//! Requires the `vt100` feature. Headless boards get a usable HMI over their
+//! debug serial port, e.g. a UART connected to `picocom` or PuTTY, with no
+//! extra hardware. Pages are drawn by ANSI escape sequences to any
+//! `embedded-io` port: the title is shown bold in the first row, the content
+//! below. The selected entry of lists (`> ` marker) and menus (`[ ]`
+//! brackets) is shown in inverse video.
+//!
+//! Keys are mapped like by the host simulator:
+//! * n, right and down arrow - next
+//! * p, left and up arrow - previous
+//! * space, enter - action
+//! * b, backspace - back
+//! * h, home - home
+//! * 0 to 9 - the digit entered directly
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut m = PageManager::new(Vt100Display::new(uart).with_size(40, 12), Box::new(home));
+//! m.set_display_info(&DisplaySize::from_info(m.display()));
+//! let mut navigation = m.dispatch(PageNavigation::SystemStart).unwrap();
+//! loop {
+//!     navigation = match m.display_mut().poll() {
+//!         Ok(Some(input)) => m.dispatch_input(input),
+//!         _ => m.dispatch(navigation),
+//!     }
+//!     .unwrap();
+//! }
+//! ```
+
+use super::{
+    paginated_title, truncate, DisplayInfo, InputEvent, Interaction, PageError, ScrollPosition,
+};
+
+use embedded_io::{Read, ReadReady, Write};
+
+/// Clear the screen and hide the cursor
+const CLEAR: &str = "\x1b[?25l\x1b[2J";
+const BOLD: &str = "\x1b[1m";
+const INVERSE: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+const ESC: u8 = 0x1b;
+
+/// State of the decoder of escape sequences received
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Escape {
+    None,
+    Started,
+    Sequence,
+}
+
+/// A VT100 terminal connected to a serial port
+pub struct Vt100Display<S> {
+    port: S,
+    cols: usize,
+    rows: usize,
+    escape: Escape,
+    last: u8,
+}
+
+impl<S> Vt100Display<S> {
+    /// A terminal of 80 by 24 characters
+    ///
+    /// Arguments
+    ///
+    /// * `port`: - The serial port the terminal is connected to
+    pub fn new(port: S) -> Self {
+        Vt100Display {
+            port,
+            cols: 80,
+            rows: 24,
+            escape: Escape::None,
+            last: 0,
+        }
+    }
+
+    /// Set the size of the terminal window in characters - including the title row
+    pub fn with_size(mut self, cols: usize, rows: usize) -> Self {
+        self.cols = cols;
+        self.rows = rows;
+        self
+    }
+
+    /// The serial port
+    pub fn into_inner(self) -> S {
+        self.port
+    }
+
+    /// Decode a received byte - `None` if the byte has no meaning or is part of a sequence
+    fn decode(&mut self, byte: u8) -> Option<InputEvent> {
+        match (self.escape, byte) {
+            (Escape::None, digit) if digit.is_ascii_digit() => {
+                self.last = digit;
+                Some(InputEvent::Char(digit as char))
+            }
+            _ => self.decode_interaction(byte).map(InputEvent::Primary),
+        }
+    }
+
+    /// Decode a received byte of a primary interaction
+    fn decode_interaction(&mut self, byte: u8) -> Option<Interaction> {
+        let last = core::mem::replace(&mut self.last, byte);
+        match (self.escape, byte) {
+            (Escape::None, ESC) => {
+                self.escape = Escape::Started;
+                None
+            }
+            (Escape::None, b'\r') | (Escape::None, b' ') => Some(Interaction::Action),
+            (Escape::None, b'\n') if last != b'\r' => Some(Interaction::Action),
+            (Escape::None, b'n') => Some(Interaction::Next),
+            (Escape::None, b'p') => Some(Interaction::Previous),
+            (Escape::None, b'b') | (Escape::None, 0x08) | (Escape::None, 0x7f) => {
+                Some(Interaction::Back)
+            }
+            (Escape::None, b'h') => Some(Interaction::Home),
+            (Escape::None, _) => None,
+            // CSI `ESC [` and SS3 `ESC O` - both are sent for cursor keys
+            (Escape::Started, b'[') | (Escape::Started, b'O') => {
+                self.escape = Escape::Sequence;
+                None
+            }
+            (Escape::Started, _) => {
+                self.escape = Escape::None;
+                None
+            }
+            // parameters of the sequence, e.g. `1` of home `ESC [ 1 ~`
+            (Escape::Sequence, b'0'..=b'9') | (Escape::Sequence, b';') => None,
+            (Escape::Sequence, end) => {
+                self.escape = Escape::None;
+                match end {
+                    b'B' | b'C' => Some(Interaction::Next),
+                    b'A' | b'D' => Some(Interaction::Previous),
+                    b'H' => Some(Interaction::Home),
+                    b'~' if last == b'1' || last == b'7' => Some(Interaction::Home),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+impl<S: Read + ReadReady> Vt100Display<S> {
+    /// Receive a key press without blocking
+    ///
+    /// Reads the bytes available at the serial port until a key with a
+    /// meaning is decoded.
+    ///
+    /// Returns
+    ///
+    /// * `Ok(None)` - if no key is pressed
+    /// * `Err(PageError)` - if the serial port fails
+    pub fn poll(&mut self) -> Result<Option<InputEvent>, PageError> {
+        while self.port.read_ready().map_err(|_| PageError)? {
+            let mut byte = [0; 1];
+            if self.port.read(&mut byte).map_err(|_| PageError)? == 0 {
+                break;
+            }
+            if let Some(input) = self.decode(byte[0]) {
+                return Ok(Some(input));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<S: Write> Vt100Display<S> {
+    /// Draw a page
+    ///
+    /// Arguments
+    ///
+    /// * `title`: - The title of the page
+    /// * `message`: - The content; lines are separated by `\n`
+    pub fn draw(&mut self, title: &str, message: &str) -> Result<(), PageError> {
+        let mut frame = String::from(CLEAR);
+        frame.push_str(&format!(
+            "\x1b[1;1H{}{}{}",
+            BOLD,
+            truncate(title, self.cols),
+            RESET
+        ));
+        let rows = self.rows.saturating_sub(1);
+        for (row, line) in message.lines().take(rows).enumerate() {
+            frame.push_str(&format!("\x1b[{};1H", row + 2));
+            let line = truncate(line, self.cols);
+            match selection(&line) {
+                Some((start, end)) => frame.push_str(&format!(
+                    "{}{}{}{}{}",
+                    &line[..start],
+                    INVERSE,
+                    &line[start..end],
+                    RESET,
+                    &line[end..]
+                )),
+                None => frame.push_str(&line),
+            }
+        }
+        self.port
+            .write_all(frame.as_bytes())
+            .map_err(|_| PageError)?;
+        self.port.flush().map_err(|_| PageError)
+    }
+
+    /// Show a page - errors of the serial port are ignored
+    pub fn update(&mut self, title: &str, message: &str) {
+        let _ = self.draw(title, message);
+    }
+
+    /// Show a page scrolling its content - the title is followed by the page shown, e.g. `Info 2/5`
+    pub fn update_scrolled(
+        &mut self,
+        title: &str,
+        message: &str,
+        position: Option<ScrollPosition>,
+    ) {
+        self.update(&paginated_title(title, position), message);
+    }
+}
+
+/// The terminal size without the title row
+impl<S> DisplayInfo for Vt100Display<S> {
+    fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn rows(&self) -> usize {
+        self.rows.saturating_sub(1)
+    }
+}
+
+/// The byte range of the selected entry in a line, if any
+///
+/// Lists mark the selected line by `> `, menus put the selected entry in brackets.
+fn selection(line: &str) -> Option<(usize, usize)> {
+    if line.starts_with("> ") {
+        return Some((0, line.len()));
+    }
+    let start = line.find("[ ")?;
+    let end = start + line[start..].find(" ]")? + 2;
+    Some((start, end))
+}
+
+// ** All pages of this crate render to the terminal
+impl_text_display!(<S: Write> Vt100Display<S>);
+
+#[cfg(test)]
+mod tests;
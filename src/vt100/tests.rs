@@ -0,0 +1,136 @@
+use super::*;
+use crate::page::{BasicPage, MenuPage, TextPage};
+use crate::{DisplaySize, PageManager, PageNavigation};
+use std::collections::VecDeque;
+use std::convert::Infallible;
+
+/// Serial port with received bytes given and sent bytes collected
+#[derive(Default)]
+struct PortMock {
+    received: VecDeque<u8>,
+    sent: Vec<u8>,
+}
+
+impl PortMock {
+    fn receive(&mut self, bytes: &[u8]) {
+        self.received.extend(bytes);
+    }
+
+    fn take_sent(&mut self) -> String {
+        String::from_utf8(std::mem::take(&mut self.sent)).unwrap()
+    }
+}
+
+impl embedded_io::ErrorType for PortMock {
+    type Error = Infallible;
+}
+
+impl Read for PortMock {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        match (buf.first_mut(), self.received.pop_front()) {
+            (Some(first), Some(byte)) => {
+                *first = byte;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+impl ReadReady for PortMock {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.received.is_empty())
+    }
+}
+
+impl Write for PortMock {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        self.sent.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[test]
+fn draw_title_and_lines_truncated() {
+    let mut display = Vt100Display::new(PortMock::default()).with_size(8, 3);
+    assert_eq!(DisplaySize::from_info(&display), DisplaySize::new(8, 2));
+    display.update("Network", "SSID HomeNet\nOnline\nhidden");
+    assert_eq!(
+        display.into_inner().take_sent(),
+        "\x1b[?25l\x1b[2J\x1b[1;1H\x1b[1mNetwork\x1b[0m\
+         \x1b[2;1HSSID ...\x1b[3;1HOnline"
+    );
+}
+
+#[test]
+fn selection_in_inverse_video() {
+    assert_eq!(selection("> Sensors"), Some((0, 9)));
+    assert_eq!(selection("  Sensors"), None);
+    assert_eq!(selection("Info [ Setup ] Exit "), Some((5, 14)));
+    assert_eq!(selection("Info [ Setup"), None);
+
+    let mut display = Vt100Display::new(PortMock::default());
+    display.update("Menu", "  Info\n> Setup");
+    let sent = display.into_inner().take_sent();
+    assert!(sent.ends_with("\x1b[2;1H  Info\x1b[3;1H\x1b[7m> Setup\x1b[0m"));
+}
+
+#[test]
+fn decode_keys_and_escape_sequences() {
+    let mut display = Vt100Display::new(PortMock::default());
+    display
+        .port
+        .receive(b"x\r\n n7\x1b[C\x1b[A\x1bOB\x1b[1~\x1b[H\x7fb\x1b[5~");
+    let mut inputs = Vec::new();
+    while let Some(input) = display.poll().unwrap() {
+        inputs.push(input);
+    }
+    assert_eq!(
+        inputs,
+        vec![
+            InputEvent::Primary(Interaction::Action),
+            InputEvent::Primary(Interaction::Action),
+            InputEvent::Primary(Interaction::Next),
+            InputEvent::Char('7'),
+            InputEvent::Primary(Interaction::Next),
+            InputEvent::Primary(Interaction::Previous),
+            InputEvent::Primary(Interaction::Next),
+            InputEvent::Primary(Interaction::Home),
+            InputEvent::Primary(Interaction::Home),
+            InputEvent::Primary(Interaction::Back),
+            InputEvent::Primary(Interaction::Back),
+        ]
+    );
+    assert!(display.port.received.is_empty());
+}
+
+#[test]
+fn terminal_controlled_page_manager() {
+    let home = MenuPage::new(BasicPage::new("Home", None), None);
+    let display = Vt100Display::new(PortMock::default()).with_size(20, 4);
+    let mut m = PageManager::new(display, Box::new(home));
+    m.register_sub(Box::new(TextPage::new(
+        BasicPage::new("Info", None),
+        "Line 1\nLine 2",
+    )));
+    m.dispatch(PageNavigation::Home).unwrap();
+    assert!(m
+        .display_mut()
+        .port
+        .take_sent()
+        .contains("\x1b[7m[ Info ]\x1b[0m"));
+
+    m.display_mut().port.receive(b"\r");
+    let input = m.display_mut().poll().unwrap().unwrap();
+    m.dispatch_input(input).unwrap();
+    assert_eq!(m.active().title, "Info");
+    assert!(m
+        .display_mut()
+        .port
+        .take_sent()
+        .ends_with("\x1b[2;1HLine 1\x1b[3;1HLine 2"));
+}
@@ -0,0 +1,192 @@
+//! Click through the HMI in a browser
+//!
+//! Requires the `web-sim` feature and the `wasm32-unknown-unknown` target.
+//! All pages of this crate render to the `DomDisplay`. It shows the title and
+//! the content of the active page within a DOM element and adds a button per
+//! interaction below.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use embedded_multi_page_hmi::{
+//!     page::{BasicPage, TextPage},
+//!     web_sim::{self, DomDisplay},
+//!     PageManager,
+//! };
+//! use wasm_bindgen::prelude::*;
+//!
+//! // rendered into <div id="hmi"></div> of the web page
+//! #[wasm_bindgen(start)]
+//! pub fn start() {
+//!     let home = TextPage::new(BasicPage::new("Home", None), "Hello");
+//!     let m = PageManager::new(DomDisplay::new("hmi").unwrap(), Box::new(home));
+//!     web_sim::run(m, 500).unwrap();
+//! }
+//! ```
+
+use super::{paginated_title, Interaction, PageError, PageManager, PageNavigation, ScrollPosition};
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Document, Element};
+
+/// The buttons added below the page and the interactions they trigger
+const BUTTONS: [(&str, Interaction); 5] = [
+    ("Previous", Interaction::Previous),
+    ("Next", Interaction::Next),
+    ("Action", Interaction::Action),
+    ("Back", Interaction::Back),
+    ("Home", Interaction::Home),
+];
+
+/// A DOM element as display
+///
+/// The elements created have the classes `hmi-title`, `hmi-content`,
+/// `hmi-buttons` and `hmi-button` for styling.
+pub struct DomDisplay {
+    title: Element,
+    content: Element,
+    clicked: Rc<RefCell<VecDeque<Interaction>>>,
+}
+
+impl DomDisplay {
+    /// Arguments
+    ///
+    /// * `element_id`: - Id of the DOM element to render into
+    ///
+    /// Returns
+    ///
+    /// * `Err(PageError)` - if there is no such element
+    pub fn new(element_id: &str) -> Result<Self, PageError> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or(PageError)?;
+        let root = document.get_element_by_id(element_id).ok_or(PageError)?;
+        let title = append(&document, &root, "div", "hmi-title")?;
+        let content = append(&document, &root, "pre", "hmi-content")?;
+        let buttons = append(&document, &root, "div", "hmi-buttons")?;
+
+        let clicked = Rc::new(RefCell::new(VecDeque::new()));
+        for (label, interaction) in BUTTONS {
+            let button = append(&document, &buttons, "button", "hmi-button")?;
+            button.set_text_content(Some(label));
+            let clicked = Rc::clone(&clicked);
+            let callback = Closure::<dyn FnMut()>::new(move || {
+                clicked.borrow_mut().push_back(interaction);
+            });
+            button
+                .add_event_listener_with_callback("click", callback.as_ref().unchecked_ref())
+                .map_err(|_| PageError)?;
+            // the callback lives as long as the button
+            callback.forget();
+        }
+        Ok(DomDisplay {
+            title,
+            content,
+            clicked,
+        })
+    }
+
+    /// Show a page
+    ///
+    /// Arguments
+    ///
+    /// * `title`: - The title of the page
+    /// * `message`: - The content; lines are separated by `\n`
+    pub fn update(&mut self, title: &str, message: &str) {
+        self.title.set_text_content(Some(title));
+        self.content.set_text_content(Some(message));
+    }
+
+    /// Show a page scrolling its content - the title is followed by the page shown, e.g. `Info 2/5`
+    pub fn update_scrolled(
+        &mut self,
+        title: &str,
+        message: &str,
+        position: Option<ScrollPosition>,
+    ) {
+        self.update(&paginated_title(title, position), message);
+    }
+
+    /// The interactions of clicked buttons
+    pub fn input(&self) -> DomInput {
+        DomInput(Rc::clone(&self.clicked))
+    }
+}
+
+fn append(
+    document: &Document,
+    parent: &Element,
+    tag: &str,
+    class: &str,
+) -> Result<Element, PageError> {
+    let element = document.create_element(tag).map_err(|_| PageError)?;
+    element.set_class_name(class);
+    parent.append_child(&element).map_err(|_| PageError)?;
+    Ok(element)
+}
+
+/// Input of the interactions of clicked buttons
+///
+/// Returns `None` if no button was clicked since the last call.
+pub struct DomInput(Rc<RefCell<VecDeque<Interaction>>>);
+
+impl Iterator for DomInput {
+    type Item = Interaction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.borrow_mut().pop_front()
+    }
+}
+
+/// Run the HMI in the browser
+///
+/// Starts the page manager by `PageNavigation::SystemStart` and dispatches
+/// the interactions of clicked buttons every `period_ms` milliseconds.
+/// Without input the navigation returned by the last dispatch is dispatched.
+/// The HMI stops after the shutdown or if a dispatch fails.
+///
+/// Arguments
+///
+/// * `manager`: - The page manager rendering to the DOM
+/// * `period_ms`: - The time between updates of the active page
+pub fn run<C: 'static>(
+    mut manager: PageManager<'static, DomDisplay, C>,
+    period_ms: i32,
+) -> Result<(), PageError> {
+    let window = web_sys::window().ok_or(PageError)?;
+    let mut input = manager.display().input();
+    let mut navigation = manager.dispatch(PageNavigation::SystemStart)?;
+
+    let interval = Rc::new(Cell::new(None));
+    let handle = Rc::clone(&interval);
+    let tick = Closure::<dyn FnMut()>::new(move || {
+        let result = match input.next() {
+            None => manager.dispatch(navigation),
+            Some(interaction) => manager.dispatch_interaction(interaction),
+        };
+        match result {
+            Ok(next) if next != PageNavigation::Terminate => navigation = next,
+            _ => {
+                if let (Some(window), Some(handle)) = (web_sys::window(), handle.get()) {
+                    window.clear_interval_with_handle(handle);
+                }
+            }
+        }
+    });
+    let id = window
+        .set_interval_with_callback_and_timeout_and_arguments_0(
+            tick.as_ref().unchecked_ref(),
+            period_ms,
+        )
+        .map_err(|_| PageError)?;
+    interval.set(Some(id));
+    // the page manager lives as long as the web page
+    tick.forget();
+    Ok(())
+}
+
+// ** All pages of this crate render to the DOM
+impl_text_display!(DomDisplay);